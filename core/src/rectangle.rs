@@ -74,6 +74,11 @@ impl Rectangle<f32> {
             && point.y <= self.y + self.height
     }
 
+    /// Returns true if the [`Rectangle`] overlaps the given one.
+    pub fn intersects(&self, other: &Rectangle<f32>) -> bool {
+        self.intersection(other).is_some()
+    }
+
     /// Computes the intersection with the given [`Rectangle`].
     pub fn intersection(
         &self,
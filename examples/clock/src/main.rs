@@ -1,5 +1,8 @@
 use iced::{
-    canvas::{self, Cache, Canvas, Cursor, Geometry, LineCap, Path, Stroke},
+    alignment,
+    canvas::{
+        self, Cache, Canvas, Cursor, Geometry, LineCap, Path, Rotation, Stroke,
+    },
     executor, Application, Color, Command, Container, Element, Length, Point,
     Rectangle, Settings, Subscription, Vector,
 };
@@ -105,6 +108,25 @@ impl<Message> canvas::Program<Message> for Clock {
 
             frame.translate(Vector::new(center.x, center.y));
 
+            // Every label faces the center of the clock
+            for (label, position, rotation) in [
+                ("XII", Point::new(0.0, -0.95 * radius), Rotation::None),
+                ("III", Point::new(0.95 * radius, 0.0), Rotation::Degrees90),
+                ("VI", Point::new(0.0, 0.95 * radius), Rotation::Degrees180),
+                ("IX", Point::new(-0.95 * radius, 0.0), Rotation::Degrees270),
+            ] {
+                frame.fill_text(canvas::Text {
+                    content: label.to_string(),
+                    position,
+                    color: Color::WHITE,
+                    size: radius / 8.0,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Top,
+                    rotation,
+                    ..canvas::Text::default()
+                });
+            }
+
             frame.with_save(|frame| {
                 frame.rotate(hand_rotation(self.now.hour(), 12));
                 frame.stroke(&short_hand, wide_stroke);
@@ -0,0 +1,137 @@
+use iced::{
+    button, executor, window, Alignment, Application, Button, Column, Command,
+    Container, Element, Length, Point, Row, Settings, Space, Subscription,
+    Text,
+};
+use iced_native::{event, mouse, Event};
+
+/// The height of the title bar, in logical pixels.
+const TITLE_BAR_HEIGHT: f32 = 40.0;
+
+pub fn main() -> iced::Result {
+    FramelessWindow::run(Settings {
+        window: window::Settings {
+            decorations: false,
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+#[derive(Default)]
+struct FramelessWindow {
+    cursor_position: Point,
+    is_maximized: bool,
+    should_exit: bool,
+    minimize: button::State,
+    maximize: button::State,
+    close: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    CursorMoved(Point),
+    TitleBarPressed,
+    Maximized(bool),
+    Minimize,
+    ToggleMaximize,
+    Close,
+}
+
+impl Application for FramelessWindow {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Frameless window - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::CursorMoved(position) => {
+                self.cursor_position = position;
+            }
+            Message::TitleBarPressed => {
+                // The press is still going on, so the platform lets the
+                // window follow the mouse until it is released
+                if self.cursor_position.y < TITLE_BAR_HEIGHT {
+                    return window::drag();
+                }
+            }
+            Message::Maximized(is_maximized) => {
+                self.is_maximized = is_maximized;
+            }
+            Message::Minimize => {
+                return window::minimize(true);
+            }
+            Message::ToggleMaximize => {
+                return window::maximize(!self.is_maximized);
+            }
+            Message::Close => {
+                self.should_exit = true;
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        iced_native::subscription::events_with(|event, status| match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Some(Message::CursorMoved(position))
+            }
+            // Presses captured by the buttons of the title bar are ignored
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if status == event::Status::Ignored =>
+            {
+                Some(Message::TitleBarPressed)
+            }
+            Event::Window(iced_native::window::Event::Maximized(
+                is_maximized,
+            )) => Some(Message::Maximized(is_maximized)),
+            _ => None,
+        })
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let maximize_label = if self.is_maximized { "Restore" } else { "[ ]" };
+
+        let title_bar = Row::new()
+            .height(Length::Units(TITLE_BAR_HEIGHT as u16))
+            .padding([0, 10])
+            .spacing(5)
+            .align_items(Alignment::Center)
+            .push(Text::new(self.title()).size(20))
+            .push(Space::with_width(Length::Fill))
+            .push(
+                Button::new(&mut self.minimize, Text::new("_"))
+                    .on_press(Message::Minimize),
+            )
+            .push(
+                Button::new(&mut self.maximize, Text::new(maximize_label))
+                    .on_press(Message::ToggleMaximize),
+            )
+            .push(
+                Button::new(&mut self.close, Text::new("X"))
+                    .on_press(Message::Close),
+            );
+
+        let content =
+            Container::new(Text::new("Drag the title bar to move the window"))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y();
+
+        Column::new().push(title_bar).push(content).into()
+    }
+}
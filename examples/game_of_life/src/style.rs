@@ -157,6 +157,14 @@ impl pick_list::StyleSheet for PickList {
             }
             .into(),
             selected_text_color: Color::WHITE,
+            disabled_text_color: Color {
+                a: 0.5,
+                ..Color::WHITE
+            },
+            separator_color: Color {
+                a: 0.7,
+                ..Color::BLACK
+            },
         }
     }
 
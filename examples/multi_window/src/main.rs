@@ -0,0 +1,146 @@
+use iced::multi_window::Application;
+use iced::{
+    alignment, button, executor, window, Alignment, Button, Column, Command,
+    Container, Element, Length, Settings, Subscription, Text,
+};
+use iced_native::{event, subscription, Event};
+
+use std::collections::BTreeMap;
+
+pub fn main() -> iced::Result {
+    MultiWindow::run(Settings::default())
+}
+
+#[derive(Debug, Default)]
+struct MultiWindow {
+    open: button::State,
+    windows: BTreeMap<window::Id, Secondary>,
+    opened: usize,
+    closed: usize,
+}
+
+#[derive(Debug)]
+struct Secondary {
+    number: usize,
+    close: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Open,
+    Close(window::Id),
+    Closed(window::Id),
+}
+
+impl Application for MultiWindow {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (MultiWindow, Command<Message>) {
+        (MultiWindow::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        match self.windows.get(&window) {
+            Some(secondary) => format!("Window {} - Iced", secondary.number),
+            None => String::from("Multi-window - Iced"),
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Open => {
+                let id = window::Id::unique();
+
+                self.opened += 1;
+
+                let _ = self.windows.insert(
+                    id,
+                    Secondary {
+                        number: self.opened,
+                        close: button::State::new(),
+                    },
+                );
+
+                window::spawn(
+                    id,
+                    window::Settings {
+                        size: (400, 200),
+                        ..window::Settings::default()
+                    },
+                )
+            }
+            Message::Close(id) => window::target(id, window::close()),
+            Message::Closed(id) => {
+                if self.windows.remove(&id).is_some() {
+                    self.closed += 1;
+                }
+
+                Command::none()
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_by_window_with(|id, event, status| {
+            match (event, status) {
+                (
+                    Event::Window(window::Event::Closed),
+                    event::Status::Ignored,
+                ) => Some(Message::Closed(id)),
+                _ => None,
+            }
+        })
+    }
+
+    fn view(&mut self, window: window::Id) -> Element<Message> {
+        let open = self.windows.len();
+
+        let content = match self.windows.get_mut(&window) {
+            Some(secondary) => Column::new()
+                .align_items(Alignment::Center)
+                .spacing(20)
+                .push(
+                    Text::new(format!("This is window {}", secondary.number))
+                        .size(30),
+                )
+                .push(
+                    labeled_button(&mut secondary.close, "Close")
+                        .on_press(Message::Close(window)),
+                ),
+            None => Column::new()
+                .align_items(Alignment::Center)
+                .spacing(20)
+                .push(Text::new(format!(
+                    "{} windows open, {} closed",
+                    open, self.closed
+                )))
+                .push(
+                    labeled_button(&mut self.open, "Open a window")
+                        .on_press(Message::Open),
+                ),
+        };
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+fn labeled_button<'a>(
+    state: &'a mut button::State,
+    label: &str,
+) -> Button<'a, Message> {
+    Button::new(
+        state,
+        Text::new(label)
+            .width(Length::Fill)
+            .horizontal_alignment(alignment::Horizontal::Center),
+    )
+    .width(Length::Units(160))
+    .padding(10)
+}
@@ -92,8 +92,8 @@ impl Sandbox for ScrollableDemo {
                             .spacing(10)
                             .width(Length::Fill)
                             .height(Length::Fill)
-                            .on_scroll(move |offset| {
-                                Message::Scrolled(i, offset)
+                            .on_scroll(move |viewport| {
+                                Message::Scrolled(i, viewport.relative_offset())
                             })
                             .style(*theme)
                             .push(Text::new(variant.title))
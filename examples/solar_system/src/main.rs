@@ -164,7 +164,7 @@ impl<Message> canvas::Program<Message> for State {
                     width: 1.0,
                     color: Color::from_rgba8(0, 153, 255, 0.1),
                     line_dash: canvas::LineDash {
-                        offset: 0,
+                        offset: 0.0,
                         segments: &[3.0, 6.0],
                     },
                     ..Stroke::default()
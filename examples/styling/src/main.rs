@@ -364,6 +364,10 @@ mod style {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    icon_color: Color::WHITE,
+                    placeholder_color: Color::from_rgb(0.4, 0.4, 0.4),
+                    value_color: Color::WHITE,
+                    selection_color: ACTIVE,
                 }
             }
 
@@ -382,18 +386,6 @@ mod style {
                     ..self.focused()
                 }
             }
-
-            fn placeholder_color(&self) -> Color {
-                Color::from_rgb(0.4, 0.4, 0.4)
-            }
-
-            fn value_color(&self) -> Color {
-                Color::WHITE
-            }
-
-            fn selection_color(&self) -> Color {
-                ACTIVE
-            }
         }
 
         pub struct Button;
@@ -0,0 +1,115 @@
+use iced::{
+    button, container, executor, window, Alignment, Application, Button, Color,
+    Command, Container, Element, Length, Row, Settings, Space, Subscription,
+    Text,
+};
+use iced_native::{event, mouse, Event};
+
+/// The height of the pill, in logical pixels.
+const HEIGHT: u32 = 64;
+
+pub fn main() -> iced::Result {
+    TransparentWindow::run(Settings {
+        window: window::Settings {
+            size: (320, HEIGHT),
+            resizable: false,
+            decorations: false,
+            transparent: true,
+            always_on_top: true,
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+#[derive(Default)]
+struct TransparentWindow {
+    should_exit: bool,
+    close: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Pressed,
+    Close,
+}
+
+impl Application for TransparentWindow {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Transparent window - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Pressed => {
+                return window::drag();
+            }
+            Message::Close => {
+                self.should_exit = true;
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        iced_native::subscription::events_with(|event, status| match event {
+            // The pill is moved from anywhere but the close button
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if status == event::Status::Ignored =>
+            {
+                Some(Message::Pressed)
+            }
+            _ => None,
+        })
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+
+    fn background_color(&self) -> Color {
+        Color::TRANSPARENT
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let content = Row::new()
+            .padding([0, 16, 0, 24])
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new("Drag me around").size(24))
+            .push(Space::with_width(Length::Fill))
+            .push(
+                Button::new(&mut self.close, Text::new("X"))
+                    .on_press(Message::Close),
+            );
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .style(Pill)
+            .into()
+    }
+}
+
+struct Pill;
+
+impl container::StyleSheet for Pill {
+    fn style(&self) -> container::Style {
+        container::Style {
+            background: Color::from_rgba8(0x36, 0x39, 0x3F, 0.9).into(),
+            text_color: Color::WHITE.into(),
+            border_radius: HEIGHT as f32 / 2.0,
+            ..container::Style::default()
+        }
+    }
+}
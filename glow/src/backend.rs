@@ -28,6 +28,7 @@ impl Backend {
         let text_pipeline = text::Pipeline::new(
             gl,
             settings.default_font,
+            settings.fallback_fonts,
             settings.text_multithreading,
         );
 
@@ -113,10 +114,12 @@ impl Backend {
             );
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
+            let mut rotated = Vec::new();
+
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = glow_glyph::Section {
+                let section = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -150,49 +153,112 @@ impl Backend {
                             z: 0.0,
                         },
                     }],
-                    layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                glow_glyph::HorizontalAlign::Right
-                            }
+                    ..Default::default()
+                };
+
+                let layout = text::Spaced::new(
+                    section_layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
+                    text.spacing,
+                    text.size,
+                    scale_factor,
+                );
+
+                // Rotated text is drawn on its own, turned around its
+                // position
+                if text.rotation != 0.0 {
+                    rotated.push((section, layout, text.rotation));
+                    continue;
+                }
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
+            }
+
+            for text in layer.rich_text.iter() {
+                let section = glow_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| glow_glyph::Text {
+                            text: span.content,
+                            scale: glow_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(span.font),
+                            extra: glow_glyph::Extra {
+                                color: span.color,
+                                z: 0.0,
+                            },
                         })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let layout = text::Spaced::from(section_layout(
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                ));
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
             }
 
-            self.text_pipeline.draw_queued(
-                gl,
-                transformation,
-                glow_glyph::Region {
-                    x: bounds.x,
-                    y: target_height - (bounds.y + bounds.height),
-                    width: bounds.width,
-                    height: bounds.height,
-                },
-            );
+            let region = || glow_glyph::Region {
+                x: bounds.x,
+                y: target_height - (bounds.y + bounds.height),
+                width: bounds.width,
+                height: bounds.height,
+            };
+
+            self.text_pipeline.draw_queued(gl, transformation, region());
+
+            for (section, layout, rotation) in rotated {
+                let (x, y) = section.screen_position;
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
+
+                self.text_pipeline.draw_queued(
+                    gl,
+                    transformation
+                        * Transformation::translate(x, y)
+                        * Transformation::rotate(rotation)
+                        * Transformation::translate(-x, -y),
+                    region(),
+                );
+            }
         }
     }
 }
 
+fn section_layout(
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> glow_glyph::Layout<glow_glyph::BuiltInLineBreaker> {
+    glow_glyph::Layout::default()
+        .h_align(match horizontal_alignment {
+            alignment::Horizontal::Left => glow_glyph::HorizontalAlign::Left,
+            alignment::Horizontal::Center => {
+                glow_glyph::HorizontalAlign::Center
+            }
+            alignment::Horizontal::Right => glow_glyph::HorizontalAlign::Right,
+        })
+        .v_align(match vertical_alignment {
+            alignment::Vertical::Top => glow_glyph::VerticalAlign::Top,
+            alignment::Vertical::Center => glow_glyph::VerticalAlign::Center,
+            alignment::Vertical::Bottom => glow_glyph::VerticalAlign::Bottom,
+        })
+}
+
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
@@ -214,8 +280,10 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: text::Spacing,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .measure(contents, size, font, bounds, spacing)
     }
 
     fn hit_test(
@@ -226,6 +294,7 @@ impl backend::Text for Backend {
         bounds: Size,
         point: iced_native::Point,
         nearest_only: bool,
+        spacing: text::Spacing,
     ) -> Option<text::Hit> {
         self.text_pipeline.hit_test(
             contents,
@@ -234,8 +303,41 @@ impl backend::Text for Backend {
             bounds,
             point,
             nearest_only,
+            spacing,
         )
     }
+
+    fn glyphs(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: text::Spacing,
+    ) -> Vec<text::Glyph> {
+        self.text_pipeline
+            .glyphs(contents, size, font, bounds, spacing)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn span_glyphs(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<text::Glyph> {
+        self.text_pipeline.span_glyphs(spans, bounds)
+    }
+
+    fn measurer(&self) -> Box<dyn backend::Measurer> {
+        Box::new(self.text_pipeline.measurer())
+    }
 }
 
 #[cfg(feature = "image")]
@@ -87,8 +87,9 @@ impl iced_graphics::window::GLCompositor for Compositor {
 
         let [r, g, b, a] = color.into_linear();
 
+        // Transparent windows are composited with premultiplied alpha
         unsafe {
-            gl.clear_color(r, g, b, a);
+            gl.clear_color(r * a, g * a, b * a, a);
             gl.clear(glow::COLOR_BUFFER_BIT);
         }
 
@@ -96,4 +97,32 @@ impl iced_graphics::window::GLCompositor for Compositor {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot(&mut self, viewport: &Viewport) -> Vec<u8> {
+        let physical_size = viewport.physical_size();
+        let bytes_per_row = physical_size.width as usize * 4;
+
+        let mut pixels = vec![0; bytes_per_row * physical_size.height as usize];
+
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                physical_size.width as i32,
+                physical_size.height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // OpenGL reads the rows starting from the bottom-left corner
+        let mut rgba = Vec::with_capacity(pixels.len());
+
+        for row in pixels.chunks_exact(bytes_per_row.max(1)).rev() {
+            rgba.extend_from_slice(row);
+        }
+
+        rgba
+    }
 }
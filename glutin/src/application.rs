@@ -1,10 +1,11 @@
 //! Create interactive, native cross-platform applications.
 use crate::mouse;
-use crate::{Error, Executor, Runtime};
+use crate::{Command, Error, Executor, Runtime};
 
 pub use iced_winit::Application;
 
 use iced_graphics::window;
+use iced_native::time::Instant;
 use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
@@ -36,7 +37,7 @@ where
     debug.startup_started();
 
     let mut event_loop = EventLoop::with_user_event();
-    let mut proxy = event_loop.create_proxy();
+    let proxy = event_loop.create_proxy();
 
     let mut runtime = {
         let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
@@ -51,9 +52,18 @@ where
         runtime.enter(|| A::new(flags))
     };
 
-    let subscription = application.subscription();
+    // The runtime keeps the size constraints of the window from now on
+    let init_command = Command::batch([
+        iced_winit::window::set_resize_increments(
+            settings.window.resize_increments,
+        ),
+        iced_winit::window::set_aspect_ratio(settings.window.aspect_ratio),
+        init_command,
+    ]);
 
     let context = {
+        let is_transparent = settings.window.transparent;
+
         let builder = settings.window.into_builder(
             &application.title(),
             application.mode(),
@@ -63,8 +73,10 @@ where
 
         log::info!("Window builder: {:#?}", builder);
 
+        // Transparent windows need an alpha channel to be composited
         let opengl_builder = ContextBuilder::new()
             .with_vsync(true)
+            .with_pixel_format(24, 8)
             .with_multisampling(C::sample_count(&compositor_settings) as u16);
 
         let opengles_builder = opengl_builder.clone().with_gl(
@@ -77,13 +89,33 @@ where
             (opengl_builder, opengles_builder)
         };
 
-        log::info!("Trying first builder: {:#?}", first_builder);
+        let build = |builder: glutin::window::WindowBuilder| {
+            log::info!("Trying first builder: {:#?}", first_builder);
+
+            first_builder
+                .clone()
+                .build_windowed(builder.clone(), &event_loop)
+                .or_else(|_| {
+                    log::info!("Trying second builder: {:#?}", second_builder);
+                    second_builder.clone().build_windowed(builder, &event_loop)
+                })
+        };
+
+        let context = build(builder.clone())
+            .or_else(|error| {
+                if !is_transparent {
+                    return Err(error);
+                }
+
+                // Platforms without compositing may not offer a pixel
+                // format with alpha for the window, so it becomes opaque
+                log::warn!(
+                    "Transparent window creation failed ({}), retrying with \
+                    an opaque window",
+                    error
+                );
 
-        let context = first_builder
-            .build_windowed(builder.clone(), &event_loop)
-            .or_else(|_| {
-                log::info!("Trying second builder: {:#?}", second_builder);
-                second_builder.build_windowed(builder, &event_loop)
+                build(builder.with_transparent(false))
             })
             .map_err(|error| {
                 use glutin::CreationError;
@@ -122,19 +154,10 @@ where
         })?
     };
 
-    let mut clipboard = Clipboard::connect(context.window());
-
-    application::run_command(
-        init_command,
-        &mut runtime,
-        &mut clipboard,
-        &mut proxy,
-        context.window(),
-        || compositor.fetch_information(),
-    );
-    runtime.track(subscription);
+    let clipboard = Clipboard::connect(context.window());
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -145,6 +168,8 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
+        init_command,
         context,
         settings.exit_on_close_request,
     ));
@@ -178,9 +203,15 @@ where
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
+            match poll {
+                task::Poll::Pending => {
+                    while let Ok(Some(flow)) = control_receiver.try_next() {
+                        *control_flow = flow;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    *control_flow = ControlFlow::Exit;
+                }
             };
         }
     });
@@ -197,6 +228,8 @@ async fn run_instance<A, E, C>(
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<glutin::event_loop::ControlFlow>,
+    init_command: Command<A::Message>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     exit_on_close_request: bool,
 ) where
@@ -209,10 +242,28 @@ async fn run_instance<A, E, C>(
 
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
+
+    let mut cache = user_interface::Cache::default();
+
+    application::run_command(
+        &mut application,
+        &mut cache,
+        &mut state,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &mut debug,
+        context.window(),
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
     let mut user_interface =
         ManuallyDrop::new(application::build_user_interface(
             &mut application,
-            user_interface::Cache::default(),
+            cache,
             &mut renderer,
             state.logical_size(),
             &mut debug,
@@ -221,6 +272,10 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut redraw_request = None;
+
+    let _ =
+        control_sender.start_send(application::control_flow(redraw_request));
 
     debug.startup_finished();
 
@@ -243,8 +298,27 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                if let Some(position) = user_interface.ime_position() {
+                    context.window().set_ime_position(
+                        conversion::ime_position(
+                            position,
+                            state.scale_factor(),
+                        ),
+                    );
+                }
+
+                redraw_request = user_interface.redraw_request();
+                let _ = control_sender
+                    .start_send(application::control_flow(redraw_request));
+
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    runtime.broadcast((
+                        iced_native::window::Id::MAIN,
+                        event,
+                        status,
+                    ));
                 }
 
                 if !messages.is_empty()
@@ -253,12 +327,15 @@ async fn run_instance<A, E, C>(
                         user_interface::State::Outdated
                     )
                 {
-                    let cache =
+                    let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
                     application::update(
                         &mut application,
+                        &mut cache,
+                        &mut state,
+                        &mut renderer,
                         &mut runtime,
                         &mut clipboard,
                         &mut proxy,
@@ -271,7 +348,8 @@ async fn run_instance<A, E, C>(
                     // Update window
                     state.synchronize(&application, context.window());
 
-                    let should_exit = application.should_exit();
+                    let should_exit =
+                        application.should_exit() || state.should_close();
 
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
@@ -302,6 +380,19 @@ async fn run_instance<A, E, C>(
 
                 context.window().request_redraw();
             }
+            event::Event::NewEvents(_) => {
+                if let Some(at) = redraw_request {
+                    let now = Instant::now();
+
+                    if now >= at {
+                        redraw_request = None;
+
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::RedrawRequested(now),
+                        ));
+                    }
+                }
+            }
             event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
                 event::MacOS::ReceivedUrl(url),
             )) => {
@@ -372,21 +463,43 @@ async fn run_instance<A, E, C>(
                     &debug.overlay(),
                 );
 
+                if state.is_screenshot_requested() {
+                    let physical_size = state.physical_size();
+                    let rgba = compositor.screenshot(state.viewport());
+
+                    state.resolve_screenshot_requests(
+                        iced_native::window::Screenshot::new(
+                            rgba,
+                            physical_size.width,
+                            physical_size.height,
+                        ),
+                    );
+                }
+
                 context.swap_buffers().expect("Swap buffers");
 
                 debug.render_finished();
-
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
             }
             event::Event::WindowEvent {
                 event: window_event,
                 ..
             } => {
                 if application::requests_exit(&window_event, state.modifiers())
-                    && exit_on_close_request
                 {
-                    break;
+                    if exit_on_close_request {
+                        break;
+                    }
+
+                    // Any other way to request closing reaches the application
+                    // like the close button of the window
+                    if !matches!(
+                        window_event,
+                        glutin::event::WindowEvent::CloseRequested
+                    ) {
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::CloseRequested,
+                        ));
+                    }
                 }
 
                 state.update(context.window(), &window_event, &mut debug);
@@ -398,6 +511,16 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let glutin::event::WindowEvent::Resized(_) = window_event {
+                    if let Some(is_maximized) =
+                        state.update_maximized(context.window())
+                    {
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::Maximized(is_maximized),
+                        ));
+                    }
+                }
             }
             _ => {}
         }
@@ -43,6 +43,7 @@ pub trait Text {
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: text::Spacing,
     ) -> (f32, f32);
 
     /// Tests whether the provided point is within the boundaries of [`Text`]
@@ -60,13 +61,70 @@ pub trait Text {
         bounds: Size,
         point: Point,
         nearest_only: bool,
+        spacing: text::Spacing,
     ) -> Option<text::Hit>;
+
+    /// Lays out [`Text`] with the given parameters and returns the glyph of
+    /// every visible character, in order.
+    fn glyphs(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: text::Spacing,
+    ) -> Vec<text::Glyph>;
+
+    /// Measures the given spans laid out as a single paragraph that fits in
+    /// the provided bounds.
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Lays out the given spans as a single paragraph and returns the glyph
+    /// of every visible character, in order.
+    fn span_glyphs(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<text::Glyph>;
+
+    /// Returns a [`Measurer`] that measures text exactly like the backend,
+    /// but without borrowing it.
+    fn measurer(&self) -> Box<dyn Measurer>;
+}
+
+/// A measurer of text that lays it out like the [`Text`] backend that
+/// created it.
+///
+/// It knows about the fonts of the backend at the moment it was created.
+pub trait Measurer {
+    /// Measures the text contents with the given size and font, like
+    /// [`Text::measure`].
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: text::Spacing,
+    ) -> (f32, f32);
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the [`Animation`] of the provided image, if it has more than
+    /// one frame.
+    ///
+    /// [`Animation`]: image::Animation
+    fn animation(&self, _handle: &image::Handle) -> Option<image::Animation> {
+        None
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
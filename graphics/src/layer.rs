@@ -7,6 +7,7 @@ use crate::{
 
 use iced_native::image;
 use iced_native::svg;
+use iced_native::text;
 
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
@@ -23,6 +24,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 }
@@ -35,6 +39,7 @@ impl<'a> Layer<'a> {
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -58,6 +63,8 @@ impl<'a> Layer<'a> {
                 font: Font::Default,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                spacing: text::Spacing::default(),
+                rotation: 0.0,
             };
 
             overlay.text.push(text);
@@ -122,6 +129,8 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                spacing,
+                rotation,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -133,6 +142,31 @@ impl<'a> Layer<'a> {
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    spacing: *spacing,
+                    rotation: *rotation,
+                });
+            }
+            Primitive::RichText {
+                spans,
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                layer.rich_text.push(RichText {
+                    spans: spans
+                        .iter()
+                        .map(|span| Span {
+                            content: &span.content,
+                            color: span.color.into_linear(),
+                            size: span.size,
+                            font: span.font,
+                        })
+                        .collect(),
+                    bounds: *bounds + translation,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
                 });
             }
             Primitive::Quad {
@@ -214,19 +248,37 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                frame,
+                filter_method,
+                opacity,
+                region,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    frame: *frame,
+                    filter_method: *filter_method,
+                    opacity: *opacity,
+                    region: *region,
                     bounds: *bounds + translation,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Svg {
+                handle,
+                tint,
+                opacity,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
+                    tint: *tint,
+                    opacity: *opacity,
                     bounds: *bounds + translation,
                 });
             }
@@ -295,6 +347,45 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The line height and letter spacing of the [`Text`].
+    pub spacing: text::Spacing,
+
+    /// The clockwise rotation of the [`Text`] around the position of its
+    /// bounds, in radians.
+    pub rotation: f32,
+}
+
+/// A paragraph of differently styled text spans.
+#[derive(Debug, Clone)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    pub spans: Vec<Span<'a>>,
+
+    /// The layout bounds of the [`RichText`].
+    pub bounds: Rectangle,
+
+    /// The horizontal alignment of the [`RichText`].
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the [`RichText`].
+    pub vertical_alignment: alignment::Vertical,
+}
+
+/// A run of text with its own style, part of a [`RichText`].
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The color of the [`Span`], in __linear RGB_.
+    pub color: [f32; 4],
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The font of the [`Span`].
+    pub font: Font,
 }
 
 /// A raster or vector image.
@@ -305,6 +396,19 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The frame of the raster image, if it is animated.
+        frame: usize,
+
+        /// The filter method of the raster image.
+        filter_method: image::FilterMethod,
+
+        /// The opacity of the raster image, from `0.0` to `1.0`.
+        opacity: f32,
+
+        /// The region of the raster image that is drawn, in pixels, or
+        /// `None` to draw all of it.
+        region: Option<Rectangle>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -313,6 +417,12 @@ pub enum Image {
         /// The handle of a vector image.
         handle: svg::Handle,
 
+        /// The tint of the vector image, if any.
+        tint: Option<svg::Tint>,
+
+        /// The opacity of the vector image, from `0.0` to `1.0`.
+        opacity: f32,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
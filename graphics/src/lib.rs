@@ -24,6 +24,7 @@ pub mod font;
 pub mod layer;
 pub mod overlay;
 pub mod renderer;
+pub mod text;
 pub mod triangle;
 pub mod widget;
 pub mod window;
@@ -35,7 +36,7 @@ pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use error::Error;
 pub use layer::Layer;
-pub use primitive::Primitive;
+pub use primitive::{Primitive, TextSpan};
 pub use renderer::Renderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
@@ -1,5 +1,6 @@
 use iced_native::image;
 use iced_native::svg;
+use iced_native::text;
 use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
 
 use crate::alignment;
@@ -33,6 +34,22 @@ pub enum Primitive {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The line height and letter spacing of the text
+        spacing: text::Spacing,
+        /// The clockwise rotation of the text around the position of its
+        /// bounds, in radians
+        rotation: f32,
+    },
+    /// A paragraph of differently styled text spans
+    RichText {
+        /// The spans of the paragraph
+        spans: Vec<TextSpan>,
+        /// The bounds of the paragraph
+        bounds: Rectangle,
+        /// The horizontal alignment of the paragraph
+        horizontal_alignment: alignment::Horizontal,
+        /// The vertical alignment of the paragraph
+        vertical_alignment: alignment::Vertical,
     },
     /// A quad primitive
     Quad {
@@ -51,6 +68,15 @@ pub enum Primitive {
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The frame of the image, if it is animated
+        frame: usize,
+        /// The filter method of the image
+        filter_method: image::FilterMethod,
+        /// The opacity of the image, from `0.0` to `1.0`
+        opacity: f32,
+        /// The region of the image that is drawn, in pixels, or `None` to
+        /// draw all of it
+        region: Option<Rectangle>,
         /// The bounds of the image
         bounds: Rectangle,
     },
@@ -59,6 +85,12 @@ pub enum Primitive {
         /// The path of the SVG file
         handle: svg::Handle,
 
+        /// The tint of the SVG, if any
+        tint: Option<svg::Tint>,
+
+        /// The opacity of the SVG, from `0.0` to `1.0`
+        opacity: f32,
+
         /// The bounds of the viewport
         bounds: Rectangle,
     },
@@ -99,6 +131,19 @@ pub enum Primitive {
     },
 }
 
+/// A run of text with its own style, part of a [`Primitive::RichText`].
+#[derive(Debug, Clone)]
+pub struct TextSpan {
+    /// The contents of the span
+    pub content: String,
+    /// The color of the span
+    pub color: Color,
+    /// The size of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
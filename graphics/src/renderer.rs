@@ -1,6 +1,6 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Vector};
+use crate::{Primitive, TextSpan, Vector};
 use iced_native::image;
 use iced_native::layout;
 use iced_native::renderer;
@@ -134,9 +134,10 @@ where
         size: u16,
         font: Font,
         bounds: Size,
+        spacing: text::Spacing,
     ) -> (f32, f32) {
         self.backend()
-            .measure(content, f32::from(size), font, bounds)
+            .measure(content, f32::from(size), font, bounds, spacing)
     }
 
     fn hit_test(
@@ -147,26 +148,97 @@ where
         bounds: Size,
         point: Point,
         nearest_only: bool,
+        spacing: text::Spacing,
     ) -> Option<text::Hit> {
-        self.backend().hit_test(
-            content,
+        // Text is hit where it is displayed, in visual order
+        let visual = text::bidi::Visual::new(content);
+
+        self.backend()
+            .hit_test(
+                visual.content(),
+                size,
+                font,
+                bounds,
+                point,
+                nearest_only,
+                spacing,
+            )
+            .map(|hit| visual.hit(hit))
+    }
+
+    fn glyphs(
+        &self,
+        content: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: text::Spacing,
+    ) -> Vec<text::Glyph> {
+        let visual = text::bidi::Visual::new(content);
+
+        let mut glyphs = self.backend().glyphs(
+            visual.content(),
             size,
             font,
             bounds,
-            point,
-            nearest_only,
-        )
+            spacing,
+        );
+
+        for glyph in &mut glyphs {
+            glyph.index = visual.logical(glyph.index);
+        }
+
+        glyphs.sort_by_key(|glyph| glyph.index);
+        glyphs
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend().measure_spans(spans, bounds)
+    }
+
+    fn span_glyphs(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<text::Glyph> {
+        self.backend().span_glyphs(spans, bounds)
     }
 
     fn fill_text(&mut self, text: Text<'_, Self::Font>) {
         self.primitives.push(Primitive::Text {
-            content: text.content.to_string(),
+            content: text::bidi::Visual::new(text.content)
+                .into_content()
+                .into_owned(),
             bounds: text.bounds,
             size: text.size,
             color: text.color,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            spacing: text.spacing,
+            rotation: text.rotation.radians(),
+        });
+    }
+
+    fn fill_rich_text(&mut self, text: text::RichText<'_, Self::Font>) {
+        self.primitives.push(Primitive::RichText {
+            spans: text
+                .spans
+                .iter()
+                .map(|span| TextSpan {
+                    content: span.content.to_string(),
+                    color: span.color,
+                    size: span.size,
+                    font: span.font,
+                })
+                .collect(),
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
         });
     }
 }
@@ -182,7 +254,46 @@ where
     }
 
     fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+        self.draw_frame(handle, 0, image::FilterMethod::default(), 1.0, bounds)
+    }
+
+    fn animation(&self, handle: &image::Handle) -> Option<image::Animation> {
+        self.backend().animation(handle)
+    }
+
+    fn draw_frame(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        filter_method: image::FilterMethod,
+        opacity: f32,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            frame,
+            filter_method,
+            opacity,
+            region: None,
+            bounds,
+        })
+    }
+
+    fn draw_region(
+        &mut self,
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        region: Rectangle,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            frame: 0,
+            filter_method,
+            opacity: 1.0,
+            region: Some(region),
+            bounds,
+        })
     }
 }
 
@@ -195,6 +306,26 @@ where
     }
 
     fn draw(&mut self, handle: svg::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Svg { handle, bounds })
+        self.draw_primitive(Primitive::Svg {
+            handle,
+            tint: None,
+            opacity: 1.0,
+            bounds,
+        })
+    }
+
+    fn draw_styled(
+        &mut self,
+        handle: svg::Handle,
+        tint: Option<svg::Tint>,
+        opacity: f32,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Svg {
+            handle,
+            tint,
+            opacity,
+            bounds,
+        })
     }
 }
@@ -0,0 +1,814 @@
+//! Lay out, measure and hit test text with [`glyph_brush`].
+//!
+//! Every backend drawing text with [`glyph_brush`] lays it out with a
+//! [`Spaced`] layout, so it can be measured here exactly like it is drawn.
+use crate::backend;
+
+use glyph_brush::ab_glyph::{self, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, GlyphCruncher, GlyphPositioner, HorizontalAlign,
+    Layout, SectionGeometry, SectionGlyph, SectionText, VerticalAlign,
+};
+use iced_native::text::{Glyph, Hit, Spacing, Wrap};
+use iced_native::{Font, Point, Rectangle, Size};
+use std::{cell::RefCell, collections::HashMap};
+
+pub use glyph_brush::{FontId, Section};
+
+/// Returns a [`Section`] with some content of the given size and font, laid
+/// out in the given bounds.
+pub fn section(
+    content: &str,
+    size: f32,
+    font_id: FontId,
+    bounds: Size,
+) -> Section<'_> {
+    Section {
+        bounds: (bounds.width, bounds.height),
+        text: vec![glyph_brush::Text {
+            text: content,
+            scale: size.into(),
+            font_id,
+            extra: glyph_brush::Extra::default(),
+        }],
+        ..Default::default()
+    }
+}
+
+/// A [`GlyphPositioner`] that lays out text like a [`Layout`], applying the
+/// line height, letter spacing, [`Wrap`] mode and tab width of some
+/// [`Spacing`].
+///
+/// Characters missing from the font of a section are displayed with the
+/// first font of the fallback chain that has them. The chain starts with
+/// the default font, followed by the amount of fallback fonts set with
+/// [`Spaced::fallbacks`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spaced {
+    layout: Layout<BuiltInLineBreaker>,
+    line_height: Option<f32>,
+    letter_spacing: f32,
+    wraps: bool,
+    tab_width: u8,
+    fallbacks: usize,
+}
+
+impl Spaced {
+    /// Creates a new [`Spaced`] layout for text of the given size, scaling
+    /// the [`Spacing`] by the given factor.
+    pub fn new(
+        layout: Layout<BuiltInLineBreaker>,
+        spacing: Spacing,
+        size: f32,
+        scale_factor: f32,
+    ) -> Self {
+        let layout = match spacing.wrap {
+            Wrap::Glyph => {
+                layout.line_breaker(BuiltInLineBreaker::AnyCharLineBreaker)
+            }
+            Wrap::Word | Wrap::None => layout,
+        };
+
+        Self {
+            layout,
+            line_height: spacing.line_height.map(|line_height| {
+                line_height.to_absolute(size) * scale_factor
+            }),
+            letter_spacing: spacing.letter_spacing * scale_factor,
+            wraps: spacing.wrap != Wrap::None,
+            tab_width: spacing.tab_width,
+            fallbacks: 0,
+        }
+    }
+
+    /// Sets the amount of fallback fonts loaded after the default one.
+    pub fn fallbacks(self, fallbacks: usize) -> Self {
+        Self { fallbacks, ..self }
+    }
+
+    /// Returns the geometry used to lay out text in the given one, which
+    /// has an unbounded width when lines do not wrap.
+    fn geometry(&self, geometry: &SectionGeometry) -> SectionGeometry {
+        if self.wraps {
+            *geometry
+        } else {
+            SectionGeometry {
+                bounds: (f32::INFINITY, geometry.bounds.1),
+                ..*geometry
+            }
+        }
+    }
+
+    /// Returns the amount of lines of the given glyphs, which are laid out
+    /// one `line_height` apart, or with the natural line height of their
+    /// font if `None`.
+    fn lines<F: ab_glyph::Font>(
+        fonts: &[F],
+        glyphs: &[SectionGlyph],
+        line_height: Option<f32>,
+    ) -> usize {
+        let (first, last) = match (glyphs.first(), glyphs.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0,
+        };
+
+        let pitch = line_height.unwrap_or_else(|| {
+            let font = fonts[first.font_id.0].as_scaled(first.glyph.scale);
+
+            font.height() + font.line_gap()
+        });
+
+        if pitch <= 0.0 {
+            return 1;
+        }
+
+        ((last.glyph.position.y - first.glyph.position.y) / pitch).round()
+            as usize
+            + 1
+    }
+
+    /// Splits the given sections in runs of text that can be displayed with
+    /// a single font.
+    ///
+    /// Every character uses the font of its section if it has a glyph for
+    /// it, or else the first font of the fallback chain that does. Every run
+    /// is returned along with the index of its section and its byte offset
+    /// in it.
+    ///
+    /// Tabs are split in runs of their own and laid out as spaces, so they
+    /// produce a glyph that can be widened up to the next tab stop.
+    fn fallback<'a, F: ab_glyph::Font>(
+        &self,
+        fonts: &[F],
+        sections: &[SectionText<'a>],
+    ) -> Vec<(usize, usize, SectionText<'a>)> {
+        let mut runs = Vec::with_capacity(sections.len());
+
+        for (index, section) in sections.iter().enumerate() {
+            let font_for = |c: char| {
+                std::iter::once(section.font_id.0)
+                    .chain(0..=self.fallbacks)
+                    .find(|id| fonts[*id].glyph_id(c).0 != 0)
+                    .unwrap_or(section.font_id.0)
+            };
+
+            let run = |start: usize, end: usize, font_id: usize| {
+                (
+                    index,
+                    start,
+                    SectionText {
+                        text: &section.text[start..end],
+                        font_id: FontId(font_id),
+                        ..*section
+                    },
+                )
+            };
+
+            let mut start = 0;
+            let mut current = section.font_id.0;
+
+            for (offset, c) in section.text.char_indices() {
+                if c == '\t' {
+                    if offset > start {
+                        runs.push(run(start, offset, current));
+                    }
+
+                    runs.push((
+                        index,
+                        offset,
+                        SectionText {
+                            text: " ",
+                            font_id: FontId(current),
+                            ..*section
+                        },
+                    ));
+
+                    start = offset + 1;
+                    continue;
+                }
+
+                // Line breaks and other control characters are never drawn
+                let font_id =
+                    if c.is_control() { current } else { font_for(c) };
+
+                if font_id != current {
+                    if offset > start {
+                        runs.push(run(start, offset, current));
+                    }
+
+                    start = offset;
+                    current = font_id;
+                }
+            }
+
+            if start < section.text.len() || section.text.is_empty() {
+                runs.push(run(start, section.text.len(), current));
+            }
+        }
+
+        runs
+    }
+
+    /// Widens the glyph of every tab in the given sections up to the next
+    /// tab stop of its line, moving the glyphs after it.
+    fn expand_tabs<F: ab_glyph::Font>(
+        &self,
+        fonts: &[F],
+        sections: &[SectionText<'_>],
+        glyphs: &mut [SectionGlyph],
+    ) {
+        let is_tab = |glyph: &SectionGlyph| {
+            sections[glyph.section_index].text[glyph.byte_index..]
+                .starts_with('\t')
+        };
+
+        if !glyphs.iter().any(is_tab) {
+            return;
+        }
+
+        let h_align = match self.layout {
+            Layout::SingleLine { h_align, .. }
+            | Layout::Wrap { h_align, .. } => h_align,
+        };
+
+        let mut start = 0;
+
+        while start < glyphs.len() {
+            let line = glyphs[start].glyph.position.y;
+            let end = glyphs[start..]
+                .iter()
+                .position(|glyph| {
+                    (glyph.glyph.position.y - line).abs() > f32::EPSILON
+                })
+                .map_or(glyphs.len(), |length| start + length);
+
+            // Tab stops are measured from the start of the line
+            let origin = glyphs[start].glyph.position.x;
+            let mut shift = 0.0;
+
+            for glyph in &mut glyphs[start..end] {
+                glyph.glyph.position.x += shift;
+
+                if is_tab(glyph) {
+                    let font =
+                        fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+
+                    let space = font.h_advance(glyph.glyph.id);
+                    let stop = space * f32::from(self.tab_width.max(1));
+
+                    let x = glyph.glyph.position.x - origin;
+                    let width = ((x / stop).floor() + 1.0) * stop - x;
+
+                    if space > 0.0 {
+                        glyph.glyph.scale.x *= width / space;
+                        shift += width - space;
+                    }
+                }
+            }
+
+            let alignment_shift = match h_align {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => -shift / 2.0,
+                HorizontalAlign::Right => -shift,
+            };
+
+            for glyph in &mut glyphs[start..end] {
+                glyph.glyph.position.x += alignment_shift;
+            }
+
+            start = end;
+        }
+    }
+
+    /// Lays out the given sections, applying the line height and letter
+    /// spacing.
+    fn spaced<F: ab_glyph::Font>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<SectionGlyph> {
+        if self.line_height.is_none() && self.letter_spacing == 0.0 {
+            return self.layout.calculate_glyphs(fonts, geometry, sections);
+        }
+
+        // Lay out every line, since they may fit once they are moved
+        let mut glyphs = self.layout.calculate_glyphs(
+            fonts,
+            &SectionGeometry {
+                bounds: (geometry.bounds.0, f32::INFINITY),
+                ..*geometry
+            },
+            sections,
+        );
+
+        let (h_align, v_align) = match self.layout {
+            Layout::SingleLine {
+                h_align, v_align, ..
+            }
+            | Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align),
+        };
+
+        let (origin, advance) = match glyphs.first() {
+            Some(first) => {
+                let font = fonts[first.font_id.0].as_scaled(first.glyph.scale);
+
+                (first.glyph.position.y, font.height() + font.line_gap())
+            }
+            None => return glyphs,
+        };
+
+        let line_of = |glyph: &SectionGlyph| {
+            ((glyph.glyph.position.y - origin) / advance).round()
+        };
+
+        // Every line keeps its natural height centered in the new one
+        let leading = self.line_height.map_or(0.0, |height| height - advance);
+        let lines = Self::lines(fonts, &glyphs, None) as f32;
+
+        let vertical_shift = match v_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => -leading * lines / 2.0,
+            VerticalAlign::Bottom => -leading * lines,
+        };
+
+        let mut start = 0;
+
+        while start < glyphs.len() {
+            let line = line_of(&glyphs[start]);
+            let end = glyphs[start..]
+                .iter()
+                .position(|glyph| line_of(glyph) != line)
+                .map_or(glyphs.len(), |length| start + length);
+
+            let extra_width = self.letter_spacing * (end - start - 1) as f32;
+
+            let horizontal_shift = match h_align {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => -extra_width / 2.0,
+                HorizontalAlign::Right => -extra_width,
+            };
+
+            for (i, glyph) in glyphs[start..end].iter_mut().enumerate() {
+                glyph.glyph.position.x +=
+                    horizontal_shift + self.letter_spacing * i as f32;
+                glyph.glyph.position.y +=
+                    vertical_shift + leading * (line + 0.5);
+            }
+
+            start = end;
+        }
+
+        let bounds = self.layout.bounds_rect(geometry);
+
+        glyphs.retain(|glyph| {
+            let font = fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+
+            glyph.glyph.position.y - font.ascent() < bounds.max.y
+                && glyph.glyph.position.y - font.descent() > bounds.min.y
+        });
+
+        glyphs
+    }
+}
+
+impl From<Layout<BuiltInLineBreaker>> for Spaced {
+    fn from(layout: Layout<BuiltInLineBreaker>) -> Self {
+        Self::new(layout, Spacing::default(), 0.0, 1.0)
+    }
+}
+
+impl std::hash::Hash for Spaced {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.layout.hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.wraps.hash(state);
+        self.tab_width.hash(state);
+        self.fallbacks.hash(state);
+    }
+}
+
+impl GlyphPositioner for Spaced {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: ab_glyph::Font,
+        S: glyph_brush::ToSectionText,
+    {
+        let sections: Vec<_> = sections
+            .iter()
+            .map(glyph_brush::ToSectionText::to_section_text)
+            .collect();
+
+        let runs = self.fallback(fonts, &sections);
+        let texts: Vec<_> = runs.iter().map(|(_, _, text)| *text).collect();
+
+        let mut glyphs = self.spaced(fonts, &self.geometry(geometry), &texts);
+
+        // Glyphs must point to the original sections, so their byte
+        // indices and extra data stay valid
+        for glyph in &mut glyphs {
+            let (section_index, byte_offset, _) = runs[glyph.section_index];
+
+            glyph.section_index = section_index;
+            glyph.byte_index += byte_offset;
+        }
+
+        self.expand_tabs(fonts, &sections, &mut glyphs);
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> ab_glyph::Rect {
+        self.layout.bounds_rect(&self.geometry(geometry))
+    }
+}
+
+/// A glyph brush that lays out text with a [`Spaced`] layout to measure and
+/// hit test it, without drawing it.
+#[derive(Debug)]
+pub struct Brush<F = ab_glyph::FontArc> {
+    brush: glyph_brush::GlyphBrush<(), glyph_brush::Extra, F>,
+    fallbacks: usize,
+}
+
+impl<F> Brush<F>
+where
+    F: ab_glyph::Font + Clone + Sync,
+{
+    /// Creates a new [`Brush`] with the given fonts, where the default font
+    /// comes first followed by the given amount of fallback fonts.
+    pub fn new(fonts: Vec<F>, fallbacks: usize) -> Self {
+        Self {
+            brush: glyph_brush::GlyphBrushBuilder::using_fonts(fonts).build(),
+            fallbacks,
+        }
+    }
+
+    /// Returns the fonts of the [`Brush`], indexed by their [`FontId`].
+    pub fn fonts(&self) -> &[F] {
+        self.brush.fonts()
+    }
+
+    /// Returns the amount of fallback fonts of the [`Brush`].
+    pub fn fallbacks(&self) -> usize {
+        self.fallbacks
+    }
+
+    /// Adds a font to the [`Brush`], returning its [`FontId`].
+    pub fn add_font(&mut self, font: F) -> FontId {
+        self.brush.add_font(font)
+    }
+
+    /// Measures the given [`Section`], returning the size of its text laid
+    /// out with the given [`Spaced`] layout.
+    ///
+    /// With a custom line height, every line takes exactly that height.
+    pub fn measure(
+        &mut self,
+        section: &Section<'_>,
+        layout: &Spaced,
+    ) -> (f32, f32) {
+        let layout = layout.fallbacks(self.fallbacks);
+
+        let text_bounds =
+            match self.brush.glyph_bounds_custom_layout(section, &layout) {
+                Some(text_bounds) => text_bounds,
+                None => return (0.0, 0.0),
+            };
+
+        let height = match layout.line_height {
+            Some(line_height) => {
+                let glyphs: Vec<_> = self
+                    .brush
+                    .glyphs_custom_layout(section, &layout)
+                    .cloned()
+                    .collect();
+
+                let lines = Spaced::lines(
+                    self.brush.fonts(),
+                    &glyphs,
+                    Some(line_height),
+                );
+
+                (lines as f32 * line_height).min(section.bounds.1)
+            }
+            None => text_bounds.height(),
+        };
+
+        (text_bounds.width().ceil(), height.ceil())
+    }
+
+    /// Lays out the given [`Section`] with the given [`Spaced`] layout and
+    /// returns the glyph of every visible character, in order.
+    ///
+    /// The characters of all the texts of the [`Section`] are indexed as if
+    /// they were a single string.
+    pub fn glyphs(
+        &mut self,
+        section: &Section<'_>,
+        layout: &Spaced,
+    ) -> Vec<Glyph> {
+        let layout = layout.fallbacks(self.fallbacks);
+
+        // The underlying type is usually FontArc, so clones are cheap.
+        let fonts = self.brush.fonts().to_vec();
+
+        // The index of the first character of every text
+        let offsets: Vec<usize> = section
+            .text
+            .iter()
+            .scan(0, |offset, text| {
+                let start = *offset;
+                *offset += text.text.chars().count();
+
+                Some(start)
+            })
+            .collect();
+
+        // Glyphs are laid out in order, so character indices can be found by
+        // walking every text once
+        let (mut section_index, mut byte_offset, mut char_offset) = (0, 0, 0);
+
+        self.brush
+            .glyphs_custom_layout(section, &layout)
+            .map(|glyph| {
+                if glyph.section_index != section_index {
+                    section_index = glyph.section_index;
+                    byte_offset = 0;
+                    char_offset = 0;
+                }
+
+                let content = section.text[section_index].text;
+
+                if glyph.byte_index > byte_offset {
+                    char_offset +=
+                        content[byte_offset..glyph.byte_index].chars().count();
+                    byte_offset = glyph.byte_index;
+                }
+
+                // Glyphs may come from a fallback font
+                let font = fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+                let id = glyph.glyph.id;
+                let position = glyph.glyph.position;
+
+                Glyph {
+                    index: offsets[section_index] + char_offset,
+                    bounds: Rectangle::new(
+                        Point::new(
+                            position.x - font.h_side_bearing(id),
+                            position.y - font.ascent(),
+                        ),
+                        Size::new(
+                            font.h_advance(id),
+                            font.ascent() - font.descent(),
+                        ),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Tests whether the given point is within the glyphs of the [`Section`]
+    /// laid out with the given [`Spaced`] layout, returning information about
+    /// the nearest character.
+    ///
+    /// If `nearest_only` is true, the hit test does not consider whether the
+    /// point is inside any glyph, returning only the character with the
+    /// nearest center.
+    pub fn hit_test(
+        &mut self,
+        section: &Section<'_>,
+        layout: &Spaced,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        let glyphs = self.glyphs(section, layout);
+
+        if !nearest_only {
+            if let Some(glyph) =
+                glyphs.iter().find(|glyph| glyph.bounds.contains(point))
+            {
+                return Some(Hit::CharOffset(glyph.index));
+            }
+        }
+
+        glyphs
+            .iter()
+            .map(|glyph| (glyph.index, glyph.bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            })
+            .map(|(index, center)| {
+                Hit::NearestCharOffset(index, point - center)
+            })
+    }
+
+    /// Trims the cache of the [`Brush`], dropping the layouts of text that
+    /// was not measured since the last trim.
+    pub fn trim(&mut self) {
+        // TODO: We should probably use a `GlyphCalculator` for this. However,
+        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
+        // This makes stuff quite inconvenient. A manual method for trimming the
+        // cache would make our lives easier.
+        loop {
+            let action = self.brush.process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.resize_texture(width, height);
+                }
+            }
+        }
+    }
+}
+
+/// A [`backend::Measurer`] with a copy of the fonts of a backend, which lays
+/// out text exactly like it without borrowing it.
+#[derive(Debug)]
+pub struct Measurer {
+    fonts: Vec<ab_glyph::FontArc>,
+    font_map: RefCell<HashMap<String, FontId>>,
+    brush: RefCell<Option<Brush>>,
+    fallbacks: usize,
+}
+
+impl Measurer {
+    /// Creates a new [`Measurer`] with the fonts of a [`Brush`] and the
+    /// [`FontId`] of every external font known by the backend.
+    pub fn new(brush: &Brush, font_map: HashMap<String, FontId>) -> Self {
+        Self {
+            fonts: brush.fonts().to_vec(),
+            font_map: RefCell::new(font_map),
+            brush: RefCell::new(None),
+            fallbacks: brush.fallbacks(),
+        }
+    }
+}
+
+impl backend::Measurer for Measurer {
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: Spacing,
+    ) -> (f32, f32) {
+        let mut brush = self.brush.borrow_mut();
+
+        let brush = brush.get_or_insert_with(|| {
+            Brush::new(self.fonts.clone(), self.fallbacks)
+        });
+
+        let font_id = match font {
+            Font::Default => FontId(0),
+            Font::External { name, bytes } => {
+                let mut font_map = self.font_map.borrow_mut();
+
+                // Fonts unknown to the backend are only added to the copy
+                *font_map.entry(String::from(name)).or_insert_with(|| {
+                    brush.add_font(
+                        ab_glyph::FontArc::try_from_slice(bytes)
+                            .expect("Load font"),
+                    )
+                })
+            }
+        };
+
+        brush.measure(
+            &section(contents, size, font_id, bounds),
+            &Spaced::new(Layout::default(), spacing, size, 1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::text::LineHeight;
+
+    const FONT: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
+
+    fn brush() -> Brush {
+        let font = ab_glyph::FontArc::try_from_slice(FONT).expect("Load font");
+
+        Brush::new(vec![font], 0)
+    }
+
+    fn measure(content: &str, width: f32, spacing: Spacing) -> (f32, f32) {
+        brush().measure(
+            &section(content, 20.0, FontId(0), Size::new(width, f32::INFINITY)),
+            &Spaced::new(Layout::default(), spacing, 20.0, 1.0),
+        )
+    }
+
+    fn height(wrap: Wrap) -> f32 {
+        let (_, height) = measure(
+            "Lorem ipsum dolor sit amet, consectetur adipiscing elit",
+            100.0,
+            Spacing {
+                wrap,
+                ..Spacing::default()
+            },
+        );
+
+        height
+    }
+
+    #[test]
+    fn text_without_wrapping_is_a_single_line() {
+        let word = height(Wrap::Word);
+        let glyph = height(Wrap::Glyph);
+        let none = height(Wrap::None);
+
+        assert!(none < 30.0);
+        assert!(word > 2.0 * none);
+        assert!(glyph > 2.0 * none);
+        assert!(glyph <= word);
+    }
+
+    #[test]
+    fn line_height_sets_the_height_of_every_line() {
+        let height = |line_height| {
+            let (_, height) = measure(
+                "First\nSecond\nThird",
+                f32::INFINITY,
+                Spacing {
+                    line_height: Some(LineHeight::Absolute(line_height)),
+                    ..Spacing::default()
+                },
+            );
+
+            height
+        };
+
+        assert_eq!(height(40.0), 120.0);
+        assert_eq!(height(10.0), 30.0);
+    }
+
+    #[test]
+    fn hits_are_indexed_by_character_across_texts() {
+        let mut brush = brush();
+
+        let section = Section {
+            bounds: (f32::INFINITY, f32::INFINITY),
+            text: vec![
+                glyph_brush::Text::new("ab").with_scale(20.0),
+                glyph_brush::Text::new("\u{E9}c").with_scale(20.0),
+            ],
+            ..Default::default()
+        };
+
+        let layout = Spaced::from(Layout::default());
+        let glyphs = brush.glyphs(&section, &layout);
+
+        assert_eq!(
+            glyphs.iter().map(|glyph| glyph.index).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+
+        let inside = glyphs[3].bounds.center();
+
+        assert_eq!(
+            brush.hit_test(&section, &layout, inside, false),
+            Some(Hit::CharOffset(3))
+        );
+
+        let after = Point::new(glyphs[3].bounds.x + 100.0, inside.y);
+
+        assert_eq!(
+            brush
+                .hit_test(&section, &layout, after, false)
+                .map(|hit| hit.cursor()),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn tabs_advance_to_the_next_tab_stop() {
+        let width =
+            |content| measure(content, f32::INFINITY, Spacing::default()).0;
+
+        let stop = width("    x");
+
+        assert!((width("\tx") - stop).abs() < 0.01);
+        assert!((width("a\tx") - stop).abs() < 0.01);
+        assert!((width("ab\tx") - width("        x")).abs() < 0.01);
+        assert!((width("\t") - width("    ")).abs() < 0.01);
+    }
+}
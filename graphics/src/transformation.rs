@@ -30,6 +30,12 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Creates a clockwise rotation transformation around the origin, with
+    /// the given angle in radians.
+    pub fn rotate(angle: f32) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle))
+    }
 }
 
 impl Mul for Transformation {
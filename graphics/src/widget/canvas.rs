@@ -4,9 +4,10 @@
 //! [`Frame`]. It can be used for animation, data visualization, game graphics,
 //! and more!
 use crate::renderer::{self, Renderer};
-use crate::{Backend, Primitive};
+use crate::{backend, Backend, Primitive};
 
 pub mod event;
+pub mod export;
 pub mod path;
 
 mod cache;
@@ -14,21 +15,28 @@ mod cursor;
 mod fill;
 mod frame;
 mod geometry;
+mod gradient;
 mod program;
 mod stroke;
-mod text;
+
+pub(crate) mod text;
 
 pub use cache::Cache;
 pub use cursor::Cursor;
+
+pub(crate) use cursor::Capture;
 pub use event::Event;
-pub use fill::{Fill, FillRule};
+pub use fill::{Fill, FillRule, Style};
 pub use frame::Frame;
 pub use geometry::Geometry;
+pub use gradient::{ColorStop, Gradient};
 pub use path::Path;
 pub use program::Program;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use text::Text;
 
+pub use iced_native::text::Rotation;
+
 use iced_native::layout;
 use iced_native::mouse;
 use iced_native::{
@@ -98,6 +106,7 @@ pub struct Canvas<Message, P: Program<Message>> {
     width: Length,
     height: Length,
     program: P,
+    capture: Capture,
     message_: PhantomData<Message>,
 }
 
@@ -110,6 +119,7 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
             width: Length::Units(Self::DEFAULT_SIZE),
             height: Length::Units(Self::DEFAULT_SIZE),
             program,
+            capture: Capture::default(),
             message_: PhantomData,
         }
     }
@@ -130,7 +140,7 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
 impl<Message, P, B> Widget<Message, Renderer<B>> for Canvas<Message, P>
 where
     P: Program<Message>,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn width(&self) -> Length {
         self.width
@@ -162,22 +172,24 @@ where
     ) -> event::Status {
         let bounds = layout.bounds();
 
-        let canvas_event = match event {
-            iced_native::Event::Mouse(mouse_event) => {
-                Some(Event::Mouse(mouse_event))
-            }
-            iced_native::Event::Keyboard(keyboard_event) => {
-                Some(Event::Keyboard(keyboard_event))
-            }
-            _ => None,
-        };
+        // Without a local state, the focus cannot be remembered
+        let mut is_focused = true;
 
-        let cursor = Cursor::from_window_position(cursor_position);
+        let canvas_event =
+            event::convert(event, bounds, cursor_position, &mut is_focused);
 
         if let Some(canvas_event) = canvas_event {
+            // Without a local state, the mouse is only captured for as long
+            // as the [`Canvas`] lives
+            self.capture.track(&canvas_event);
+
+            let cursor = self.capture.cursor(cursor_position);
+
             let (event_status, message) =
                 self.program.update(canvas_event, bounds, cursor);
 
+            self.capture.update(&canvas_event, event_status, cursor);
+
             if let Some(message) = message {
                 shell.publish(message);
             }
@@ -196,7 +208,7 @@ where
         _renderer: &Renderer<B>,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
-        let cursor = Cursor::from_window_position(cursor_position);
+        let cursor = self.capture.cursor(cursor_position);
 
         self.program.mouse_interaction(bounds, cursor)
     }
@@ -218,13 +230,16 @@ where
         }
 
         let translation = Vector::new(bounds.x, bounds.y);
-        let cursor = Cursor::from_window_position(cursor_position);
+        let cursor = self.capture.cursor(cursor_position);
+
+        let geometries =
+            text::with_measurer(renderer.backend().measurer(), || {
+                self.program.draw(bounds, cursor)
+            });
 
         renderer.with_translation(translation, |renderer| {
             renderer.draw_primitive(Primitive::Group {
-                primitives: self
-                    .program
-                    .draw(bounds, cursor)
+                primitives: geometries
                     .into_iter()
                     .map(Geometry::into_primitive)
                     .collect(),
@@ -238,7 +253,7 @@ impl<'a, Message, P, B> From<Canvas<Message, P>>
 where
     Message: 'static,
     P: Program<Message> + 'a,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn from(canvas: Canvas<Message, P>) -> Element<'a, Message, Renderer<B>> {
         Element::new(canvas)
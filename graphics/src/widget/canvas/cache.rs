@@ -1,9 +1,9 @@
 use crate::{
     canvas::{Frame, Geometry},
-    Primitive,
+    triangle, Primitive, Transformation,
 };
 
-use iced_native::Size;
+use iced_native::{Point, Rectangle, Size, Vector};
 use std::{cell::RefCell, sync::Arc};
 
 enum State {
@@ -11,9 +11,17 @@ enum State {
     Filled {
         bounds: Size,
         primitive: Arc<Primitive>,
+        transformed: Option<Transformed>,
     },
 }
 
+/// The stored [`Geometry`] of a [`Cache`] after applying a transformation.
+struct Transformed {
+    bounds: Size,
+    transformation: Transformation,
+    primitive: Arc<Primitive>,
+}
+
 impl Default for State {
     fn default() -> Self {
         State::Empty
@@ -22,9 +30,18 @@ impl Default for State {
 /// A simple cache that stores generated [`Geometry`] to avoid recomputation.
 ///
 /// A [`Cache`] will not redraw its geometry unless the dimensions of its layer
-/// change or it is explicitly cleared.
+/// change or it is explicitly cleared. The images drawn in its geometry are
+/// kept alive while it is stored.
+///
+/// # Memory
+/// A [`Cache`] stores the [`Geometry`] it drew and, if it was last drawn
+/// with a transformation, a single transformed copy of it. Clearing or
+/// dropping the [`Cache`] releases both, once the [`Geometry`] it returned
+/// is not drawn anymore. Every [`Cache`] is independent, so using many of
+/// them only costs the geometry each one stores.
 #[derive(Debug, Default)]
 pub struct Cache {
+    reference_size: Option<Size>,
     state: RefCell<State>,
 }
 
@@ -32,6 +49,20 @@ impl Cache {
     /// Creates a new empty [`Cache`].
     pub fn new() -> Self {
         Cache {
+            reference_size: None,
+            state: Default::default(),
+        }
+    }
+
+    /// Creates a new empty [`Cache`] for geometry that does not depend on
+    /// the size of its layer.
+    ///
+    /// The geometry is drawn once on a [`Frame`] of the given reference size,
+    /// which must not be empty, and then stretched to fill the bounds it is
+    /// drawn with, instead of being drawn again when they change.
+    pub fn with_reference_size(reference_size: Size) -> Self {
+        Cache {
+            reference_size: Some(reference_size),
             state: Default::default(),
         }
     }
@@ -45,42 +76,291 @@ impl Cache {
     /// [`Cache`].
     ///
     /// The closure will only be called when
-    /// - the bounds have changed since the previous draw call.
+    /// - the bounds have changed since the previous draw call, unless the
+    ///   [`Cache`] has a reference size.
     /// - the [`Cache`] is empty or has been explicitly cleared.
     ///
     /// Otherwise, the previously stored [`Geometry`] will be returned. The
     /// [`Cache`] is not cleared in this case. In other words, it will keep
     /// returning the stored [`Geometry`] if needed.
     pub fn draw(&self, bounds: Size, draw_fn: impl Fn(&mut Frame)) -> Geometry {
-        use std::ops::Deref;
+        self.draw_with_transform(bounds, Transformation::identity(), draw_fn)
+    }
+
+    /// Draws [`Geometry`] like [`draw`], applying the given
+    /// [`Transformation`] to it, in the coordinates of the [`Frame`].
+    ///
+    /// Changing the [`Transformation`] does not call the closure again. The
+    /// stored [`Geometry`] is transformed instead, which is much cheaper than
+    /// drawing it again and makes it ideal for zooming and panning.
+    ///
+    /// The [`Transformation`] scales the lines of strokes and the size of
+    /// text along with everything else. Curves are not flattened again, so
+    /// they may look angular when zooming in a lot.
+    ///
+    /// [`draw`]: Self::draw
+    pub fn draw_with_transform(
+        &self,
+        bounds: Size,
+        transformation: Transformation,
+        draw_fn: impl Fn(&mut Frame),
+    ) -> Geometry {
+        let (frame_size, transformation) = match self.reference_size {
+            Some(reference_size) => (
+                reference_size,
+                transformation
+                    * Transformation::scale(
+                        bounds.width / reference_size.width,
+                        bounds.height / reference_size.height,
+                    ),
+            ),
+            None => (bounds, transformation),
+        };
+
+        let is_drawn = matches!(
+            &*self.state.borrow(),
+            State::Filled { bounds, .. } if *bounds == frame_size
+        );
+
+        if !is_drawn {
+            let mut frame = Frame::new(frame_size);
+            draw_fn(&mut frame);
+
+            *self.state.borrow_mut() = State::Filled {
+                bounds: frame_size,
+                primitive: Arc::new(frame.into_geometry().into_primitive()),
+                transformed: None,
+            };
+        }
+
+        let mut state = self.state.borrow_mut();
 
-        if let State::Filled {
-            bounds: cached_bounds,
-            primitive,
-        } = self.state.borrow().deref()
+        let (primitive, transformed) = match &mut *state {
+            State::Filled {
+                primitive,
+                transformed,
+                ..
+            } => (primitive, transformed),
+            State::Empty => unreachable!("The cache was just filled"),
+        };
+
+        if bounds == frame_size && transformation == Transformation::identity()
         {
-            if *cached_bounds == bounds {
-                return Geometry::from_primitive(Primitive::Cached {
-                    cache: primitive.clone(),
-                });
-            }
+            *transformed = None;
+
+            return Geometry::from_primitive(Primitive::Cached {
+                cache: primitive.clone(),
+            });
         }
 
-        let mut frame = Frame::new(bounds);
-        draw_fn(&mut frame);
+        let cache = match transformed {
+            Some(transformed)
+                if transformed.bounds == bounds
+                    && transformed.transformation == transformation =>
+            {
+                transformed.primitive.clone()
+            }
+            _ => {
+                let cache = Arc::new(transform_primitive(
+                    primitive,
+                    &to_lyon(transformation),
+                    Rectangle::with_size(bounds),
+                ));
 
-        let primitive = {
-            let geometry = frame.into_geometry();
+                *transformed = Some(Transformed {
+                    bounds,
+                    transformation,
+                    primitive: cache.clone(),
+                });
 
-            Arc::new(geometry.into_primitive())
+                cache
+            }
         };
 
-        *self.state.borrow_mut() = State::Filled {
+        Geometry::from_primitive(Primitive::Cached { cache })
+    }
+}
+
+/// Converts a [`Transformation`] into the 2D transform it performs.
+fn to_lyon(transformation: Transformation) -> lyon::math::Transform {
+    let matrix: [f32; 16] = transformation.into();
+
+    lyon::math::Transform::new(
+        matrix[0], matrix[1], matrix[4], matrix[5], matrix[12], matrix[13],
+    )
+}
+
+/// Applies a transform to the [`Primitive`] of some [`Geometry`], clipping
+/// its meshes to the given bounds.
+fn transform_primitive(
+    primitive: &Primitive,
+    transform: &lyon::math::Transform,
+    bounds: Rectangle,
+) -> Primitive {
+    let point = |point: Point| {
+        let point =
+            transform.transform_point(lyon::math::Point::new(point.x, point.y));
+
+        Point::new(point.x, point.y)
+    };
+
+    let scale = |x: f32, y: f32| {
+        transform
+            .transform_vector(lyon::math::Vector::new(x, y))
+            .length()
+    };
+
+    let bounding_box = |rectangle: Rectangle| {
+        let corners = [
+            point(Point::new(rectangle.x, rectangle.y)),
+            point(Point::new(rectangle.x + rectangle.width, rectangle.y)),
+            point(Point::new(rectangle.x, rectangle.y + rectangle.height)),
+            point(Point::new(
+                rectangle.x + rectangle.width,
+                rectangle.y + rectangle.height,
+            )),
+        ];
+
+        let (min, max) = corners.iter().fold(
+            (corners[0], corners[0]),
+            |(min, max), corner| {
+                (
+                    Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                    Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+                )
+            },
+        );
+
+        Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+    };
+
+    match primitive {
+        Primitive::Group { primitives } => Primitive::Group {
+            primitives: primitives
+                .iter()
+                .map(|primitive| {
+                    transform_primitive(primitive, transform, bounds)
+                })
+                .collect(),
+        },
+        Primitive::Translate {
+            translation,
+            content,
+        } => transform_primitive(
+            content,
+            &lyon::math::Transform::translation(translation.x, translation.y)
+                .then(transform),
             bounds,
-            primitive: primitive.clone(),
-        };
+        ),
+        Primitive::Cached { cache } => {
+            transform_primitive(cache, transform, bounds)
+        }
+        Primitive::Clip {
+            bounds: clip,
+            content,
+        } => Primitive::Clip {
+            bounds: bounding_box(*clip),
+            content: Box::new(transform_primitive(content, transform, bounds)),
+        },
+        Primitive::Mesh2D { buffers, size } => {
+            // The mesh is clipped to its transformed region, which can only
+            // start at its translation
+            let clip = match bounding_box(Rectangle::with_size(*size))
+                .intersection(&bounds)
+            {
+                Some(clip) => clip,
+                None => return Primitive::None,
+            };
+
+            let vertices = buffers
+                .vertices
+                .iter()
+                .map(|vertex| {
+                    let [x, y] = vertex.position;
+                    let position = point(Point::new(x, y));
+
+                    triangle::Vertex2D {
+                        position: [position.x - clip.x, position.y - clip.y],
+                        color: vertex.color,
+                    }
+                })
+                .collect();
+
+            Primitive::Translate {
+                translation: Vector::new(clip.x, clip.y),
+                content: Box::new(Primitive::Mesh2D {
+                    buffers: triangle::Mesh2D {
+                        vertices,
+                        indices: buffers.indices.clone(),
+                    },
+                    size: clip.size(),
+                }),
+            }
+        }
+        Primitive::Text {
+            content,
+            bounds: text_bounds,
+            color,
+            size,
+            font,
+            horizontal_alignment,
+            vertical_alignment,
+            spacing,
+            rotation,
+        } => {
+            let position = point(text_bounds.position());
+            let axis =
+                transform.transform_vector(lyon::math::Vector::new(1.0, 0.0));
 
-        Geometry::from_primitive(Primitive::Cached { cache: primitive })
+            Primitive::Text {
+                content: content.clone(),
+                bounds: Rectangle::new(
+                    position,
+                    Size::new(
+                        text_bounds.width * axis.length(),
+                        text_bounds.height * scale(0.0, 1.0),
+                    ),
+                ),
+                color: *color,
+                size: size * axis.length(),
+                font: *font,
+                horizontal_alignment: *horizontal_alignment,
+                vertical_alignment: *vertical_alignment,
+                spacing: *spacing,
+                rotation: rotation + axis.y.atan2(axis.x),
+            }
+        }
+        Primitive::Image {
+            handle,
+            frame,
+            filter_method,
+            opacity,
+            region,
+            bounds: image_bounds,
+        } => {
+            // Images stay upright, like when they are drawn on a frame
+            let center = point(image_bounds.center());
+            let size = Size::new(
+                image_bounds.width * scale(1.0, 0.0),
+                image_bounds.height * scale(0.0, 1.0),
+            );
+
+            Primitive::Image {
+                handle: handle.clone(),
+                frame: *frame,
+                filter_method: *filter_method,
+                opacity: *opacity,
+                region: *region,
+                bounds: Rectangle::new(
+                    Point::new(
+                        center.x - size.width / 2.0,
+                        center.y - size.height / 2.0,
+                    ),
+                    size,
+                ),
+            }
+        }
+        primitive => primitive.clone(),
     }
 }
 
@@ -88,7 +368,9 @@ impl std::fmt::Debug for State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             State::Empty => write!(f, "Empty"),
-            State::Filled { primitive, bounds } => f
+            State::Filled {
+                primitive, bounds, ..
+            } => f
                 .debug_struct("Filled")
                 .field("primitive", primitive)
                 .field("bounds", bounds)
@@ -96,3 +378,106 @@ impl std::fmt::Debug for State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::canvas::Path;
+    use iced_native::Color;
+
+    use std::cell::Cell;
+
+    const BOUNDS: Size = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn draw_square(frame: &mut Frame) {
+        frame.fill(
+            &Path::rectangle(Point::new(10.0, 10.0), Size::new(20.0, 20.0)),
+            Color::BLACK,
+        );
+    }
+
+    fn stored(cache: &Cache) -> (Arc<Primitive>, Option<Arc<Primitive>>) {
+        match &*cache.state.borrow() {
+            State::Filled {
+                primitive,
+                transformed,
+                ..
+            } => (
+                primitive.clone(),
+                transformed
+                    .as_ref()
+                    .map(|transformed| transformed.primitive.clone()),
+            ),
+            State::Empty => panic!("The cache is empty"),
+        }
+    }
+
+    #[test]
+    fn transformations_do_not_draw_again() {
+        let cache = Cache::new();
+        let draws = Cell::new(0);
+
+        for zoom in [1.0, 2.0, 3.0, 2.0] {
+            let _ = cache.draw_with_transform(
+                BOUNDS,
+                Transformation::scale(zoom, zoom),
+                |frame| {
+                    draws.set(draws.get() + 1);
+                    draw_square(frame);
+                },
+            );
+        }
+
+        assert_eq!(draws.get(), 1);
+    }
+
+    #[test]
+    fn reference_size_ignores_bounds() {
+        let cache = Cache::with_reference_size(BOUNDS);
+        let draws = Cell::new(0);
+
+        for size in [50.0, 100.0, 200.0] {
+            let _ = cache.draw(Size::new(size, size), |frame| {
+                draws.set(draws.get() + 1);
+                assert_eq!(frame.size(), BOUNDS);
+            });
+        }
+
+        assert_eq!(draws.get(), 1);
+    }
+
+    #[test]
+    fn many_caches_keep_a_single_transformed_copy() {
+        let caches: Vec<_> = (0..100).map(|_| Cache::new()).collect();
+
+        for zoom in [2.0, 3.0, 4.0] {
+            for cache in &caches {
+                let _ = cache.draw_with_transform(
+                    BOUNDS,
+                    Transformation::scale(zoom, zoom),
+                    draw_square,
+                );
+            }
+        }
+
+        for cache in &caches {
+            let (primitive, transformed) = stored(cache);
+            let transformed = transformed.expect("Transformed geometry");
+
+            // Only the cache and this test hold the geometry
+            assert_eq!(Arc::strong_count(&primitive), 2);
+            assert_eq!(Arc::strong_count(&transformed), 2);
+        }
+
+        for cache in &caches {
+            let _ = cache.draw(BOUNDS, draw_square);
+
+            // The transformed copy is released when it is not used anymore
+            assert!(stored(cache).1.is_none());
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use crate::canvas::event::{self, Event};
+
+use iced_native::mouse;
 use iced_native::{Point, Rectangle};
 
 /// The mouse cursor state.
@@ -44,6 +47,11 @@ impl Cursor {
 
     /// Returns the relative position of the [`Cursor`] from the given origin,
     /// if available.
+    ///
+    /// Unlike [`position_in`], it does not hide positions out of some bounds,
+    /// which is useful while dragging.
+    ///
+    /// [`position_in`]: Self::position_in
     pub fn position_from(&self, origin: Point) -> Option<Point> {
         match self {
             Cursor::Available(position) => {
@@ -62,3 +70,64 @@ impl Cursor {
         }
     }
 }
+
+/// The capture of the mouse by a [`Canvas`].
+///
+/// A [`Canvas`] captures the mouse when its [`Program`] captures the press of
+/// a button. Until the button is released, its [`Cursor`] stays available
+/// with the latest position of the mouse, even out of the bounds of the
+/// [`Canvas`] or the window.
+///
+/// [`Canvas`]: crate::widget::Canvas
+/// [`Program`]: crate::widget::canvas::Program
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Capture {
+    captured: Option<(mouse::Button, Point)>,
+}
+
+impl Capture {
+    /// Returns the [`Cursor`] of a [`Canvas`] with the given cursor position.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub(crate) fn cursor(&self, cursor_position: Point) -> Cursor {
+        match self.captured {
+            Some((_, position)) => Cursor::Available(position),
+            None => Cursor::from_window_position(cursor_position),
+        }
+    }
+
+    /// Follows the mouse with an [`Event`], before it is handled.
+    pub(crate) fn track(&mut self, event: &Event) {
+        if let (
+            Some((_, position)),
+            Event::Mouse(mouse::Event::CursorMoved { position: moved }),
+        ) = (&mut self.captured, event)
+        {
+            *position = *moved;
+        }
+    }
+
+    /// Captures or releases the mouse after an [`Event`] was handled with the
+    /// given [`event::Status`].
+    pub(crate) fn update(
+        &mut self,
+        event: &Event,
+        status: event::Status,
+        cursor: Cursor,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(button))
+                if status == event::Status::Captured
+                    && self.captured.is_none() =>
+            {
+                self.captured =
+                    cursor.position().map(|position| (*button, position));
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(button)) if matches!(self.captured, Some((captured, _)) if captured == *button) =>
+            {
+                self.captured = None;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Handle events of a canvas.
 use iced_native::keyboard;
 use iced_native::mouse;
+use iced_native::touch;
+use iced_native::{Point, Rectangle};
 
 pub use iced_native::event::Status;
 
@@ -13,5 +15,48 @@ pub enum Event {
     Mouse(mouse::Event),
 
     /// A keyboard event.
+    ///
+    /// Keyboard events are only delivered to a focused [`Canvas`], which is
+    /// the last one clicked or touched. A [`Canvas`] that is not [`pure`] has
+    /// no state to remember its focus, so it is always focused.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    /// [`pure`]: crate::widget::pure::Canvas
     Keyboard(keyboard::Event),
 }
+
+/// Converts a runtime event into an [`Event`] for a [`Canvas`] with the
+/// given bounds, updating whether the [`Canvas`] is focused.
+///
+/// A [`Canvas`] gains focus when it is clicked or touched, and loses it when
+/// anything else is.
+///
+/// [`Canvas`]: crate::widget::Canvas
+pub(crate) fn convert(
+    event: iced_native::Event,
+    bounds: Rectangle,
+    cursor_position: Point,
+    is_focused: &mut bool,
+) -> Option<Event> {
+    match event {
+        iced_native::Event::Mouse(mouse_event) => {
+            if let mouse::Event::ButtonPressed(_) = mouse_event {
+                *is_focused = bounds.contains(cursor_position);
+            }
+
+            Some(Event::Mouse(mouse_event))
+        }
+        iced_native::Event::Touch(touch::Event::FingerPressed {
+            position,
+            ..
+        }) => {
+            *is_focused = bounds.contains(position);
+
+            None
+        }
+        iced_native::Event::Keyboard(keyboard_event) if *is_focused => {
+            Some(Event::Keyboard(keyboard_event))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,474 @@
+//! Export the geometry of a canvas to other formats.
+use crate::alignment;
+use crate::canvas::Geometry;
+use crate::{Font, Primitive, Rectangle, Size, Vector};
+
+use iced_native::futures::channel::oneshot;
+use iced_native::image;
+use iced_native::Command;
+
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The height of a line of text in an SVG document, relative to its size.
+///
+/// It approximates the line height of the default font of the renderers.
+const LINE_HEIGHT: f32 = 1.2;
+
+/// Serializes the given layers of [`Geometry`] of a canvas with the given
+/// size into an SVG document.
+///
+/// The meshes of the [`Geometry`] are exported as the triangles they were
+/// tessellated into, so gradients are approximated with a flat color per
+/// triangle. Text is kept as SVG text, which is laid out by the viewer of
+/// the document. Images are embedded with base64, and the ones that cannot
+/// be read are left out.
+pub fn svg(size: Size, geometries: &[Geometry]) -> String {
+    let mut writer = Writer::default();
+
+    for geometry in geometries {
+        writer.primitive(geometry.as_primitive(), Vector::new(0.0, 0.0));
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" \
+        height=\"{height}\" viewBox=\"0 0 {width} {height}\">\
+        <defs>{defs}</defs>{body}</svg>\n",
+        width = number(size.width),
+        height = number(size.height),
+        defs = writer.defs,
+        body = writer.body,
+    )
+}
+
+/// Saves the given layers of [`Geometry`] of a canvas with the given size
+/// to an SVG file, in a background thread.
+///
+/// The document is produced like with [`svg`].
+pub fn save_svg(
+    path: impl Into<PathBuf>,
+    size: Size,
+    geometries: &[Geometry],
+) -> Command<Result<(), Arc<io::Error>>> {
+    let path = path.into();
+    let document = svg(size, geometries);
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = std::thread::spawn(move || {
+        let _ = sender.send(std::fs::write(path, document).map_err(Arc::new));
+    });
+
+    Command::perform(
+        async move {
+            receiver.await.unwrap_or_else(|_| {
+                Err(Arc::new(io::Error::other(
+                    "the SVG file could not be saved",
+                )))
+            })
+        },
+        std::convert::identity,
+    )
+}
+
+#[derive(Debug, Default)]
+struct Writer {
+    defs: String,
+    body: String,
+    clips: usize,
+}
+
+impl Writer {
+    fn primitive(&mut self, primitive: &Primitive, translation: Vector) {
+        match primitive {
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.primitive(primitive, translation);
+                }
+            }
+            Primitive::Translate {
+                translation: offset,
+                content,
+            } => self.primitive(content, translation + *offset),
+            Primitive::Cached { cache } => self.primitive(cache, translation),
+            Primitive::Clip { bounds, content } => {
+                self.clipped(*bounds + translation, |writer| {
+                    writer.primitive(content, translation)
+                });
+            }
+            Primitive::Mesh2D { buffers, size } => {
+                let bounds = Rectangle::new(
+                    iced_native::Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                self.clipped(bounds, |writer| {
+                    writer.mesh(buffers, translation)
+                });
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+                rotation,
+                ..
+            } => {
+                let x = number(bounds.x + translation.x);
+                let y = number(bounds.y + translation.y);
+                let (fill, opacity) = paint(color.into_linear());
+
+                let _ = write!(
+                    self.body,
+                    "<text x=\"{x}\" y=\"{y}\" font-size=\"{}\" \
+                    font-family=\"{}\" fill=\"{fill}\"{opacity} \
+                    text-anchor=\"{}\" dominant-baseline=\"{}\"",
+                    number(*size),
+                    escape(&family(*font)),
+                    match horizontal_alignment {
+                        alignment::Horizontal::Left => "start",
+                        alignment::Horizontal::Center => "middle",
+                        alignment::Horizontal::Right => "end",
+                    },
+                    match vertical_alignment {
+                        alignment::Vertical::Top => "text-before-edge",
+                        alignment::Vertical::Center => "central",
+                        alignment::Vertical::Bottom => "text-after-edge",
+                    },
+                );
+
+                if *rotation != 0.0 {
+                    let _ = write!(
+                        self.body,
+                        " transform=\"rotate({} {x} {y})\"",
+                        number(rotation.to_degrees()),
+                    );
+                }
+
+                self.body.push('>');
+
+                let lines: Vec<_> = content.lines().collect();
+
+                // The lines are aligned as a block, like in a canvas
+                let breaks = lines.len().max(1) as f32 - 1.0;
+
+                let first = match vertical_alignment {
+                    alignment::Vertical::Top => 0.0,
+                    alignment::Vertical::Center => -breaks / 2.0,
+                    alignment::Vertical::Bottom => -breaks,
+                };
+
+                for (i, line) in lines.iter().enumerate() {
+                    let dy = if i == 0 {
+                        first * LINE_HEIGHT
+                    } else {
+                        LINE_HEIGHT
+                    };
+
+                    let _ = write!(
+                        self.body,
+                        "<tspan x=\"{x}\" dy=\"{}em\">{}</tspan>",
+                        number(dy),
+                        escape(line),
+                    );
+                }
+
+                self.body.push_str("</text>");
+            }
+            Primitive::Image {
+                handle,
+                opacity,
+                region,
+                bounds,
+                ..
+            } => {
+                let uri = match data_uri(handle) {
+                    Some(uri) => uri,
+                    None => return,
+                };
+
+                let bounds = *bounds + translation;
+
+                let opacity = if *opacity < 1.0 {
+                    format!(" opacity=\"{}\"", number(*opacity))
+                } else {
+                    String::new()
+                };
+
+                let position = format!(
+                    "x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+                    number(bounds.x),
+                    number(bounds.y),
+                    number(bounds.width),
+                    number(bounds.height),
+                );
+
+                match region {
+                    Some(region) => {
+                        // A nested viewport shows the region of the image
+                        let _ = write!(
+                            self.body,
+                            "<svg {position} viewBox=\"{} {} {} {}\" \
+                            preserveAspectRatio=\"none\"{opacity}>\
+                            <image href=\"{uri}\"/></svg>",
+                            number(region.x),
+                            number(region.y),
+                            number(region.width),
+                            number(region.height),
+                        );
+                    }
+                    None => {
+                        let _ = write!(
+                            self.body,
+                            "<image {position} preserveAspectRatio=\"none\"\
+                            {opacity} href=\"{uri}\"/>",
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Writes the SVG elements produced by the given function inside a
+    /// group clipped to the given bounds.
+    fn clipped(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        let id = self.clips;
+        self.clips += 1;
+
+        let _ = write!(
+            self.defs,
+            "<clipPath id=\"clip{id}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" \
+            height=\"{}\"/></clipPath>",
+            number(bounds.x),
+            number(bounds.y),
+            number(bounds.width),
+            number(bounds.height),
+        );
+
+        let _ = write!(self.body, "<g clip-path=\"url(#clip{id})\">");
+
+        f(self);
+
+        self.body.push_str("</g>");
+    }
+
+    /// Writes the triangles of a mesh, merging consecutive triangles of the
+    /// same color into a single path.
+    fn mesh(&mut self, buffers: &crate::triangle::Mesh2D, translation: Vector) {
+        let mut current: Option<(String, String)> = None;
+
+        for triangle in buffers.indices.chunks_exact(3) {
+            let vertices =
+                triangle.iter().map(|i| buffers.vertices[*i as usize]);
+
+            let mut color = [0.0; 4];
+
+            for vertex in vertices.clone() {
+                for (channel, value) in color.iter_mut().zip(vertex.color) {
+                    *channel += value / 3.0;
+                }
+            }
+
+            let style = paint(color);
+
+            if current.as_ref() != Some(&style) {
+                if current.is_some() {
+                    self.body.push_str("\"/>");
+                }
+
+                let _ = write!(
+                    self.body,
+                    "<path fill=\"{}\"{} d=\"",
+                    style.0, style.1
+                );
+
+                current = Some(style);
+            }
+
+            for (i, vertex) in vertices.enumerate() {
+                let [x, y] = vertex.position;
+
+                let _ = write!(
+                    self.body,
+                    "{}{} {}",
+                    if i == 0 { "M" } else { "L" },
+                    number(x + translation.x),
+                    number(y + translation.y),
+                );
+            }
+
+            self.body.push('Z');
+        }
+
+        if current.is_some() {
+            self.body.push_str("\"/>");
+        }
+    }
+}
+
+/// Returns the SVG color and opacity attribute of a linear RGBA color.
+fn paint(linear: [f32; 4]) -> (String, String) {
+    // As described in:
+    // https://en.wikipedia.org/wiki/SRGB#The_forward_transformation
+    fn srgb_component(u: f32) -> u8 {
+        let u = if u <= 0.0031308 {
+            u * 12.92
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        };
+
+        (u.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
+    let [r, g, b, a] = linear;
+
+    let fill = format!(
+        "#{:02x}{:02x}{:02x}",
+        srgb_component(r),
+        srgb_component(g),
+        srgb_component(b)
+    );
+
+    let opacity = if a < 1.0 {
+        format!(" fill-opacity=\"{}\"", number(a.max(0.0)))
+    } else {
+        String::new()
+    };
+
+    (fill, opacity)
+}
+
+/// Returns the CSS font family of a [`Font`].
+fn family(font: Font) -> String {
+    match font {
+        Font::Default => String::from("sans-serif"),
+        Font::External { name, .. } => format!("'{}', sans-serif", name),
+    }
+}
+
+/// Returns an image as a base64 data URI, if it can be read.
+fn data_uri(handle: &image::Handle) -> Option<String> {
+    let bytes = match handle.data() {
+        image::Data::Path(path) => std::fs::read(path).ok()?,
+        image::Data::Bytes(bytes) => bytes.clone(),
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => encode_png(*width, *height, pixels)?,
+    };
+
+    let mime = if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(&[0xff, 0xd8]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else {
+        return None;
+    };
+
+    Some(format!("data:{};base64,{}", mime, base64::encode(bytes)))
+}
+
+/// Encodes BGRA pixels as a PNG image.
+fn encode_png(width: u32, height: u32, pixels: &[u8]) -> Option<Vec<u8>> {
+    let rgba: Vec<u8> = pixels
+        .chunks_exact(4)
+        .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+        .collect();
+
+    let mut bytes = Vec::new();
+
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(png::ColorType::RGBA);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().ok()?;
+        writer.write_image_data(&rgba).ok()?;
+    }
+
+    Some(bytes)
+}
+
+/// Formats a number with up to three decimals.
+fn number(value: f32) -> String {
+    let formatted = format!("{:.3}", value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    if formatted == "-0" {
+        String::from("0")
+    } else {
+        String::from(formatted)
+    }
+}
+
+/// Escapes the characters of some text that are special in XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::canvas::{Frame, Path, Text};
+    use crate::Color;
+    use iced_native::Point;
+
+    #[test]
+    fn exports_fills_and_text() {
+        let mut frame = Frame::new(Size::new(100.0, 50.0));
+
+        frame.fill(
+            &Path::rectangle(Point::new(10.0, 10.0), Size::new(20.0, 20.0)),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+
+        frame.fill_text(Text {
+            content: String::from("Sales & <costs>\n2022"),
+            position: Point::new(50.0, 25.0),
+            vertical_alignment: alignment::Vertical::Center,
+            ..Text::default()
+        });
+
+        let document = svg(frame.size(), &[frame.into_geometry()]);
+
+        assert!(document.starts_with("<svg "));
+        assert!(document.contains("viewBox=\"0 0 100 50\""));
+        assert!(document.contains("<path fill=\"#ff0000\" d=\"M"));
+        assert!(document.contains("dominant-baseline=\"central\""));
+        assert!(document.contains(
+            "<tspan x=\"50\" dy=\"-0.6em\">Sales &amp; &lt;costs&gt;</tspan>"
+        ));
+        assert!(document.contains("<tspan x=\"50\" dy=\"1.2em\">2022</tspan>"));
+    }
+
+    #[test]
+    fn embeds_pixels_as_png() {
+        let handle = image::Handle::from_pixels(1, 1, vec![0, 0, 255, 255]);
+        let uri = data_uri(&handle).expect("Encode image");
+
+        assert!(uri.starts_with("data:image/png;base64,iVBORw0KGgo"));
+    }
+
+    #[test]
+    fn numbers_are_short() {
+        assert_eq!(number(10.0), "10");
+        assert_eq!(number(0.125), "0.125");
+        assert_eq!(number(-0.0001), "0");
+    }
+}
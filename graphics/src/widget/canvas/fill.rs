@@ -1,12 +1,14 @@
+use crate::canvas::Gradient;
+
 use iced_native::Color;
 
 /// The style used to fill geometry.
 #[derive(Debug, Clone, Copy)]
-pub struct Fill {
-    /// The color used to fill geometry.
+pub struct Fill<'a> {
+    /// The color, or [`Gradient`], used to fill geometry.
     ///
     /// By default, it is set to `BLACK`.
-    pub color: Color,
+    pub style: Style<'a>,
 
     /// The fill rule defines how to determine what is inside and what is
     /// outside of a shape.
@@ -19,24 +21,43 @@ pub struct Fill {
     pub rule: FillRule,
 }
 
-impl Default for Fill {
-    fn default() -> Fill {
+impl<'a> Default for Fill<'a> {
+    fn default() -> Fill<'a> {
         Fill {
-            color: Color::BLACK,
+            style: Style::Solid(Color::BLACK),
             rule: FillRule::NonZero,
         }
     }
 }
 
-impl From<Color> for Fill {
-    fn from(color: Color) -> Fill {
+impl<'a> From<Color> for Fill<'a> {
+    fn from(color: Color) -> Fill<'a> {
         Fill {
-            color,
+            style: Style::Solid(color),
             ..Fill::default()
         }
     }
 }
 
+impl<'a> From<&'a Gradient> for Fill<'a> {
+    fn from(gradient: &'a Gradient) -> Fill<'a> {
+        Fill {
+            style: Style::Gradient(gradient),
+            ..Fill::default()
+        }
+    }
+}
+
+/// The coloring style of some geometry.
+#[derive(Debug, Clone, Copy)]
+pub enum Style<'a> {
+    /// A solid color.
+    Solid(Color),
+
+    /// A [`Gradient`].
+    Gradient(&'a Gradient),
+}
+
 /// The fill rule defines how to determine what is inside and what is outside of
 /// a shape.
 ///
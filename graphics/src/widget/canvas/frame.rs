@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use iced_native::image;
 use iced_native::{Point, Rectangle, Size, Vector};
 
 use crate::{
     canvas::path,
-    canvas::{Fill, Geometry, Path, Stroke, Text},
+    canvas::text,
+    canvas::{Fill, Geometry, Path, Stroke, Style, Text},
     triangle, Primitive,
 };
 
@@ -16,8 +18,11 @@ use lyon::tessellation;
 #[allow(missing_debug_implementations)]
 pub struct Frame {
     size: Size,
+    clip: Size,
     buffers: lyon::tessellation::VertexBuffers<triangle::Vertex2D, u32>,
     primitives: Vec<Primitive>,
+    segments: Vec<Vec<Primitive>>,
+    has_images: bool,
     transforms: Transforms,
     fill_tessellator: tessellation::FillTessellator,
     stroke_tessellator: tessellation::StrokeTessellator,
@@ -43,8 +48,11 @@ impl Frame {
     pub fn new(size: Size) -> Frame {
         Frame {
             size,
+            clip: size,
             buffers: lyon::tessellation::VertexBuffers::new(),
             primitives: Vec::new(),
+            segments: Vec::new(),
+            has_images: false,
             transforms: Transforms {
                 previous: Vec::new(),
                 current: Transform {
@@ -83,50 +91,38 @@ impl Frame {
 
     /// Draws the given [`Path`] on the [`Frame`] by filling it with the
     /// provided style.
-    pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
-        let Fill { color, rule } = fill.into();
+    pub fn fill<'a>(&mut self, path: &Path, fill: impl Into<Fill<'a>>) {
+        self.split();
 
-        let mut buffers = tessellation::BuffersBuilder::new(
-            &mut self.buffers,
-            FillVertex(color.into_linear()),
-        );
+        let Fill { style, rule } = fill.into();
 
         let options =
             tessellation::FillOptions::default().with_fill_rule(rule.into());
 
-        let result = if self.transforms.current.is_identity {
-            self.fill_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
+        let path = if self.transforms.current.is_identity {
+            Cow::Borrowed(path)
         } else {
-            let path = path.transformed(&self.transforms.current.raw);
-
-            self.fill_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
+            Cow::Owned(path.transformed(&self.transforms.current.raw))
         };
 
+        let result = self.fill_with(style, |tessellator, buffers| {
+            tessellator.tessellate_path(path.raw(), &options, buffers)
+        });
+
         let _ = result.expect("Tessellate path");
     }
 
     /// Draws an axis-aligned rectangle given its top-left corner coordinate and
     /// its `Size` on the [`Frame`] by filling it with the provided style.
-    pub fn fill_rectangle(
+    pub fn fill_rectangle<'a>(
         &mut self,
         top_left: Point,
         size: Size,
-        fill: impl Into<Fill>,
+        fill: impl Into<Fill<'a>>,
     ) {
-        let Fill { color, rule } = fill.into();
+        self.split();
 
-        let mut buffers = tessellation::BuffersBuilder::new(
-            &mut self.buffers,
-            FillVertex(color.into_linear()),
-        );
+        let Fill { style, rule } = fill.into();
 
         let top_left =
             self.transforms.current.raw.transform_point(
@@ -141,19 +137,67 @@ impl Frame {
         let options =
             tessellation::FillOptions::default().with_fill_rule(rule.into());
 
-        let _ = self
-            .fill_tessellator
-            .tessellate_rectangle(
+        let result = self.fill_with(style, |tessellator, buffers| {
+            tessellator.tessellate_rectangle(
                 &lyon::math::Rect::new(top_left, size.into()),
                 &options,
-                &mut buffers,
+                buffers,
             )
-            .expect("Fill rectangle");
+        });
+
+        let _ = result.expect("Fill rectangle");
+    }
+
+    /// Runs the given fill tessellation, coloring its output with the given
+    /// [`Style`].
+    fn fill_with(
+        &mut self,
+        style: Style<'_>,
+        tessellate: impl FnOnce(
+            &mut tessellation::FillTessellator,
+            &mut dyn tessellation::FillGeometryBuilder,
+        ) -> tessellation::TessellationResult,
+    ) -> tessellation::TessellationResult {
+        match style {
+            Style::Solid(color) => {
+                let mut buffers = tessellation::BuffersBuilder::new(
+                    &mut self.buffers,
+                    FillVertex(color.into_linear()),
+                );
+
+                tessellate(&mut self.fill_tessellator, &mut buffers)
+            }
+            Style::Gradient(gradient) => {
+                let mut geometry = tessellation::VertexBuffers::new();
+
+                let result = tessellate(
+                    &mut self.fill_tessellator,
+                    &mut tessellation::BuffersBuilder::new(
+                        &mut geometry,
+                        PositionVertex,
+                    ),
+                );
+
+                // The gradient is in the same coordinate system as the
+                // geometry, which is transformed before tessellation
+                if self.transforms.current.is_identity {
+                    gradient.paint(&geometry, &mut self.buffers);
+                } else {
+                    gradient
+                        .transformed(&self.transforms.current.raw)
+                        .paint(&geometry, &mut self.buffers);
+                }
+
+                result
+            }
+        }
     }
 
     /// Draws the stroke of the given [`Path`] on the [`Frame`] with the
     /// provided style.
     pub fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        self.split();
+
         let stroke = stroke.into();
 
         let mut buffers = tessellation::BuffersBuilder::new(
@@ -195,16 +239,16 @@ impl Frame {
     /// Draws the characters of the given [`Text`] on the [`Frame`], filling
     /// them with the given color.
     ///
-    /// __Warning:__ Text currently does not work well with rotations and scale
-    /// transforms! The position will be correctly transformed, but the
-    /// resulting glyphs will not be rotated or scaled properly.
+    /// The text is aligned around its position, line by line, and follows
+    /// the current transform of the [`Frame`], so it is translated, rotated
+    /// and scaled like any other geometry.
     ///
-    /// Additionally, all text will be rendered on top of all the layers of
+    /// __Warning:__ All text will be rendered on top of all the layers of
     /// a [`Canvas`]. Therefore, it is currently only meant to be used for
     /// overlays, which is the most common use case.
     ///
-    /// Support for vectorial text is planned, and should address all these
-    /// limitations.
+    /// Support for vectorial text is planned, and should address this
+    /// limitation.
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub fn fill_text(&mut self, text: impl Into<Text>) {
@@ -212,19 +256,33 @@ impl Frame {
 
         let text = text.into();
 
-        let position = if self.transforms.current.is_identity {
-            text.position
-        } else {
-            let transformed = self.transforms.current.raw.transform_point(
-                lyon::math::Point::new(text.position.x, text.position.y),
-            );
+        let (position, size, rotation) =
+            if self.transforms.current.is_identity {
+                (text.position, text.size, text.rotation.radians())
+            } else {
+                let transform = &self.transforms.current.raw;
 
-            Point::new(transformed.x, transformed.y)
-        };
+                let position = transform.transform_point(
+                    lyon::math::Point::new(text.position.x, text.position.y),
+                );
+
+                // The transform of a frame only rotates and scales uniformly,
+                // so the image of the x axis is enough to find both
+                let axis = transform
+                    .transform_vector(lyon::math::Vector::new(1.0, 0.0));
+
+                (
+                    Point::new(position.x, position.y),
+                    text.size * axis.length(),
+                    text.rotation.radians() + axis.y.atan2(axis.x),
+                )
+            };
 
         // TODO: Use vectorial text instead of primitive
         self.primitives.push(Primitive::Text {
-            content: text.content,
+            content: iced_native::text::bidi::Visual::new(&text.content)
+                .into_content()
+                .into_owned(),
             bounds: Rectangle {
                 x: position.x,
                 y: position.y,
@@ -232,13 +290,77 @@ impl Frame {
                 height: f32::INFINITY,
             },
             color: text.color,
-            size: text.size,
+            size,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            spacing: iced_native::text::Spacing::default(),
+            rotation,
         });
     }
 
+    /// Measures the size of the given [`Text`] once laid out, exactly like
+    /// [`fill_text`] would draw it.
+    ///
+    /// The size is measured in the coordinates of the [`Frame`], along the
+    /// axes of the text, so it ignores both the current transform and the
+    /// rotation of the [`Text`].
+    ///
+    /// Text can only be measured while a [`Canvas`] is drawing its
+    /// [`Program`]; otherwise, its size is zero.
+    ///
+    /// [`fill_text`]: Self::fill_text
+    /// [`Canvas`]: crate::widget::Canvas
+    /// [`Program`]: crate::widget::canvas::Program
+    pub fn measure_text(&self, text: &Text) -> Size {
+        text::measure(text)
+    }
+
+    /// Draws the raster image of the given [`image::Handle`] on the [`Frame`],
+    /// stretched to fill the given bounds.
+    ///
+    /// The image keeps its place among the other drawings of the [`Frame`],
+    /// so geometry drawn afterwards covers it. Any part of it outside of the
+    /// [`Frame`] is clipped.
+    ///
+    /// __Warning:__ Images do not support rotations yet! The center of the
+    /// bounds is correctly transformed, but the image stays upright.
+    pub fn draw_image(&mut self, handle: image::Handle, bounds: Rectangle) {
+        let bounds = if self.transforms.current.is_identity {
+            bounds
+        } else {
+            let transform = &self.transforms.current.raw;
+
+            let center = bounds.center();
+            let center = transform
+                .transform_point(lyon::math::Point::new(center.x, center.y));
+
+            let scale = transform
+                .transform_vector(lyon::math::Vector::new(1.0, 0.0))
+                .length();
+
+            let size = Size::new(bounds.width * scale, bounds.height * scale);
+
+            Rectangle {
+                x: center.x - size.width / 2.0,
+                y: center.y - size.height / 2.0,
+                width: size.width,
+                height: size.height,
+            }
+        };
+
+        self.primitives.push(Primitive::Image {
+            handle,
+            frame: 0,
+            filter_method: image::FilterMethod::default(),
+            opacity: 1.0,
+            region: None,
+            bounds,
+        });
+
+        self.has_images = true;
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -257,21 +379,75 @@ impl Frame {
     /// clipping any geometry that overflows its bounds. Any transformations
     /// performed are local to the provided closure.
     ///
+    /// The closure gets a [`Frame`] with the size of the region and its
+    /// origin at the top-left corner of it, which follows the current
+    /// transform. Strokes are clipped along their outline, and nested clips
+    /// only keep what is inside all of them.
+    ///
+    /// If the current transform rotates the region, the geometry is clipped
+    /// to the smallest rectangle around it, aligned with the axes of the
+    /// [`Canvas`].
+    ///
     /// This method is useful to perform drawing operations that need to be
     /// clipped.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
     #[inline]
     pub fn with_clip(&mut self, region: Rectangle, f: impl FnOnce(&mut Frame)) {
+        let transform = self
+            .transforms
+            .current
+            .raw
+            .pre_translate(lyon::math::Vector::new(region.x, region.y));
+
+        let corners = [
+            (0.0, 0.0),
+            (region.width, 0.0),
+            (0.0, region.height),
+            (region.width, region.height),
+        ]
+        .map(|(x, y)| transform.transform_point(lyon::math::Point::new(x, y)));
+
+        let (min, max) = corners
+            .iter()
+            .fold((corners[0], corners[0]), |(min, max), corner| {
+                (min.min(*corner), max.max(*corner))
+            });
+
+        // Nested clips are intersected with the region of the outer one
+        let clip = Rectangle::new(
+            Point::new(min.x, min.y),
+            Size::new(max.x - min.x, max.y - min.y),
+        )
+        .intersection(&Rectangle::with_size(self.clip))
+        .unwrap_or(Rectangle::new(Point::new(min.x, min.y), Size::ZERO));
+
         let mut frame = Frame::new(region.size());
 
+        // The frame draws relative to the clip, which is where it is placed
+        let raw =
+            transform.then_translate(lyon::math::Vector::new(-clip.x, -clip.y));
+
+        frame.clip = clip.size();
+        frame.transforms.current = Transform {
+            raw,
+            is_identity: raw == lyon::math::Transform::identity(),
+        };
+
         f(&mut frame);
 
+        let has_images = frame.has_images || !frame.segments.is_empty();
         let primitives = frame.into_primitives();
 
         let (text, meshes) = primitives
             .into_iter()
             .partition(|primitive| matches!(primitive, Primitive::Text { .. }));
 
-        let translation = Vector::new(region.x, region.y);
+        let translation = Vector::new(clip.x, clip.y);
+
+        // The clipped geometry goes after everything drawn before
+        self.split();
+        self.flush_mesh();
 
         self.primitives.push(Primitive::Group {
             primitives: vec![
@@ -282,7 +458,7 @@ impl Frame {
                 Primitive::Translate {
                     translation,
                     content: Box::new(Primitive::Clip {
-                        bounds: region,
+                        bounds: Rectangle::with_size(clip.size()),
                         content: Box::new(Primitive::Group {
                             primitives: text,
                         }),
@@ -290,6 +466,8 @@ impl Frame {
                 },
             ],
         });
+
+        self.has_images |= has_images;
     }
 
     /// Applies a translation to the current transform of the [`Frame`].
@@ -333,17 +511,53 @@ impl Frame {
     }
 
     fn into_primitives(mut self) -> Vec<Primitive> {
+        self.flush_mesh();
+
+        if self.segments.is_empty() && !self.has_images {
+            return self.primitives;
+        }
+
+        // Renderers draw the images of a layer on top of its meshes, so
+        // every segment gets its own layer to keep the order of the drawings
+        let bounds = Rectangle::with_size(self.clip);
+
+        self.segments.push(self.primitives);
+
+        self.segments
+            .into_iter()
+            .map(|primitives| Primitive::Clip {
+                bounds,
+                content: Box::new(Primitive::Group { primitives }),
+            })
+            .collect()
+    }
+
+    /// Turns the tessellated geometry drawn so far into a mesh.
+    fn flush_mesh(&mut self) {
         if !self.buffers.indices.is_empty() {
+            let buffers = std::mem::replace(
+                &mut self.buffers,
+                lyon::tessellation::VertexBuffers::new(),
+            );
+
             self.primitives.push(Primitive::Mesh2D {
                 buffers: triangle::Mesh2D {
-                    vertices: self.buffers.vertices,
-                    indices: self.buffers.indices,
+                    vertices: buffers.vertices,
+                    indices: buffers.indices,
                 },
-                size: self.size,
+                size: self.clip,
             });
         }
+    }
 
-        self.primitives
+    /// Starts a new segment of drawings if there are images in the current
+    /// one, so the drawings that follow are not covered by them.
+    fn split(&mut self) {
+        if self.has_images {
+            self.flush_mesh();
+            self.segments.push(std::mem::take(&mut self.primitives));
+            self.has_images = false;
+        }
     }
 }
 
@@ -365,6 +579,19 @@ impl lyon::tessellation::FillVertexConstructor<triangle::Vertex2D>
     }
 }
 
+struct PositionVertex;
+
+impl lyon::tessellation::FillVertexConstructor<Point> for PositionVertex {
+    fn new_vertex(
+        &mut self,
+        vertex: lyon::tessellation::FillVertex<'_>,
+    ) -> Point {
+        let position = vertex.position();
+
+        Point::new(position.x, position.y)
+    }
+}
+
 struct StrokeVertex([f32; 4]);
 
 impl lyon::tessellation::StrokeVertexConstructor<triangle::Vertex2D>
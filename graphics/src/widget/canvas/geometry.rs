@@ -15,6 +15,10 @@ impl Geometry {
         Self(primitive)
     }
 
+    pub(crate) fn as_primitive(&self) -> &Primitive {
+        &self.0
+    }
+
     /// Turns the [`Geometry`] into a [`Primitive`].
     ///
     /// This can be useful if you are building a custom widget.
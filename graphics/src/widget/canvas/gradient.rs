@@ -0,0 +1,430 @@
+use crate::triangle;
+
+use iced_native::{Color, Point};
+
+/// A smooth transition between colors, used to fill geometry.
+///
+/// The colors of a [`Gradient`] are interpolated in linear RGB, like the
+/// rest of the geometry of a [`Canvas`]. Before its first stop and after its
+/// last one, it keeps the color of that stop.
+///
+/// [`Canvas`]: crate::widget::Canvas
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    kind: Kind,
+    stops: Vec<ColorStop>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Linear { start: Point, end: Point },
+    Radial { center: Point, radius: f32 },
+}
+
+/// A color at some offset along a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the [`ColorStop`], from `0.0` to `1.0`.
+    pub offset: f32,
+
+    /// The color of the [`ColorStop`].
+    pub color: Color,
+}
+
+impl Gradient {
+    /// Creates a new linear [`Gradient`] going from the `start` point, at
+    /// offset `0.0`, to the `end` point, at offset `1.0`.
+    pub fn linear(start: Point, end: Point) -> Self {
+        Gradient {
+            kind: Kind::Linear { start, end },
+            stops: Vec::new(),
+        }
+    }
+
+    /// Creates a new radial [`Gradient`] going from its `center`, at offset
+    /// `0.0`, to the circle of the given `radius`, at offset `1.0`.
+    pub fn radial(center: Point, radius: f32) -> Self {
+        Gradient {
+            kind: Kind::Radial { center, radius },
+            stops: Vec::new(),
+        }
+    }
+
+    /// Adds a [`ColorStop`] to the [`Gradient`].
+    ///
+    /// Stops are kept sorted by their offset, so they can be added in any
+    /// order. Stops with the same offset make a sharp transition, in the
+    /// order they were added.
+    ///
+    /// # Panics
+    /// Panics if the offset is not between `0.0` and `1.0`.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&offset),
+            "the offset of a color stop must be between 0.0 and 1.0, \
+            but it was {}",
+            offset
+        );
+
+        let index = self.stops.partition_point(|stop| stop.offset <= offset);
+
+        self.stops.insert(index, ColorStop { offset, color });
+        self
+    }
+
+    /// Returns the [`ColorStop`] list of the [`Gradient`], sorted by their
+    /// offset.
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Returns the [`Gradient`] with the given transform applied.
+    ///
+    /// The transforms of a [`Frame`] keep angles, so the scale of a radial
+    /// [`Gradient`] is the length of a transformed unit vector.
+    ///
+    /// [`Frame`]: crate::widget::canvas::Frame
+    pub(crate) fn transformed(
+        &self,
+        transform: &lyon::math::Transform,
+    ) -> Self {
+        let point = |point: Point| {
+            let point = transform
+                .transform_point(lyon::math::Point::new(point.x, point.y));
+
+            Point::new(point.x, point.y)
+        };
+
+        let kind = match self.kind {
+            Kind::Linear { start, end } => Kind::Linear {
+                start: point(start),
+                end: point(end),
+            },
+            Kind::Radial { center, radius } => Kind::Radial {
+                center: point(center),
+                radius: radius
+                    * transform
+                        .transform_vector(lyon::math::Vector::new(1.0, 0.0))
+                        .length(),
+            },
+        };
+
+        Gradient {
+            kind,
+            stops: self.stops.clone(),
+        }
+    }
+
+    /// Returns the offset along the [`Gradient`] at the given point.
+    fn offset(&self, point: Point) -> f32 {
+        match self.kind {
+            Kind::Linear { start, end } => {
+                let direction = end - start;
+                let length =
+                    direction.x * direction.x + direction.y * direction.y;
+
+                // A gradient without length is painted with its last stop
+                if length == 0.0 {
+                    return 1.0;
+                }
+
+                let from_start = point - start;
+
+                (from_start.x * direction.x + from_start.y * direction.y)
+                    / length
+            }
+            Kind::Radial { center, radius } => {
+                if radius <= 0.0 {
+                    return 1.0;
+                }
+
+                point.distance(center) / radius
+            }
+        }
+    }
+
+    /// Returns the color of the [`Gradient`] at the given offset, in linear
+    /// RGB.
+    fn color(&self, offset: f32) -> [f32; 4] {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Color::TRANSPARENT.into_linear(),
+        };
+
+        if offset <= first.offset {
+            return first.color.into_linear();
+        }
+
+        if offset >= last.offset {
+            return last.color.into_linear();
+        }
+
+        let next = self.stops.partition_point(|stop| stop.offset <= offset);
+        let (from, to) = (self.stops[next - 1], self.stops[next]);
+
+        let amount = (offset - from.offset) / (to.offset - from.offset);
+
+        let from = from.color.into_linear();
+        let to = to.color.into_linear();
+
+        [0, 1, 2, 3].map(|i| from[i] + (to[i] - from[i]) * amount)
+    }
+
+    /// Paints the triangles of the given tessellation with the [`Gradient`]
+    /// and adds them to the `buffers`.
+    ///
+    /// The triangles are sliced along every line where the gradient changes
+    /// direction, so interpolating the colors of their vertices gives the
+    /// exact colors of a linear [`Gradient`]. A radial [`Gradient`] is not
+    /// linear anywhere, so it is approximated by slicing the triangles along
+    /// a grid instead.
+    pub(crate) fn paint(
+        &self,
+        tessellation: &lyon::tessellation::VertexBuffers<Point, u32>,
+        buffers: &mut lyon::tessellation::VertexBuffers<
+            triangle::Vertex2D,
+            u32,
+        >,
+    ) {
+        let cell = match self.kind {
+            Kind::Linear { .. } => 0.0,
+            Kind::Radial { radius, .. } => {
+                grid_cell(radius, &tessellation.vertices)
+            }
+        };
+
+        let mut slices = Vec::new();
+
+        for triangle in tessellation.indices.chunks_exact(3) {
+            let polygon: Vec<Point> = triangle
+                .iter()
+                .map(|index| tessellation.vertices[*index as usize])
+                .collect();
+
+            slices.clear();
+
+            match self.kind {
+                Kind::Linear { .. } => {
+                    let offsets: Vec<f32> =
+                        self.stops.iter().map(|stop| stop.offset).collect();
+
+                    slice(
+                        polygon,
+                        |point| self.offset(point),
+                        &offsets,
+                        &mut slices,
+                    );
+                }
+                Kind::Radial { center, .. } => {
+                    let mut columns = Vec::new();
+
+                    let lines = grid(&polygon, |point| point.x, center.x, cell);
+                    slice(polygon, |point| point.x, &lines, &mut columns);
+
+                    for column in columns {
+                        let lines =
+                            grid(&column, |point| point.y, center.y, cell);
+
+                        slice(column, |point| point.y, &lines, &mut slices);
+                    }
+                }
+            }
+
+            for polygon in &slices {
+                let first = buffers.vertices.len() as u32;
+
+                buffers.vertices.extend(polygon.iter().map(|point| {
+                    triangle::Vertex2D {
+                        position: [point.x, point.y],
+                        color: self.color(self.offset(*point)),
+                    }
+                }));
+
+                for i in 1..polygon.len() as u32 - 1 {
+                    buffers.indices.extend([first, first + i, first + i + 1]);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the size of the cells of the grid used to approximate a radial
+/// gradient, which is never so small that the geometry gets too many
+/// triangles.
+fn grid_cell(radius: f32, vertices: &[Point]) -> f32 {
+    let (min, max) = vertices.iter().fold(
+        (Point::new(f32::INFINITY, f32::INFINITY), Point::ORIGIN),
+        |(min, max), vertex| {
+            (
+                Point::new(min.x.min(vertex.x), min.y.min(vertex.y)),
+                Point::new(max.x.max(vertex.x), max.y.max(vertex.y)),
+            )
+        },
+    );
+
+    let extent = (max.x - min.x).max(max.y - min.y);
+
+    (radius / 32.0).max(extent / 128.0).max(0.5)
+}
+
+/// Returns the lines of a grid with the given origin and cell size that
+/// cross a polygon, along the axis measured by `f`.
+///
+/// Every polygon is sliced along the same lines, so the triangles next to
+/// each other are split at the same points of their shared edges.
+fn grid(
+    polygon: &[Point],
+    f: impl Fn(Point) -> f32,
+    origin: f32,
+    cell: f32,
+) -> Vec<f32> {
+    let (min, max) = polygon
+        .iter()
+        .map(|point| f(*point))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+
+    let first = ((min - origin) / cell).ceil() as i64;
+    let last = ((max - origin) / cell).floor() as i64;
+
+    (first..=last).map(|i| origin + i as f32 * cell).collect()
+}
+
+/// Slices a convex polygon along the given sorted lines, where the affine
+/// function `f` takes their values.
+fn slice(
+    polygon: Vec<Point>,
+    f: impl Fn(Point) -> f32,
+    lines: &[f32],
+    slices: &mut Vec<Vec<Point>>,
+) {
+    let mut rest = polygon;
+
+    for line in lines {
+        let (below, above) = split(&rest, &f, *line);
+
+        if below.len() >= 3 {
+            slices.push(below);
+        }
+
+        if above.len() < 3 {
+            return;
+        }
+
+        rest = above;
+    }
+
+    slices.push(rest);
+}
+
+/// Splits a convex polygon in the parts below and above the line where the
+/// affine function `f` takes the given value.
+fn split(
+    polygon: &[Point],
+    f: impl Fn(Point) -> f32,
+    line: f32,
+) -> (Vec<Point>, Vec<Point>) {
+    let mut below = Vec::with_capacity(polygon.len() + 1);
+    let mut above = Vec::with_capacity(polygon.len() + 1);
+
+    for (i, p) in polygon.iter().enumerate() {
+        let q = polygon[(i + 1) % polygon.len()];
+
+        let fp = f(*p) - line;
+        let fq = f(q) - line;
+
+        if fp <= 0.0 {
+            below.push(*p);
+        }
+
+        if fp >= 0.0 {
+            above.push(*p);
+        }
+
+        if (fp < 0.0 && fq > 0.0) || (fp > 0.0 && fq < 0.0) {
+            let crossing = crossing((*p, fp), (q, fq));
+
+            below.push(crossing);
+            above.push(crossing);
+        }
+    }
+
+    (below, above)
+}
+
+/// Returns the point where an edge crosses a line, given the signed
+/// distances of its endpoints to it.
+///
+/// The endpoints are sorted first, so an edge shared by two triangles is
+/// crossed at the very same point in both of them.
+fn crossing(a: (Point, f32), b: (Point, f32)) -> Point {
+    let ((p, fp), (q, fq)) = if (a.0.x, a.0.y) <= (b.0.x, b.0.y) {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let t = fp / (fp - fq);
+
+    Point::new(p.x + (q.x - p.x) * t, p.y + (q.y - p.y) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_are_sorted() {
+        let gradient = Gradient::linear(Point::ORIGIN, Point::new(1.0, 0.0))
+            .add_stop(1.0, Color::WHITE)
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(0.5, Color::TRANSPARENT);
+
+        let offsets: Vec<f32> =
+            gradient.stops().iter().map(|stop| stop.offset).collect();
+
+        assert_eq!(offsets, [0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "between 0.0 and 1.0")]
+    fn stops_out_of_range_panic() {
+        let _ =
+            Gradient::radial(Point::ORIGIN, 1.0).add_stop(1.5, Color::BLACK);
+    }
+
+    #[test]
+    fn linear_gradient_is_exact() {
+        let gradient = Gradient::linear(Point::ORIGIN, Point::new(10.0, 0.0))
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(0.5, Color::WHITE)
+            .add_stop(1.0, Color::BLACK);
+
+        let tessellation = lyon::tessellation::VertexBuffers {
+            vertices: vec![
+                Point::ORIGIN,
+                Point::new(10.0, 0.0),
+                Point::new(0.0, 10.0),
+            ],
+            indices: vec![0, 1, 2],
+        };
+
+        let mut buffers = lyon::tessellation::VertexBuffers::new();
+        gradient.paint(&tessellation, &mut buffers);
+
+        // The triangle is split where the gradient turns back
+        assert!(buffers.vertices.iter().any(|vertex| {
+            vertex.position == [5.0, 0.0] && vertex.color == [1.0; 4]
+        }));
+
+        assert!(buffers.vertices.iter().all(|vertex| {
+            let expected = gradient.color(
+                gradient
+                    .offset(Point::new(vertex.position[0], vertex.position[1])),
+            );
+
+            vertex.color == expected
+        }));
+    }
+}
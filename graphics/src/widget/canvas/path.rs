@@ -7,10 +7,9 @@ mod builder;
 pub use arc::Arc;
 pub use builder::Builder;
 
-use crate::canvas::LineDash;
+use crate::canvas::{FillRule, LineDash};
 
-use iced_native::{Point, Size};
-use lyon::algorithms::walk::{walk_along_path, RepeatedPattern};
+use iced_native::{Point, Rectangle, Size, Vector};
 use lyon::path::iterator::PathIterator;
 
 /// An immutable set of points that may or may not be connected.
@@ -55,6 +54,124 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Creates a new [`Path`] representing a rectangle with rounded corners
+    /// given its top-left corner coordinate, its `Size`, and the [`Radii`] of
+    /// its corners.
+    ///
+    /// Radii larger than half of the smallest side of the rectangle are
+    /// clamped to it.
+    pub fn rounded_rectangle(
+        top_left: Point,
+        size: Size,
+        radii: impl Into<Radii>,
+    ) -> Self {
+        Self::new(|p| p.rounded_rectangle(top_left, size, radii))
+    }
+
+    /// Creates a new [`Path`] representing an ellipse given its center
+    /// coordinate, the radii of its axes, and its rotation in radians.
+    pub fn ellipse(center: Point, radii: Vector, rotation: f32) -> Self {
+        Self::new(|p| {
+            p.ellipse(arc::Elliptical {
+                center,
+                radii,
+                rotation,
+                start_angle: 0.0,
+                end_angle: 2.0 * std::f32::consts::PI,
+            });
+            p.close();
+        })
+    }
+
+    /// Creates a new [`Path`] representing a pie slice given the center
+    /// coordinate and the radius of its circle, going from `start_angle` to
+    /// `end_angle` in a clockwise direction.
+    pub fn pie(
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+    ) -> Self {
+        Self::new(|p| p.pie(center, radius, start_angle, end_angle))
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the whole [`Path`],
+    /// including its curves.
+    ///
+    /// It can be used as a cheap check before [`contains`] or
+    /// [`distance_to`].
+    ///
+    /// [`contains`]: Self::contains
+    /// [`distance_to`]: Self::distance_to
+    pub fn bounding_box(&self) -> Rectangle {
+        let rect = lyon::algorithms::aabb::bounding_rect(self.raw.iter());
+
+        Rectangle {
+            x: rect.origin.x,
+            y: rect.origin.y,
+            width: rect.size.width,
+            height: rect.size.height,
+        }
+    }
+
+    /// Returns true if the given [`Point`] is inside the [`Path`] when it is
+    /// filled with the given [`FillRule`].
+    ///
+    /// Like when filling, every subpath is considered closed. Points on an
+    /// edge of the [`Path`] are inside of it.
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        let mut winding = 0;
+
+        for (from, to) in self.edges(true) {
+            if distance_to_line(point, from, to) <= TOLERANCE {
+                return true;
+            }
+
+            winding += crossing(point, from, to);
+        }
+
+        match rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the distance between the given [`Point`] and the closest
+    /// point of the outline of the [`Path`], as it would be stroked.
+    ///
+    /// It can be compared with the width of a stroke to hit test it. The
+    /// distance is infinite for a [`Path`] without any lines.
+    pub fn distance_to(&self, point: Point) -> f32 {
+        self.edges(false)
+            .map(|(from, to)| distance_to_line(point, from, to))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Returns the lines of the [`Path`], with its curves flattened.
+    ///
+    /// Subpaths that are not closed get a closing line if `close_all` is
+    /// true.
+    fn edges(
+        &self,
+        close_all: bool,
+    ) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .filter_map(move |event| match event {
+                lyon::path::PathEvent::Line { from, to } => Some((from, to)),
+                lyon::path::PathEvent::End { last, first, close }
+                    if close || close_all =>
+                {
+                    Some((last, first))
+                }
+                _ => None,
+            })
+            .map(|(from, to)| {
+                (Point::new(from.x, from.y), Point::new(to.x, to.y))
+            })
+    }
+
     #[inline]
     pub(crate) fn raw(&self) -> &lyon::path::Path {
         &self.raw
@@ -71,42 +188,421 @@ impl Path {
     }
 }
 
+/// The radius of every corner of a rounded rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Radii {
+    /// The radius of the top-left corner.
+    pub top_left: f32,
+    /// The radius of the top-right corner.
+    pub top_right: f32,
+    /// The radius of the bottom-right corner.
+    pub bottom_right: f32,
+    /// The radius of the bottom-left corner.
+    pub bottom_left: f32,
+}
+
+impl From<f32> for Radii {
+    fn from(radius: f32) -> Self {
+        Radii {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+impl From<[f32; 4]> for Radii {
+    /// Creates the [`Radii`] of the corners in clockwise order, starting from
+    /// the top-left one.
+    fn from(
+        [top_left, top_right, bottom_right, bottom_left]: [f32; 4],
+    ) -> Self {
+        Radii {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
+
+/// The maximum distance between a curve and the lines it is flattened into.
+const TOLERANCE: f32 = 0.01;
+
+/// Returns the distance between a [`Point`] and the line between two others.
+fn distance_to_line(point: Point, from: Point, to: Point) -> f32 {
+    let line = to - from;
+    let length_squared = line.x * line.x + line.y * line.y;
+
+    if length_squared <= 0.0 {
+        return point.distance(from);
+    }
+
+    let offset = point - from;
+    let t = ((offset.x * line.x + offset.y * line.y) / length_squared)
+        .clamp(0.0, 1.0);
+
+    point.distance(from + line * t)
+}
+
+/// Returns how the line between two points winds around a [`Point`]: `1`
+/// or `-1`, depending on its direction, if it crosses the horizontal ray to
+/// the right of the [`Point`], and `0` otherwise.
+fn crossing(point: Point, from: Point, to: Point) -> i32 {
+    let side = (to.x - from.x) * (point.y - from.y)
+        - (point.x - from.x) * (to.y - from.y);
+
+    if from.y <= point.y {
+        if to.y > point.y && side > 0.0 {
+            1
+        } else {
+            0
+        }
+    } else if to.y <= point.y && side < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Splits a [`Path`] into the dashes of the given [`LineDash`].
+///
+/// The pattern follows the arc length of every subpath, restarting at the
+/// beginning of each of them, and dashes keep the shape of the curves and
+/// corners they span.
+///
+/// Negative or invalid lengths in the pattern are treated as zero, and a
+/// pattern without any visible length leaves the [`Path`] solid.
 pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
+    let mut segments: Vec<f32> = line_dash
+        .segments
+        .iter()
+        .map(|segment| {
+            if segment.is_finite() {
+                segment.max(0.0)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    // An odd pattern alternates between lines and gaps every repetition
+    if segments.len() % 2 == 1 {
+        segments.extend_from_within(..);
+    }
+
+    let total: f32 = segments.iter().sum();
+
+    // Patterns shorter than the flattening tolerance would be invisible, and
+    // too short to make progress along long lines
+    if !(total >= TOLERANCE && total.is_finite()) {
+        return path.clone();
+    }
+
+    let offset = if line_dash.offset.is_finite() {
+        line_dash.offset.rem_euclid(total)
+    } else {
+        0.0
+    };
+
+    let mut dashes = Dashes {
+        start: Dashes::start(&segments, offset),
+        segments: &segments,
+        index: 0,
+        remaining: 0.0,
+    };
+
     Path::new(|builder| {
-        let segments_odd = (line_dash.segments.len() % 2 == 1).then(|| {
-            [&line_dash.segments[..], &line_dash.segments[..]].concat()
+        for event in path.raw().iter().flattened(TOLERANCE) {
+            match event {
+                lyon::path::PathEvent::Begin { at } => {
+                    dashes.begin(builder, Point::new(at.x, at.y));
+                }
+                lyon::path::PathEvent::Line { from, to } => {
+                    dashes.walk(
+                        builder,
+                        Point::new(from.x, from.y),
+                        Point::new(to.x, to.y),
+                    );
+                }
+                lyon::path::PathEvent::End {
+                    last,
+                    first,
+                    close: true,
+                } => {
+                    dashes.walk(
+                        builder,
+                        Point::new(last.x, last.y),
+                        Point::new(first.x, first.y),
+                    );
+                }
+                _ => {}
+            }
+        }
+    })
+}
+
+/// The progress along a dash pattern while walking a [`Path`].
+struct Dashes<'a> {
+    segments: &'a [f32],
+    start: (usize, f32),
+    index: usize,
+    remaining: f32,
+}
+
+impl<'a> Dashes<'a> {
+    /// Returns the index and the length left of the segment where a pattern
+    /// starts at the given offset.
+    fn start(segments: &[f32], offset: f32) -> (usize, f32) {
+        let mut index = 0;
+        let mut left = offset;
+
+        while left >= segments[index] && index < segments.len() - 1 {
+            left -= segments[index];
+            index += 1;
+        }
+
+        (index, segments[index] - left)
+    }
+
+    fn is_line(&self) -> bool {
+        self.index.is_multiple_of(2)
+    }
+
+    /// Restarts the pattern at the beginning of a subpath.
+    fn begin(&mut self, builder: &mut Builder, at: Point) {
+        let (index, remaining) = self.start;
+
+        self.index = index;
+        self.remaining = remaining;
+
+        if self.is_line() {
+            builder.move_to(at);
+        }
+    }
+
+    /// Walks the pattern along a line, adding the dashes it covers.
+    fn walk(&mut self, builder: &mut Builder, from: Point, to: Point) {
+        let mut from = from;
+        let mut length = from.distance(to);
+
+        if length <= 0.0 {
+            return;
+        }
+
+        let direction = (to - from) * (1.0 / length);
+
+        // A dash ending right at the end of the line is finished by the next
+        // one, so the end of a path never gets an empty dash
+        while length > self.remaining {
+            let point = from + direction * self.remaining;
+
+            if self.is_line() {
+                builder.line_to(point);
+            } else {
+                builder.move_to(point);
+            }
+
+            length -= self.remaining;
+            from = point;
+
+            self.index = (self.index + 1) % self.segments.len();
+            self.remaining = self.segments[self.index];
+        }
+
+        self.remaining -= length;
+
+        if self.is_line() {
+            builder.line_to(to);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A "C" shape, open to the right.
+    fn concave() -> Path {
+        Path::new(|p| {
+            p.move_to(Point::new(0.0, 0.0));
+            p.line_to(Point::new(30.0, 0.0));
+            p.line_to(Point::new(30.0, 10.0));
+            p.line_to(Point::new(10.0, 10.0));
+            p.line_to(Point::new(10.0, 20.0));
+            p.line_to(Point::new(30.0, 20.0));
+            p.line_to(Point::new(30.0, 30.0));
+            p.line_to(Point::new(0.0, 30.0));
+            p.close();
+        })
+    }
+
+    /// Two squares wound in the same direction, one inside the other.
+    fn nested() -> Path {
+        Path::new(|p| {
+            p.rectangle(Point::new(0.0, 0.0), Size::new(30.0, 30.0));
+            p.rectangle(Point::new(10.0, 10.0), Size::new(10.0, 10.0));
+        })
+    }
+
+    #[test]
+    fn concave_polygon() {
+        let path = concave();
+
+        assert!(path.contains(Point::new(5.0, 15.0), FillRule::NonZero));
+        assert!(path.contains(Point::new(20.0, 5.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(20.0, 15.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(40.0, 15.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn fill_rules() {
+        let path = nested();
+        let center = Point::new(15.0, 15.0);
+
+        assert!(path.contains(center, FillRule::NonZero));
+        assert!(!path.contains(center, FillRule::EvenOdd));
+
+        let between = Point::new(5.0, 15.0);
+
+        assert!(path.contains(between, FillRule::NonZero));
+        assert!(path.contains(between, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn points_on_edges_are_inside() {
+        let path = concave();
+
+        // On an outer edge, a notch edge, and a corner
+        assert!(path.contains(Point::new(0.0, 15.0), FillRule::EvenOdd));
+        assert!(path.contains(Point::new(20.0, 10.0), FillRule::EvenOdd));
+        assert!(path.contains(Point::new(30.0, 30.0), FillRule::EvenOdd));
+
+        assert_eq!(path.distance_to(Point::new(20.0, 10.0)), 0.0);
+    }
+
+    #[test]
+    fn distance_to_curves() {
+        let path = Path::circle(Point::new(0.0, 0.0), 10.0);
+
+        assert!((path.distance_to(Point::ORIGIN) - 10.0).abs() <= TOLERANCE);
+        assert!(
+            (path.distance_to(Point::new(0.0, 15.0)) - 5.0).abs() <= TOLERANCE
+        );
+        assert!(path.contains(Point::new(7.0, 7.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(7.5, 7.5), FillRule::NonZero));
+    }
+
+    #[test]
+    fn open_paths_are_only_closed_when_filled() {
+        let path = Path::new(|p| {
+            p.move_to(Point::new(0.0, 0.0));
+            p.line_to(Point::new(10.0, 0.0));
+            p.line_to(Point::new(10.0, 10.0));
         });
 
-        let mut draw_line = false;
-
-        walk_along_path(
-            path.raw().iter().flattened(0.01),
-            0.0,
-            &mut RepeatedPattern {
-                callback: |position: lyon::algorithms::math::Point,
-                           _tangent,
-                           _distance| {
-                    let point = Point {
-                        x: position.x,
-                        y: position.y,
-                    };
-
-                    if draw_line {
-                        builder.line_to(point);
-                    } else {
-                        builder.move_to(point);
-                    }
-
-                    draw_line = !draw_line;
-
-                    true
-                },
-                index: line_dash.offset,
-                intervals: segments_odd
-                    .as_ref()
-                    .map(Vec::as_slice)
-                    .unwrap_or(line_dash.segments),
-            },
+        assert!(path.contains(Point::new(7.0, 3.0), FillRule::NonZero));
+        assert_eq!(path.distance_to(Point::new(0.0, 10.0)), 10.0);
+    }
+
+    /// Returns the vertices of the flattened outline of a [`Path`].
+    fn vertices(path: &Path) -> Vec<Point> {
+        path.edges(false).map(|(from, _)| from).collect()
+    }
+
+    #[test]
+    fn rounded_rectangle_corners() {
+        let path = Path::rounded_rectangle(
+            Point::new(0.0, 0.0),
+            Size::new(40.0, 20.0),
+            [0.0, 5.0, 50.0, 2.0],
         );
-    })
+
+        let bounds = path.bounding_box();
+
+        assert!((bounds.width - 40.0).abs() <= TOLERANCE);
+        assert!((bounds.height - 20.0).abs() <= TOLERANCE);
+
+        // The top-left corner is sharp
+        assert!(path.contains(Point::new(0.1, 0.1), FillRule::NonZero));
+
+        // The top-right corner follows its circle
+        let (sin, cos) = std::f32::consts::FRAC_PI_4.sin_cos();
+        let on_arc = Point::new(35.0 + 5.0 * cos, 5.0 - 5.0 * sin);
+
+        assert!(path.distance_to(on_arc) <= TOLERANCE);
+        assert!(!path.contains(Point::new(39.5, 0.5), FillRule::NonZero));
+
+        // The bottom-right radius is clamped to half of the height
+        for vertex in vertices(&path) {
+            if vertex.x > 30.0 && vertex.y > 10.0 {
+                let distance = vertex.distance(Point::new(30.0, 10.0));
+
+                assert!((distance - 10.0).abs() <= TOLERANCE);
+            }
+        }
+    }
+
+    #[test]
+    fn ellipse_follows_its_equation() {
+        let center = Point::new(10.0, 20.0);
+        let rotation = std::f32::consts::FRAC_PI_6;
+        let path = Path::ellipse(center, Vector::new(30.0, 10.0), rotation);
+
+        let (sin, cos) = rotation.sin_cos();
+
+        for vertex in vertices(&path) {
+            let offset = vertex - center;
+
+            // Back to the axes of the ellipse
+            let x = offset.x * cos + offset.y * sin;
+            let y = offset.y * cos - offset.x * sin;
+
+            let value = (x / 30.0).powi(2) + (y / 10.0).powi(2);
+
+            assert!((value - 1.0).abs() <= 0.01, "{:?}: {}", vertex, value);
+        }
+
+        assert!(path.contains(center, FillRule::NonZero));
+    }
+
+    #[test]
+    fn pie_is_a_closed_wedge() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let center = Point::new(0.0, 0.0);
+        let path = Path::pie(center, 10.0, 0.0, FRAC_PI_2);
+
+        let vertices = vertices(&path);
+
+        assert_eq!(vertices[0], center);
+        assert!(vertices[1..]
+            .iter()
+            .all(|vertex| (vertex.distance(center) - 10.0).abs() <= TOLERANCE));
+
+        let bounds = path.bounding_box();
+
+        assert!((bounds.width - 10.0).abs() <= TOLERANCE);
+        assert!((bounds.height - 10.0).abs() <= TOLERANCE);
+
+        // Clockwise from the right, the slice covers the bottom-right quadrant
+        assert!(path.contains(Point::new(5.0, 5.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(5.0, -5.0), FillRule::NonZero));
+        assert!(path.distance_to(Point::new(5.0, 0.0)) <= TOLERANCE);
+
+        let half = Path::pie(center, 10.0, PI, 2.0 * PI);
+
+        assert!(half.contains(Point::new(0.0, -5.0), FillRule::NonZero));
+        assert!(!half.contains(Point::new(0.0, 5.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn bounding_box_includes_curves() {
+        let path = Path::circle(Point::new(5.0, 5.0), 5.0);
+        let bounds = path.bounding_box();
+
+        assert!((bounds.width - 10.0).abs() <= TOLERANCE);
+        assert!((bounds.x - 0.0).abs() <= TOLERANCE);
+    }
 }
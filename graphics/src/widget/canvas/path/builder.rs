@@ -1,4 +1,4 @@
-use crate::canvas::path::{arc, Arc, Path};
+use crate::canvas::path::{arc, Arc, Path, Radii};
 
 use iced_native::{Point, Size};
 use lyon::path::builder::SvgPathBuilder;
@@ -80,6 +80,62 @@ impl Builder {
         });
     }
 
+    /// Adds a circular arc to the current sub-path, going from `start_angle`
+    /// by `sweep_angle` in a clockwise direction, connected to the previous
+    /// point by a straight line, if necessary.
+    ///
+    /// The arc is made of one cubic Bézier curve per quarter of a turn, which
+    /// stay within 0.03% of the radius of the circle.
+    fn arc_segment(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+    ) {
+        use lyon::math;
+
+        let sweep_angle = sweep_angle
+            .clamp(-2.0 * std::f32::consts::PI, 2.0 * std::f32::consts::PI);
+
+        let point = |angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+
+            math::Point::new(center.x + radius * cos, center.y + radius * sin)
+        };
+
+        let tangent = |angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+
+            math::Vector::new(-radius * sin, radius * cos)
+        };
+
+        let start = point(start_angle);
+
+        if self.raw.current_position() != start {
+            let _ = self.raw.line_to(start);
+        }
+
+        let steps = (sweep_angle.abs() / std::f32::consts::FRAC_PI_2)
+            .ceil()
+            .max(1.0);
+        let step = sweep_angle / steps;
+
+        // The length of the control handles of a circular arc
+        let handle = 4.0 / 3.0 * (step / 4.0).tan();
+
+        for i in 0..steps as usize {
+            let from = start_angle + step * i as f32;
+            let to = from + step;
+
+            let _ = self.raw.cubic_bezier_to(
+                point(from) + tangent(from) * handle,
+                point(to) - tangent(to) * handle,
+                point(to),
+            );
+        }
+    }
+
     /// Adds a cubic Bézier curve to the [`Path`] given its two control points
     /// and its end point.
     #[inline]
@@ -124,6 +180,77 @@ impl Builder {
         self.close();
     }
 
+    /// Adds a rectangle with rounded corners to the [`Path`] given its
+    /// top-left corner coordinate, its `Size`, and the [`Radii`] of its
+    /// corners.
+    ///
+    /// Negative radii are treated as zero, and radii larger than half of the
+    /// smallest side of the rectangle are clamped to it.
+    pub fn rounded_rectangle(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        radii: impl Into<Radii>,
+    ) {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let radii = radii.into();
+        let max = size.width.min(size.height).max(0.0) / 2.0;
+        let clamp = |radius: f32| radius.max(0.0).min(max);
+
+        let (left, top) = (top_left.x, top_left.y);
+        let (right, bottom) = (left + size.width, top + size.height);
+
+        let top_left_radius = clamp(radii.top_left);
+
+        // Every corner with the angle where its arc ends, clockwise
+        let corners = [
+            (clamp(radii.top_right), right, top, 0.0),
+            (clamp(radii.bottom_right), right, bottom, FRAC_PI_2),
+            (clamp(radii.bottom_left), left, bottom, PI),
+            (top_left_radius, left, top, PI + FRAC_PI_2),
+        ];
+
+        self.move_to(Point::new(left + top_left_radius, top));
+
+        for (radius, x, y, end_angle) in corners {
+            if radius == 0.0 {
+                self.line_to(Point::new(x, y));
+                continue;
+            }
+
+            // The center of the corner, moved inwards from the rectangle
+            let center = Point::new(
+                if x == left { x + radius } else { x - radius },
+                if y == top { y + radius } else { y - radius },
+            );
+
+            self.arc_segment(center, radius, end_angle - FRAC_PI_2, FRAC_PI_2);
+        }
+
+        self.close();
+    }
+
+    /// Adds a pie slice to the [`Path`]: a wedge given the center coordinate
+    /// and the radius of its circle, going from `start_angle` to `end_angle`
+    /// in a clockwise direction.
+    ///
+    /// The wedge is closed, with straight lines between the center and the
+    /// ends of its arc.
+    pub fn pie(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+    ) {
+        self.move_to(center);
+
+        self.arc_segment(center, radius, start_angle, end_angle - start_angle);
+
+        self.close();
+    }
+
     /// Adds a circle to the [`Path`] given its center coordinate and its
     /// radius.
     #[inline]
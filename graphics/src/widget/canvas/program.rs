@@ -17,6 +17,11 @@ pub trait Program<Message> {
     /// This method can optionally return a `Message` to notify an application
     /// of any meaningful interactions.
     ///
+    /// Mouse events out of the bounds of the [`Canvas`] are delivered too,
+    /// but this [`Canvas`] has no state to capture the mouse. Use the pure
+    /// [`Canvas`](crate::widget::pure::Canvas) to keep the [`Cursor`]
+    /// available while dragging out of the window.
+    ///
     /// By default, this method does and returns nothing.
     ///
     /// [`Canvas`]: crate::widget::Canvas
@@ -108,11 +108,19 @@ impl From<LineJoin> for lyon::tessellation::LineJoin {
 }
 
 /// The dash pattern used when stroking the line.
+///
+/// A dotted line is a pattern of zero-length lines with a round or square
+/// [`LineCap`].
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LineDash<'a> {
     /// The alternating lengths of lines and gaps which describe the pattern.
+    ///
+    /// A pattern with an odd number of lengths is repeated twice, and an
+    /// empty pattern, or one without any length, draws a solid line.
     pub segments: &'a [f32],
 
-    /// The offset of [`LineDash::segments`] to start the pattern.
-    pub offset: usize,
+    /// The distance along the pattern where it starts.
+    ///
+    /// Animating it makes the dashes march along the line.
+    pub offset: f32,
 }
@@ -1,5 +1,10 @@
 use crate::alignment;
-use crate::{Color, Font, Point};
+use crate::backend;
+use crate::{Color, Font, Point, Size};
+
+use iced_native::text::{Rotation, Spacing};
+
+use std::cell::RefCell;
 
 /// A bunch of text that can be drawn to a canvas
 #[derive(Debug, Clone)]
@@ -25,6 +30,10 @@ pub struct Text {
     pub horizontal_alignment: alignment::Horizontal,
     /// The vertical alignment of the text
     pub vertical_alignment: alignment::Vertical,
+    /// The rotation of the text around its position, added to the rotation
+    /// of the transform of the frame.
+    /// The alignment properties apply along the rotated axes of the text.
+    pub rotation: Rotation,
 }
 
 impl Default for Text {
@@ -37,6 +46,7 @@ impl Default for Text {
             font: Font::Default,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            rotation: Rotation::None,
         }
     }
 }
@@ -55,3 +65,46 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+thread_local! {
+    /// The text measurer of the backend drawing a canvas, while its program
+    /// draws.
+    static MEASURER: RefCell<Option<Box<dyn backend::Measurer>>> =
+        RefCell::new(None);
+}
+
+/// Runs the given function with a [`backend::Measurer`] available to
+/// [`measure`].
+pub(crate) fn with_measurer<T>(
+    measurer: Box<dyn backend::Measurer>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let previous = MEASURER.with(|current| current.replace(Some(measurer)));
+
+    let result = f();
+
+    MEASURER.with(|current| *current.borrow_mut() = previous);
+
+    result
+}
+
+/// Measures the laid out size of some [`Text`], ignoring its rotation.
+///
+/// Text can only be measured while a canvas is drawing; otherwise, its size
+/// is zero.
+pub(crate) fn measure(text: &Text) -> Size {
+    MEASURER.with(|measurer| match measurer.borrow().as_ref() {
+        Some(measurer) => {
+            let (width, height) = measurer.measure(
+                &text.content,
+                text.size,
+                text.font,
+                Size::INFINITY,
+                Spacing::default(),
+            );
+
+            Size::new(width, height)
+        }
+        None => Size::ZERO,
+    })
+}
@@ -9,7 +9,9 @@ pub use crate::widget::canvas::{Canvas as _, Program as _, *};
 
 pub use program::Program;
 
-use crate::{Backend, Primitive, Renderer};
+use crate::widget::canvas::text;
+use crate::widget::canvas::Capture;
+use crate::{backend, Backend, Primitive, Renderer};
 
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
@@ -103,17 +105,28 @@ where
     }
 }
 
+/// The local state of a [`Canvas`], next to the state of its [`Program`].
+struct State<T> {
+    is_focused: bool,
+    capture: Capture,
+    program: T,
+}
+
 impl<Message, P, B> Widget<Message, Renderer<B>> for Canvas<Message, P>
 where
     P: Program<Message>,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<P::State>()
+        tree::Tag::of::<State<P::State>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(P::State::default())
+        tree::State::new(State {
+            is_focused: false,
+            capture: Capture::default(),
+            program: P::State::default(),
+        })
     }
 
     fn width(&self) -> Length {
@@ -147,23 +160,28 @@ where
     ) -> event::Status {
         let bounds = layout.bounds();
 
-        let canvas_event = match event {
-            iced_native::Event::Mouse(mouse_event) => {
-                Some(Event::Mouse(mouse_event))
-            }
-            iced_native::Event::Keyboard(keyboard_event) => {
-                Some(Event::Keyboard(keyboard_event))
-            }
-            _ => None,
-        };
+        let state = tree.state.downcast_mut::<State<P::State>>();
 
-        let cursor = Cursor::from_window_position(cursor_position);
+        let canvas_event = event::convert(
+            event,
+            bounds,
+            cursor_position,
+            &mut state.is_focused,
+        );
 
         if let Some(canvas_event) = canvas_event {
-            let state = tree.state.downcast_mut::<P::State>();
+            state.capture.track(&canvas_event);
 
-            let (event_status, message) =
-                self.program.update(state, canvas_event, bounds, cursor);
+            let cursor = state.capture.cursor(cursor_position);
+
+            let (event_status, message) = self.program.update(
+                &mut state.program,
+                canvas_event,
+                bounds,
+                cursor,
+            );
+
+            state.capture.update(&canvas_event, event_status, cursor);
 
             if let Some(message) = message {
                 shell.publish(message);
@@ -184,8 +202,9 @@ where
         _renderer: &Renderer<B>,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
-        let cursor = Cursor::from_window_position(cursor_position);
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
+        let cursor = state.capture.cursor(cursor_position);
+        let state = &state.program;
 
         self.program.mouse_interaction(state, bounds, cursor)
     }
@@ -208,14 +227,18 @@ where
         }
 
         let translation = Vector::new(bounds.x, bounds.y);
-        let cursor = Cursor::from_window_position(cursor_position);
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
+        let cursor = state.capture.cursor(cursor_position);
+        let state = &state.program;
+
+        let geometries =
+            text::with_measurer(renderer.backend().measurer(), || {
+                self.program.draw(state, bounds, cursor)
+            });
 
         renderer.with_translation(translation, |renderer| {
             renderer.draw_primitive(Primitive::Group {
-                primitives: self
-                    .program
-                    .draw(state, bounds, cursor)
+                primitives: geometries
                     .into_iter()
                     .map(Geometry::into_primitive)
                     .collect(),
@@ -229,7 +252,7 @@ impl<'a, Message, P, B> From<Canvas<Message, P>>
 where
     Message: 'a,
     P: Program<Message> + 'a,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn from(canvas: Canvas<Message, P>) -> Element<'a, Message, Renderer<B>> {
         Element::new(canvas)
@@ -21,6 +21,10 @@ pub trait Program<Message> {
     /// This method can optionally return a `Message` to notify an application
     /// of any meaningful interactions.
     ///
+    /// Capturing the press of a mouse button captures the mouse: until the
+    /// button is released, the [`Cursor`] stays available with the position
+    /// of the mouse, even out of the bounds of the [`Canvas`] or the window.
+    ///
     /// By default, this method does and returns nothing.
     ///
     /// [`Canvas`]: crate::widget::Canvas
@@ -55,6 +55,23 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Draws the [`Renderer`] primitives into an offscreen buffer with the
+    /// size of the [`Viewport`], like [`present`] would draw them, and returns
+    /// its 32bpp RGBA pixels.
+    ///
+    /// The pixels are stored row by row, starting from the top-left corner,
+    /// without any padding between rows.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`present`]: Self::present
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
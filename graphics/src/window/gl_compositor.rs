@@ -65,4 +65,13 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Reads the 32bpp RGBA pixels of the frame drawn by the last call to
+    /// [`present`], before its buffers are swapped.
+    ///
+    /// The pixels are stored row by row, starting from the top-left corner,
+    /// without any padding between rows.
+    ///
+    /// [`present`]: Self::present
+    fn screenshot(&mut self, viewport: &Viewport) -> Vec<u8>;
 }
@@ -164,6 +164,10 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let mut component = self
                 .state
@@ -391,6 +395,10 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let mut component =
                 self.instance.state.take().unwrap().into_heads().component;
@@ -196,6 +196,10 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -430,6 +434,10 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let overlay = self.overlay.take().unwrap().into_heads();
             let mut heads = overlay.instance.state.take().unwrap().into_heads();
@@ -1,6 +1,7 @@
 //! Access the clipboard.
 use iced_futures::MaybeSend;
 
+use std::borrow::Cow;
 use std::fmt;
 
 /// A buffer for short-term storage and transfer within and between
@@ -11,6 +12,139 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    ///
+    /// By default, it returns `None`, for clipboards without images.
+    fn read_image(&self) -> Option<ImageData> {
+        None
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// By default, it does nothing, for clipboards without images.
+    fn write_image(&mut self, _image: ImageData) {}
+
+    /// Reads the current content of the primary selection of the
+    /// [`Clipboard`] as text.
+    ///
+    /// By default, it returns `None`, for platforms without a primary
+    /// selection.
+    fn read_primary(&self) -> Option<String> {
+        None
+    }
+
+    /// Writes the given text contents to the primary selection of the
+    /// [`Clipboard`].
+    ///
+    /// By default, it does nothing, for platforms without a primary
+    /// selection.
+    fn write_primary(&mut self, _contents: String) {}
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`MimeType`].
+    ///
+    /// By default, it only supports [`MimeType::TEXT`], falling back to
+    /// [`Clipboard::read`].
+    fn read_data(&self, mime_type: &MimeType) -> Option<Vec<u8>> {
+        if *mime_type == MimeType::TEXT {
+            self.read().map(String::into_bytes)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the given contents to the [`Clipboard`], each one in its own
+    /// [`MimeType`], so applications pasting them can choose the
+    /// representation they prefer.
+    ///
+    /// By default, it only writes the [`MimeType::TEXT`] contents, if any,
+    /// with [`Clipboard::write`].
+    fn write_data(&mut self, data: Vec<(MimeType, Vec<u8>)>) {
+        if let Some(contents) = text(data) {
+            self.write(contents);
+        }
+    }
+}
+
+/// The MIME type of some contents in a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MimeType(Cow<'static, str>);
+
+impl MimeType {
+    /// Plain text, encoded as UTF-8.
+    pub const TEXT: Self = Self(Cow::Borrowed("text/plain"));
+
+    /// An HTML fragment, encoded as UTF-8.
+    pub const HTML: Self = Self(Cow::Borrowed("text/html"));
+
+    /// Rich text, in the RTF format.
+    pub const RTF: Self = Self(Cow::Borrowed("text/rtf"));
+
+    /// Creates a new [`MimeType`] with the given name, like `image/svg+xml`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the name of the [`MimeType`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MimeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Returns the [`MimeType::TEXT`] contents of the given data, if any.
+///
+/// Useful to fall back to [`Clipboard::write`] in a [`Clipboard`] that does
+/// not support any other [`MimeType`].
+pub fn text(data: Vec<(MimeType, Vec<u8>)>) -> Option<String> {
+    data.into_iter()
+        .find(|(mime_type, _)| *mime_type == MimeType::TEXT)
+        .and_then(|(_, bytes)| String::from_utf8(bytes).ok())
+}
+
+/// A kind of text [`Clipboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Kind {
+    /// The clipboard of every platform, used to copy and paste.
+    #[default]
+    Standard,
+
+    /// The primary selection of X11 and Wayland, which holds the last
+    /// selected text and is pasted with the middle mouse button.
+    ///
+    /// Other platforms do not have it.
+    Primary,
+}
+
+/// An image in a [`Clipboard`], as 32bpp RGBA pixels.
+///
+/// The pixels are stored row by row, starting from the top-left corner of the
+/// image, without any padding between rows.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ImageData {
+    /// The width of the image, in pixels.
+    pub width: u32,
+
+    /// The height of the image, in pixels.
+    pub height: u32,
+
+    /// The 32bpp RGBA data of the image.
+    pub bytes: Vec<u8>,
+}
+
+impl fmt::Debug for ImageData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageData")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
 }
 
 /// A null implementation of the [`Clipboard`] trait.
@@ -29,11 +163,26 @@ impl Clipboard for Null {
 ///
 /// [`Command`]: crate::Command
 pub enum Action<T> {
-    /// Read the clipboard and produce `T` with the result.
-    Read(Box<dyn Fn(Option<String>) -> T>),
+    /// Read the clipboard of the given [`Kind`] and produce `T` with the
+    /// result.
+    Read(Box<dyn Fn(Option<String>) -> T>, Kind),
+
+    /// Write the given contents to the clipboard of the given [`Kind`].
+    Write(String, Kind),
+
+    /// Read the contents of the clipboard in the given [`MimeType`] and
+    /// produce `T` with the result.
+    ReadData(MimeType, Box<dyn Fn(Option<Vec<u8>>) -> T>),
 
-    /// Write the given contents to the clipboard.
-    Write(String),
+    /// Write the given contents to the clipboard, each one in its own
+    /// [`MimeType`].
+    WriteData(Vec<(MimeType, Vec<u8>)>),
+
+    /// Read an image from the clipboard and produce `T` with the result.
+    ReadImage(Box<dyn Fn(Option<ImageData>) -> T>),
+
+    /// Write the given image to the clipboard.
+    WriteImage(ImageData),
 }
 
 impl<T> Action<T> {
@@ -46,8 +195,16 @@ impl<T> Action<T> {
         T: 'static,
     {
         match self {
-            Self::Read(o) => Action::Read(Box::new(move |s| f(o(s)))),
-            Self::Write(content) => Action::Write(content),
+            Self::Read(o, kind) => {
+                Action::Read(Box::new(move |s| f(o(s))), kind)
+            }
+            Self::Write(content, kind) => Action::Write(content, kind),
+            Self::ReadData(mime_type, o) => {
+                Action::ReadData(mime_type, Box::new(move |s| f(o(s))))
+            }
+            Self::WriteData(data) => Action::WriteData(data),
+            Self::ReadImage(o) => Action::ReadImage(Box::new(move |s| f(o(s)))),
+            Self::WriteImage(image) => Action::WriteImage(image),
         }
     }
 }
@@ -55,8 +212,68 @@ impl<T> Action<T> {
 impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Read(_) => write!(f, "Action::Read"),
-            Self::Write(_) => write!(f, "Action::Write"),
+            Self::Read(_, kind) => write!(f, "Action::Read({:?})", kind),
+            Self::Write(_, kind) => write!(f, "Action::Write({:?})", kind),
+            Self::ReadData(mime_type, _) => {
+                write!(f, "Action::ReadData({})", mime_type)
+            }
+            Self::WriteData(data) => {
+                let mime_types: Vec<_> = data
+                    .iter()
+                    .map(|(mime_type, _)| mime_type.as_str())
+                    .collect();
+
+                write!(f, "Action::WriteData({:?})", mime_types)
+            }
+            Self::ReadImage(_) => write!(f, "Action::ReadImage"),
+            Self::WriteImage(_) => write!(f, "Action::WriteImage"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Text(Option<String>);
+
+    impl Clipboard for Text {
+        fn read(&self) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn write(&mut self, contents: String) {
+            self.0 = Some(contents);
         }
     }
+
+    #[test]
+    fn text_finds_the_plain_text_contents() {
+        let data = vec![
+            (MimeType::HTML, b"<b>bold</b>".to_vec()),
+            (MimeType::TEXT, b"bold".to_vec()),
+        ];
+
+        assert_eq!(text(data), Some(String::from("bold")));
+    }
+
+    #[test]
+    fn text_ignores_missing_or_invalid_contents() {
+        assert_eq!(text(vec![(MimeType::HTML, b"<br>".to_vec())]), None);
+        assert_eq!(text(vec![(MimeType::TEXT, vec![0xff, 0xfe])]), None);
+    }
+
+    #[test]
+    fn data_falls_back_to_text() {
+        let mut clipboard = Text(None);
+
+        clipboard.write_data(vec![
+            (MimeType::HTML, b"<i>hi</i>".to_vec()),
+            (MimeType::TEXT, b"hi".to_vec()),
+        ]);
+
+        assert_eq!(clipboard.read(), Some(String::from("hi")));
+        assert_eq!(clipboard.read_data(&MimeType::TEXT), Some(b"hi".to_vec()));
+        assert_eq!(clipboard.read_data(&MimeType::HTML), None);
+    }
 }
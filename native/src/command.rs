@@ -3,6 +3,8 @@ mod action;
 
 pub use action::Action;
 
+use crate::widget;
+
 use iced_futures::MaybeSend;
 
 use std::fmt;
@@ -34,6 +36,12 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs a [`widget::Operation`] on the
+    /// widget tree of the application.
+    pub fn widget(operation: impl widget::Operation<T> + 'static) -> Self {
+        Self::single(Action::Widget(widget::Action::new(operation)))
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
@@ -51,6 +59,7 @@ impl<T> Command<T> {
     ) -> Command<A>
     where
         T: 'static,
+        A: 'static,
     {
         let Command(command) = self;
 
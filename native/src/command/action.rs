@@ -1,5 +1,6 @@
 use crate::clipboard;
 use crate::system;
+use crate::widget;
 use crate::window;
 
 use iced_futures::MaybeSend;
@@ -19,10 +20,13 @@ pub enum Action<T> {
     Clipboard(clipboard::Action<T>),
 
     /// Run a window action.
-    Window(window::Action),
+    Window(window::Action<T>),
 
     /// Run a system action.
     System(system::Action<T>),
+
+    /// Run a widget action.
+    Widget(widget::Action<T>),
 }
 
 impl<T> Action<T> {
@@ -35,14 +39,16 @@ impl<T> Action<T> {
     ) -> Action<A>
     where
         T: 'static,
+        A: 'static,
     {
         use iced_futures::futures::FutureExt;
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
-            Self::Window(window) => Action::Window(window),
+            Self::Window(window) => Action::Window(window.map(f)),
             Self::System(system) => Action::System(system.map(f)),
+            Self::Widget(widget) => Action::Widget(widget.map(f)),
         }
     }
 }
@@ -56,6 +62,7 @@ impl<T> fmt::Debug for Action<T> {
             }
             Self::Window(action) => write!(f, "Action::Window({:?})", action),
             Self::System(action) => write!(f, "Action::System({:?})", action),
+            Self::Widget(_) => write!(f, "Action::Widget"),
         }
     }
 }
@@ -3,6 +3,7 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::operation::{self, Operation};
 use crate::{
     Clipboard, Color, Layout, Length, Point, Rectangle, Shell, Widget,
 };
@@ -277,6 +278,15 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout, renderer)
     }
+
+    /// Applies an [`Operation`] to the [`Element`].
+    pub fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.widget.operate(layout, operation)
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -385,6 +395,15 @@ where
             .overlay(layout, renderer)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<B>,
+    ) {
+        self.widget
+            .operate(layout, &mut operation::MapRef::new(operation))
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -502,4 +521,12 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout, renderer)
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.element.operate(layout, operation)
+    }
 }
@@ -1,8 +1,16 @@
 //! Load and draw raster graphics.
+#[cfg(feature = "image")]
+mod load;
+
+#[cfg(feature = "image")]
+pub use load::{cached, decode, load, Error, Request, Source, Status};
+
+use crate::time::Duration;
 use crate::{Hasher, Rectangle};
 
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
+use std::sync::atomic::{self, AtomicU64};
 use std::sync::Arc;
 
 /// A handle of some image data.
@@ -25,14 +33,61 @@ impl Handle {
     /// pixels.
     ///
     /// This is useful if you have already decoded your image.
+    ///
+    /// Every [`Handle`] created this way is unique, even if its pixels are
+    /// the same as the ones of another [`Handle`]. Renderers drop the pixels
+    /// of a [`Handle`] once it is not drawn anymore, so a new [`Handle`] can
+    /// be created for every frame of a video, for instance.
     pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
-        Self::from_data(Data::Pixels {
+        Self::from_unique_data(Data::Pixels {
             width,
             height,
             pixels,
         })
     }
 
+    /// Creates an image [`Handle`] containing the image pixels directly, in
+    /// the given [`Format`].
+    ///
+    /// The `stride` is the amount of bytes between the start of two rows of
+    /// pixels, if they are padded. The pixels are converted once, when the
+    /// [`Handle`] is created.
+    ///
+    /// Like [`Handle::from_pixels`], every [`Handle`] created this way is
+    /// unique.
+    pub fn from_formatted_pixels(
+        width: u32,
+        height: u32,
+        format: Format,
+        stride: Option<u32>,
+        pixels: Vec<u8>,
+    ) -> Handle {
+        let row_length = width as usize * 4;
+        let stride = stride.map_or(row_length, |stride| stride as usize);
+
+        let mut pixels = if stride == row_length {
+            pixels
+        } else {
+            // Rows shorter than the image are dropped, which leaves an
+            // invalid image behind
+            pixels
+                .chunks(stride.max(1))
+                .take(height as usize)
+                .filter_map(|row| row.get(..row_length))
+                .flatten()
+                .copied()
+                .collect()
+        };
+
+        if format == Format::Rgba8 {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        Self::from_pixels(width, height, pixels)
+    }
+
     /// Creates an image [`Handle`] containing the image data directly.
     ///
     /// Makes an educated guess about the image format by examining the given data.
@@ -53,6 +108,23 @@ impl Handle {
         }
     }
 
+    /// Creates a [`Handle`] that does not share its identifier with any other
+    /// one, which saves hashing large amounts of pixels.
+    fn from_unique_data(data: Data) -> Handle {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let mut hasher = Hasher::default();
+        "unique".hash(&mut hasher);
+        NEXT_ID
+            .fetch_add(1, atomic::Ordering::Relaxed)
+            .hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::new(data),
+        }
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
         self.id
@@ -99,6 +171,74 @@ pub enum Data {
     },
 }
 
+/// The frames of an animated image, like a GIF or an APNG.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    /// How long every frame is displayed.
+    pub delays: Arc<[Duration]>,
+
+    /// How many times the frames are played, or `None` if they loop forever.
+    pub repetitions: Option<u32>,
+}
+
+impl Animation {
+    /// Returns the frame displayed once the given time has elapsed since the
+    /// start of the [`Animation`], and how long it is displayed for.
+    ///
+    /// The last frame is displayed forever once every repetition is over.
+    pub fn frame(&self, elapsed: Duration) -> (usize, Option<Duration>) {
+        let total: Duration = self.delays.iter().sum();
+        let last = self.delays.len().saturating_sub(1);
+
+        if total.is_zero() {
+            return (last, None);
+        }
+
+        let cycles = elapsed.as_nanos() / total.as_nanos();
+
+        if let Some(repetitions) = self.repetitions {
+            if cycles >= u128::from(repetitions) {
+                return (last, None);
+            }
+        }
+
+        let mut left = Duration::from_nanos(
+            (elapsed.as_nanos() % total.as_nanos()) as u64,
+        );
+
+        for (index, delay) in self.delays.iter().enumerate() {
+            if left < *delay {
+                return (index, Some(*delay - left));
+            }
+
+            left -= *delay;
+        }
+
+        (last, None)
+    }
+}
+
+/// The way an image is sampled when it is drawn at a different size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilterMethod {
+    /// The pixels are blended smoothly. This suits photographs.
+    #[default]
+    Linear,
+
+    /// The closest pixel is used, which keeps the edges of pixel art sharp.
+    Nearest,
+}
+
+/// The layout of the bytes of some image pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Four bytes per pixel, in red, green, blue, and alpha order.
+    Rgba8,
+
+    /// Four bytes per pixel, in blue, green, red, and alpha order.
+    Bgra8,
+}
+
 impl std::fmt::Debug for Data {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -126,4 +266,115 @@ pub trait Renderer: crate::Renderer {
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
     fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+
+    /// Returns the [`Animation`] of the image of the given [`Handle`], if it
+    /// has more than one frame.
+    ///
+    /// By default, images are not animated.
+    fn animation(&self, _handle: &Self::Handle) -> Option<Animation> {
+        None
+    }
+
+    /// Draws the given frame of an image with the given [`Handle`],
+    /// [`FilterMethod`], and opacity inside the provided `bounds`.
+    ///
+    /// By default, the image is drawn as if it was not animated, opaque, and
+    /// with the default [`FilterMethod`].
+    fn draw_frame(
+        &mut self,
+        handle: Self::Handle,
+        _frame: usize,
+        _filter_method: FilterMethod,
+        _opacity: f32,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle, bounds)
+    }
+
+    /// Draws the given `region` of an image with the given [`Handle`] and
+    /// [`FilterMethod`], stretched to fill the provided `bounds`.
+    ///
+    /// The `region` is in the pixels of the image.
+    ///
+    /// By default, the whole image is scaled and clipped to the `bounds`,
+    /// which is correct but needs a layer for every region.
+    fn draw_region(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        region: Rectangle,
+        bounds: Rectangle,
+    ) {
+        if region.width <= 0.0 || region.height <= 0.0 {
+            return;
+        }
+
+        let (width, height) = self.dimensions(&handle);
+
+        let scale_x = bounds.width / region.width;
+        let scale_y = bounds.height / region.height;
+
+        let image_bounds = Rectangle {
+            x: bounds.x - region.x * scale_x,
+            y: bounds.y - region.y * scale_y,
+            width: width as f32 * scale_x,
+            height: height as f32 * scale_y,
+        };
+
+        self.with_layer(bounds, |renderer| {
+            renderer.draw_frame(handle, 0, filter_method, 1.0, image_bounds)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_with_the_same_pixels_do_not_alias() {
+        let pixels = vec![0, 0, 0, 255];
+
+        let a = Handle::from_pixels(1, 1, pixels.clone());
+        let b = Handle::from_pixels(1, 1, pixels);
+
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn animations_stop_on_their_last_frame() {
+        let animation = Animation {
+            delays: Arc::from(vec![
+                Duration::from_millis(100),
+                Duration::from_millis(50),
+            ]),
+            repetitions: Some(2),
+        };
+
+        let frame = |millis| animation.frame(Duration::from_millis(millis));
+
+        assert_eq!(frame(0), (0, Some(Duration::from_millis(100))));
+        assert_eq!(frame(120), (1, Some(Duration::from_millis(30))));
+        assert_eq!(frame(160), (0, Some(Duration::from_millis(90))));
+        assert_eq!(frame(300), (1, None));
+    }
+
+    #[test]
+    fn formatted_pixels_are_converted_to_bgra() {
+        let rgba = vec![
+            1, 2, 3, 4, 5, 6, 7, 8, 0, 0, // First row, with padding
+            9, 10, 11, 12, 13, 14, 15, 16, 0, 0, // Second row
+        ];
+
+        let handle =
+            Handle::from_formatted_pixels(2, 2, Format::Rgba8, Some(10), rgba);
+
+        match handle.data() {
+            Data::Pixels { pixels, .. } => assert_eq!(
+                pixels,
+                &[3, 2, 1, 4, 7, 6, 5, 8, 11, 10, 9, 12, 15, 14, 13, 16]
+            ),
+            data => panic!("unexpected data: {:?}", data),
+        }
+    }
 }
@@ -0,0 +1,447 @@
+use crate::image::Handle;
+use crate::Command;
+
+use iced_futures::futures::channel::oneshot;
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+
+/// The amount of loaded images that are kept in memory, so they are not
+/// loaded again every time a view is rebuilt.
+const CACHE_SIZE: usize = 64;
+
+/// The source of an image to load.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// A file on disk.
+    Path(PathBuf),
+
+    /// A remote image fetched over HTTP.
+    ///
+    /// Fetching remote images needs the `image_http` feature.
+    Url(String),
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Source::Path(path)
+    }
+}
+
+impl From<&Path> for Source {
+    fn from(path: &Path) -> Self {
+        Source::Path(path.to_path_buf())
+    }
+}
+
+impl From<&str> for Source {
+    fn from(source: &str) -> Self {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            Source::Url(source.to_owned())
+        } else {
+            Source::Path(PathBuf::from(source))
+        }
+    }
+}
+
+impl From<String> for Source {
+    fn from(source: String) -> Self {
+        Source::from(source.as_str())
+    }
+}
+
+/// An error that occurred while loading an image.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The image could not be read.
+    Io(Arc<io::Error>),
+
+    /// The image could not be fetched.
+    Http(String),
+
+    /// The image could not be decoded.
+    Decode(String),
+
+    /// The image is remote, but the `image_http` feature is disabled.
+    Unsupported,
+
+    /// The image stopped loading before it was finished.
+    Cancelled,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => {
+                write!(f, "the image could not be read: {}", error)
+            }
+            Error::Http(error) => {
+                write!(f, "the image could not be fetched: {}", error)
+            }
+            Error::Decode(error) => {
+                write!(f, "the image could not be decoded: {}", error)
+            }
+            Error::Unsupported => {
+                write!(
+                    f,
+                    "fetching remote images needs the `image_http` feature"
+                )
+            }
+            Error::Cancelled => write!(f, "the image stopped loading"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The progress of loading an image.
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is still loading.
+    Loading,
+
+    /// The image was loaded.
+    Loaded(Handle),
+
+    /// The image could not be loaded.
+    Failed(Error),
+}
+
+impl From<Result<Handle, Error>> for Status {
+    fn from(result: Result<Handle, Error>) -> Self {
+        match result {
+            Ok(handle) => Status::Loaded(handle),
+            Err(error) => Status::Failed(error),
+        }
+    }
+}
+
+/// Loads the image of the given [`Source`] in a background thread.
+///
+/// Both reading and decoding happen off the thread of the user interface.
+/// Loaded images are kept in a small cache, while failed ones are loaded
+/// again.
+pub fn load(source: impl Into<Source>) -> Command<Result<Handle, Error>> {
+    let source = source.into();
+    let interest = Arc::new(Interest);
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = request(&source, &interest, true, Some(sender));
+
+    Command::perform(
+        async move {
+            let result = receiver.await.unwrap_or(Err(Error::Cancelled));
+
+            // The load is wanted until it is finished
+            drop(interest);
+
+            result
+        },
+        std::convert::identity,
+    )
+}
+
+/// Decodes the encoded bytes of an image into a [`Handle`] with its pixels,
+/// honoring the orientation in its EXIF metadata.
+///
+/// GIFs and APNGs are left encoded, so renderers can play their animations.
+pub fn decode(bytes: Vec<u8>) -> Result<Handle, Error> {
+    if bytes.starts_with(b"GIF") || is_apng(&bytes) {
+        return Ok(Handle::from_memory(bytes));
+    }
+
+    let image = image_rs::load_from_memory(&bytes)
+        .map_err(|error| Error::Decode(error.to_string()))?;
+
+    let image = match orientation(&bytes) {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.rotate90().fliph(),
+        6 => image.rotate90(),
+        7 => image.rotate270().fliph(),
+        8 => image.rotate270(),
+        _ => image,
+    };
+
+    let image = image.to_bgra8();
+
+    Ok(Handle::from_pixels(
+        image.width(),
+        image.height(),
+        image.into_raw(),
+    ))
+}
+
+/// Returns the [`Status`] of the image of the given [`Source`] without
+/// loading it, or `None` if it is neither loaded nor loading.
+pub fn cached(source: &Source) -> Option<Status> {
+    match cache().entries.get(source)? {
+        Entry::Loading { .. } => Some(Status::Loading),
+        Entry::Loaded(result) => Some(Status::from(result.clone())),
+    }
+}
+
+/// A request to load the image of a [`Source`], which keeps track of its
+/// [`Status`].
+///
+/// Dropping every [`Request`] of an image that is still loading cancels it.
+#[derive(Debug)]
+pub struct Request {
+    source: Source,
+    status: Status,
+    interest: Arc<Interest>,
+}
+
+impl Request {
+    /// Starts loading the image of the given [`Source`], unless it is
+    /// already loaded or loading.
+    pub fn new(source: Source) -> Self {
+        let interest = Arc::new(Interest);
+        let status = request(&source, &interest, false, None);
+
+        Request {
+            source,
+            status,
+            interest,
+        }
+    }
+
+    /// Returns the [`Source`] of the [`Request`].
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// Returns the [`Status`] of the [`Request`], as of its last poll.
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    /// Updates the [`Status`] of the [`Request`], returning whether it
+    /// changed.
+    pub fn poll(&mut self) -> bool {
+        if !matches!(self.status, Status::Loading) {
+            return false;
+        }
+
+        // The image may have been evicted from the cache in the meantime, so
+        // the request is made again
+        self.status = request(&self.source, &self.interest, false, None);
+
+        !matches!(self.status, Status::Loading)
+    }
+}
+
+/// A marker of the interest in an image; its load is cancelled once nothing
+/// is interested anymore.
+#[derive(Debug)]
+struct Interest;
+
+#[derive(Debug)]
+enum Entry {
+    Loading {
+        interests: Vec<Weak<Interest>>,
+        waiters: Vec<oneshot::Sender<Result<Handle, Error>>>,
+    },
+    Loaded(Result<Handle, Error>),
+}
+
+#[derive(Debug, Default)]
+struct Cache {
+    entries: HashMap<Source, Entry>,
+    recent: VecDeque<Source>,
+}
+
+fn cache() -> MutexGuard<'static, Cache> {
+    static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+    CACHE
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Registers the interest in the image of a [`Source`] and starts loading
+/// it, unless it is already loaded or loading.
+fn request(
+    source: &Source,
+    interest: &Arc<Interest>,
+    retry_failed: bool,
+    waiter: Option<oneshot::Sender<Result<Handle, Error>>>,
+) -> Status {
+    let mut cache = cache();
+
+    match cache.entries.get_mut(source) {
+        Some(Entry::Loaded(result)) if result.is_ok() || !retry_failed => {
+            let result = result.clone();
+
+            if let Some(waiter) = waiter {
+                let _ = waiter.send(result.clone());
+            }
+
+            cache.recent.retain(|recent| recent != source);
+            cache.recent.push_back(source.clone());
+
+            return Status::from(result);
+        }
+        Some(Entry::Loading { interests, waiters }) => {
+            let interest = Arc::downgrade(interest);
+
+            if !interests.iter().any(|other| other.ptr_eq(&interest)) {
+                interests.push(interest);
+            }
+
+            waiters.extend(waiter);
+
+            return Status::Loading;
+        }
+        _ => {}
+    }
+
+    let _ = cache.entries.insert(
+        source.clone(),
+        Entry::Loading {
+            interests: vec![Arc::downgrade(interest)],
+            waiters: waiter.into_iter().collect(),
+        },
+    );
+
+    let source = source.clone();
+
+    let _ = std::thread::spawn(move || {
+        let result = if is_wanted(&source) {
+            fetch(&source).and_then(|bytes| {
+                if is_wanted(&source) {
+                    decode(bytes)
+                } else {
+                    Err(Error::Cancelled)
+                }
+            })
+        } else {
+            Err(Error::Cancelled)
+        };
+
+        finish(source, result);
+    });
+
+    Status::Loading
+}
+
+/// Returns whether anything is still interested in the image of a
+/// [`Source`] that is loading.
+fn is_wanted(source: &Source) -> bool {
+    match cache().entries.get_mut(source) {
+        Some(Entry::Loading { interests, .. }) => {
+            interests.retain(|interest| interest.strong_count() > 0);
+
+            !interests.is_empty()
+        }
+        _ => false,
+    }
+}
+
+fn finish(source: Source, result: Result<Handle, Error>) {
+    let mut cache = cache();
+
+    if let Some(Entry::Loading { waiters, .. }) = cache.entries.remove(&source)
+    {
+        for waiter in waiters {
+            let _ = waiter.send(result.clone());
+        }
+    }
+
+    if matches!(result, Err(Error::Cancelled)) {
+        return;
+    }
+
+    let _ = cache.entries.insert(source.clone(), Entry::Loaded(result));
+
+    cache.recent.retain(|recent| *recent != source);
+    cache.recent.push_back(source);
+
+    while cache.recent.len() > CACHE_SIZE {
+        if let Some(oldest) = cache.recent.pop_front() {
+            let _ = cache.entries.remove(&oldest);
+        }
+    }
+}
+
+fn fetch(source: &Source) -> Result<Vec<u8>, Error> {
+    match source {
+        Source::Path(path) => {
+            std::fs::read(path).map_err(|error| Error::Io(Arc::new(error)))
+        }
+        #[cfg(feature = "image_http")]
+        Source::Url(url) => {
+            use std::io::Read;
+
+            let response = ureq::get(url)
+                .call()
+                .map_err(|error| Error::Http(error.to_string()))?;
+
+            let mut bytes = Vec::new();
+
+            let _ = response
+                .into_reader()
+                .read_to_end(&mut bytes)
+                .map_err(|error| Error::Io(Arc::new(error)))?;
+
+            Ok(bytes)
+        }
+        #[cfg(not(feature = "image_http"))]
+        Source::Url(_) => Err(Error::Unsupported),
+    }
+}
+
+/// Returns whether the given bytes are an animated PNG, which has an
+/// animation control chunk.
+fn is_apng(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"\x89PNG")
+        && bytes.windows(4).any(|window| window == b"acTL")
+}
+
+/// Reads the EXIF orientation of an image, from `1` to `8`.
+fn orientation(bytes: &[u8]) -> u32 {
+    exif::Reader::new()
+        .read_from_container(&mut io::Cursor::new(bytes))
+        .ok()
+        .and_then(|exif| {
+            exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+                .and_then(|field| field.value.get_uint(0))
+        })
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_from_str() {
+        assert_eq!(
+            Source::from("https://iced.rs/logo.png"),
+            Source::Url(String::from("https://iced.rs/logo.png"))
+        );
+
+        assert_eq!(
+            Source::from("resources/logo.png"),
+            Source::Path(PathBuf::from("resources/logo.png"))
+        );
+    }
+
+    #[test]
+    fn missing_file_fails() {
+        let mut request = Request::new(Source::from("does/not/exist.png"));
+
+        while matches!(request.status(), Status::Loading) {
+            let _ = request.poll();
+
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert!(matches!(request.status(), Status::Failed(Error::Io(_))));
+    }
+}
@@ -3,7 +3,7 @@ use crate::time::Instant;
 use crate::Point;
 
 /// A mouse click.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Click {
     kind: Kind,
     position: Point,
@@ -11,7 +11,7 @@ pub struct Click {
 }
 
 /// The kind of mouse click.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
     /// A single click
     Single,
@@ -61,6 +61,11 @@ impl Click {
         self.kind
     }
 
+    /// Returns the position of the [`Click`].
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
     fn is_consecutive(&self, new_position: Point, time: Instant) -> bool {
         let duration = if time > self.time {
             Some(time - self.time)
@@ -74,3 +79,24 @@ impl Click {
                 .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicks_in_place_are_consecutive() {
+        let first = Click::new(Point::new(10.0, 10.0), None);
+        let second = Click::new(Point::new(10.0, 10.0), Some(first));
+
+        assert_eq!(second.kind(), Kind::Double);
+    }
+
+    #[test]
+    fn clicks_apart_are_not_consecutive() {
+        let first = Click::new(Point::new(10.0, 10.0), None);
+        let second = Click::new(Point::new(11.0, 10.0), Some(first));
+
+        assert_eq!(second.kind(), Kind::Single);
+    }
+}
@@ -1,6 +1,7 @@
 //! Build and show dropdown menus.
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
@@ -23,7 +24,11 @@ pub struct Menu<'a, T, Renderer: text::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    search: Option<&'a mut String>,
+    is_enabled: Option<&'a dyn Fn(&T) -> bool>,
+    separators_after: &'a [usize],
     width: u16,
+    max_height: Option<u16>,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -48,7 +53,11 @@ where
             options,
             hovered_option,
             last_selection,
+            search: None,
+            is_enabled: None,
+            separators_after: &[],
             width: 0,
+            max_height: None,
             padding: Padding::ZERO,
             text_size: None,
             font: Default::default(),
@@ -56,12 +65,49 @@ where
         }
     }
 
+    /// Makes the [`Menu`] searchable with the given query.
+    ///
+    /// While the [`Menu`] is open, typed characters are appended to the
+    /// query and only the options that contain it, ignoring case, are
+    /// displayed. Up and Down move the hovered option, Enter selects it, and
+    /// Escape clears the query.
+    pub fn search(mut self, query: &'a mut String) -> Self {
+        self.search = Some(query);
+        self
+    }
+
+    /// Sets the function that decides which options of the [`Menu`] can be
+    /// chosen.
+    ///
+    /// Disabled options are displayed, but they are not highlighted and
+    /// cannot be selected.
+    pub fn is_enabled(mut self, is_enabled: &'a dyn Fn(&T) -> bool) -> Self {
+        self.is_enabled = Some(is_enabled);
+        self
+    }
+
+    /// Sets the indices of the options of the [`Menu`] that are followed by
+    /// a separator.
+    pub fn separators_after(mut self, indices: &'a [usize]) -> Self {
+        self.separators_after = indices;
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: u16) -> Self {
         self.width = width;
         self
     }
 
+    /// Sets the maximum height of the [`Menu`]. Its options can be scrolled
+    /// when they do not fit.
+    ///
+    /// By default, the [`Menu`] is only limited by the size of the window.
+    pub fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Menu`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -89,9 +135,8 @@ where
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
-    /// The `target_height` will be used to display the menu either on top
-    /// of the target or under it, depending on the screen position and the
-    /// dimensions of the [`Menu`].
+    /// The `target_height` will be used to display the menu under the
+    /// target, or on top of it when there is not enough room below.
     pub fn overlay<Message: 'a>(
         self,
         position: Point,
@@ -108,6 +153,9 @@ where
 #[derive(Debug, Clone, Default)]
 pub struct State {
     scrollable: scrollable::State,
+    reveal: Option<Rectangle>,
+    viewport: (Rectangle, Rectangle),
+    center_hovered: bool,
 }
 
 impl State {
@@ -115,11 +163,22 @@ impl State {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Scrolls the next [`Menu`] built with this [`State`] so that its
+    /// hovered option ends up in the middle of it, if possible.
+    ///
+    /// Menus usually do this when they open, so the current choice is in
+    /// sight.
+    pub fn center_hovered(&mut self) {
+        self.center_hovered = true;
+    }
 }
 
 struct Overlay<'a, Message, Renderer: text::Renderer> {
     container: Container<'a, Message, Renderer>,
+    viewport: &'a mut (Rectangle, Rectangle),
     width: u16,
+    max_height: Option<u16>,
     target_height: f32,
     style: Style,
 }
@@ -138,30 +197,79 @@ where
             options,
             hovered_option,
             last_selection,
+            search,
+            is_enabled,
+            separators_after,
             width,
+            max_height,
             padding,
             font,
             text_size,
             style,
         } = menu;
 
+        let State {
+            scrollable,
+            reveal,
+            viewport,
+            center_hovered,
+        } = state;
+
+        // Scroll the option requested by the last keyboard navigation into
+        // view, now that the scrollable is not borrowed by the list
+        if let Some(option) = reveal.take() {
+            let (bounds, content_bounds) = *viewport;
+            let offset = scrollable.offset(bounds, content_bounds).y as f32;
+
+            let delta = if option.y < offset {
+                offset - option.y
+            } else if option.y + option.height > offset + bounds.height {
+                offset + bounds.height - option.y - option.height
+            } else {
+                0.0
+            };
+
+            scrollable.scroll(Vector::new(0.0, delta), bounds, content_bounds);
+        }
+
+        let list = List {
+            options,
+            hovered_option,
+            last_selection,
+            search,
+            reveal,
+            is_enabled,
+            separators_after,
+            font,
+            text_size,
+            padding,
+            style: style.clone(),
+        };
+
+        // The heights of the options are only known here when the text size
+        // is, since the renderer is not around yet. The height of the menu
+        // is only known once it is laid out, so the scrollable resolves the
+        // offset itself
+        if let (true, Some(text_size)) =
+            (std::mem::take(center_hovered), text_size)
+        {
+            let option_height = f32::from(text_size + padding.vertical());
+
+            if let Some(center) = list.hovered_center(option_height) {
+                scrollable.center_on(center);
+            }
+        }
+
         let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
-                options,
-                hovered_option,
-                last_selection,
-                font,
-                text_size,
-                padding,
-                style: style.clone(),
-            }))
-            .padding(1);
+            Container::new(Scrollable::new(scrollable).push(list)).padding(1);
 
         Self {
             container,
-            width: width,
+            viewport,
+            width,
+            max_height,
             target_height,
-            style: style,
+            style,
         }
     }
 }
@@ -180,25 +288,27 @@ where
         let space_below = bounds.height - (position.y + self.target_height);
         let space_above = position.y;
 
+        let max_height = self
+            .max_height
+            .map(f32::from)
+            .unwrap_or(f32::INFINITY)
+            .min(space_below.max(space_above));
+
         let limits = layout::Limits::new(
             Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
+            Size::new(bounds.width - position.x, max_height),
         )
         .width(Length::Units(self.width));
 
         let mut node = self.container.layout(renderer, &limits);
+        let height = node.size().height;
 
-        node.move_to(if space_below > space_above {
+        // The menu always fits on the side with the most room, but it only
+        // opens upwards when it does not fit below
+        node.move_to(if height <= space_below || space_below >= space_above {
             position + Vector::new(0.0, self.target_height)
         } else {
-            position - Vector::new(0.0, node.size().height)
+            position - Vector::new(0.0, height)
         });
 
         node
@@ -213,14 +323,42 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.container.on_event(
+        if let Some(scrollable) = layout.children().next() {
+            let content = scrollable.children().next().unwrap();
+
+            *self.viewport = (
+                Rectangle::new(Point::ORIGIN, scrollable.bounds().size()),
+                Rectangle::new(Point::ORIGIN, content.bounds().size()),
+            );
+        }
+
+        let status = self.container.on_event(
             event.clone(),
             layout,
             cursor_position,
             renderer,
             clipboard,
             shell,
-        )
+        );
+
+        // Scrolling moves the options under a cursor that stays still, so
+        // the hovered option is found again with the new offset
+        if let Event::Mouse(mouse::Event::WheelScrolled { .. }) = event {
+            if layout.bounds().contains(cursor_position) {
+                let _ = self.container.on_event(
+                    Event::Mouse(mouse::Event::CursorMoved {
+                        position: cursor_position,
+                    }),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -262,16 +400,182 @@ where
     }
 }
 
+/// The height of a separator between the options of a [`Menu`].
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
 struct List<'a, T, Renderer: text::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    search: Option<&'a mut String>,
+    reveal: &'a mut Option<Rectangle>,
+    is_enabled: Option<&'a dyn Fn(&T) -> bool>,
+    separators_after: &'a [usize],
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
     style: Style,
 }
 
+/// A row of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// The option with the given index.
+    Option(usize),
+
+    /// A separator between options.
+    Separator,
+}
+
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    T: ToString,
+    Renderer: text::Renderer,
+{
+    /// Returns the rows of the options that match the search query, in
+    /// order.
+    ///
+    /// Separators are only displayed while there is no query, since the
+    /// sections they divide are lost while filtering.
+    fn rows(&self) -> Vec<Row> {
+        let query = match &self.search {
+            Some(query) if !query.is_empty() => Some(query.to_lowercase()),
+            _ => None,
+        };
+
+        let mut rows = Vec::with_capacity(self.options.len());
+
+        for (i, option) in self.options.iter().enumerate() {
+            if let Some(query) = &query {
+                if !option.to_string().to_lowercase().contains(query) {
+                    continue;
+                }
+            }
+
+            rows.push(Row::Option(i));
+
+            if query.is_none()
+                && i + 1 < self.options.len()
+                && self.separators_after.contains(&i)
+            {
+                rows.push(Row::Separator);
+            }
+        }
+
+        rows
+    }
+
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        f32::from(text_size + self.padding.vertical())
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        match (self.is_enabled, self.options.get(index)) {
+            (Some(is_enabled), Some(option)) => is_enabled(option),
+            (None, Some(_)) => true,
+            (_, None) => false,
+        }
+    }
+
+    /// Returns the enabled options in the given rows, together with their
+    /// vertical offset in the [`List`].
+    fn selectable(
+        &self,
+        rows: &[Row],
+        option_height: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut y = 0.0;
+        let mut selectable = Vec::new();
+
+        for row in rows {
+            match row {
+                Row::Option(index) => {
+                    if self.is_enabled(*index) {
+                        selectable.push((*index, y));
+                    }
+
+                    y += option_height;
+                }
+                Row::Separator => {
+                    y += SEPARATOR_HEIGHT;
+                }
+            }
+        }
+
+        selectable
+    }
+
+    /// Returns the enabled option at the given vertical offset of the
+    /// [`List`], if any.
+    fn option_at(
+        &self,
+        rows: &[Row],
+        option_height: f32,
+        offset: f32,
+    ) -> Option<usize> {
+        let mut y = 0.0;
+
+        for row in rows {
+            let height = match row {
+                Row::Option(_) => option_height,
+                Row::Separator => SEPARATOR_HEIGHT,
+            };
+
+            if offset < y + height {
+                return match row {
+                    Row::Option(index) if self.is_enabled(*index) => {
+                        Some(*index)
+                    }
+                    _ => None,
+                };
+            }
+
+            y += height;
+        }
+
+        None
+    }
+
+    /// Returns the vertical position of the middle of the hovered option,
+    /// if any.
+    fn hovered_center(&self, option_height: f32) -> Option<f32> {
+        let hovered = (*self.hovered_option)?;
+        let mut y = 0.0;
+
+        for row in self.rows() {
+            match row {
+                Row::Option(index) if index == hovered => {
+                    return Some(y + option_height / 2.0);
+                }
+                Row::Option(_) => {
+                    y += option_height;
+                }
+                Row::Separator => {
+                    y += SEPARATOR_HEIGHT;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Hovers the given option and requests it to be scrolled into view.
+    fn hover(&mut self, option: Option<(usize, f32)>, option_height: f32) {
+        *self.hovered_option = option.map(|(index, _)| index);
+
+        if let Some((_, y)) = option {
+            *self.reveal = Some(Rectangle {
+                x: 0.0,
+                y,
+                width: 0.0,
+                height: option_height,
+            });
+        }
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for List<'a, T, Renderer>
 where
@@ -291,19 +595,20 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        use std::f32;
-
         let limits = limits.width(Length::Fill).height(Length::Shrink);
-        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let option_height = self.option_height(renderer);
 
         let size = {
-            let intrinsic = Size::new(
-                0.0,
-                f32::from(text_size + self.padding.vertical())
-                    * self.options.len() as f32,
-            );
-
-            limits.resolve(intrinsic)
+            let height = self
+                .rows()
+                .iter()
+                .map(|row| match row {
+                    Row::Option(_) => option_height,
+                    Row::Separator => SEPARATOR_HEIGHT,
+                })
+                .sum();
+
+            limits.resolve(Size::new(0.0, height))
         };
 
         layout::Node::new(size)
@@ -316,52 +621,132 @@ where
         cursor_position: Point,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
-        _shell: &mut Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let bounds = layout.bounds();
+        let bounds = layout.bounds();
+        let option_height = self.option_height(renderer);
+        let rows = self.rows();
 
-                if bounds.contains(cursor_position) {
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
-                        }
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                if let Some(index) = *self.hovered_option {
+                    if self.is_enabled(index) {
+                        *self.last_selection = self.options.get(index).cloned();
                     }
                 }
             }
-            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                let bounds = layout.bounds();
-
-                if bounds.contains(cursor_position) {
-                    let text_size =
-                        self.text_size.unwrap_or(renderer.default_size());
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                *self.hovered_option = self.option_at(
+                    &rows,
+                    option_height,
+                    cursor_position.y - bounds.y,
+                );
+            }
+            Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                *self.hovered_option = self.option_at(
+                    &rows,
+                    option_height,
+                    cursor_position.y - bounds.y,
+                );
 
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
-                    );
+                if let Some(index) = *self.hovered_option {
+                    *self.last_selection = self.options.get(index).cloned();
                 }
             }
-            Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let bounds = layout.bounds();
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.search.is_some() && !c.is_control() =>
+            {
+                if let Some(query) = &mut self.search {
+                    query.push(c);
+                }
 
-                if bounds.contains(cursor_position) {
-                    let text_size =
-                        self.text_size.unwrap_or(renderer.default_size());
+                let rows = self.rows();
+                let selectable = self.selectable(&rows, option_height);
 
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
-                    );
+                self.hover(selectable.first().copied(), option_height);
+                shell.invalidate_layout();
 
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.search.is_some() => {
+                let selectable = self.selectable(&rows, option_height);
+                let position = self.hovered_option.and_then(|hovered| {
+                    selectable.iter().position(|(index, _)| *index == hovered)
+                });
+
+                match key_code {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        if !selectable.is_empty() {
+                            let last = selectable.len() - 1;
+
+                            let position = match (key_code, position) {
+                                (keyboard::KeyCode::Up, Some(position)) => {
+                                    position.saturating_sub(1)
+                                }
+                                (keyboard::KeyCode::Up, None) => last,
+                                (_, Some(position)) => (position + 1).min(last),
+                                (_, None) => 0,
+                            };
+
+                            self.hover(
+                                Some(selectable[position]),
+                                option_height,
+                            );
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Backspace
+                    | keyboard::KeyCode::Escape => {
+                        let query = self.search.as_mut().unwrap();
+
+                        if !query.is_empty() {
+                            if key_code == keyboard::KeyCode::Backspace {
+                                let _ = query.pop();
+                            } else {
+                                query.clear();
+                            }
+
+                            let rows = self.rows();
+                            let selectable =
+                                self.selectable(&rows, option_height);
+
+                            let option = self
+                                .hovered_option
+                                .and_then(|hovered| {
+                                    selectable
+                                        .iter()
+                                        .find(|(index, _)| *index == hovered)
+                                })
+                                .or_else(|| selectable.first())
+                                .copied();
+
+                            self.hover(option, option_height);
+                            shell.invalidate_layout();
+
+                            return event::Status::Captured;
                         }
                     }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        // The selection is published by the owner of the
+                        // menu, which also receives the key press
+                        if let Some(position) = position {
+                            let (index, _) = selectable[position];
+
+                            *self.last_selection =
+                                self.options.get(index).cloned();
+                        }
+                    }
+                    _ => {}
                 }
             }
             _ => {}
@@ -397,30 +782,62 @@ where
         let bounds = layout.bounds();
 
         let text_size = self.text_size.unwrap_or(renderer.default_size());
-        let option_height = (text_size + self.padding.vertical()) as usize;
+        let option_height = self.option_height(renderer);
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height as f32) as usize;
-        let end =
-            ((offset + viewport.height) / option_height as f32).ceil() as usize;
+        let mut y = bounds.y;
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
-
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+        for row in self.rows() {
+            let height = match row {
+                Row::Option(_) => option_height,
+                Row::Separator => SEPARATOR_HEIGHT,
+            };
 
-            let bounds = Rectangle {
+            let row_bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + (option_height * i) as f32,
+                y,
                 width: bounds.width,
-                height: f32::from(text_size + self.padding.vertical()),
+                height,
             };
 
+            y += height;
+
+            if row_bounds.y + row_bounds.height < viewport.y {
+                continue;
+            } else if row_bounds.y > viewport.y + viewport.height {
+                break;
+            }
+
+            let index = match row {
+                Row::Option(index) => index,
+                Row::Separator => {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: row_bounds.x + f32::from(self.padding.left),
+                                y: row_bounds.center_y().floor(),
+                                width: row_bounds.width
+                                    - f32::from(self.padding.horizontal()),
+                                height: 1.0,
+                            },
+                            border_color: Color::TRANSPARENT,
+                            border_width: 0.0,
+                            border_radius: 0.0,
+                        },
+                        self.style.separator_color,
+                    );
+
+                    continue;
+                }
+            };
+
+            let option = &self.options[index];
+            let is_enabled = self.is_enabled(index);
+            let is_selected = is_enabled && *self.hovered_option == Some(index);
+
             if is_selected {
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds,
+                        bounds: row_bounds,
                         border_color: Color::TRANSPARENT,
                         border_width: 0.0,
                         border_radius: 0.0,
@@ -432,20 +849,24 @@ where
             renderer.fill_text(Text {
                 content: &option.to_string(),
                 bounds: Rectangle {
-                    x: bounds.x + self.padding.left as f32,
-                    y: bounds.center_y(),
+                    x: row_bounds.x + self.padding.left as f32,
+                    y: row_bounds.center_y(),
                     width: f32::INFINITY,
-                    ..bounds
+                    ..row_bounds
                 },
                 size: f32::from(text_size),
                 font: self.font.clone(),
-                color: if is_selected {
+                color: if !is_enabled {
+                    self.style.disabled_text_color
+                } else if is_selected {
                     self.style.selected_text_color
                 } else {
                     self.style.text_color
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                spacing: text::Spacing::default(),
+                rotation: text::Rotation::None,
             });
         }
     }
@@ -4,6 +4,11 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
+#[cfg(test)]
+mod monospace;
+#[cfg(test)]
+pub(crate) use monospace::Monospace;
+
 use crate::layout;
 use crate::{Background, Color, Element, Rectangle, Vector};
 
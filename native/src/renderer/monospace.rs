@@ -0,0 +1,171 @@
+use crate::renderer::{self, Renderer};
+use crate::text::{self, Text};
+use crate::{Background, Font, Point, Rectangle, Size, Vector};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A renderer that does not draw anything, but measures text as if every
+/// grapheme was half as wide as the text size and every line as tall.
+///
+/// Unlike [`Null`], it produces non-zero measurements, so widgets can be
+/// tested for the way they lay out and hit-test text.
+///
+/// [`Null`]: crate::renderer::Null
+#[derive(Debug, Clone, Copy)]
+pub struct Monospace;
+
+impl Monospace {
+    fn advance(size: f32) -> f32 {
+        size / 2.0
+    }
+
+    fn lines(content: &str) -> impl Iterator<Item = &str> {
+        content.split('\n')
+    }
+}
+
+impl Renderer for Monospace {
+    fn with_layer(&mut self, _bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        f(self)
+    }
+
+    fn with_translation(
+        &mut self,
+        _translation: Vector,
+        f: impl FnOnce(&mut Self),
+    ) {
+        f(self)
+    }
+
+    fn clear(&mut self) {}
+
+    fn fill_quad(
+        &mut self,
+        _quad: renderer::Quad,
+        _background: impl Into<Background>,
+    ) {
+    }
+}
+
+impl text::Renderer for Monospace {
+    type Font = Font;
+
+    const ICON_FONT: Font = Font::Default;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        size: u16,
+        _font: Font,
+        _bounds: Size,
+        spacing: text::Spacing,
+    ) -> (f32, f32) {
+        let size = f32::from(size);
+
+        let width = Self::lines(content)
+            .map(|line| {
+                let count = line.graphemes(true).count() as f32;
+
+                count * Self::advance(size)
+                    + (count - 1.0).max(0.0) * spacing.letter_spacing
+            })
+            .fold(0.0, f32::max);
+
+        (width, Self::lines(content).count() as f32 * size)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        _font: Self::Font,
+        _bounds: Size,
+        point: Point,
+        _nearest_only: bool,
+        spacing: text::Spacing,
+    ) -> Option<text::Hit> {
+        let count = contents.graphemes(true).count();
+
+        if count == 0 {
+            return None;
+        }
+
+        let pitch = Self::advance(size) + spacing.letter_spacing;
+        let index = ((point.x / pitch).max(0.0) as usize).min(count - 1);
+        let center = index as f32 * pitch + Self::advance(size) / 2.0;
+
+        Some(text::Hit::NearestCharOffset(
+            index,
+            Vector::new(point.x - center, 0.0),
+        ))
+    }
+
+    fn glyphs(
+        &self,
+        contents: &str,
+        size: f32,
+        _font: Self::Font,
+        _bounds: Size,
+        spacing: text::Spacing,
+    ) -> Vec<text::Glyph> {
+        let pitch = Self::advance(size) + spacing.letter_spacing;
+
+        contents
+            .graphemes(true)
+            .enumerate()
+            .map(|(index, _)| text::Glyph {
+                index,
+                bounds: Rectangle {
+                    x: index as f32 * pitch,
+                    y: 0.0,
+                    width: Self::advance(size),
+                    height: size,
+                },
+            })
+            .collect()
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        let size = spans.first().map(|span| span.size).unwrap_or(20.0);
+        let content: String = spans.iter().map(|span| span.content).collect();
+
+        self.measure(
+            &content,
+            size as u16,
+            Font::Default,
+            bounds,
+            text::Spacing::default(),
+        )
+    }
+
+    fn span_glyphs(
+        &self,
+        spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> Vec<text::Glyph> {
+        let size = spans.first().map(|span| span.size).unwrap_or(20.0);
+        let content: String = spans.iter().map(|span| span.content).collect();
+
+        self.glyphs(
+            &content,
+            size,
+            Font::Default,
+            Size::INFINITY,
+            text::Spacing::default(),
+        )
+    }
+
+    fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+
+    fn fill_rich_text(&mut self, _text: text::RichText<'_, Self::Font>) {}
+}
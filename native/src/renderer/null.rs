@@ -52,6 +52,7 @@ impl text::Renderer for Null {
         _size: u16,
         _font: Font,
         _bounds: Size,
+        _spacing: text::Spacing,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
@@ -64,9 +65,39 @@ impl text::Renderer for Null {
         _bounds: Size,
         _point: Point,
         _nearest_only: bool,
+        _spacing: text::Spacing,
     ) -> Option<text::Hit> {
         None
     }
 
+    fn glyphs(
+        &self,
+        _contents: &str,
+        _size: f32,
+        _font: Self::Font,
+        _bounds: Size,
+        _spacing: text::Spacing,
+    ) -> Vec<text::Glyph> {
+        Vec::new()
+    }
+
+    fn measure_spans(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn span_glyphs(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> Vec<text::Glyph> {
+        Vec::new()
+    }
+
     fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+
+    fn fill_rich_text(&mut self, _text: text::RichText<'_, Self::Font>) {}
 }
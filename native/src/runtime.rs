@@ -1,5 +1,6 @@
 //! Run commands and subscriptions.
 use crate::event::{self, Event};
+use crate::window;
 use crate::Hasher;
 
 /// A native runtime with a generic executor and receiver of results.
@@ -11,7 +12,7 @@ use crate::Hasher;
 /// [`Subscription`]: crate::Subscription
 pub type Runtime<Executor, Receiver, Message> = iced_futures::Runtime<
     Hasher,
-    (Event, event::Status),
+    (window::Id, Event, event::Status),
     Executor,
     Receiver,
     Message,
@@ -1,3 +1,6 @@
+use crate::time::Instant;
+use crate::Point;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
@@ -9,6 +12,8 @@ pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    ime_position: Option<Point>,
+    redraw_request: Option<Instant>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -18,6 +23,8 @@ impl<'a, Message> Shell<'a, Message> {
             messages,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            ime_position: None,
+            redraw_request: None,
         }
     }
 
@@ -55,6 +62,39 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Requests the input method editor of the platform to be placed at the
+    /// given position.
+    ///
+    /// Widgets that receive text should call this when focused, so the
+    /// candidate window of the input method appears next to the text cursor.
+    pub fn request_ime_position(&mut self, position: Point) {
+        self.ime_position = Some(position);
+    }
+
+    /// Returns the position of the input method editor requested by the
+    /// widgets, if any.
+    pub fn ime_position(&self) -> Option<Point> {
+        self.ime_position
+    }
+
+    /// Requests a new frame to be drawn at the given [`Instant`].
+    ///
+    /// Widgets that animate over time should call this, since the shell
+    /// only redraws on its own when an event happens. When many requests are
+    /// made, the earliest one wins.
+    pub fn request_redraw(&mut self, at: Instant) {
+        self.redraw_request = Some(
+            self.redraw_request
+                .filter(|requested| *requested < at)
+                .unwrap_or(at),
+        );
+    }
+
+    /// Returns the earliest redraw requested by the widgets, if any.
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -67,6 +107,12 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        self.ime_position = other.ime_position.or(self.ime_position);
+
+        if let Some(at) = other.redraw_request {
+            self.request_redraw(at);
+        }
     }
 
     /// Returns whether the widgets of the current application have been
@@ -1,5 +1,6 @@
 //! Listen to external events in your application.
 use crate::event::{self, Event};
+use crate::window;
 use crate::Hasher;
 
 use iced_futures::futures::{self, Future, Stream};
@@ -20,16 +21,19 @@ use std::hash::Hash;
 ///
 /// [`Command`]: crate::Command
 pub type Subscription<T> =
-    iced_futures::Subscription<Hasher, (Event, event::Status), T>;
+    iced_futures::Subscription<Hasher, (window::Id, Event, event::Status), T>;
 
-/// A stream of runtime events.
+/// A stream of runtime events, along with the [`window::Id`] of the window
+/// that produced them.
 ///
 /// It is the input of a [`Subscription`] in the native runtime.
-pub type EventStream = BoxStream<(Event, event::Status)>;
+pub type EventStream = BoxStream<(window::Id, Event, event::Status)>;
 
 /// A native [`Subscription`] tracker.
-pub type Tracker =
-    iced_futures::subscription::Tracker<Hasher, (Event, event::Status)>;
+pub type Tracker = iced_futures::subscription::Tracker<
+    Hasher,
+    (window::Id, Event, event::Status),
+>;
 
 pub use iced_futures::subscription::Recipe;
 
@@ -64,13 +68,49 @@ where
             use futures::future;
             use futures::stream::StreamExt;
 
-            events.filter_map(move |(event, status)| {
+            events.filter_map(move |(_window, event, status)| {
                 future::ready(f(event, status))
             })
         },
     })
 }
 
+/// Returns a [`Subscription`] to all the runtime events, along with the
+/// [`window::Id`] of the window that produced them.
+///
+/// Like [`events`], it will only notify your application of any [`Event`]
+/// that was not captured by any widget.
+pub fn events_by_window() -> Subscription<(window::Id, Event)> {
+    events_by_window_with(|window, event, status| match status {
+        event::Status::Ignored => Some((window, event)),
+        event::Status::Captured => None,
+    })
+}
+
+/// Returns a [`Subscription`] that filters all the runtime events, along
+/// with the [`window::Id`] of the window that produced them, with the
+/// provided function.
+///
+/// It works like [`events_with`], but in every window of the application.
+pub fn events_by_window_with<Message>(
+    f: fn(window::Id, Event, event::Status) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + MaybeSend,
+{
+    Subscription::from_recipe(Runner {
+        id: f,
+        spawn: move |events| {
+            use futures::future;
+            use futures::stream::StreamExt;
+
+            events.filter_map(move |(window, event, status)| {
+                future::ready(f(window, event, status))
+            })
+        },
+    })
+}
+
 /// Returns a [`Subscription`] that will create and asynchronously run the
 /// given [`Stream`].
 ///
@@ -186,7 +226,7 @@ where
     spawn: F,
 }
 
-impl<I, S, F, Message> Recipe<Hasher, (Event, event::Status)>
+impl<I, S, F, Message> Recipe<Hasher, (window::Id, Event, event::Status)>
     for Runner<I, F, S, Message>
 where
     I: Hash + 'static,
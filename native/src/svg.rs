@@ -1,6 +1,8 @@
 //! Load and draw vector graphics.
 use crate::{Hasher, Rectangle};
 
+pub use iced_style::svg::Tint;
+
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -85,4 +87,18 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws an SVG with the given [`Handle`] and inside the provided `bounds`.
     fn draw(&mut self, handle: Handle, bounds: Rectangle);
+
+    /// Draws an SVG with the given [`Handle`], [`Tint`], if any, and opacity
+    /// inside the provided `bounds`.
+    ///
+    /// By default, the [`Tint`] and the opacity are ignored.
+    fn draw_styled(
+        &mut self,
+        handle: Handle,
+        _tint: Option<Tint>,
+        _opacity: f32,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle, bounds)
+    }
 }
@@ -1,7 +1,12 @@
 //! Draw and interact with text.
+pub mod bidi;
+
 use crate::alignment;
 use crate::{Color, Point, Rectangle, Size, Vector};
 
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
 /// A paragraph.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a, Font> {
@@ -9,6 +14,9 @@ pub struct Text<'a, Font> {
     pub content: &'a str,
 
     /// The bounds of the paragraph.
+    ///
+    /// A rotated [`Text`] turns around the position of its bounds, and the
+    /// size of its bounds is measured along its rotated axes.
     pub bounds: Rectangle,
 
     /// The size of the [`Text`].
@@ -25,6 +33,202 @@ pub struct Text<'a, Font> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The [`Spacing`] of the [`Text`].
+    pub spacing: Spacing,
+
+    /// The [`Rotation`] of the [`Text`].
+    pub rotation: Rotation,
+}
+
+/// A clockwise rotation of some text.
+///
+/// The alignment of rotated text applies along its rotated axes. For
+/// instance, text rotated by 90 degrees that is aligned to the left starts
+/// at the top of its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// The text is not rotated.
+    #[default]
+    None,
+
+    /// The text is rotated by 90 degrees, reading from top to bottom.
+    Degrees90,
+
+    /// The text is rotated by 180 degrees, upside down.
+    Degrees180,
+
+    /// The text is rotated by 270 degrees, reading from bottom to top.
+    Degrees270,
+}
+
+impl Rotation {
+    /// Returns true if the lines of text with this [`Rotation`] are vertical.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Degrees90 | Self::Degrees270)
+    }
+
+    /// Returns the angle of the [`Rotation`], in radians.
+    pub fn radians(self) -> f32 {
+        match self {
+            Self::None => 0.0,
+            Self::Degrees90 => std::f32::consts::FRAC_PI_2,
+            Self::Degrees180 => std::f32::consts::PI,
+            Self::Degrees270 => 3.0 * std::f32::consts::FRAC_PI_2,
+        }
+    }
+
+    /// Converts a [`Size`] between the axes of the screen and the rotated
+    /// axes of the text, which only differ for vertical rotations.
+    pub fn rotate(self, size: Size) -> Size {
+        if self.is_vertical() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+}
+
+/// The side of a control, like a checkbox, where its label is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The label is placed before the control.
+    Left,
+
+    /// The label is placed after the control.
+    Right,
+}
+
+/// The height of a line of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// A multiple of the size of the text.
+    Relative(f32),
+
+    /// An amount of logical pixels.
+    Absolute(f32),
+}
+
+impl LineHeight {
+    /// Returns the height in logical pixels of a line of text with the given
+    /// size.
+    pub fn to_absolute(self, size: f32) -> f32 {
+        match self {
+            Self::Relative(factor) => factor * size,
+            Self::Absolute(height) => height,
+        }
+    }
+}
+
+/// The way the lines of some text are broken when they do not fit in its
+/// width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// Lines are broken between words.
+    #[default]
+    Word,
+
+    /// Lines are broken between any two characters.
+    Glyph,
+
+    /// Lines are only broken by line breaks in the text, overflowing its
+    /// width otherwise.
+    None,
+}
+
+/// The spacing between the lines and the characters of some text, as well
+/// as the way its lines [`Wrap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    /// The height of every line, or the natural line height of the font if
+    /// `None`.
+    pub line_height: Option<LineHeight>,
+
+    /// The amount of logical pixels added between consecutive characters.
+    ///
+    /// Letter spacing does not affect where lines wrap.
+    pub letter_spacing: f32,
+
+    /// The way lines are broken when they do not fit in the width of the
+    /// text.
+    pub wrap: Wrap,
+
+    /// The distance between tab stops, in spaces.
+    ///
+    /// A tab character advances to the next tab stop of its line. Like
+    /// letter spacing, tabs do not affect where lines wrap.
+    pub tab_width: u8,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            line_height: None,
+            letter_spacing: 0.0,
+            wrap: Wrap::default(),
+            tab_width: 4,
+        }
+    }
+}
+
+/// A run of text with its own style, laid out as part of a [`RichText`].
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a, Font> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The color of the [`Span`].
+    pub color: Color,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+}
+
+/// A paragraph made of differently styled [`Span`]s, which wraps across
+/// their boundaries and shares a baseline in every line.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a, Font> {
+    /// The spans of the paragraph.
+    pub spans: &'a [Span<'a, Font>],
+
+    /// The bounds of the paragraph.
+    pub bounds: Rectangle,
+
+    /// The horizontal alignment of the [`RichText`].
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the [`RichText`].
+    pub vertical_alignment: alignment::Vertical,
+}
+
+/// The way a single line of text is shortened when it does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Truncation {
+    /// The text is never shortened.
+    #[default]
+    None,
+
+    /// The end of the text is replaced with an ellipsis.
+    End,
+
+    /// The middle of the text is replaced with an ellipsis.
+    Middle,
+
+    /// The start of the text is replaced with an ellipsis.
+    Start,
+}
+
+/// A laid out character of some text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    /// The index of the character in the text.
+    pub index: usize,
+
+    /// The bounds of the glyph, relative to the origin of the text.
+    pub bounds: Rectangle,
 }
 
 /// The result of hit testing on text.
@@ -83,15 +287,78 @@ pub trait Renderer: crate::Renderer {
         size: u16,
         font: Self::Font,
         bounds: Size,
+        spacing: Spacing,
     ) -> (f32, f32);
 
     /// Measures the width of the text as if it were laid out in a single line.
-    fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
-        let (width, _) = self.measure(content, size, font, Size::INFINITY);
+    fn measure_width(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        spacing: Spacing,
+    ) -> f32 {
+        let (width, _) =
+            self.measure(content, size, font, Size::INFINITY, spacing);
 
         width
     }
 
+    /// Shortens the given text with an ellipsis, following the given
+    /// [`Truncation`], so it fits in a single line of the given width.
+    ///
+    /// The text is cut at grapheme boundaries and it is only shortened when
+    /// it overflows, in which case an owned [`Cow`] is returned.
+    fn truncate<'a>(
+        &self,
+        content: &'a str,
+        size: u16,
+        font: Self::Font,
+        max_width: f32,
+        truncation: Truncation,
+        spacing: Spacing,
+    ) -> Cow<'a, str> {
+        if truncation == Truncation::None
+            || self.measure_width(content, size, font.clone(), spacing)
+                <= max_width
+        {
+            return Cow::Borrowed(content);
+        }
+
+        let graphemes: Vec<&str> = content.graphemes(true).collect();
+
+        let shorten = |kept: usize| match truncation {
+            Truncation::Middle => {
+                let start = graphemes[..kept - kept / 2].concat();
+                let end = graphemes[graphemes.len() - kept / 2..].concat();
+
+                format!("{}\u{2026}{}", start, end)
+            }
+            Truncation::Start => format!(
+                "\u{2026}{}",
+                graphemes[graphemes.len() - kept..].concat()
+            ),
+            _ => format!("{}\u{2026}", graphemes[..kept].concat()),
+        };
+
+        // Find the most graphemes that fit, knowing that all of them do not
+        let (mut fitting, mut overflowing) = (0, graphemes.len());
+
+        while overflowing - fitting > 1 {
+            let kept = (fitting + overflowing) / 2;
+
+            if self.measure_width(&shorten(kept), size, font.clone(), spacing)
+                <= max_width
+            {
+                fitting = kept;
+            } else {
+                overflowing = kept;
+            }
+        }
+
+        Cow::Owned(shorten(fitting))
+    }
+
     /// Tests whether the provided point is within the boundaries of text
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -107,8 +374,160 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
         point: Point,
         nearest_only: bool,
+        spacing: Spacing,
     ) -> Option<Hit>;
 
+    /// Lays out text with the given parameters and returns the [`Glyph`] of
+    /// every visible character, in order.
+    ///
+    /// Characters that do not produce a glyph, like line breaks, are skipped.
+    fn glyphs(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Self::Font,
+        bounds: Size,
+        spacing: Spacing,
+    ) -> Vec<Glyph>;
+
+    /// Measures the given [`Span`]s laid out as a single paragraph in the
+    /// given bounds and returns the minimum boundaries that can fit them.
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Lays out the given [`Span`]s as a single paragraph and returns the
+    /// [`Glyph`] of every visible character, in order.
+    ///
+    /// The index of a [`Glyph`] counts the characters of all the [`Span`]s,
+    /// as if their contents were concatenated.
+    fn span_glyphs(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> Vec<Glyph>;
+
+    /// Returns the index of the [`Span`] with a glyph under the given point,
+    /// relative to the origin of the paragraph, if any.
+    fn hit_test_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize> {
+        let glyph = self
+            .span_glyphs(spans, bounds)
+            .into_iter()
+            .find(|glyph| glyph.bounds.contains(point))?;
+
+        let mut end = 0;
+
+        spans.iter().position(|span| {
+            end += span.content.chars().count();
+
+            glyph.index < end
+        })
+    }
+
     /// Draws the given [`Text`].
     fn fill_text(&mut self, text: Text<'_, Self::Font>);
+
+    /// Draws the given [`RichText`].
+    fn fill_rich_text(&mut self, text: RichText<'_, Self::Font>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Monospace;
+
+    // Every grapheme of the test renderer, including the ellipsis, is 10
+    // pixels wide at this size
+    fn truncate(
+        content: &str,
+        max_width: f32,
+        truncation: Truncation,
+    ) -> Cow<'_, str> {
+        Monospace.truncate(
+            content,
+            20,
+            Default::default(),
+            max_width,
+            truncation,
+            Spacing::default(),
+        )
+    }
+
+    #[test]
+    fn fitting_text_is_not_truncated() {
+        for truncation in
+            [Truncation::End, Truncation::Middle, Truncation::Start]
+        {
+            assert!(matches!(
+                truncate("Hello", 50.0, truncation),
+                Cow::Borrowed("Hello")
+            ));
+        }
+    }
+
+    #[test]
+    fn text_is_not_truncated_without_truncation() {
+        assert!(matches!(
+            truncate("Hello, world!", 60.0, Truncation::None),
+            Cow::Borrowed("Hello, world!")
+        ));
+    }
+
+    #[test]
+    fn overflowing_text_is_truncated_at_the_end() {
+        assert_eq!(
+            truncate("Hello, world!", 60.0, Truncation::End),
+            "Hello\u{2026}"
+        );
+    }
+
+    #[test]
+    fn overflowing_text_is_truncated_in_the_middle() {
+        assert_eq!(
+            truncate("Hello, world!", 60.0, Truncation::Middle),
+            "Hel\u{2026}d!"
+        );
+    }
+
+    #[test]
+    fn overflowing_text_is_truncated_at_the_start() {
+        assert_eq!(
+            truncate("Hello, world!", 60.0, Truncation::Start),
+            "\u{2026}orld!"
+        );
+    }
+
+    #[test]
+    fn truncation_keeps_graphemes_whole() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let accented = "e\u{301}";
+        let content = format!("{}{}{}{}", family, accented, family, accented);
+
+        assert_eq!(
+            truncate(&content, 30.0, Truncation::End),
+            format!("{}{}\u{2026}", family, accented)
+        );
+        assert_eq!(
+            truncate(&content, 30.0, Truncation::Start),
+            format!("\u{2026}{}{}", family, accented)
+        );
+        assert_eq!(
+            truncate(&content, 30.0, Truncation::Middle),
+            format!("{}\u{2026}{}", family, accented)
+        );
+    }
+
+    #[test]
+    fn nothing_is_kept_when_only_the_ellipsis_fits() {
+        assert_eq!(truncate("Hello", 10.0, Truncation::End), "\u{2026}");
+        assert_eq!(truncate("Hello", 10.0, Truncation::Start), "\u{2026}");
+    }
 }
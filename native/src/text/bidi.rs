@@ -0,0 +1,331 @@
+//! Display text that mixes left-to-right and right-to-left scripts.
+//!
+//! Text is stored in logical order, the order in which it is read and typed.
+//! Right-to-left scripts, like Arabic or Hebrew, are displayed in the
+//! opposite direction, so their runs need to be reordered before drawing.
+//!
+//! Every line is reordered on its own, following the [Unicode Bidirectional
+//! Algorithm]. Grapheme clusters are kept whole, but glyphs are not shaped
+//! nor mirrored.
+//!
+//! [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+use crate::text::Hit;
+use crate::Vector;
+
+use std::borrow::Cow;
+use std::ops::Range;
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Some text in visual order, remembering where every character comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Visual<'a> {
+    content: Cow<'a, str>,
+    characters: Vec<Character>,
+}
+
+/// The origin of a character of some [`Visual`] text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Character {
+    logical: usize,
+    is_rtl: bool,
+}
+
+impl<'a> Visual<'a> {
+    /// Reorders the given text, stored in logical order, for display.
+    ///
+    /// Text written in a single direction is borrowed as it is.
+    pub fn new(content: &'a str) -> Self {
+        let clusters = match clusters(content) {
+            Some(clusters) => clusters,
+            None => {
+                return Self {
+                    content: Cow::Borrowed(content),
+                    characters: Vec::new(),
+                }
+            }
+        };
+
+        let mut visual = String::with_capacity(content.len());
+        let mut characters = Vec::with_capacity(content.len());
+
+        for cluster in clusters {
+            let logical = content[..cluster.range.start].chars().count();
+            let text = &content[cluster.range];
+
+            visual.push_str(text);
+
+            characters.extend((0..text.chars().count()).map(|offset| {
+                Character {
+                    logical: logical + offset,
+                    is_rtl: cluster.is_rtl,
+                }
+            }));
+        }
+
+        Self {
+            content: Cow::Owned(visual),
+            characters,
+        }
+    }
+
+    /// Returns the text in visual order.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Turns the [`Visual`] text into its content.
+    pub fn into_content(self) -> Cow<'a, str> {
+        self.content
+    }
+
+    /// Returns the index of the character of the logical text displayed at
+    /// the given index of the visual text.
+    pub fn logical(&self, index: usize) -> usize {
+        self.characters
+            .get(index)
+            .map_or(index, |character| character.logical)
+    }
+
+    /// Maps a [`Hit`] on the visual text to the logical text.
+    ///
+    /// A point to the right of a right-to-left character is before it in
+    /// the logical text, so the direction of its delta is flipped.
+    pub fn hit(&self, hit: Hit) -> Hit {
+        match hit {
+            Hit::CharOffset(index) => Hit::CharOffset(self.logical(index)),
+            Hit::NearestCharOffset(index, delta) => {
+                let is_rtl = self
+                    .characters
+                    .get(index)
+                    .is_some_and(|character| character.is_rtl);
+
+                Hit::NearestCharOffset(
+                    self.logical(index),
+                    if is_rtl {
+                        Vector::new(-delta.x, delta.y)
+                    } else {
+                        delta
+                    },
+                )
+            }
+        }
+    }
+}
+
+/// Returns the text displayed to the left of a caret placed before the
+/// grapheme cluster at the given logical index of a single line of text.
+///
+/// Measuring it gives the horizontal position of the caret. In a
+/// right-to-left run, the caret before a grapheme is found on its right.
+pub fn before_caret(content: &str, index: usize) -> Cow<'_, str> {
+    let clusters = match clusters(content) {
+        Some(clusters) => clusters,
+        None => {
+            let end = content
+                .grapheme_indices(true)
+                .nth(index)
+                .map_or(content.len(), |(start, _)| start);
+
+            return Cow::Borrowed(&content[..end]);
+        }
+    };
+
+    let starts: Vec<usize> = content
+        .grapheme_indices(true)
+        .map(|(start, _)| start)
+        .collect();
+
+    // The caret sticks to the grapheme after it or, at the end, to the
+    // last one
+    let (target, is_after) = match starts.get(index) {
+        Some(&start) => (start, false),
+        None => (starts.last().copied().unwrap_or(0), true),
+    };
+
+    let position = clusters
+        .iter()
+        .position(|cluster| cluster.range.start == target)
+        .unwrap_or(clusters.len());
+
+    let is_rtl = clusters.get(position).is_some_and(|cluster| cluster.is_rtl);
+
+    let visible = if is_rtl != is_after {
+        position + 1
+    } else {
+        position
+    };
+
+    Cow::Owned(
+        clusters[..visible.min(clusters.len())]
+            .iter()
+            .map(|cluster| &content[cluster.range.clone()])
+            .collect(),
+    )
+}
+
+/// A grapheme cluster of some text, in logical order.
+#[derive(Debug, Clone)]
+struct Cluster {
+    range: Range<usize>,
+    is_rtl: bool,
+}
+
+/// Returns the grapheme clusters of the given text in visual order, or
+/// `None` if there is nothing to reorder.
+fn clusters(content: &str) -> Option<Vec<Cluster>> {
+    let info = BidiInfo::new(content, None);
+
+    if !info.has_rtl() {
+        return None;
+    }
+
+    let mut clusters = Vec::new();
+
+    for paragraph in &info.paragraphs {
+        // Paragraph separators stay at the end of their line, whatever its
+        // direction
+        let separators: usize = content[paragraph.range.clone()]
+            .chars()
+            .rev()
+            .take_while(|&c| bidi_class(c) == BidiClass::B)
+            .map(char::len_utf8)
+            .sum();
+
+        let end = paragraph.range.end - separators;
+
+        let (levels, runs) =
+            info.visual_runs(paragraph, paragraph.range.start..end);
+
+        for run in runs {
+            let is_rtl = levels[run.start].is_rtl();
+            let graphemes = content[run.clone()].grapheme_indices(true).map(
+                |(offset, grapheme)| Cluster {
+                    range: run.start + offset
+                        ..run.start + offset + grapheme.len(),
+                    is_rtl,
+                },
+            );
+
+            if is_rtl {
+                clusters.extend(graphemes.rev());
+            } else {
+                clusters.extend(graphemes);
+            }
+        }
+
+        clusters.extend(content[end..paragraph.range.end].char_indices().map(
+            |(offset, c)| Cluster {
+                range: end + offset..end + offset + c.len_utf8(),
+                is_rtl: false,
+            },
+        ));
+    }
+
+    Some(clusters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hebrew letters are written from right to left
+    const ALEF: &str = "\u{5D0}";
+    const BET: &str = "\u{5D1}";
+    const GIMEL: &str = "\u{5D2}";
+    // A bet with a dagesh, a mark combined with the letter before it
+    const BET_DAGESH: &str = "\u{5D1}\u{5BC}";
+
+    fn hebrew() -> String {
+        [ALEF, BET, GIMEL].concat()
+    }
+
+    #[test]
+    fn left_to_right_text_is_borrowed() {
+        let visual = Visual::new("Hello, world!");
+
+        assert!(matches!(visual.into_content(), Cow::Borrowed(_)));
+        assert!(matches!(before_caret("Hello", 2), Cow::Borrowed("He")));
+    }
+
+    #[test]
+    fn right_to_left_runs_are_reversed() {
+        let content = format!("abc {} def", hebrew());
+        let visual = Visual::new(&content);
+
+        assert_eq!(
+            visual.content(),
+            format!("abc {}{}{} def", GIMEL, BET, ALEF)
+        );
+        assert_eq!(visual.logical(0), 0);
+        assert_eq!(visual.logical(4), 6);
+        assert_eq!(visual.logical(6), 4);
+        assert_eq!(visual.logical(8), 8);
+    }
+
+    #[test]
+    fn right_to_left_paragraphs_put_embedded_runs_first() {
+        let content = format!("{} abc", hebrew());
+
+        assert_eq!(
+            Visual::new(&content).content(),
+            format!("abc {}{}{}", GIMEL, BET, ALEF)
+        );
+    }
+
+    #[test]
+    fn lines_are_reordered_on_their_own() {
+        let content = format!("{}\nabc", hebrew());
+
+        assert_eq!(
+            Visual::new(&content).content(),
+            format!("{}{}{}\nabc", GIMEL, BET, ALEF)
+        );
+    }
+
+    #[test]
+    fn graphemes_are_kept_whole() {
+        let content = [ALEF, BET_DAGESH, GIMEL].concat();
+        let visual = Visual::new(&content);
+
+        assert_eq!(visual.content(), [GIMEL, BET_DAGESH, ALEF].concat());
+        assert_eq!(visual.logical(1), 1);
+        assert_eq!(visual.logical(2), 2);
+    }
+
+    #[test]
+    fn hits_on_right_to_left_characters_are_flipped() {
+        let content = format!("ab{}", hebrew());
+        let visual = Visual::new(&content);
+
+        // The gimel is displayed right after "ab"
+        let right_of_gimel =
+            visual.hit(Hit::NearestCharOffset(2, Vector::new(3.0, 0.0)));
+
+        assert_eq!(right_of_gimel.cursor(), 4);
+
+        let left_of_gimel =
+            visual.hit(Hit::NearestCharOffset(2, Vector::new(-3.0, 0.0)));
+
+        assert_eq!(left_of_gimel.cursor(), 5);
+
+        let on_b = visual.hit(Hit::NearestCharOffset(1, Vector::new(3.0, 0.0)));
+
+        assert_eq!(on_b.cursor(), 2);
+    }
+
+    #[test]
+    fn caret_is_placed_visually_in_right_to_left_runs() {
+        let content = format!("ab{}", hebrew());
+
+        // Displayed as "ab" followed by gimel, bet and alef
+        assert_eq!(before_caret(&content, 1), "a");
+        assert_eq!(
+            before_caret(&content, 2),
+            format!("ab{}{}{}", GIMEL, BET, ALEF)
+        );
+        assert_eq!(before_caret(&content, 3), format!("ab{}{}", GIMEL, BET));
+        assert_eq!(before_caret(&content, 4), format!("ab{}", GIMEL));
+        assert_eq!(before_caret(&content, 5), "ab");
+    }
+}
@@ -3,6 +3,8 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::time::Instant;
+use crate::widget;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -23,6 +25,8 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: layout::Node,
     overlay: Option<layout::Node>,
     bounds: Size,
+    ime_position: Option<Point>,
+    redraw_request: Option<Instant>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -99,6 +103,8 @@ where
             base,
             overlay: None,
             bounds,
+            ime_position: None,
+            redraw_request: None,
         }
     }
 
@@ -180,6 +186,8 @@ where
         use std::mem::ManuallyDrop;
 
         let mut state = State::Updated;
+        self.redraw_request = None;
+        self.ime_position = None;
         let mut manual_overlay = ManuallyDrop::new(
             self.root.overlay(Layout::new(&self.base), renderer),
         );
@@ -231,6 +239,18 @@ where
                 if shell.are_widgets_invalid() {
                     state = State::Outdated;
                 }
+
+                if let Some(position) = shell.ime_position() {
+                    self.ime_position = Some(position);
+                }
+
+                if let Some(at) = shell.redraw_request() {
+                    self.redraw_request = Some(
+                        self.redraw_request
+                            .filter(|requested| *requested < at)
+                            .unwrap_or(at),
+                    );
+                }
             }
 
             let base_cursor = if layout.bounds().contains(cursor_position) {
@@ -282,6 +302,18 @@ where
                     state = State::Outdated;
                 }
 
+                if let Some(position) = shell.ime_position() {
+                    self.ime_position = Some(position);
+                }
+
+                if let Some(at) = shell.redraw_request() {
+                    self.redraw_request = Some(
+                        self.redraw_request
+                            .filter(|requested| *requested < at)
+                            .unwrap_or(at),
+                    );
+                }
+
                 event_status.merge(overlay_status)
             })
             .collect();
@@ -452,6 +484,34 @@ where
             .unwrap_or(base_interaction)
     }
 
+    /// Applies a [`widget::Operation`] to the [`UserInterface`].
+    pub fn operate(&mut self, operation: &mut dyn widget::Operation<Message>) {
+        self.root.operate(Layout::new(&self.base), operation);
+    }
+
+    /// Returns the position where the input method editor of the platform
+    /// was requested to be placed by the widgets during the last
+    /// [`UserInterface::update`], if any.
+    ///
+    /// You should update the position of the input method editor accordingly
+    /// in your system after calling [`UserInterface::update`]. It is `None`
+    /// when no focused widget is receiving text.
+    pub fn ime_position(&self) -> Option<Point> {
+        self.ime_position
+    }
+
+    /// Returns the earliest [`Instant`] at which the widgets requested a new
+    /// frame to be drawn during the last [`UserInterface::update`], if any.
+    ///
+    /// When it is reached, you should produce a
+    /// [`window::Event::RedrawRequested`] event and update the
+    /// [`UserInterface`] with it, so animated widgets can keep going.
+    ///
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -16,21 +16,36 @@ pub mod checkbox;
 pub mod column;
 pub mod container;
 pub mod image;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod lazy_image;
+pub mod link;
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod rich_text;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod space;
 pub mod svg;
 pub mod text;
+pub mod text_area;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
 
+mod action;
+mod id;
+
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -41,6 +56,14 @@ pub use column::Column;
 pub use container::Container;
 #[doc(no_inline)]
 pub use image::Image;
+#[cfg(feature = "image")]
+#[doc(no_inline)]
+pub use lazy_image::LazyImage;
+#[doc(no_inline)]
+pub use link::Link;
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
@@ -50,12 +73,18 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
@@ -64,12 +93,18 @@ pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_area::TextArea;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 
+pub use action::Action;
+pub use id::Id;
+pub use operation::Operation;
+
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -176,4 +211,14 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// By default, it does nothing.
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        _operation: &mut dyn Operation<Message>,
+    ) {
+    }
 }
@@ -6,7 +6,10 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
+use crate::widget::Operation;
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Vector, Widget,
@@ -59,6 +62,9 @@ pub struct Button<'a, Message, Renderer> {
     state: &'a mut State,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    on_right_press: Option<Message>,
+    on_middle_press: Option<Message>,
+    repeat: Option<(Duration, Duration)>,
     width: Length,
     height: Length,
     padding: Padding,
@@ -80,6 +86,9 @@ where
             state,
             content: content.into(),
             on_press: None,
+            on_right_press: None,
+            on_middle_press: None,
+            repeat: None,
             width: Length::Shrink,
             height: Length::Shrink,
             padding: Padding::new(5),
@@ -112,6 +121,48 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is pressed,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Button`] will be disabled.
+    pub fn on_press_maybe(mut self, msg: Option<Message>) -> Self {
+        self.on_press = msg;
+        self
+    }
+
+    /// Makes the [`Button`] produce its [`Button::on_press`] message as soon
+    /// as it is pressed, and then repeatedly while it is held: first after
+    /// the given initial delay, and then at the given interval.
+    ///
+    /// The repetition pauses while the cursor is outside of the [`Button`],
+    /// and no message is produced when it is released.
+    pub fn on_press_repeat(
+        mut self,
+        initial_delay: Duration,
+        interval: Duration,
+    ) -> Self {
+        self.repeat = Some((initial_delay, interval));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    ///
+    /// Unlike [`Button::on_press`], it does not enable the [`Button`].
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the middle mouse button.
+    ///
+    /// Unlike [`Button::on_press`], it does not enable the [`Button`].
+    pub fn on_middle_press(mut self, msg: Message) -> Self {
+        self.on_middle_press = Some(msg);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     pub fn style(
         mut self,
@@ -126,6 +177,11 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_right_pressed: bool,
+    is_middle_pressed: bool,
+    finger: Option<touch::Finger>,
+    is_finger_over: bool,
+    next_repeat: Option<Instant>,
 }
 
 impl State {
@@ -133,60 +189,219 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is being touched by a finger that has
+    /// not left its bounds.
+    fn is_touched(&self) -> bool {
+        self.finger.is_some() && self.is_finger_over
+    }
 }
 
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
 /// accordingly.
+///
+/// If `repeat` is set to an initial delay and an interval, the `on_press`
+/// message is produced when the [`Button`] is pressed and then repeatedly
+/// while it is held, instead of when it is released.
 pub fn update<'a, Message: Clone>(
     event: Event,
     layout: Layout<'_>,
     cursor_position: Point,
     shell: &mut Shell<'_, Message>,
     on_press: &Option<Message>,
+    on_right_press: &Option<Message>,
+    on_middle_press: &Option<Message>,
+    repeat: Option<(Duration, Duration)>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status {
-    match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if on_press.is_some() {
-                let bounds = layout.bounds();
+    let state = state();
+    let is_mouse_over = layout.bounds().contains(cursor_position);
 
-                if bounds.contains(cursor_position) {
-                    let state = state();
+    // A disabled button keeps track of no presses, so it is not displayed
+    // as pressed nor does it produce a message once it is enabled again
+    if on_press.is_none() {
+        state.is_pressed = false;
+        state.finger = None;
+        state.is_finger_over = false;
+        state.next_repeat = None;
+    }
 
+    let status = match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            if is_mouse_over =>
+        {
+            match on_press.clone() {
+                Some(on_press) => {
                     state.is_pressed = true;
 
-                    return event::Status::Captured;
+                    if let Some((delay, _)) = repeat {
+                        shell.publish(on_press);
+
+                        state.next_repeat = Some(Instant::now() + delay);
+                    }
+
+                    event::Status::Captured
                 }
+                None => event::Status::Ignored,
             }
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerLifted { .. }) => {
-            if let Some(on_press) = on_press.clone() {
-                let state = state();
+            if state.is_pressed =>
+        {
+            state.is_pressed = false;
+
+            // A repeating button has already produced its messages
+            let is_repeating = state.next_repeat.take().is_some();
+
+            match on_press.clone() {
+                Some(on_press) => {
+                    if is_mouse_over && !is_repeating {
+                        shell.publish(on_press);
+                    }
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonPressed(
+            button @ (mouse::Button::Right | mouse::Button::Middle),
+        )) => {
+            let (on_press, is_pressed) =
+                secondary(button, state, on_right_press, on_middle_press);
+
+            if on_press.is_some() && is_mouse_over {
+                *is_pressed = true;
+
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(
+            button @ (mouse::Button::Right | mouse::Button::Middle),
+        )) => {
+            let (on_press, is_pressed) =
+                secondary(button, state, on_right_press, on_middle_press);
 
-                if state.is_pressed {
-                    state.is_pressed = false;
+            if *is_pressed {
+                *is_pressed = false;
 
-                    let bounds = layout.bounds();
+                if let Some(on_press) = on_press.clone() {
+                    if is_mouse_over {
+                        shell.publish(on_press);
+                    }
+                }
 
-                    if bounds.contains(cursor_position) {
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        // Only the finger that pressed the button can release it, and it
+        // is followed on its own since it does not move the mouse cursor
+        Event::Touch(touch::Event::FingerPressed { id, position })
+            if state.finger.is_none() && layout.bounds().contains(position) =>
+        {
+            match on_press.clone() {
+                Some(on_press) => {
+                    state.finger = Some(id);
+                    state.is_finger_over = true;
+
+                    if let Some((delay, _)) = repeat {
                         shell.publish(on_press);
+
+                        state.next_repeat = Some(Instant::now() + delay);
                     }
 
-                    return event::Status::Captured;
+                    event::Status::Captured
                 }
+                None => event::Status::Ignored,
             }
         }
-        Event::Touch(touch::Event::FingerLost { .. }) => {
-            let state = state();
+        Event::Touch(touch::Event::FingerMoved { id, position })
+            if state.finger == Some(id) =>
+        {
+            state.is_finger_over = layout.bounds().contains(position);
 
-            state.is_pressed = false;
+            event::Status::Ignored
+        }
+        Event::Touch(touch::Event::FingerLifted { id, position })
+            if state.finger == Some(id) =>
+        {
+            state.finger = None;
+
+            let is_repeating = state.next_repeat.take().is_some();
+
+            match on_press.clone() {
+                Some(on_press) => {
+                    if layout.bounds().contains(position) && !is_repeating {
+                        shell.publish(on_press);
+                    }
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            }
+        }
+        Event::Touch(touch::Event::FingerLost { id, .. })
+            if state.finger == Some(id) =>
+        {
+            state.finger = None;
+            state.next_repeat = None;
+
+            event::Status::Ignored
+        }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            // The repetition pauses while the cursor is outside of the
+            // button, and resumes when it comes back
+            if let (Some(next_at), Some((_, interval))) =
+                (state.next_repeat, repeat)
+            {
+                let is_over = if state.finger.is_some() {
+                    state.is_finger_over
+                } else {
+                    is_mouse_over
+                };
+
+                if now >= next_at {
+                    if let Some(on_press) = on_press.clone() {
+                        if is_over {
+                            shell.publish(on_press);
+                        }
+
+                        state.next_repeat = Some(now + interval);
+                    } else {
+                        state.next_repeat = None;
+                    }
+                }
+            }
+
+            event::Status::Ignored
         }
-        _ => {}
+        _ => event::Status::Ignored,
+    };
+
+    // A held button is redrawn until its next repetition
+    if let Some(next_at) = state.next_repeat {
+        shell.request_redraw(next_at);
     }
 
-    event::Status::Ignored
+    status
+}
+
+/// Returns the message and the pressed flag of the given secondary mouse
+/// button, since every mouse button pairs its own press and release.
+fn secondary<'a, 'b, Message>(
+    button: mouse::Button,
+    state: &'b mut State,
+    on_right_press: &'a Option<Message>,
+    on_middle_press: &'a Option<Message>,
+) -> (&'a Option<Message>, &'b mut bool) {
+    match button {
+        mouse::Button::Right => (on_right_press, &mut state.is_right_pressed),
+        _ => (on_middle_press, &mut state.is_middle_pressed),
+    }
 }
 
 /// Draws a [`Button`].
@@ -202,16 +417,16 @@ pub fn draw<'a, Renderer: crate::Renderer>(
 
     let styling = if !is_enabled {
         style_sheet.disabled()
-    } else if is_mouse_over {
+    } else {
         let state = state();
 
-        if state.is_pressed {
+        if (is_mouse_over && state.is_pressed) || state.is_touched() {
             style_sheet.pressed()
-        } else {
+        } else if is_mouse_over {
             style_sheet.hovered()
+        } else {
+            style_sheet.active()
         }
-    } else {
-        style_sheet.active()
     };
 
     if styling.background.is_some() || styling.border_width > 0.0 {
@@ -278,7 +493,7 @@ pub fn mouse_interaction(
     if is_mouse_over && is_enabled {
         mouse::Interaction::Pointer
     } else {
-        mouse::Interaction::default()
+        mouse::Interaction::Idle
     }
 }
 
@@ -337,6 +552,9 @@ where
             cursor_position,
             shell,
             &self.on_press,
+            &self.on_right_press,
+            &self.on_middle_press,
+            self.repeat,
             || &mut self.state,
         )
     }
@@ -390,6 +608,17 @@ where
         self.content
             .overlay(layout.children().next().unwrap(), renderer)
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<Button<'a, Message, Renderer>>
@@ -404,3 +633,274 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Size;
+
+    const DELAY: Duration = Duration::from_millis(400);
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    fn update(
+        state: &mut State,
+        event: Event,
+        cursor_position: Point,
+    ) -> Vec<()> {
+        update_with_repeat(
+            state,
+            event,
+            cursor_position,
+            Some((DELAY, INTERVAL)),
+        )
+    }
+
+    fn update_with_repeat(
+        state: &mut State,
+        event: Event,
+        cursor_position: Point,
+        repeat: Option<(Duration, Duration)>,
+    ) -> Vec<()> {
+        update_with(state, event, cursor_position, &Some(()), repeat)
+    }
+
+    fn update_with(
+        state: &mut State,
+        event: Event,
+        cursor_position: Point,
+        on_press: &Option<()>,
+        repeat: Option<(Duration, Duration)>,
+    ) -> Vec<()> {
+        let node = layout::Node::new(Size::new(100.0, 50.0));
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = super::update(
+            event,
+            Layout::new(&node),
+            cursor_position,
+            &mut shell,
+            on_press,
+            &None,
+            &None,
+            repeat,
+            || state,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn held_button_repeats_while_hovered() {
+        let mut state = State::new();
+        let inside = Point::new(50.0, 25.0);
+        let outside = Point::new(150.0, 25.0);
+
+        let redraw = |at| Event::Window(window::Event::RedrawRequested(at));
+
+        let pressed = update(
+            &mut state,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            inside,
+        );
+        assert_eq!(pressed.len(), 1);
+
+        let start = state.next_repeat.unwrap() - DELAY;
+
+        assert!(update(&mut state, redraw(start), inside).is_empty());
+        assert_eq!(update(&mut state, redraw(start + DELAY), inside).len(), 1);
+        assert!(
+            update(&mut state, redraw(start + DELAY + INTERVAL), outside)
+                .is_empty()
+        );
+        assert_eq!(
+            update(&mut state, redraw(start + DELAY + INTERVAL * 2), inside)
+                .len(),
+            1
+        );
+
+        let released = update(
+            &mut state,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            inside,
+        );
+        assert!(released.is_empty());
+        assert_eq!(state.next_repeat, None);
+    }
+
+    #[test]
+    fn touch_press_is_released_by_its_own_finger() {
+        let mut state = State::new();
+        let inside = Point::new(50.0, 25.0);
+        let outside = Point::new(150.0, 25.0);
+        // The mouse cursor stays away during touch interactions
+        let cursor = Point::new(-1.0, -1.0);
+
+        let touch = |state: &mut State, event| {
+            update_with_repeat(state, Event::Touch(event), cursor, None)
+        };
+
+        let finger = touch::Finger(0);
+        let other = touch::Finger(1);
+
+        assert!(touch(
+            &mut state,
+            touch::Event::FingerPressed {
+                id: finger,
+                position: inside
+            }
+        )
+        .is_empty());
+        assert!(state.is_touched());
+
+        assert!(touch(
+            &mut state,
+            touch::Event::FingerLifted {
+                id: other,
+                position: inside
+            }
+        )
+        .is_empty());
+        assert!(state.is_touched());
+
+        assert_eq!(
+            touch(
+                &mut state,
+                touch::Event::FingerLifted {
+                    id: finger,
+                    position: inside
+                }
+            ),
+            vec![()]
+        );
+        assert!(!state.is_touched());
+
+        let _ = touch(
+            &mut state,
+            touch::Event::FingerPressed {
+                id: finger,
+                position: inside,
+            },
+        );
+        let _ = touch(
+            &mut state,
+            touch::Event::FingerMoved {
+                id: finger,
+                position: outside,
+            },
+        );
+        assert!(!state.is_touched());
+        assert!(touch(
+            &mut state,
+            touch::Event::FingerLifted {
+                id: finger,
+                position: outside
+            }
+        )
+        .is_empty());
+
+        let _ = touch(
+            &mut state,
+            touch::Event::FingerPressed {
+                id: finger,
+                position: inside,
+            },
+        );
+        assert!(touch(
+            &mut state,
+            touch::Event::FingerLost {
+                id: finger,
+                position: inside
+            }
+        )
+        .is_empty());
+        assert_eq!(state.finger, None);
+    }
+
+    #[test]
+    fn disabled_button_tracks_no_presses() {
+        let mut state = State::new();
+        let inside = Point::new(50.0, 25.0);
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let release =
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        let touch = Event::Touch(touch::Event::FingerPressed {
+            id: touch::Finger(0),
+            position: inside,
+        });
+
+        assert!(update_with(&mut state, press.clone(), inside, &None, None)
+            .is_empty());
+        assert!(!state.is_pressed);
+
+        assert!(update_with(&mut state, touch, inside, &None, None).is_empty());
+        assert!(!state.is_touched());
+
+        // A button disabled while held is released right away
+        let _ = update(&mut state, press, inside);
+        assert!(state.is_pressed);
+
+        let _ = update_with(
+            &mut state,
+            Event::Mouse(mouse::Event::CursorMoved { position: inside }),
+            inside,
+            &None,
+            None,
+        );
+        assert!(!state.is_pressed);
+        assert_eq!(state.next_repeat, None);
+
+        assert!(update(&mut state, release, inside).is_empty());
+    }
+
+    #[test]
+    fn disabled_button_is_drawn_with_the_disabled_style() {
+        struct Pressed;
+
+        impl StyleSheet for Pressed {
+            fn active(&self) -> Style {
+                Style::default()
+            }
+
+            fn pressed(&self) -> Style {
+                Style {
+                    text_color: Color::WHITE,
+                    ..self.active()
+                }
+            }
+        }
+
+        let mut state = State::new();
+        let inside = Point::new(50.0, 25.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0));
+
+        let _ = update_with_repeat(
+            &mut state,
+            Event::Touch(touch::Event::FingerPressed {
+                id: touch::Finger(0),
+                position: inside,
+            }),
+            Point::new(-1.0, -1.0),
+            None,
+        );
+
+        let text_color = |is_enabled| {
+            draw(
+                &mut crate::renderer::Null::new(),
+                bounds,
+                inside,
+                is_enabled,
+                &Pressed,
+                || &state,
+            )
+            .text_color
+        };
+
+        assert_eq!(text_color(true), Color::WHITE);
+        assert_eq!(text_color(false), Pressed.disabled().text_color);
+        assert_eq!(text_color(false).a, 0.5);
+    }
+}
@@ -8,10 +8,11 @@ use crate::text;
 use crate::touch;
 use crate::widget::{self, Row, Text};
 use crate::{
-    Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
-    Widget,
+    Alignment, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Widget,
 };
 
+pub use crate::text::Position;
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
@@ -34,12 +35,15 @@ pub use iced_style::checkbox::{Style, StyleSheet};
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<'a, Message, Renderer: text::Renderer> {
     is_checked: bool,
+    is_indeterminate: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: String,
     width: Length,
     size: u16,
     spacing: u16,
     text_size: Option<u16>,
+    text_alignment: alignment::Horizontal,
+    text_position: Position,
     font: Renderer::Font,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
@@ -65,17 +69,31 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
     {
         Checkbox {
             is_checked,
+            is_indeterminate: false,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
             size: Self::DEFAULT_SIZE,
             spacing: Self::DEFAULT_SPACING,
             text_size: None,
+            text_alignment: alignment::Horizontal::Left,
+            text_position: Position::Right,
             font: Renderer::Font::default(),
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets whether the [`Checkbox`] is indeterminate, like a checkbox that
+    /// selects a group of options when only some of them are selected.
+    ///
+    /// An indeterminate [`Checkbox`] displays a dash instead of a checkmark,
+    /// regardless of whether it is checked. By convention, clicking it
+    /// produces the message for `true`, so the whole group gets selected.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
     /// Sets the size of the [`Checkbox`].
     pub fn size(mut self, size: u16) -> Self {
         self.size = size;
@@ -100,6 +118,22 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the horizontal alignment of the text of the [`Checkbox`].
+    pub fn text_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    /// Sets the side of the [`Checkbox`] where its text is placed.
+    ///
+    /// By default, the text is placed to the right of the box. When the
+    /// text is on the left and the [`Checkbox`] fills its width, the box
+    /// sits at the far end.
+    pub fn text_position(mut self, position: Position) -> Self {
+        self.text_position = position;
+        self
+    }
+
     /// Sets the [`Font`] of the text of the [`Checkbox`].
     ///
     /// [`Font`]: crate::text::Renderer::Font
@@ -136,22 +170,30 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
+        let row = Row::<(), Renderer>::new()
             .width(self.width)
             .spacing(self.spacing)
-            .align_items(Alignment::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(
-                Text::new(&self.label)
-                    .font(self.font.clone())
-                    .width(self.width)
-                    .size(self.text_size.unwrap_or(renderer.default_size())),
-            )
-            .layout(renderer, limits)
+            .align_items(Alignment::Center);
+
+        let check = Row::new()
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size));
+
+        // The label takes whatever room the box leaves, which pushes the box
+        // to the far end when the label comes first
+        let label = Text::new(&self.label)
+            .font(self.font.clone())
+            .width(match self.width {
+                Length::Shrink => Length::Shrink,
+                _ => Length::Fill,
+            })
+            .size(self.text_size.unwrap_or(renderer.default_size()));
+
+        match self.text_position {
+            Position::Left => row.push(label).push(check),
+            Position::Right => row.push(check).push(label),
+        }
+        .layout(renderer, limits)
     }
 
     fn on_event(
@@ -169,7 +211,9 @@ where
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_checked));
+                    shell.publish((self.on_toggle)(
+                        self.is_indeterminate || !self.is_checked,
+                    ));
 
                     return event::Status::Captured;
                 }
@@ -206,16 +250,24 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let mut children = layout.children();
+        let first = children.next().unwrap();
+        let second = children.next().unwrap();
+
+        let (check_layout, label_layout) = match self.text_position {
+            Position::Left => (second, first),
+            Position::Right => (first, second),
+        };
 
-        let custom_style = if is_mouse_over {
+        let custom_style = if self.is_indeterminate {
+            self.style_sheet.indeterminate(is_mouse_over)
+        } else if is_mouse_over {
             self.style_sheet.hovered(self.is_checked)
         } else {
             self.style_sheet.active(self.is_checked)
         };
 
         {
-            let layout = children.next().unwrap();
-            let bounds = layout.bounds();
+            let bounds = check_layout.bounds();
 
             renderer.fill_quad(
                 renderer::Quad {
@@ -227,7 +279,24 @@ where
                 custom_style.background,
             );
 
-            if self.is_checked {
+            if self.is_indeterminate {
+                let thickness = (bounds.height * 0.12).round().max(2.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (bounds.x + bounds.width * 0.25).round(),
+                            y: (bounds.center_y() - thickness / 2.0).round(),
+                            width: (bounds.width * 0.5).round(),
+                            height: thickness,
+                        },
+                        border_radius: thickness / 2.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    custom_style.checkmark_color,
+                );
+            } else if self.is_checked {
                 renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
                     font: Renderer::ICON_FONT,
@@ -240,25 +309,25 @@ where
                     color: custom_style.checkmark_color,
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
+                    spacing: text::Spacing::default(),
+                    rotation: text::Rotation::None,
                 });
             }
         }
 
-        {
-            let label_layout = children.next().unwrap();
-
-            widget::text::draw(
-                renderer,
-                style,
-                label_layout,
-                &self.label,
-                self.font.clone(),
-                self.text_size,
-                custom_style.text_color,
-                alignment::Horizontal::Left,
-                alignment::Vertical::Center,
-            );
-        }
+        widget::text::draw(
+            renderer,
+            style,
+            label_layout,
+            &self.label,
+            self.font.clone(),
+            self.text_size,
+            custom_style.text_color,
+            self.text_alignment,
+            alignment::Vertical::Center,
+            text::Spacing::default(),
+            text::Rotation::None,
+        );
     }
 }
 
@@ -4,6 +4,7 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::Operation;
 use crate::{
     Alignment, Clipboard, Element, Layout, Length, Padding, Point, Rectangle,
     Shell, Widget,
@@ -172,6 +173,7 @@ where
         self.children
             .iter()
             .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
             .map(|(child, layout)| {
                 child.widget.mouse_interaction(
                     layout,
@@ -192,7 +194,12 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
-        for (child, layout) in self.children.iter().zip(layout.children()) {
+        for (child, layout) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
             child.draw(renderer, style, layout, cursor_position, viewport);
         }
     }
@@ -210,6 +217,20 @@ where
             })
             .next()
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children.iter_mut().zip(layout.children()).for_each(
+                |(child, layout)| {
+                    child.operate(layout, operation);
+                },
+            )
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<Column<'a, Message, Renderer>>
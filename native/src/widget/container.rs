@@ -5,6 +5,7 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::Operation;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Widget,
@@ -239,6 +240,17 @@ where
         self.content
             .overlay(layout.children().next().unwrap(), renderer)
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
 }
 
 /// Draws the background of a [`Container`] given its [`Style`] and its `bounds`.
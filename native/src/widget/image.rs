@@ -1,15 +1,22 @@
 //! Display images in your user interface.
+pub mod nine_slice;
 pub mod viewer;
+pub use nine_slice::NineSlice;
 pub use viewer::Viewer;
 
-use crate::image;
+use crate::event::{self, Event};
+use crate::image::{self, FilterMethod};
 use crate::layout;
 use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::window;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
-use std::hash::Hash;
+use std::hash::{Hash, Hasher as _};
+use std::sync::OnceLock;
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
@@ -23,12 +30,17 @@ use std::hash::Hash;
 /// ```
 ///
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-#[derive(Debug, Hash)]
+///
+/// Animated images, like GIFs, are played if the renderer supports them.
+#[derive(Debug)]
 pub struct Image<Handle> {
     handle: Handle,
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    filter_method: FilterMethod,
+    paused: bool,
+    opacity: f32,
 }
 
 impl<Handle> Image<Handle> {
@@ -39,6 +51,9 @@ impl<Handle> Image<Handle> {
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            paused: false,
+            opacity: 1.0,
         }
     }
 
@@ -63,6 +78,109 @@ impl<Handle> Image<Handle> {
             ..self
         }
     }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] is paused, if it has one.
+    ///
+    /// A paused [`Image`] keeps showing its current frame, as long as it has
+    /// a [`State`] to remember it. Otherwise, it shows its first frame.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], from `0.0` to `1.0`.
+    ///
+    /// A fully transparent [`Image`] is not drawn at all.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// The playback state of an animated or fading [`Image`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    started_at: Option<Instant>,
+    paused_at: Option<Duration>,
+    shown: Option<(u64, Instant)>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        match (self.paused_at, self.started_at) {
+            (Some(paused_at), _) => paused_at,
+            (None, Some(started_at)) => {
+                now.saturating_duration_since(started_at)
+            }
+            (None, None) => Duration::ZERO,
+        }
+    }
+
+    /// Returns the moment the image with the given hash was first shown, if
+    /// it is the image of the [`State`].
+    fn shown_at(&self, hash: u64) -> Option<Instant> {
+        self.shown
+            .filter(|(shown, _)| *shown == hash)
+            .map(|(_, at)| at)
+    }
+}
+
+fn hash<Handle: Hash>(handle: &Handle) -> u64 {
+    let mut hasher = Hasher::default();
+    handle.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Returns the opacity of an [`Image`] fading in at the given moment.
+fn fade<Handle: Hash>(
+    state: Option<&State>,
+    handle: &Handle,
+    fade_in: Option<Duration>,
+    now: Instant,
+) -> f32 {
+    match (state, fade_in) {
+        (Some(state), Some(fade_in)) if !fade_in.is_zero() => {
+            // An image is hidden until its state learns that it is shown
+            state
+                .shown_at(hash(handle))
+                .map(|shown_at| {
+                    now.saturating_duration_since(shown_at).as_secs_f32()
+                        / fade_in.as_secs_f32()
+                })
+                .unwrap_or(0.0)
+                .min(1.0)
+        }
+        _ => 1.0,
+    }
+}
+
+/// Returns the time elapsed in the animation of an [`Image`] at the given
+/// moment.
+///
+/// Without a [`State`], every animation plays in unison from the moment the
+/// first one is seen.
+fn elapsed(state: Option<&State>, paused: bool, now: Instant) -> Duration {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+    match state {
+        Some(state) => state.elapsed(now),
+        None if paused => Duration::ZERO,
+        None => now.saturating_duration_since(*EPOCH.get_or_init(Instant::now)),
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -105,6 +223,140 @@ where
     layout::Node::new(final_size)
 }
 
+/// Processes an [`Event`] and updates the [`State`] of an [`Image`], if any,
+/// accordingly.
+///
+/// A redraw is requested for the moment the next frame of an animated
+/// [`Image`] is due, and for every frame while it fades in.
+pub fn update<Message, Renderer, Handle>(
+    event: &Event,
+    renderer: &Renderer,
+    shell: &mut Shell<'_, Message>,
+    handle: &Handle,
+    paused: bool,
+    fade_in: Option<Duration>,
+    mut state: Option<&mut State>,
+) -> event::Status
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Hash,
+{
+    let now = match event {
+        Event::Window(window::Event::RedrawRequested(now)) => *now,
+        _ => Instant::now(),
+    };
+
+    if let (Some(state), Some(fade_in)) = (state.as_deref_mut(), fade_in) {
+        let hash = hash(handle);
+
+        // A new image fades in again
+        let shown_at = match state.shown_at(hash) {
+            Some(shown_at) => shown_at,
+            None => {
+                state.shown = Some((hash, now));
+
+                now
+            }
+        };
+
+        if now < shown_at + fade_in {
+            shell.request_redraw(now);
+        }
+    }
+
+    let animation = match renderer.animation(handle) {
+        Some(animation) => animation,
+        None => return event::Status::Ignored,
+    };
+
+    let state = state.map(|state| {
+        let _ = state.started_at.get_or_insert(now);
+
+        if paused {
+            if state.paused_at.is_none() {
+                state.paused_at = Some(state.elapsed(now));
+            }
+        } else if let Some(paused_at) = state.paused_at.take() {
+            // Resume from the frame shown while paused
+            state.started_at = now.checked_sub(paused_at).or(Some(now));
+        }
+
+        &*state
+    });
+
+    if !paused {
+        if let (_, Some(left)) = animation.frame(elapsed(state, paused, now)) {
+            shell.request_redraw(now + left);
+        }
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws an [`Image`].
+pub fn draw<Renderer, Handle>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    handle: &Handle,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    paused: bool,
+    opacity: f32,
+    fade_in: Option<Duration>,
+    state: Option<&State>,
+) where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    let now = Instant::now();
+    let opacity = opacity * fade(state, handle, fade_in, now);
+
+    if opacity <= 0.0 {
+        return;
+    }
+
+    let (width, height) = renderer.dimensions(handle);
+    let image_size = Size::new(width as f32, height as f32);
+
+    let bounds = layout.bounds();
+    let adjusted_fit = content_fit.fit(image_size, bounds.size());
+
+    let frame = renderer
+        .animation(handle)
+        .map(|animation| animation.frame(elapsed(state, paused, now)).0)
+        .unwrap_or(0);
+
+    let render = |renderer: &mut Renderer| {
+        // Content that overflows the bounds, like with `Cover` or `None`,
+        // is centered as well and cropped evenly on both sides
+        let offset = Vector::new(
+            (bounds.width - adjusted_fit.width) / 2.0,
+            (bounds.height - adjusted_fit.height) / 2.0,
+        );
+
+        let drawing_bounds = Rectangle {
+            width: adjusted_fit.width,
+            height: adjusted_fit.height,
+            ..bounds
+        };
+
+        renderer.draw_frame(
+            handle.clone(),
+            frame,
+            filter_method,
+            opacity,
+            drawing_bounds + offset,
+        )
+    };
+
+    if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
+    {
+        renderer.with_layer(bounds, render);
+    } else {
+        render(renderer)
+    }
+}
+
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
@@ -133,6 +385,26 @@ where
         )
     }
 
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            &event,
+            renderer,
+            shell,
+            &self.handle,
+            self.paused,
+            None,
+            None,
+        )
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -141,34 +413,17 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        let (width, height) = renderer.dimensions(&self.handle);
-        let image_size = Size::new(width as f32, height as f32);
-
-        let bounds = layout.bounds();
-        let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
-
-        let render = |renderer: &mut Renderer| {
-            let offset = Vector::new(
-                (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
-                (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
-            );
-
-            let drawing_bounds = Rectangle {
-                width: adjusted_fit.width,
-                height: adjusted_fit.height,
-                ..bounds
-            };
-
-            renderer.draw(self.handle.clone(), drawing_bounds + offset)
-        };
-
-        if adjusted_fit.width > bounds.width
-            || adjusted_fit.height > bounds.height
-        {
-            renderer.with_layer(bounds, render);
-        } else {
-            render(renderer)
-        }
+        draw(
+            renderer,
+            layout,
+            &self.handle,
+            self.content_fit,
+            self.filter_method,
+            self.paused,
+            self.opacity,
+            None,
+            None,
+        )
     }
 }
 
@@ -0,0 +1,422 @@
+//! Stretch an image with fixed corners, like the border of a panel.
+use crate::image::{self, FilterMethod};
+use crate::layout;
+use crate::renderer;
+use crate::{Element, Layout, Length, Padding, Point, Rectangle, Size, Widget};
+
+use std::hash::Hash;
+
+/// An image cut into nine slices by four insets, which can grow to any size
+/// without distorting its corners.
+///
+/// The corners are drawn at their actual size, the edges are stretched along
+/// one axis, and the center is stretched, or tiled, to fill the rest.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::widget::image::NineSlice;
+/// # use iced_native::image;
+/// #
+/// let panel = NineSlice::<image::Handle>::new("resources/panel.png", 12);
+/// ```
+#[derive(Debug, Hash)]
+pub struct NineSlice<Handle> {
+    handle: Handle,
+    insets: Padding,
+    width: Length,
+    height: Length,
+    filter_method: FilterMethod,
+    tile_center: bool,
+}
+
+impl<Handle> NineSlice<Handle> {
+    /// Creates a new [`NineSlice`] with the given image and the insets of
+    /// its corners, in pixels of the image.
+    pub fn new<T: Into<Handle>>(handle: T, insets: impl Into<Padding>) -> Self {
+        NineSlice {
+            handle: handle.into(),
+            insets: insets.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            filter_method: FilterMethod::default(),
+            tile_center: false,
+        }
+    }
+
+    /// Sets the width of the [`NineSlice`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`NineSlice`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`NineSlice`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the center of the [`NineSlice`] is tiled at its actual
+    /// size, instead of stretched.
+    pub fn tile_center(mut self, tile_center: bool) -> Self {
+        self.tile_center = tile_center;
+        self
+    }
+}
+
+/// A slice of a [`NineSlice`]: a region of its image and the bounds where
+/// it is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slice {
+    /// The region of the image, in pixels.
+    pub region: Rectangle,
+
+    /// The bounds where the region is drawn.
+    pub bounds: Rectangle,
+}
+
+/// Shrinks two opposite insets proportionally until they fit in the
+/// available length.
+fn fit(start: f32, end: f32, available: f32) -> (f32, f32) {
+    let total = start + end;
+
+    if total > available && total > 0.0 {
+        let ratio = available.max(0.0) / total;
+
+        (start * ratio, end * ratio)
+    } else {
+        (start, end)
+    }
+}
+
+/// Returns the insets of a [`NineSlice`] limited to the size of its image,
+/// so opposite insets never overlap.
+fn clamp_insets(insets: Padding, image_size: Size) -> [f32; 4] {
+    let (top, bottom) = fit(
+        f32::from(insets.top),
+        f32::from(insets.bottom),
+        image_size.height,
+    );
+    let (left, right) = fit(
+        f32::from(insets.left),
+        f32::from(insets.right),
+        image_size.width,
+    );
+
+    [top, right, bottom, left]
+}
+
+/// Computes the size of a [`NineSlice`], which is never smaller than the sum
+/// of its insets.
+fn resolve(
+    limits: &layout::Limits,
+    image_size: Size,
+    width: Length,
+    height: Length,
+    insets: Padding,
+) -> Size {
+    let [top, right, bottom, left] = clamp_insets(insets, image_size);
+
+    let size = limits.width(width).height(height).resolve(image_size);
+
+    Size::new(size.width.max(left + right), size.height.max(top + bottom))
+}
+
+/// Computes the layout of a [`NineSlice`].
+pub fn layout<Renderer, Handle>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    handle: &Handle,
+    width: Length,
+    height: Length,
+    insets: Padding,
+) -> layout::Node
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    let (image_width, image_height) = renderer.dimensions(handle);
+    let image_size = Size::new(image_width as f32, image_height as f32);
+
+    layout::Node::new(resolve(limits, image_size, width, height, insets))
+}
+
+/// Returns the slices of a [`NineSlice`] with the given image size and
+/// insets drawn inside the given bounds.
+///
+/// Slices without area are skipped. If the bounds are too small for the
+/// corners, they shrink proportionally instead of overlapping.
+pub fn slices(
+    bounds: Rectangle,
+    image_size: Size,
+    insets: Padding,
+) -> Vec<Slice> {
+    let [top, right, bottom, left] = clamp_insets(insets, image_size);
+
+    let (target_left, target_right) = fit(left, right, bounds.width);
+    let (target_top, target_bottom) = fit(top, bottom, bounds.height);
+
+    let columns = |x: f32, width: f32, start: f32, end: f32| {
+        [x, x + start, x + width - end, x + width]
+    };
+
+    let region_columns = columns(0.0, image_size.width, left, right);
+    let region_rows = columns(0.0, image_size.height, top, bottom);
+
+    let bounds_columns =
+        columns(bounds.x, bounds.width, target_left, target_right);
+    let bounds_rows =
+        columns(bounds.y, bounds.height, target_top, target_bottom);
+
+    let span = |edges: [f32; 4], i: usize| (edges[i], edges[i + 1] - edges[i]);
+
+    let mut slices = Vec::with_capacity(9);
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let (region_x, region_width) = span(region_columns, column);
+            let (region_y, region_height) = span(region_rows, row);
+            let (x, width) = span(bounds_columns, column);
+            let (y, height) = span(bounds_rows, row);
+
+            if region_width <= 0.0
+                || region_height <= 0.0
+                || width <= 0.0
+                || height <= 0.0
+            {
+                continue;
+            }
+
+            slices.push(Slice {
+                region: Rectangle {
+                    x: region_x,
+                    y: region_y,
+                    width: region_width,
+                    height: region_height,
+                },
+                bounds: Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                },
+            });
+        }
+    }
+
+    slices
+}
+
+/// Splits a [`Slice`] into tiles of the actual size of its region, cutting
+/// the last tiles of every row and column short.
+fn tiles(slice: Slice) -> impl Iterator<Item = Slice> {
+    let Slice { region, bounds } = slice;
+
+    let steps = |length: f32, tile: f32| {
+        let count = (length / tile).ceil() as usize;
+
+        (0..count).map(move |i| {
+            let start = i as f32 * tile;
+
+            (start, (length - start).min(tile))
+        })
+    };
+
+    steps(bounds.height, region.height).flat_map(move |(y, height)| {
+        steps(bounds.width, region.width).map(move |(x, width)| Slice {
+            region: Rectangle {
+                width,
+                height,
+                ..region
+            },
+            bounds: Rectangle {
+                x: bounds.x + x,
+                y: bounds.y + y,
+                width,
+                height,
+            },
+        })
+    })
+}
+
+/// Draws a [`NineSlice`].
+pub fn draw<Renderer, Handle>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    handle: &Handle,
+    insets: Padding,
+    filter_method: FilterMethod,
+    tile_center: bool,
+) where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    let (image_width, image_height) = renderer.dimensions(handle);
+    let image_size = Size::new(image_width as f32, image_height as f32);
+
+    let [top, _, _, left] = clamp_insets(insets, image_size);
+
+    for slice in slices(layout.bounds(), image_size, insets) {
+        let is_center = slice.region.x == left && slice.region.y == top;
+
+        if tile_center && is_center {
+            for tile in tiles(slice) {
+                renderer.draw_region(
+                    handle.clone(),
+                    filter_method,
+                    tile.region,
+                    tile.bounds,
+                );
+            }
+        } else {
+            renderer.draw_region(
+                handle.clone(),
+                filter_method,
+                slice.region,
+                slice.bounds,
+            );
+        }
+    }
+}
+
+impl<Message, Renderer, Handle> Widget<Message, Renderer> for NineSlice<Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout(
+            renderer,
+            limits,
+            &self.handle,
+            self.width,
+            self.height,
+            self.insets,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            layout,
+            &self.handle,
+            self.insets,
+            self.filter_method,
+            self.tile_center,
+        )
+    }
+}
+
+impl<'a, Message, Renderer, Handle> From<NineSlice<Handle>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(nine_slice: NineSlice<Handle>) -> Element<'a, Message, Renderer> {
+        Element::new(nine_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMAGE: Size = Size {
+        width: 30.0,
+        height: 30.0,
+    };
+
+    #[test]
+    fn layout_is_never_smaller_than_insets() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(4.0, 4.0));
+
+        let size = resolve(
+            &limits,
+            IMAGE,
+            Length::Fill,
+            Length::Fill,
+            Padding::new(10),
+        );
+
+        assert_eq!(size, Size::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn slices_fill_bounds() {
+        let bounds =
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(100.0, 50.0));
+        let slices = slices(bounds, IMAGE, Padding::new(10));
+
+        assert_eq!(slices.len(), 9);
+
+        let area: f32 = slices
+            .iter()
+            .map(|slice| slice.bounds.width * slice.bounds.height)
+            .sum();
+
+        assert_eq!(area, bounds.width * bounds.height);
+        assert_eq!(slices[0].bounds.size(), Size::new(10.0, 10.0));
+        assert_eq!(slices[4].bounds.size(), Size::new(80.0, 30.0));
+    }
+
+    #[test]
+    fn corners_shrink_in_tiny_bounds() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 4.0));
+        let slices = slices(bounds, IMAGE, Padding::new(10));
+
+        // Only the four corners are left, and they meet without overlapping
+        assert_eq!(slices.len(), 4);
+
+        for slice in &slices {
+            assert_eq!(slice.bounds.size(), Size::new(5.0, 2.0));
+            assert_eq!(slice.region.size(), Size::new(10.0, 10.0));
+        }
+
+        assert_eq!(slices[3].bounds.position(), Point::new(5.0, 2.0));
+    }
+
+    #[test]
+    fn insets_larger_than_image_are_clamped() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let slices = slices(bounds, IMAGE, Padding::new(20));
+
+        assert!(slices.iter().all(|slice| {
+            slice.region.x + slice.region.width <= IMAGE.width
+                && slice.region.y + slice.region.height <= IMAGE.height
+        }));
+    }
+
+    #[test]
+    fn empty_bounds_have_no_slices() {
+        let slices = slices(Rectangle::default(), IMAGE, Padding::new(10));
+
+        assert!(slices.is_empty());
+    }
+}
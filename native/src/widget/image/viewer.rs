@@ -1,6 +1,6 @@
 //! Zoom and pan on an image.
-use crate::event::{self, Event};
-use crate::image;
+use crate::event;
+use crate::image::{self, FilterMethod};
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -13,28 +13,53 @@ use std::hash::Hash;
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
 #[allow(missing_debug_implementations)]
-pub struct Viewer<'a, Handle> {
+pub struct Viewer<'a, Message, Handle> {
     state: &'a mut State,
+    on_interaction: Option<Box<dyn Fn(Event) -> Message + 'a>>,
     padding: u16,
     width: Length,
     height: Length,
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    is_panning_clamped: bool,
+    filter_method: FilterMethod,
     handle: Handle,
 }
 
-impl<'a, Handle> Viewer<'a, Handle> {
+/// An interaction of the user with a [`Viewer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The image was zoomed to the given scale and offset.
+    Zoomed {
+        /// The new scale of the image.
+        scale: f32,
+
+        /// The new offset of the image.
+        offset: Vector,
+    },
+
+    /// The image was panned to the given offset.
+    Panned {
+        /// The new offset of the image.
+        offset: Vector,
+    },
+}
+
+impl<'a, Message, Handle> Viewer<'a, Message, Handle> {
     /// Creates a new [`Viewer`] with the given [`State`].
     pub fn new(state: &'a mut State, handle: Handle) -> Self {
         Viewer {
             state,
+            on_interaction: None,
             padding: 0,
             width: Length::Shrink,
             height: Length::Shrink,
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            is_panning_clamped: true,
+            filter_method: FilterMethod::default(),
             handle,
         }
     }
@@ -82,6 +107,34 @@ impl<'a, Handle> Viewer<'a, Handle> {
         self
     }
 
+    /// Sets whether panning is limited, so the image cannot be pushed off
+    /// the [`Viewer`].
+    ///
+    /// Default is `true`, which keeps at least half of the [`Viewer`]
+    /// covered by the image when it is larger.
+    pub fn clamp_panning(mut self, clamp: bool) -> Self {
+        self.is_panning_clamped = clamp;
+        self
+    }
+
+    /// Sets the message that should be produced when the user zooms or pans
+    /// the image of the [`Viewer`].
+    pub fn on_interaction(
+        mut self,
+        on_interaction: impl Fn(Event) -> Message + 'a,
+    ) -> Self {
+        self.on_interaction = Some(Box::new(on_interaction));
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the image of the [`Viewer`].
+    ///
+    /// Default is [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
     /// Returns the bounds of the underlying image, given the bounds of
     /// the [`Viewer`]. Scaling will be applied and original aspect ratio
     /// will be respected.
@@ -90,30 +143,54 @@ impl<'a, Handle> Viewer<'a, Handle> {
         Renderer: image::Renderer<Handle = Handle>,
     {
         let (width, height) = renderer.dimensions(&self.handle);
+        let base = base_size(bounds, Size::new(width as f32, height as f32));
 
-        let (width, height) = {
-            let dimensions = (width as f32, height as f32);
+        Size::new(
+            base.width * self.state.scale,
+            base.height * self.state.scale,
+        )
+    }
+}
 
-            let width_ratio = bounds.width / dimensions.0;
-            let height_ratio = bounds.height / dimensions.1;
+/// Returns the size of an image in a [`Viewer`] with a scale of `1.0`, which
+/// is shrunk to fit the [`Viewer`] if it is larger.
+fn base_size(bounds: Size, image_size: Size) -> Size {
+    let ratio = (bounds.width / image_size.width)
+        .min(bounds.height / image_size.height);
 
-            let ratio = width_ratio.min(height_ratio);
+    if ratio < 1.0 {
+        Size::new(image_size.width * ratio, image_size.height * ratio)
+    } else {
+        image_size
+    }
+}
 
-            let scale = self.state.scale;
+/// Returns how far an image can be panned in a [`Viewer`], in every
+/// direction.
+///
+/// When panning is clamped, a larger image always covers at least half of
+/// the [`Viewer`], and a smaller one cannot be panned at all.
+fn pan_limits(bounds: Size, image_size: Size, is_clamped: bool) -> Vector {
+    if !is_clamped {
+        return Vector::new(f32::INFINITY, f32::INFINITY);
+    }
 
-            if ratio < 1.0 {
-                (dimensions.0 * ratio * scale, dimensions.1 * ratio * scale)
-            } else {
-                (dimensions.0 * scale, dimensions.1 * scale)
-            }
-        };
+    let limit = |image: f32, bounds: f32| {
+        if image > bounds {
+            (image - bounds / 2.0).round()
+        } else {
+            0.0
+        }
+    };
 
-        Size::new(width, height)
-    }
+    Vector::new(
+        limit(image_size.width, bounds.width),
+        limit(image_size.height, bounds.height),
+    )
 }
 
 impl<'a, Message, Renderer, Handle> Widget<Message, Renderer>
-    for Viewer<'a, Handle>
+    for Viewer<'a, Message, Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
@@ -164,18 +241,18 @@ where
 
     fn on_event(
         &mut self,
-        event: Event,
+        event: event::Event,
         layout: Layout<'_>,
         cursor_position: Point,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
-        _shell: &mut Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
         match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta })
+            event::Event::Mouse(mouse::Event::WheelScrolled { delta })
                 if is_mouse_over =>
             {
                 match delta {
@@ -186,91 +263,78 @@ where
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            self.state.scale = (if y > 0.0 {
-                                self.state.scale * (1.0 + self.scale_step)
+                            let scale = (if y > 0.0 {
+                                previous_scale * (1.0 + self.scale_step)
                             } else {
-                                self.state.scale / (1.0 + self.scale_step)
+                                previous_scale / (1.0 + self.scale_step)
                             })
                             .max(self.min_scale)
                             .min(self.max_scale);
 
+                            // The point under the cursor stays in place
+                            self.state.zoom_to(
+                                scale,
+                                cursor_position - bounds.center(),
+                            );
+
                             let image_size =
                                 self.image_size(renderer, bounds.size());
 
-                            let factor =
-                                self.state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center =
-                                cursor_position - bounds.center();
-
-                            let adjustment = cursor_to_center * factor
-                                + self.state.current_offset * factor;
-
-                            self.state.current_offset = Vector::new(
-                                if image_size.width > bounds.width {
-                                    self.state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if image_size.height > bounds.height {
-                                    self.state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
+                            self.state.current_offset = self.state.clamp(
+                                bounds.size(),
+                                image_size,
+                                self.is_panning_clamped,
                             );
+
+                            if let Some(on_interaction) = &self.on_interaction {
+                                shell.publish(on_interaction(Event::Zoomed {
+                                    scale,
+                                    offset: self.state.current_offset,
+                                }));
+                            }
                         }
                     }
                 }
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                if is_mouse_over =>
-            {
+            event::Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )) if is_mouse_over => {
                 self.state.cursor_grabbed_at = Some(cursor_position);
                 self.state.starting_offset = self.state.current_offset;
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                if self.state.cursor_grabbed_at.is_some() =>
-            {
+            event::Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            )) if self.state.cursor_grabbed_at.is_some() => {
                 self.state.cursor_grabbed_at = None;
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            event::Event::Mouse(mouse::Event::CursorMoved { position }) => {
                 if let Some(origin) = self.state.cursor_grabbed_at {
                     let image_size = self.image_size(renderer, bounds.size());
 
-                    let hidden_width = (image_size.width - bounds.width / 2.0)
-                        .max(0.0)
-                        .round();
+                    let previous_offset = self.state.current_offset;
 
-                    let hidden_height = (image_size.height
-                        - bounds.height / 2.0)
-                        .max(0.0)
-                        .round();
+                    self.state.current_offset =
+                        self.state.starting_offset - (position - origin);
 
-                    let delta = position - origin;
+                    self.state.current_offset = self.state.clamp(
+                        bounds.size(),
+                        image_size,
+                        self.is_panning_clamped,
+                    );
 
-                    let x = if bounds.width < image_size.width {
-                        (self.state.starting_offset.x - delta.x)
-                            .min(hidden_width)
-                            .max(-hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < image_size.height {
-                        (self.state.starting_offset.y - delta.y)
-                            .min(hidden_height)
-                            .max(-hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    self.state.current_offset = Vector::new(x, y);
+                    if self.state.current_offset != previous_offset {
+                        if let Some(on_interaction) = &self.on_interaction {
+                            shell.publish(on_interaction(Event::Panned {
+                                offset: self.state.current_offset,
+                            }));
+                        }
+                    }
 
                     event::Status::Captured
                 } else {
@@ -318,14 +382,22 @@ where
                 bounds.height / 2.0 - image_size.height / 2.0,
             );
 
-            image_top_left - self.state.offset(bounds, image_size)
+            image_top_left
+                - self.state.clamp(
+                    bounds.size(),
+                    image_size,
+                    self.is_panning_clamped,
+                )
         };
 
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(translation, |renderer| {
-                image::Renderer::draw(
+                image::Renderer::draw_frame(
                     renderer,
                     self.handle.clone(),
+                    0,
+                    self.filter_method,
+                    1.0,
                     Rectangle {
                         x: bounds.x,
                         y: bounds.y,
@@ -363,18 +435,61 @@ impl State {
         State::default()
     }
 
-    /// Returns the current offset of the [`State`], given the bounds
-    /// of the [`Viewer`] and its image.
-    fn offset(&self, bounds: Rectangle, image_size: Size) -> Vector {
-        let hidden_width =
-            (image_size.width - bounds.width / 2.0).max(0.0).round();
+    /// Returns the scale of the image of the [`Viewer`].
+    ///
+    /// A scale of `1.0` shows the image at its actual size, or shrunk to fit
+    /// the [`Viewer`] if it is larger.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the offset of the center of the image from the center of the
+    /// [`Viewer`], in logical pixels.
+    pub fn offset(&self) -> Vector {
+        self.current_offset
+    }
+
+    /// Zooms the image of the [`Viewer`] to the given scale, keeping the
+    /// point at the given `anchor` in place.
+    ///
+    /// The `anchor` is relative to the center of the [`Viewer`], so a zero
+    /// [`Vector`] zooms into the center.
+    pub fn zoom_to(&mut self, scale: f32, anchor: Vector) {
+        let factor = scale / self.scale - 1.0;
+
+        self.current_offset =
+            self.current_offset + (anchor + self.current_offset) * factor;
+        self.scale = scale;
+    }
+
+    /// Pans the image of the [`Viewer`] to the given offset.
+    pub fn pan_to(&mut self, offset: Vector) {
+        self.current_offset = offset;
+    }
+
+    /// Zooms the image of the [`Viewer`] so it fills as much of the given
+    /// bounds as possible, and centers it.
+    pub fn fit(&mut self, bounds: Size, image_size: Size) {
+        let base = base_size(bounds, image_size);
 
-        let hidden_height =
-            (image_size.height - bounds.height / 2.0).max(0.0).round();
+        self.scale =
+            (bounds.width / base.width).min(bounds.height / base.height);
+        self.current_offset = Vector::default();
+    }
+
+    /// Returns the offset of the [`State`], limited by the bounds of the
+    /// [`Viewer`] and the size of its image.
+    fn clamp(
+        &self,
+        bounds: Size,
+        image_size: Size,
+        is_clamped: bool,
+    ) -> Vector {
+        let limits = pan_limits(bounds, image_size, is_clamped);
 
         Vector::new(
-            self.current_offset.x.min(hidden_width).max(-hidden_width),
-            self.current_offset.y.min(hidden_height).max(-hidden_height),
+            self.current_offset.x.min(limits.x).max(-limits.x),
+            self.current_offset.y.min(limits.y).max(-limits.y),
         )
     }
 
@@ -384,14 +499,16 @@ impl State {
     }
 }
 
-impl<'a, Message, Renderer, Handle> From<Viewer<'a, Handle>>
+impl<'a, Message, Renderer, Handle> From<Viewer<'a, Message, Handle>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + image::Renderer<Handle = Handle>,
     Message: 'a,
     Handle: Clone + Hash + 'a,
 {
-    fn from(viewer: Viewer<'a, Handle>) -> Element<'a, Message, Renderer> {
+    fn from(
+        viewer: Viewer<'a, Message, Handle>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(viewer)
     }
 }
@@ -0,0 +1,340 @@
+//! Load images in the background, showing a placeholder meanwhile.
+//!
+//! A [`LazyImage`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::image::{self, FilterMethod, Request, Source, Status};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::time::Duration;
+use crate::widget::{self, Space};
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Layout, Length, Point, Rectangle, Shell,
+    Widget,
+};
+
+/// How often a [`LazyImage`] checks whether its image finished loading.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An image that is loaded in the background, instead of when it is drawn
+/// for the first time.
+///
+/// A placeholder is shown while the image is loading, and an error element
+/// if it fails to load.
+///
+/// Dropping its [`State`] cancels the loading, unless something else is
+/// waiting for the same image.
+#[allow(missing_debug_implementations)]
+pub struct LazyImage<'a, Message, Renderer> {
+    state: &'a mut State,
+    placeholder: Element<'a, Message, Renderer>,
+    error: Element<'a, Message, Renderer>,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    opacity: f32,
+    fade_in: Option<Duration>,
+}
+
+impl<'a, Message, Renderer> LazyImage<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    /// Creates a new [`LazyImage`] with some local [`State`], which starts
+    /// loading the image of the given [`Source`].
+    pub fn new(state: &'a mut State, source: impl Into<Source>) -> Self {
+        state.load(source.into());
+
+        LazyImage {
+            state,
+            placeholder: Space::new(Length::Shrink, Length::Shrink).into(),
+            error: Space::new(Length::Shrink, Length::Shrink).into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+            fade_in: None,
+        }
+    }
+
+    /// Sets the element shown while the image of the [`LazyImage`] is
+    /// loading.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the element shown if the image of the [`LazyImage`] fails to
+    /// load.
+    pub fn error(
+        mut self,
+        error: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.error = error.into();
+        self
+    }
+
+    /// Sets the width of the [`LazyImage`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LazyImage`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the image of the [`LazyImage`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the image of the [`LazyImage`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the image of the [`LazyImage`], from `0.0` to
+    /// `1.0`.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`Duration`] of the fade-in of the [`LazyImage`], which
+    /// animates the opacity of its image from `0.0` once it is loaded.
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+}
+
+/// The local state of a [`LazyImage`].
+///
+/// Dropping the [`State`] cancels the loading of its image, unless something
+/// else is waiting for it.
+#[derive(Debug, Default)]
+pub struct State {
+    request: Option<Request>,
+    image: widget::image::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts loading the image of the given [`Source`], unless it is
+    /// already the image of the [`State`].
+    pub fn load(&mut self, source: Source) {
+        let is_loaded = self
+            .request
+            .as_ref()
+            .map(|request| *request.source() == source)
+            .unwrap_or(false);
+
+        if !is_loaded {
+            self.request = Some(Request::new(source));
+            self.image = widget::image::State::new();
+        }
+    }
+
+    /// Returns the [`Status`] of the image of the [`State`].
+    pub fn status(&self) -> &Status {
+        self.request
+            .as_ref()
+            .map(Request::status)
+            .unwrap_or(&Status::Loading)
+    }
+
+    /// Returns the [`widget::image::State`] of the loaded image.
+    pub fn image(&self) -> &widget::image::State {
+        &self.image
+    }
+
+    /// Returns the mutable [`widget::image::State`] of the loaded image.
+    pub fn image_mut(&mut self) -> &mut widget::image::State {
+        &mut self.image
+    }
+}
+
+/// Checks whether the image of a [`LazyImage`] finished loading, and
+/// invalidates the layout once it does.
+pub fn update<Message>(
+    event: &Event,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+) {
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if let Some(request) = &mut state.request {
+            if request.poll() {
+                shell.invalidate_layout();
+            } else if matches!(request.status(), Status::Loading) {
+                shell.request_redraw(*now + POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> LazyImage<'a, Message, Renderer> {
+    fn content(&self) -> &Element<'a, Message, Renderer> {
+        match self.state.status() {
+            Status::Failed(_) => &self.error,
+            _ => &self.placeholder,
+        }
+    }
+
+    fn content_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        match self.state.status() {
+            Status::Failed(_) => &mut self.error,
+            _ => &mut self.placeholder,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LazyImage<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match self.state.status() {
+            Status::Loaded(handle) => widget::image::layout(
+                renderer,
+                limits,
+                handle,
+                self.width,
+                self.height,
+                self.content_fit,
+            ),
+            _ => self.content().layout(
+                renderer,
+                &limits.width(self.width).height(self.height),
+            ),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The content is laid out for the status before the poll
+        let status = match self.state.status().clone() {
+            Status::Loaded(handle) => widget::image::update(
+                &event,
+                renderer,
+                shell,
+                &handle,
+                false,
+                self.fade_in,
+                Some(self.state.image_mut()),
+            ),
+            _ => self.content_mut().on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+        };
+
+        update(&event, shell, self.state);
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        match self.state.status() {
+            Status::Loaded(handle) => widget::image::draw(
+                renderer,
+                layout,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                false,
+                self.opacity,
+                self.fade_in,
+                Some(self.state.image()),
+            ),
+            _ => self.content().draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            ),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.state.status() {
+            Status::Loaded(_) => mouse::Interaction::default(),
+            _ => self.content().mouse_interaction(
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<LazyImage<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(
+        lazy_image: LazyImage<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy_image)
+    }
+}
@@ -0,0 +1,499 @@
+//! Display clickable text, like a hyperlink.
+//!
+//! A [`Link`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Vector, Widget,
+};
+
+pub use iced_style::link::{Style, StyleSheet};
+
+/// Some text that produces a message when clicked, like a hyperlink.
+///
+/// Only the text itself reacts to the mouse, not the rest of the bounds of
+/// the [`Link`].
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::link;
+/// #
+/// # type Link<'a, Message> =
+/// #     iced_native::widget::Link<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     OpenDocs,
+/// }
+///
+/// let mut state = link::State::new();
+/// let link = Link::new(&mut state, "Read the docs").on_press(Message::OpenDocs);
+/// ```
+///
+/// If [`Link::on_press`] is not set, the [`Link`] is disabled.
+#[allow(missing_debug_implementations)]
+pub struct Link<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    content: String,
+    on_press: Option<Message>,
+    size: Option<u16>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    underline: bool,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Link<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Link`] with some local [`State`] and the given text.
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        Link {
+            state,
+            content: content.into(),
+            on_press: None,
+            size: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            underline: true,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Link`] is clicked.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the text size of the [`Link`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Link`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`Link`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Link`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the text of the [`Link`] is underlined.
+    ///
+    /// By default, it is.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets the style of the [`Link`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> Properties<'_, Message, Renderer::Font> {
+        Properties {
+            content: &self.content,
+            size: self.size,
+            font: &self.font,
+            width: self.width,
+            height: self.height,
+            underline: self.underline,
+            on_press: &self.on_press,
+        }
+    }
+}
+
+/// The local state of a [`Link`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+/// The properties of a [`Link`] that define its text and what it produces.
+///
+/// Every field is set by the builder method of the [`Link`] with the same
+/// name, except for the content, which is given to its constructor.
+#[derive(Debug)]
+pub struct Properties<'a, Message, Font> {
+    /// The text of the [`Link`].
+    pub content: &'a str,
+    /// The text size, or the default size of the renderer if `None`.
+    pub size: Option<u16>,
+    /// The font of the text.
+    pub font: &'a Font,
+    /// The width of the [`Link`] boundaries.
+    pub width: Length,
+    /// The height of the [`Link`] boundaries.
+    pub height: Length,
+    /// Whether the text is underlined.
+    pub underline: bool,
+    /// The message produced when the [`Link`] is clicked, if it is enabled.
+    pub on_press: &'a Option<Message>,
+}
+
+/// Computes the layout of a [`Link`].
+pub fn layout<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    properties: Properties<'_, Message, Renderer::Font>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let limits = limits.width(properties.width).height(properties.height);
+
+    let (width, height) = renderer.measure(
+        properties.content,
+        properties.size.unwrap_or(renderer.default_size()),
+        properties.font.clone(),
+        limits.max(),
+        text::Spacing::default(),
+    );
+
+    layout::Node::new(limits.resolve(crate::Size::new(width, height)))
+}
+
+/// Returns the bounds of every line of text of a [`Link`], which are the
+/// only parts of a [`Link`] that can be clicked.
+pub fn lines<Renderer>(
+    renderer: &Renderer,
+    layout: Layout<'_>,
+    content: &str,
+    size: Option<u16>,
+    font: Renderer::Font,
+) -> Vec<Rectangle>
+where
+    Renderer: text::Renderer,
+{
+    let bounds = layout.bounds();
+    let offset = Vector::new(bounds.x, bounds.y);
+
+    let glyphs = renderer.glyphs(
+        content,
+        f32::from(size.unwrap_or(renderer.default_size())),
+        font,
+        bounds.size(),
+        text::Spacing::default(),
+    );
+
+    let is_whitespace: Vec<bool> =
+        content.chars().map(char::is_whitespace).collect();
+
+    let mut lines: Vec<Rectangle> = Vec::new();
+
+    // Whitespace only matters between words, so it never starts or ends
+    // a line
+    for glyph in glyphs {
+        if is_whitespace.get(glyph.index).copied().unwrap_or(false) {
+            continue;
+        }
+
+        let glyph_bounds = glyph.bounds + offset;
+
+        match lines.last_mut() {
+            Some(line) if (line.y - glyph_bounds.y).abs() < 1.0 => {
+                line.width = glyph_bounds.x + glyph_bounds.width - line.x;
+            }
+            _ => lines.push(glyph_bounds),
+        }
+    }
+
+    lines
+}
+
+/// Processes the given [`Event`] and updates the [`State`] of a [`Link`]
+/// accordingly.
+pub fn update<'a, Message, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    shell: &mut Shell<'_, Message>,
+    properties: Properties<'_, Message, Renderer::Font>,
+    state: impl FnOnce() -> &'a mut State,
+) -> event::Status
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        on_press,
+        ..
+    } = properties;
+
+    let is_mouse_over = || {
+        lines(renderer, layout, content, size, font.clone())
+            .iter()
+            .any(|line| line.contains(cursor_position))
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if on_press.is_some() && is_mouse_over() =>
+        {
+            let state = state();
+
+            state.is_pressed = true;
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) => {
+            if let Some(on_press) = on_press.clone() {
+                let state = state();
+
+                if state.is_pressed {
+                    state.is_pressed = false;
+
+                    if is_mouse_over() {
+                        shell.publish(on_press);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+        Event::Touch(touch::Event::FingerLost { .. }) => {
+            let state = state();
+
+            state.is_pressed = false;
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws a [`Link`].
+pub fn draw<Message, Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    state: &State,
+    properties: Properties<'_, Message, Renderer::Font>,
+    style_sheet: &dyn StyleSheet,
+) where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        underline,
+        on_press,
+        ..
+    } = properties;
+    let is_enabled = on_press.is_some();
+
+    let bounds = layout.bounds();
+    let lines = lines(renderer, layout, content, size, font.clone());
+
+    let is_mouse_over = lines.iter().any(|line| line.contains(cursor_position));
+
+    let style = if !is_enabled {
+        style_sheet.disabled()
+    } else if state.is_pressed {
+        style_sheet.pressed()
+    } else if is_mouse_over {
+        style_sheet.hovered()
+    } else {
+        style_sheet.active()
+    };
+
+    let size = size.unwrap_or(renderer.default_size());
+
+    renderer.fill_text(text::Text {
+        content,
+        size: f32::from(size),
+        bounds,
+        color: style.color,
+        font: font.clone(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        spacing: text::Spacing::default(),
+        rotation: text::Rotation::None,
+    });
+
+    if underline {
+        let thickness = (f32::from(size) / 16.0).max(1.0);
+
+        for line in lines {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: line.y + line.height - thickness,
+                        height: thickness,
+                        ..line
+                    },
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color(style.color),
+            );
+        }
+    }
+}
+
+/// Returns the [`mouse::Interaction`] of a [`Link`].
+pub fn mouse_interaction<Message, Renderer>(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    properties: Properties<'_, Message, Renderer::Font>,
+) -> mouse::Interaction
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        on_press,
+        ..
+    } = properties;
+
+    let is_mouse_over = on_press.is_some()
+        && layout.bounds().contains(cursor_position)
+        && lines(renderer, layout, content, size, font.clone())
+            .iter()
+            .any(|line| line.contains(cursor_position));
+
+    if is_mouse_over {
+        mouse::Interaction::Pointer
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Link<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout(renderer, limits, self.properties())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            shell,
+            Properties {
+                content: &self.content,
+                size: self.size,
+                font: &self.font,
+                width: self.width,
+                height: self.height,
+                underline: self.underline,
+                on_press: &self.on_press,
+            },
+            || &mut self.state,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            layout,
+            cursor_position,
+            self.state,
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position, renderer, self.properties())
+    }
+}
+
+impl<'a, Message, Renderer> From<Link<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        link: Link<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(link)
+    }
+}
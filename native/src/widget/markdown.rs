@@ -0,0 +1,532 @@
+//! Display Markdown content.
+//!
+//! Markdown is parsed into a list of [`Block`]s, which a [`Markdown`] turns
+//! into an [`Element`] made of the built-in widgets. Headings, paragraphs,
+//! lists, code blocks, block quotes, rules, and links are supported. Inline
+//! emphasis is displayed as plain text.
+use crate::text;
+use crate::widget::container;
+use crate::widget::rich_text::{self, RichText};
+use crate::widget::{Column, Container, Row, Rule, Text};
+use crate::{Element, Length};
+
+pub use iced_style::markdown::{Style, StyleSheet};
+
+/// The destination of a link, as written in the Markdown source.
+pub type Url = String;
+
+/// A block of Markdown content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// A heading with a level from 1 to 6.
+    Heading(u8, Vec<Inline>),
+    /// A paragraph of text.
+    Paragraph(Vec<Inline>),
+    /// A list of items, made of blocks themselves.
+    List {
+        /// The number of the first item, if the list is ordered.
+        start: Option<u64>,
+        /// The items of the list.
+        items: Vec<Vec<Block>>,
+    },
+    /// A block of code.
+    Code(String),
+    /// A block quote.
+    Quote(Vec<Block>),
+    /// A horizontal rule.
+    Rule,
+}
+
+/// A run of text inside a [`Block`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inline {
+    /// The text of the run.
+    pub text: String,
+    /// Whether the run is inline code.
+    pub is_code: bool,
+    /// The destination of the run, if it is part of a link.
+    pub link: Option<Url>,
+}
+
+/// Parses the given Markdown into a list of [`Block`]s.
+pub fn parse(content: &str) -> Vec<Block> {
+    use pulldown_cmark::{Event, Parser, Tag};
+
+    // The blocks of the root, and of every open quote and list item
+    let mut containers: Vec<Vec<Block>> = vec![Vec::new()];
+    let mut lists: Vec<(Option<u64>, Vec<Vec<Block>>)> = Vec::new();
+    let mut inlines = Vec::new();
+    let mut link = None;
+    let mut code: Option<String> = None;
+
+    for event in Parser::new(content) {
+        let blocks = containers.last_mut().expect("Root container");
+
+        match event {
+            Event::Start(Tag::List(start)) => {
+                // Tight list items contain text without a paragraph
+                paragraph(blocks, &mut inlines);
+                lists.push((start, Vec::new()));
+            }
+            Event::Start(Tag::BlockQuote) | Event::Start(Tag::Item) => {
+                paragraph(blocks, &mut inlines);
+                containers.push(Vec::new());
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                code = Some(String::new());
+            }
+            Event::Start(Tag::Link(_, destination, _)) => {
+                link = Some(destination.to_string());
+            }
+            Event::End(Tag::Paragraph) => {
+                paragraph(blocks, &mut inlines);
+            }
+            Event::End(Tag::Heading(level, _, _)) => {
+                blocks.push(Block::Heading(
+                    level as u8,
+                    std::mem::take(&mut inlines),
+                ));
+            }
+            Event::End(Tag::List(_)) => {
+                if let Some((start, items)) = lists.pop() {
+                    blocks.push(Block::List { start, items });
+                }
+            }
+            Event::End(Tag::Item) => {
+                paragraph(blocks, &mut inlines);
+
+                let item = containers.pop().unwrap_or_default();
+
+                if let Some((_, items)) = lists.last_mut() {
+                    items.push(item);
+                }
+            }
+            Event::End(Tag::BlockQuote) => {
+                paragraph(blocks, &mut inlines);
+
+                let quote = containers.pop().unwrap_or_default();
+
+                if let Some(blocks) = containers.last_mut() {
+                    blocks.push(Block::Quote(quote));
+                }
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                if let Some(mut code) = code.take() {
+                    if code.ends_with('\n') {
+                        let _ = code.pop();
+                    }
+
+                    blocks.push(Block::Code(code));
+                }
+            }
+            Event::End(Tag::Link(..)) => {
+                link = None;
+            }
+            Event::Text(text) => match &mut code {
+                Some(code) => code.push_str(&text),
+                None => push(&mut inlines, &text, false, &link),
+            },
+            Event::Code(text) => push(&mut inlines, &text, true, &link),
+            Event::SoftBreak => push(&mut inlines, " ", false, &link),
+            Event::HardBreak => push(&mut inlines, "\n", false, &link),
+            Event::FootnoteReference(label) => {
+                push(&mut inlines, &format!("[{}]", label), false, &link)
+            }
+            Event::TaskListMarker(checked) => push(
+                &mut inlines,
+                if checked { "☑ " } else { "☐ " },
+                false,
+                &link,
+            ),
+            Event::Rule => {
+                paragraph(blocks, &mut inlines);
+                blocks.push(Block::Rule);
+            }
+            _ => {}
+        }
+    }
+
+    let mut blocks = containers.swap_remove(0);
+    paragraph(&mut blocks, &mut inlines);
+
+    blocks
+}
+
+/// Appends some text to the given [`Inline`]s, merging it with the last one
+/// when they share the same style.
+fn push(
+    inlines: &mut Vec<Inline>,
+    text: &str,
+    is_code: bool,
+    link: &Option<Url>,
+) {
+    match inlines.last_mut() {
+        Some(last) if last.is_code == is_code && &last.link == link => {
+            last.text.push_str(text);
+        }
+        _ => inlines.push(Inline {
+            text: text.to_owned(),
+            is_code,
+            link: link.clone(),
+        }),
+    }
+}
+
+/// Turns the pending [`Inline`]s into a paragraph, if there are any.
+fn paragraph(blocks: &mut Vec<Block>, inlines: &mut Vec<Inline>) {
+    if !inlines.is_empty() {
+        blocks.push(Block::Paragraph(std::mem::take(inlines)));
+    }
+}
+
+/// Some Markdown content displayed with the built-in widgets.
+///
+/// # Example
+/// ```
+/// # type Markdown<'a, Message> =
+/// #     iced_native::widget::Markdown<'a, Message, iced_native::renderer::Null>;
+/// # type Element<'a, Message> =
+/// #     iced_native::Element<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let changelog: Element<Message> = Markdown::new(
+///     "# 1.0\n\n- First release, see [the docs](https://docs.rs)",
+///     Message::LinkClicked,
+/// )
+/// .into();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Markdown<'a, Message, Renderer: text::Renderer> {
+    blocks: Vec<Block>,
+    on_link: Box<dyn Fn(Url) -> Message + 'a>,
+    text_size: u16,
+    code_font: Renderer::Font,
+    spacing: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Markdown`] with the given content and a function
+    /// that produces a message when a link is clicked.
+    pub fn new(content: &str, on_link: impl Fn(Url) -> Message + 'a) -> Self {
+        Self::with_blocks(parse(content), on_link)
+    }
+
+    /// Creates a new [`Markdown`] with some already parsed [`Block`]s.
+    pub fn with_blocks(
+        blocks: Vec<Block>,
+        on_link: impl Fn(Url) -> Message + 'a,
+    ) -> Self {
+        Markdown {
+            blocks,
+            on_link: Box::new(on_link),
+            text_size: 20,
+            code_font: Default::default(),
+            spacing: 10,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the text of the [`Markdown`]. Headings are scaled
+    /// relative to it.
+    ///
+    /// By default, it is 20.
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font used to display code, which should be monospaced.
+    pub fn code_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.code_font = font.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the blocks of the [`Markdown`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the style of the [`Markdown`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn blocks(
+        &self,
+        blocks: &[Block],
+        style: &Style,
+    ) -> Element<'a, Message, Renderer> {
+        blocks
+            .iter()
+            .fold(
+                Column::new().spacing(self.spacing).width(Length::Fill),
+                |column, block| column.push(self.block(block, style)),
+            )
+            .into()
+    }
+
+    fn block(
+        &self,
+        block: &Block,
+        style: &Style,
+    ) -> Element<'a, Message, Renderer> {
+        match block {
+            Block::Heading(level, inlines) => {
+                rich_text(inlines, &self.code_font, style, &self.on_link)
+                    .size(heading_size(self.text_size, *level))
+                    .into()
+            }
+            Block::Paragraph(inlines) => {
+                rich_text(inlines, &self.code_font, style, &self.on_link)
+                    .size(self.text_size)
+                    .into()
+            }
+            Block::List { start, items } => items
+                .iter()
+                .enumerate()
+                .fold(
+                    Column::new().spacing(self.spacing / 2),
+                    |column, (i, item)| {
+                        column.push(
+                            Row::new()
+                                .spacing(self.spacing / 2)
+                                .padding([0, 0, 0, self.spacing])
+                                .push(
+                                    Text::new(bullet(*start, i))
+                                        .size(self.text_size),
+                                )
+                                .push(self.blocks(item, style)),
+                        )
+                    },
+                )
+                .into(),
+            Block::Code(code) => Container::new(
+                Text::new(code.as_str())
+                    .size(self.text_size)
+                    .font(self.code_font.clone()),
+            )
+            .width(Length::Fill)
+            .padding(self.spacing)
+            .style(CodeBlock(*style))
+            .into(),
+            Block::Quote(blocks) => Container::new(self.blocks(blocks, style))
+                .padding([0, 0, 0, self.spacing * 2])
+                .into(),
+            Block::Rule => Rule::horizontal(self.spacing).into(),
+        }
+    }
+}
+
+/// Turns some [`Inline`]s into a [`RichText`], producing the message of
+/// `on_link` when one of its links is clicked.
+pub fn rich_text<Message, Renderer>(
+    inlines: &[Inline],
+    code_font: &Renderer::Font,
+    style: &Style,
+    on_link: &dyn Fn(Url) -> Message,
+) -> RichText<Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    let spans = inlines
+        .iter()
+        .map(|inline| {
+            let mut span = rich_text::Span::new(inline.text.as_str());
+
+            if inline.is_code {
+                span = span.font(code_font.clone());
+            }
+
+            if let Some(link) = &inline.link {
+                span = span.color(style.link_color).link(on_link(link.clone()));
+            }
+
+            span
+        })
+        .collect();
+
+    RichText::new(spans).width(Length::Fill)
+}
+
+/// Returns the bullet of the item at the given index of a list.
+pub fn bullet(start: Option<u64>, index: usize) -> String {
+    match start {
+        Some(start) => format!("{}.", start + index as u64),
+        None => String::from("•"),
+    }
+}
+
+/// Returns the text size of a heading of the given level.
+pub fn heading_size(text_size: u16, level: u8) -> u16 {
+    let scale = match level {
+        1 => 2.0,
+        2 => 1.5,
+        3 => 1.25,
+        _ => 1.0,
+    };
+
+    (f32::from(text_size) * scale).round() as u16
+}
+
+/// The [`container::StyleSheet`] of a block of code.
+#[derive(Debug, Clone, Copy)]
+pub struct CodeBlock(pub Style);
+
+impl container::StyleSheet for CodeBlock {
+    fn style(&self) -> container::Style {
+        container::Style {
+            text_color: self.0.code_color,
+            background: self.0.code_background,
+            border_radius: self.0.code_border_radius,
+            ..container::Style::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Markdown<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        markdown: Markdown<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        let style = markdown.style_sheet.style();
+
+        markdown.blocks(&markdown.blocks, &style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Inline {
+        Inline {
+            text: text.to_owned(),
+            is_code: false,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let corpus = [
+            ("", vec![]),
+            (
+                "Hello, world!",
+                vec![Block::Paragraph(vec![text("Hello, world!")])],
+            ),
+            (
+                "# Title\n\nSome\ntext\n\n---",
+                vec![
+                    Block::Heading(1, vec![text("Title")]),
+                    Block::Paragraph(vec![text("Some text")]),
+                    Block::Rule,
+                ],
+            ),
+            ("### Small", vec![Block::Heading(3, vec![text("Small")])]),
+            (
+                "```rust\nfn main() {}\n```",
+                vec![Block::Code(String::from("fn main() {}"))],
+            ),
+            (
+                "    indented\n    code",
+                vec![Block::Code(String::from("indented\ncode"))],
+            ),
+            (
+                "> Quoted\n>\n> - item",
+                vec![Block::Quote(vec![
+                    Block::Paragraph(vec![text("Quoted")]),
+                    Block::List {
+                        start: None,
+                        items: vec![vec![Block::Paragraph(vec![text("item")])]],
+                    },
+                ])],
+            ),
+            (
+                "3. three\n4. four\n   - nested",
+                vec![Block::List {
+                    start: Some(3),
+                    items: vec![
+                        vec![Block::Paragraph(vec![text("three")])],
+                        vec![
+                            Block::Paragraph(vec![text("four")]),
+                            Block::List {
+                                start: None,
+                                items: vec![vec![Block::Paragraph(vec![
+                                    text("nested"),
+                                ])]],
+                            },
+                        ],
+                    ],
+                }],
+            ),
+            (
+                "- loose\n\n- list",
+                vec![Block::List {
+                    start: None,
+                    items: vec![
+                        vec![Block::Paragraph(vec![text("loose")])],
+                        vec![Block::Paragraph(vec![text("list")])],
+                    ],
+                }],
+            ),
+            (
+                "Plain *emphasis* and **strong**",
+                vec![Block::Paragraph(vec![text("Plain emphasis and strong")])],
+            ),
+        ];
+
+        for (markdown, blocks) in corpus {
+            assert_eq!(parse(markdown), blocks, "{:?}", markdown);
+        }
+    }
+
+    #[test]
+    fn parses_links_and_code() {
+        assert_eq!(
+            parse("See [the `docs`](https://docs.rs) or `cargo doc`."),
+            vec![Block::Paragraph(vec![
+                text("See "),
+                Inline {
+                    text: String::from("the "),
+                    is_code: false,
+                    link: Some(String::from("https://docs.rs")),
+                },
+                Inline {
+                    text: String::from("docs"),
+                    is_code: true,
+                    link: Some(String::from("https://docs.rs")),
+                },
+                text(" or "),
+                Inline {
+                    text: String::from("cargo doc"),
+                    is_code: true,
+                    link: None,
+                },
+                text("."),
+            ])]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Query or update internal widget state.
+pub mod focusable;
+pub mod pick_list;
+pub mod scrollable;
+pub mod text_input;
+
+pub use focusable::Focusable;
+pub use pick_list::PickList;
+pub use scrollable::Scrollable;
+pub use text_input::TextInput;
+
+use crate::widget::Id;
+
+use std::fmt;
+
+/// A piece of logic that can traverse the widget tree of an application in
+/// order to query or update some widget state.
+pub trait Operation<T> {
+    /// Operates on a widget that contains other widgets.
+    ///
+    /// The `operate_on_children` function can be called to return control to
+    /// the widget tree and keep traversing it.
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    );
+
+    /// Operates on a widget that can be focused.
+    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that can be scrolled.
+    fn scrollable(&mut self, _state: &mut dyn Scrollable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that has text input.
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
+
+    /// Operates on a widget that displays a dropdown list.
+    fn pick_list(&mut self, _state: &mut dyn PickList, _id: Option<&Id>) {}
+
+    /// Finishes the [`Operation`] and returns its [`Outcome`].
+    fn finish(&self) -> Outcome<T> {
+        Outcome::None
+    }
+}
+
+/// The result of an [`Operation`].
+pub enum Outcome<T> {
+    /// The [`Operation`] produced no result.
+    None,
+
+    /// The [`Operation`] produced some result.
+    Some(T),
+
+    /// The [`Operation`] needs to be followed by another [`Operation`].
+    Chain(Box<dyn Operation<T>>),
+}
+
+impl<T> fmt::Debug for Outcome<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "Outcome::None"),
+            Self::Some(output) => write!(f, "Outcome::Some({:?})", output),
+            Self::Chain(_) => write!(f, "Outcome::Chain(...)"),
+        }
+    }
+}
+
+/// An [`Operation`] that forwards to another [`Operation`] with a different
+/// output type.
+///
+/// Useful to traverse widgets that map their messages.
+#[allow(missing_debug_implementations)]
+pub struct MapRef<'a, A> {
+    operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A> MapRef<'a, A> {
+    /// Creates a new [`MapRef`] wrapping the given [`Operation`].
+    pub fn new(operation: &'a mut dyn Operation<A>) -> Self {
+        Self { operation }
+    }
+}
+
+impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn pick_list(&mut self, state: &mut dyn PickList, id: Option<&Id>) {
+        self.operation.pick_list(state, id);
+    }
+}
@@ -0,0 +1,128 @@
+//! Operate on widgets that can be focused.
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+
+/// The internal state of a widget that can be focused.
+pub trait Focusable {
+    /// Returns whether the widget is focused or not.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Unfocuses the widget.
+    fn unfocus(&mut self);
+}
+
+/// Produces an [`Operation`] that focuses the widget with the given [`Id`].
+///
+/// Every other focusable widget is unfocused. If no widget matches the
+/// [`Id`], the focus is left untouched.
+pub fn focus<T>(target: Id) -> impl Operation<T> {
+    struct Find {
+        target: Id,
+        is_found: bool,
+    }
+
+    impl<T> Operation<T> for Find {
+        fn focusable(&mut self, _state: &mut dyn Focusable, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                self.is_found = true;
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            if self.is_found {
+                Outcome::Chain(Box::new(Focus {
+                    target: self.target.clone(),
+                }))
+            } else {
+                Outcome::None
+            }
+        }
+    }
+
+    struct Focus {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.focus();
+                }
+                _ => {
+                    state.unfocus();
+                }
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Find {
+        target,
+        is_found: false,
+    }
+}
+
+/// The amount of focusable widgets in a widget tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Count {
+    /// The index of the focused widget, if any.
+    pub focused: Option<usize>,
+
+    /// The total amount of focusable widgets.
+    pub total: usize,
+}
+
+/// Produces an [`Operation`] that counts the focusable widgets and finds
+/// the focused one, if any.
+pub fn count<T>(f: fn(Count) -> T) -> impl Operation<T> {
+    struct CountFocusable<T> {
+        count: Count,
+        f: fn(Count) -> T,
+    }
+
+    impl<T> Operation<T> for CountFocusable<T> {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            if state.is_focused() {
+                self.count.focused = Some(self.count.total);
+            }
+
+            self.count.total += 1;
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Some((self.f)(self.count))
+        }
+    }
+
+    CountFocusable {
+        count: Count::default(),
+        f,
+    }
+}
@@ -0,0 +1,35 @@
+//! Operate on widgets that display a dropdown list.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+
+/// The internal state of a widget that displays a dropdown list.
+pub trait PickList {
+    /// Opens the dropdown list of the widget.
+    fn open(&mut self);
+}
+
+/// Produces an [`Operation`] that opens the dropdown list of the widget with
+/// the given [`Id`].
+pub fn open<T>(target: Id) -> impl Operation<T> {
+    struct Open {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Open {
+        fn pick_list(&mut self, state: &mut dyn PickList, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                state.open();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Open { target }
+}
@@ -0,0 +1,57 @@
+//! Operate on widgets that can be scrolled.
+use crate::widget::operation::Operation;
+use crate::widget::scrollable::AbsoluteOffset;
+use crate::widget::Id;
+
+/// The internal state of a widget that can be scrolled.
+pub trait Scrollable {
+    /// Snaps the vertical scroll position of the widget to a relative
+    /// amount.
+    fn snap_to(&mut self, percentage: f32);
+
+    /// Scrolls the widget to an [`AbsoluteOffset`].
+    fn scroll_to(&mut self, offset: AbsoluteOffset);
+}
+
+/// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
+/// the provided relative vertical position.
+pub fn snap_to<T>(target: Id, percentage: f32) -> impl Operation<T> {
+    with_target(target, move |state| state.snap_to(percentage))
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`]
+/// to the provided [`AbsoluteOffset`].
+pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
+    with_target(target, move |state| state.scroll_to(offset))
+}
+
+fn with_target<T>(
+    target: Id,
+    f: impl Fn(&mut dyn Scrollable),
+) -> impl Operation<T> {
+    struct WithTarget<F> {
+        target: Id,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for WithTarget<F>
+    where
+        F: Fn(&mut dyn Scrollable),
+    {
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                (self.f)(state);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    WithTarget { target, f }
+}
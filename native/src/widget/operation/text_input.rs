@@ -0,0 +1,73 @@
+//! Operate on widgets that have text input.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+
+/// The internal state of a widget that has text input.
+pub trait TextInput {
+    /// Moves the cursor of the text input to the front of the input text.
+    fn move_cursor_to_front(&mut self);
+
+    /// Moves the cursor of the text input to the end of the input text.
+    fn move_cursor_to_end(&mut self);
+
+    /// Moves the cursor of the text input to an arbitrary location.
+    fn move_cursor_to(&mut self, position: usize);
+
+    /// Selects all the content of the text input.
+    fn select_all(&mut self);
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the front.
+pub fn move_cursor_to_front<T>(target: Id) -> impl Operation<T> {
+    with_target(target, |state| state.move_cursor_to_front())
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the end.
+pub fn move_cursor_to_end<T>(target: Id) -> impl Operation<T> {
+    with_target(target, |state| state.move_cursor_to_end())
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the provided position.
+pub fn move_cursor_to<T>(target: Id, position: usize) -> impl Operation<T> {
+    with_target(target, move |state| state.move_cursor_to(position))
+}
+
+/// Produces an [`Operation`] that selects all the content of the widget
+/// with the given [`Id`].
+pub fn select_all<T>(target: Id) -> impl Operation<T> {
+    with_target(target, |state| state.select_all())
+}
+
+fn with_target<T>(
+    target: Id,
+    f: impl Fn(&mut dyn TextInput),
+) -> impl Operation<T> {
+    struct WithTarget<F> {
+        target: Id,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for WithTarget<F>
+    where
+        F: Fn(&mut dyn TextInput),
+    {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                (self.f)(state);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    WithTarget { target, f }
+}
@@ -36,6 +36,7 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::touch;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
     Vector, Widget,
@@ -788,6 +789,20 @@ where
             .filter_map(|((_, pane), layout)| pane.overlay(layout, renderer))
             .next()
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.elements.iter_mut().zip(layout.children()).for_each(
+                |((_, pane), layout)| {
+                    pane.operate(layout, operation);
+                },
+            )
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<PaneGrid<'a, Message, Renderer>>
@@ -5,6 +5,7 @@ use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
 use crate::widget::pane_grid::{Draggable, TitleBar};
+use crate::widget::Operation;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 /// The content of a [`Pane`].
@@ -181,6 +182,24 @@ where
         event_status.merge(body_status)
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let body_layout = if let Some(title_bar) = &mut self.title_bar {
+            let mut children = layout.children();
+
+            title_bar.operate(children.next().unwrap(), operation);
+
+            children.next().unwrap()
+        } else {
+            layout
+        };
+
+        self.body.operate(body_layout, operation);
+    }
+
     pub(crate) fn mouse_interaction(
         &self,
         layout: Layout<'_>,
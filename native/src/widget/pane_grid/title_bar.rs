@@ -4,6 +4,7 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Layout, Padding, Point, Rectangle, Shell, Size,
 };
@@ -301,6 +302,26 @@ where
         }
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+
+        self.content.operate(title_layout, operation);
+
+        if let Some(controls) = &mut self.controls {
+            if let Some(controls_layout) = children.next() {
+                controls.operate(controls_layout, operation);
+            }
+        }
+    }
+
     pub(crate) fn overlay(
         &mut self,
         layout: Layout<'_>,
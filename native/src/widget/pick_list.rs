@@ -9,9 +9,11 @@ use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
 use crate::{
-    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
-    Widget,
+    Clipboard, Command, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
 };
 use std::borrow::Cow;
 
@@ -24,14 +26,21 @@ where
     [T]: ToOwned<Owned = Vec<T>>,
 {
     state: &'a mut State<T>,
+    id: Option<Id>,
     on_selected: Box<dyn Fn(T) -> Message>,
+    on_open: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn() -> Message + 'a>>,
     options: Cow<'a, [T]>,
     placeholder: Option<String>,
     selected: Option<T>,
     width: Length,
     padding: Padding,
     text_size: Option<u16>,
+    menu_height: Option<u16>,
     font: Renderer::Font,
+    is_enabled: Box<dyn Fn(&T) -> bool + 'a>,
+    separators_after: Vec<usize>,
+    searchable: bool,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -41,8 +50,10 @@ pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    was_open: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
+    search: String,
 }
 
 impl<T> State<T> {
@@ -52,10 +63,31 @@ impl<T> State<T> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            was_open: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            search: String::new(),
         }
     }
+
+    /// Returns the query typed to filter the options of a searchable
+    /// [`PickList`].
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Returns whether the menu of the [`PickList`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl<T> operation::PickList for State<T> {
+    fn open(&mut self) {
+        self.is_open = true;
+        self.search.clear();
+        self.menu.center_hovered();
+    }
 }
 
 impl<T> Default for State<T> {
@@ -84,18 +116,54 @@ where
     ) -> Self {
         Self {
             state,
+            id: None,
             on_selected: Box::new(on_selected),
+            on_open: None,
+            on_close: None,
             options: options.into(),
             placeholder: None,
             selected,
             width: Length::Shrink,
             text_size: None,
+            menu_height: None,
             padding: Self::DEFAULT_PADDING,
             font: Default::default(),
+            is_enabled: Box::new(|_| true),
+            separators_after: Vec::new(),
+            searchable: false,
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the message that should be produced when the menu of the
+    /// [`PickList`] opens.
+    ///
+    /// When the menu is opened by an operation, like [`open`], the message
+    /// is produced with the next event processed by the [`PickList`].
+    pub fn on_open(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_open = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the menu of the
+    /// [`PickList`] closes, be it because an option was selected or not.
+    pub fn on_close(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_close = Some(Box::new(move || message.clone()));
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -120,12 +188,49 @@ where
         self
     }
 
+    /// Sets the maximum height of the menu of the [`PickList`]. Its options
+    /// can be scrolled when they do not fit.
+    ///
+    /// By default, the menu is only limited by the size of the window.
+    pub fn menu_height(mut self, height: u16) -> Self {
+        self.menu_height = Some(height);
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: Renderer::Font) -> Self {
         self.font = font;
         self
     }
 
+    /// Sets the function that decides which options of the [`PickList`] can
+    /// be chosen.
+    ///
+    /// Disabled options are displayed with a distinct style in the menu, but
+    /// they cannot be selected with the mouse or the keyboard.
+    pub fn is_enabled(mut self, is_enabled: impl Fn(&T) -> bool + 'a) -> Self {
+        self.is_enabled = Box::new(is_enabled);
+        self
+    }
+
+    /// Sets the indices of the options of the [`PickList`] that are followed
+    /// by a separator in the menu.
+    pub fn separators_after(mut self, indices: impl Into<Vec<usize>>) -> Self {
+        self.separators_after = indices.into();
+        self
+    }
+
+    /// Sets whether the options of the [`PickList`] can be filtered by
+    /// typing while its menu is open.
+    ///
+    /// The typed query replaces the selected value in the [`PickList`].
+    /// Up and Down move through the matching options, Enter selects one, and
+    /// Escape clears the query or closes the menu.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     pub fn style(
         mut self,
@@ -165,6 +270,7 @@ where
                     text_size,
                     font.clone(),
                     Size::new(f32::INFINITY, f32::INFINITY),
+                    text::Spacing::default(),
                 );
 
                 width.round() as u32
@@ -202,17 +308,23 @@ pub fn update<'a, T, Message>(
     cursor_position: Point,
     shell: &mut Shell<'_, Message>,
     on_selected: &dyn Fn(T) -> Message,
+    on_open: Option<&dyn Fn() -> Message>,
+    on_close: Option<&dyn Fn() -> Message>,
     selected: Option<&T>,
     options: &[T],
+    is_enabled: &dyn Fn(&T) -> bool,
+    searchable: bool,
     state: impl FnOnce() -> &'a mut State<T>,
 ) -> event::Status
 where
     T: PartialEq + Clone + 'a,
 {
-    match event {
+    let state = state();
+
+    let status = match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            let state = state();
+            state.search.clear();
 
             let event_status = if state.is_open {
                 // TODO: Encode cursor availability in the type system
@@ -224,6 +336,7 @@ where
                 state.is_open = true;
                 state.hovered_option =
                     options.iter().position(|option| Some(option) == selected);
+                state.menu.center_hovered();
 
                 event::Status::Captured
             } else {
@@ -243,8 +356,6 @@ where
         Event::Mouse(mouse::Event::WheelScrolled {
             delta: mouse::ScrollDelta::Lines { y, .. },
         }) => {
-            let state = state();
-
             if state.keyboard_modifiers.command()
                 && layout.bounds().contains(cursor_position)
                 && !state.is_open
@@ -258,17 +369,24 @@ where
                     options.next()
                 }
 
+                // Disabled options are skipped, unless they are selected
+                let candidates = || {
+                    options.iter().filter(|option| {
+                        Some(*option) == selected || is_enabled(option)
+                    })
+                };
+
                 let next_option = if y < 0.0 {
                     if let Some(selected) = selected {
-                        find_next(selected, options.iter())
+                        find_next(selected, candidates())
                     } else {
-                        options.first()
+                        candidates().next()
                     }
                 } else if y > 0.0 {
                     if let Some(selected) = selected {
-                        find_next(selected, options.iter().rev())
+                        find_next(selected, candidates().rev())
                     } else {
-                        options.last()
+                        candidates().last()
                     }
                 } else {
                     None
@@ -283,14 +401,104 @@ where
                 event::Status::Ignored
             }
         }
-        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
-            let state = state();
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_open =>
+        {
+            match key_code {
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter
+                    if searchable =>
+                {
+                    if let Some(last_selection) = state.last_selection.take() {
+                        shell.publish((on_selected)(last_selection));
+
+                        state.is_open = false;
+                        state.search.clear();
+                    }
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Escape => {
+                    state.is_open = false;
+                    state.search.clear();
 
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             state.keyboard_modifiers = modifiers;
 
             event::Status::Ignored
         }
         _ => event::Status::Ignored,
+    };
+
+    // The menu may also be opened by an operation, which cannot produce
+    // messages, so transitions are published with the next event instead of
+    // where they happen
+    if state.is_open != state.was_open {
+        state.was_open = state.is_open;
+
+        let on_transition = if state.is_open { on_open } else { on_close };
+
+        if let Some(on_transition) = on_transition {
+            shell.publish(on_transition());
+        }
+    }
+
+    status
+}
+
+/// The identifier of a [`PickList`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that opens the menu of the [`PickList`] with the
+/// given [`Id`].
+///
+/// The menu appears the next time the user interface is drawn.
+pub fn open<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::pick_list::open(id.0))
+}
+
+/// Applies an [`Operation`] to the [`State`] of a [`PickList`].
+pub fn operate<T, Message>(
+    state: &mut State<T>,
+    id: Option<&Id>,
+    selected: Option<&T>,
+    options: &[T],
+    operation: &mut dyn Operation<Message>,
+) where
+    T: PartialEq,
+{
+    let was_open = state.is_open;
+
+    operation.pick_list(state, id.map(|id| &id.0));
+
+    if state.is_open && !was_open {
+        state.hovered_option =
+            options.iter().position(|option| Some(option) == selected);
     }
 }
 
@@ -314,9 +522,13 @@ pub fn overlay<'a, T, Message, Renderer>(
     layout: Layout<'_>,
     state: &'a mut State<T>,
     padding: Padding,
-    text_size: Option<u16>,
+    text_size: u16,
+    menu_height: Option<u16>,
     font: Renderer::Font,
     options: &'a [T],
+    is_enabled: &'a dyn Fn(&T) -> bool,
+    separators_after: &'a [usize],
+    searchable: bool,
     style_sheet: &dyn StyleSheet,
 ) -> Option<overlay::Element<'a, Message, Renderer>>
 where
@@ -327,19 +539,29 @@ where
     if state.is_open {
         let bounds = layout.bounds();
 
-        let mut menu = Menu::new(
-            &mut state.menu,
-            options,
-            &mut state.hovered_option,
-            &mut state.last_selection,
-        )
-        .width(bounds.width.round() as u16)
-        .padding(padding)
-        .font(font)
-        .style(style_sheet.menu());
+        let State {
+            menu,
+            hovered_option,
+            last_selection,
+            search,
+            ..
+        } = state;
+
+        let mut menu = Menu::new(menu, options, hovered_option, last_selection)
+            .width(bounds.width.round() as u16)
+            .padding(padding)
+            .is_enabled(is_enabled)
+            .separators_after(separators_after)
+            .text_size(text_size)
+            .font(font)
+            .style(style_sheet.menu());
+
+        if let Some(menu_height) = menu_height {
+            menu = menu.max_height(menu_height);
+        }
 
-        if let Some(text_size) = text_size {
-            menu = menu.text_size(text_size);
+        if searchable {
+            menu = menu.search(search);
         }
 
         Some(menu.overlay(layout.position(), bounds.height))
@@ -358,6 +580,7 @@ pub fn draw<T, Renderer>(
     font: &Renderer::Font,
     placeholder: Option<&str>,
     selected: Option<&T>,
+    search: &str,
     style_sheet: &dyn StyleSheet,
 ) where
     Renderer: text::Renderer,
@@ -395,22 +618,30 @@ pub fn draw<T, Renderer>(
         color: style.text_color,
         horizontal_alignment: alignment::Horizontal::Right,
         vertical_alignment: alignment::Vertical::Center,
+        spacing: text::Spacing::default(),
+        rotation: text::Rotation::None,
     });
 
     let label = selected.map(ToString::to_string);
 
-    if let Some(label) =
-        label.as_ref().map(String::as_str).or_else(|| placeholder)
-    {
+    let label = if search.is_empty() {
+        label.as_deref().or(placeholder)
+    } else {
+        Some(search)
+    };
+
+    if let Some(label) = label {
         let text_size = f32::from(text_size.unwrap_or(renderer.default_size()));
 
         renderer.fill_text(Text {
             content: label,
             size: text_size,
             font: font.clone(),
-            color: is_selected
-                .then(|| style.text_color)
-                .unwrap_or(style.placeholder_color),
+            color: if is_selected || !search.is_empty() {
+                style.text_color
+            } else {
+                style.placeholder_color
+            },
             bounds: Rectangle {
                 x: bounds.x + f32::from(padding.left),
                 y: bounds.center_y() - text_size / 2.0,
@@ -419,6 +650,8 @@ pub fn draw<T, Renderer>(
             },
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            spacing: text::Spacing::default(),
+            rotation: text::Rotation::None,
         });
     }
 }
@@ -471,8 +704,12 @@ where
             cursor_position,
             shell,
             self.on_selected.as_ref(),
+            self.on_open.as_deref(),
+            self.on_close.as_deref(),
             self.selected.as_ref(),
             &self.options,
+            self.is_enabled.as_ref(),
+            self.searchable,
             || &mut self.state,
         )
     }
@@ -504,22 +741,41 @@ where
             &self.font,
             self.placeholder.as_ref().map(String::as_str),
             self.selected.as_ref(),
+            self.state.search(),
             self.style_sheet.as_ref(),
         )
     }
 
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operate(
+            self.state,
+            self.id.as_ref(),
+            self.selected.as_ref(),
+            &self.options,
+            operation,
+        )
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         overlay(
             layout,
             &mut self.state,
             self.padding,
-            self.text_size,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.menu_height,
             self.font.clone(),
             &self.options,
+            self.is_enabled.as_ref(),
+            &self.separators_after,
+            self.searchable,
             self.style_sheet.as_ref(),
         )
     }
@@ -537,3 +793,86 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Opened,
+        Closed,
+    }
+
+    const OPTIONS: [&str; 3] = ["Rust", "Elm", "Haskell"];
+
+    fn next_event(state: &mut State<&'static str>) -> Vec<Message> {
+        let node = layout::Node::new(Size::new(200.0, 40.0));
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = update(
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(-1.0, -1.0),
+            }),
+            Layout::new(&node),
+            Point::new(-1.0, -1.0),
+            &mut shell,
+            &Message::Selected,
+            Some(&|| Message::Opened),
+            Some(&|| Message::Closed),
+            Some(&"Elm"),
+            &OPTIONS,
+            &|_| true,
+            false,
+            || state,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn opening_by_operation_is_reported_and_hovers_the_selection() {
+        let id = Id::new("languages");
+        let mut state = State::new();
+
+        operate(
+            &mut state,
+            Some(&id),
+            Some(&"Elm"),
+            &OPTIONS,
+            &mut operation::pick_list::open::<Message>(id.clone().into()),
+        );
+
+        assert!(state.is_open());
+        assert_eq!(state.hovered_option, Some(1));
+
+        assert_eq!(next_event(&mut state), vec![Message::Opened]);
+        assert_eq!(next_event(&mut state), vec![]);
+
+        state.is_open = false;
+
+        assert_eq!(next_event(&mut state), vec![Message::Closed]);
+    }
+
+    #[test]
+    fn operations_targeting_other_pick_lists_are_ignored() {
+        let mut state = State::new();
+
+        operate(
+            &mut state,
+            Some(&Id::new("languages")),
+            Some(&"Elm"),
+            &OPTIONS,
+            &mut operation::pick_list::open::<Message>(
+                Id::new("editors").into(),
+            ),
+        );
+
+        assert!(!state.is_open());
+        assert_eq!(state.hovered_option, None);
+        assert_eq!(next_event(&mut state), vec![]);
+    }
+}
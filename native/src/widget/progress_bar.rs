@@ -1,11 +1,21 @@
 //! Provide progress feedback to your users.
+use crate::event::{self, Event};
 use crate::layout;
 use crate::renderer;
-use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
 
 use std::ops::RangeInclusive;
+use std::sync::OnceLock;
 
-pub use iced_style::progress_bar::{Style, StyleSheet};
+pub use iced_style::progress_bar::{Indeterminate, Style, StyleSheet};
+
+/// The time between the frames of an indeterminate [`ProgressBar`].
+const ANIMATION_FRAME: Duration = Duration::from_millis(16);
 
 /// A bar that displays progress.
 ///
@@ -17,11 +27,20 @@ pub use iced_style::progress_bar::{Style, StyleSheet};
 /// ProgressBar::new(0.0..=100.0, value);
 /// ```
 ///
+/// When the total amount of work is unknown, an indeterminate
+/// [`ProgressBar`] can be used instead:
+///
+/// ```
+/// # use iced_native::widget::ProgressBar;
+/// ProgressBar::new(0.0..=100.0, 0.0).indeterminate();
+/// ```
+///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<'a> {
     range: RangeInclusive<f32>,
     value: f32,
+    is_indeterminate: bool,
     width: Length,
     height: Option<Length>,
     style_sheet: Box<dyn StyleSheet + 'a>,
@@ -40,12 +59,23 @@ impl<'a> ProgressBar<'a> {
         ProgressBar {
             value: value.max(*range.start()).min(*range.end()),
             range,
+            is_indeterminate: false,
             width: Length::Fill,
             height: None,
             style_sheet: Default::default(),
         }
     }
 
+    /// Turns the [`ProgressBar`] into an indeterminate one, which ignores its
+    /// value and shows a segment sweeping back and forth instead.
+    ///
+    /// The segment keeps moving for as long as the [`ProgressBar`] is part
+    /// of the user interface.
+    pub fn indeterminate(mut self) -> Self {
+        self.is_indeterminate = true;
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -94,6 +124,31 @@ where
         layout::Node::new(size)
     }
 
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        // Nothing to animate when determinate or collapsed, so no frame is
+        // requested and the animation stops right away
+        if self.is_indeterminate && bounds.width > 0.0 && bounds.height > 0.0 {
+            let now = match event {
+                Event::Window(window::Event::RedrawRequested(now)) => now,
+                _ => Instant::now(),
+            };
+
+            shell.request_redraw(now + ANIMATION_FRAME);
+        }
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -114,6 +169,29 @@ where
 
         let style = self.style_sheet.style();
 
+        let (bar, bar_background) = if self.is_indeterminate {
+            let indeterminate = self.style_sheet.indeterminate();
+            let width =
+                bounds.width * indeterminate.segment_width.clamp(0.0, 1.0);
+
+            let bar = Rectangle {
+                x: bounds.x
+                    + (bounds.width - width)
+                        * sweep(&indeterminate, Instant::now()),
+                width,
+                ..bounds
+            };
+
+            (bar, indeterminate.segment)
+        } else {
+            let bar = Rectangle {
+                width: active_progress_width,
+                ..bounds
+            };
+
+            (bar, style.bar)
+        };
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle { ..bounds },
@@ -124,23 +202,42 @@ where
             style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if bar.width > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
+                    bounds: bar,
                     border_radius: style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 },
-                style.bar,
+                bar_background,
             );
         }
     }
 }
 
+/// Returns the position of the sweeping segment of an indeterminate
+/// [`ProgressBar`] at the given moment, from `0.0` at the start of the bar
+/// to `1.0` at its end.
+fn sweep(indeterminate: &Indeterminate, now: Instant) -> f32 {
+    // Every indeterminate bar sweeps in unison
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+    let epoch = *EPOCH.get_or_init(Instant::now);
+    let period = indeterminate.period.as_secs_f32();
+
+    if period <= 0.0 {
+        return 0.0;
+    }
+
+    let phase = (now.duration_since(epoch).as_secs_f32() / period).fract();
+
+    // There and back again, slowing down at both ends
+    let t = 1.0 - (2.0 * phase - 1.0).abs();
+
+    t * t * (3.0 - 2.0 * t)
+}
+
 impl<'a, Message, Renderer> From<ProgressBar<'a>>
     for Element<'a, Message, Renderer>
 where
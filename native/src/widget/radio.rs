@@ -12,6 +12,9 @@ use crate::{
     Shell, Widget,
 };
 
+pub mod group;
+
+pub use group::{group, Group};
 pub use iced_style::radio::{Style, StyleSheet};
 
 /// A circular button representing a choice.
@@ -39,6 +42,9 @@ pub use iced_style::radio::{Style, StyleSheet};
 /// Radio::new(Choice::B, "This is B", selected_choice, Message::RadioSelected);
 /// ```
 ///
+/// A set of [`Radio`] buttons where the selection can be cleared can be built
+/// with [`Radio::new_optional`].
+///
 /// ![Radio buttons drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Radio<'a, Message, Renderer: text::Renderer> {
@@ -94,6 +100,28 @@ where
         }
     }
 
+    /// Creates a new [`Radio`] button that clears the selection when it is
+    /// clicked while already selected.
+    ///
+    /// The function receives `Some` value of the radio when it gets selected
+    /// and `None` when it gets deselected.
+    pub fn new_optional<F, V>(
+        value: V,
+        label: impl Into<String>,
+        selected: Option<V>,
+        f: F,
+    ) -> Self
+    where
+        V: Eq + Copy,
+        F: FnOnce(Option<V>) -> Message,
+    {
+        let is_selected = Some(value) == selected;
+
+        Self::new(value, label, selected, |value| {
+            f(if is_selected { None } else { Some(value) })
+        })
+    }
+
     /// Sets the size of the [`Radio`] button.
     pub fn size(mut self, size: u16) -> Self {
         self.size = size;
@@ -275,6 +303,8 @@ where
                 custom_style.text_color,
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                text::Spacing::default(),
+                text::Rotation::None,
             );
         }
     }
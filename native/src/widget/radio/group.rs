@@ -0,0 +1,485 @@
+//! Lay out a set of radio buttons that share their appearance.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::radio::{Radio, Style, StyleSheet};
+use crate::widget::{Column, Row};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A set of [`Radio`] buttons, one for every option, that share their size,
+/// spacing, and style.
+///
+/// Once one of its buttons is clicked, the arrow keys cycle the selection
+/// through the options of the [`Group`], as long as it has some local
+/// [`State`].
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::radio;
+/// #
+/// # type Group<'a, V, Message> =
+/// #     radio::Group<'a, V, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Choice {
+///     A,
+///     B,
+/// }
+///
+/// impl std::fmt::Display for Choice {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     ChoiceSelected(Choice),
+/// }
+///
+/// let mut state = radio::group::State::new();
+///
+/// let group: Group<_, Message> =
+///     radio::group([Choice::A, Choice::B], Some(Choice::A), Message::ChoiceSelected)
+///         .state(&mut state);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Group<'a, V, Message, Renderer: text::Renderer> {
+    state: Option<&'a mut State>,
+    options: Vec<V>,
+    selected: Option<V>,
+    on_select: Box<dyn Fn(V) -> Message + 'a>,
+    is_horizontal: bool,
+    width: Length,
+    spacing: u16,
+    size: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// Creates a new [`Group`] with a [`Radio`] button for every option, the
+/// current selected option, and a function that produces a message when an
+/// option is selected.
+pub fn group<'a, V, Message, Renderer>(
+    options: impl IntoIterator<Item = V>,
+    selected: Option<V>,
+    on_select: impl Fn(V) -> Message + 'a,
+) -> Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    Group::new(options, selected, on_select)
+}
+
+impl<'a, V, Message, Renderer> Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    /// The default spacing between the [`Radio`] buttons of a [`Group`].
+    pub const DEFAULT_SPACING: u16 = 10;
+
+    /// Creates a new [`Group`] with a [`Radio`] button for every option, the
+    /// current selected option, and a function that produces a message when
+    /// an option is selected.
+    pub fn new(
+        options: impl IntoIterator<Item = V>,
+        selected: Option<V>,
+        on_select: impl Fn(V) -> Message + 'a,
+    ) -> Self {
+        Group {
+            state: None,
+            options: options.into_iter().collect(),
+            selected,
+            on_select: Box::new(on_select),
+            is_horizontal: false,
+            width: Length::Shrink,
+            spacing: Self::DEFAULT_SPACING,
+            size: Radio::<Message, Renderer>::DEFAULT_SIZE,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the local [`State`] of the [`Group`], which lets the arrow keys
+    /// change its selection.
+    pub fn state(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Lays the [`Radio`] buttons of the [`Group`] out in a row, instead of a
+    /// column.
+    pub fn horizontal(mut self) -> Self {
+        self.is_horizontal = true;
+        self
+    }
+
+    /// Sets the width of the [`Group`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the [`Radio`] buttons of the [`Group`].
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of the [`Radio`] buttons of the [`Group`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the text size of the [`Radio`] buttons of the [`Group`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the text font of the [`Radio`] buttons of the [`Group`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Radio`] buttons of the [`Group`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn content(&self) -> Element<'_, Message, Renderer> {
+        content(
+            Properties {
+                options: &self.options,
+                selected: self.selected,
+                on_select: self.on_select.as_ref(),
+                is_horizontal: self.is_horizontal,
+                width: self.width,
+                spacing: self.spacing,
+                size: self.size,
+                text_size: self.text_size,
+                font: &self.font,
+            },
+            self.style_sheet.as_ref(),
+        )
+    }
+}
+
+/// The local state of a [`Group`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the arrow keys change the selection of the [`Group`].
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// The properties of a [`Group`] that define its [`Radio`] buttons and how
+/// they are laid out.
+///
+/// Most fields are set by the builder method of the [`Group`] with the same
+/// name.
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, V, Message, Font> {
+    /// The options of the [`Group`], one for every [`Radio`] button.
+    pub options: &'a [V],
+    /// The selected option, if any.
+    pub selected: Option<V>,
+    /// The function producing a message when an option is selected.
+    pub on_select: &'a dyn Fn(V) -> Message,
+    /// Whether the [`Radio`] buttons are laid out in a row, instead of a
+    /// column.
+    pub is_horizontal: bool,
+    /// The width of the [`Group`].
+    pub width: Length,
+    /// The spacing between the [`Radio`] buttons.
+    pub spacing: u16,
+    /// The size of the [`Radio`] buttons.
+    pub size: u16,
+    /// The text size of the [`Radio`] buttons, or the default size of the
+    /// renderer if `None`.
+    pub text_size: Option<u16>,
+    /// The text font of the [`Radio`] buttons.
+    pub font: &'a Font,
+}
+
+/// Builds the [`Radio`] buttons of a [`Group`].
+pub fn content<'b, V, Message, Renderer>(
+    properties: Properties<'_, V, Message, Renderer::Font>,
+    style_sheet: &'b dyn StyleSheet,
+) -> Element<'b, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: 'b + Clone,
+    Renderer: 'b + text::Renderer,
+{
+    let Properties {
+        options,
+        selected,
+        on_select,
+        is_horizontal,
+        width,
+        spacing,
+        size,
+        text_size,
+        font,
+    } = properties;
+
+    let radios = options.iter().map(|option| {
+        let radio =
+            Radio::new(*option, option.to_string(), selected, on_select)
+                .size(size)
+                .font(font.clone())
+                .style(Shared(style_sheet));
+
+        match text_size {
+            Some(text_size) => radio.text_size(text_size),
+            None => radio,
+        }
+    });
+
+    if is_horizontal {
+        radios
+            .fold(Row::new().spacing(spacing).width(width), Row::push)
+            .into()
+    } else {
+        radios
+            .fold(Column::new().spacing(spacing).width(width), Column::push)
+            .into()
+    }
+}
+
+/// Processes an [`Event`] that has already gone through the [`Radio`]
+/// buttons of a [`Group`], and updates its [`State`] accordingly.
+pub fn update<V, Message>(
+    event: &Event,
+    status: event::Status,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+    options: &[V],
+    selected: Option<V>,
+    on_select: &dyn Fn(V) -> Message,
+) -> event::Status
+where
+    V: Copy + Eq,
+{
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            state.is_focused = status == event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused && !options.is_empty() =>
+        {
+            let current = selected.and_then(|selected| {
+                options.iter().position(|o| *o == selected)
+            });
+
+            let next = match (key_code, current) {
+                (
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Right,
+                    Some(current),
+                ) => (current + 1) % options.len(),
+                (keyboard::KeyCode::Down | keyboard::KeyCode::Right, None) => 0,
+                (keyboard::KeyCode::Up | keyboard::KeyCode::Left, Some(0))
+                | (keyboard::KeyCode::Up | keyboard::KeyCode::Left, None) => {
+                    options.len() - 1
+                }
+                (
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Left,
+                    Some(current),
+                ) => current - 1,
+                _ => return status,
+            };
+
+            shell.publish(on_select(options[next]));
+
+            return event::Status::Captured;
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// Shares the [`StyleSheet`] of a [`Group`] with all of its [`Radio`]
+/// buttons.
+struct Shared<'a>(&'a dyn StyleSheet);
+
+impl<'a> StyleSheet for Shared<'a> {
+    fn active(&self) -> Style {
+        self.0.active()
+    }
+
+    fn hovered(&self) -> Style {
+        self.0.hovered()
+    }
+}
+
+impl<'a, V, Message, Renderer> Widget<Message, Renderer>
+    for Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content().on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        match self.state.as_deref_mut() {
+            Some(state) => update(
+                &event,
+                status,
+                shell,
+                state,
+                &self.options,
+                self.selected,
+                self.on_select.as_ref(),
+            ),
+            None => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content().mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content()
+            .draw(renderer, style, layout, cursor_position, viewport)
+    }
+}
+
+impl<'a, V, Message, Renderer> From<Group<'a, V, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    V: 'a + Copy + Eq + ToString,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        group: Group<'a, V, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(
+        state: &mut State,
+        selected: Option<u8>,
+        key_code: keyboard::KeyCode,
+    ) -> Vec<u8> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = update(
+            &Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+            event::Status::Ignored,
+            &mut shell,
+            state,
+            &[1, 2, 3],
+            selected,
+            &|option| option,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn arrow_keys_cycle_a_focused_group() {
+        let mut state = State::new();
+
+        assert!(press(&mut state, Some(1), keyboard::KeyCode::Down).is_empty());
+
+        state.is_focused = true;
+
+        assert_eq!(press(&mut state, Some(1), keyboard::KeyCode::Down), [2]);
+        assert_eq!(press(&mut state, Some(3), keyboard::KeyCode::Right), [1]);
+        assert_eq!(press(&mut state, Some(1), keyboard::KeyCode::Up), [3]);
+        assert_eq!(press(&mut state, None, keyboard::KeyCode::Down), [1]);
+        assert_eq!(press(&mut state, None, keyboard::KeyCode::Left), [3]);
+    }
+}
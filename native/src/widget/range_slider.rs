@@ -0,0 +1,660 @@
+//! Display an interactive selector of a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::touch;
+use crate::widget::slider;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+pub use iced_style::range_slider::{Handle, HandleShape, Style, StyleSheet};
+
+/// An horizontal bar with two handles that select a range of values between
+/// some bounds.
+///
+/// A [`RangeSlider`] will try to fill the horizontal space of its container.
+///
+/// The handles cannot cross each other. By default, a dragged handle stops at
+/// the other one, but it can also push it instead.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::range_slider::{self, RangeSlider};
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceChanged((f32, f32)),
+/// }
+///
+/// let state = &mut range_slider::State::new();
+/// let price = (20.0, 80.0);
+///
+/// RangeSlider::new(state, 0.0..=100.0, price, Message::PriceChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message> {
+    state: &'a mut State,
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    values: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message>,
+    on_release: Option<Message>,
+    push: bool,
+    width: Length,
+    height: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: u16 = 22;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`RangeSlider`]
+    ///   * a function that will be called when a handle is dragged.
+    ///     It receives the new start and end values of the [`RangeSlider`]
+    ///     and must produce a `Message`.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn((T, T)) -> Message,
+    {
+        RangeSlider {
+            state,
+            values: clamp(values, &range),
+            range,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            push: false,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets whether a dragged handle pushes the other one instead of
+    /// stopping at it.
+    ///
+    /// By default, it stops.
+    pub fn push(mut self, push: bool) -> Self {
+        self.push = push;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    ///
+    /// It snaps values like the step of a [`Slider`](slider::Slider) does.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`] used while Shift is held,
+    /// which allows finer adjustments.
+    pub fn shift_step(mut self, shift_step: T) -> Self {
+        self.shift_step = Some(shift_step);
+        self
+    }
+}
+
+/// Clamps the given values to the bounds, keeping them ordered.
+fn clamp<T>((start, end): (T, T), range: &RangeInclusive<T>) -> (T, T)
+where
+    T: Copy + std::cmp::PartialOrd,
+{
+    let clamp = |value: T| {
+        if value < *range.start() {
+            *range.start()
+        } else if value > *range.end() {
+            *range.end()
+        } else {
+            value
+        }
+    };
+
+    let (start, end) = (clamp(start), clamp(end));
+
+    if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    }
+}
+
+/// Returns the horizontal position of the given value in the bounds of a
+/// [`RangeSlider`].
+fn position<T>(value: T, range: &RangeInclusive<T>, bounds: Rectangle) -> f32
+where
+    T: Copy + Into<f64>,
+{
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+
+    if start >= end {
+        bounds.x
+    } else {
+        bounds.x
+            + (f64::from(bounds.width) * (value.into() - start) / (end - start))
+                as f32
+    }
+}
+
+/// The properties of a [`RangeSlider`] that define how [`update`] processes
+/// events.
+///
+/// Most fields are set by the builder method of the [`RangeSlider`] with the
+/// same name.
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, T, Message> {
+    /// The inclusive range of possible values.
+    pub range: &'a RangeInclusive<T>,
+    /// The step size that values snap to.
+    pub step: T,
+    /// The step size used while Shift is held, if any.
+    pub shift_step: Option<T>,
+    /// Whether a dragged handle pushes the other one instead of stopping at
+    /// it.
+    pub push: bool,
+    /// The function producing a message when a handle is dragged.
+    pub on_change: &'a dyn Fn((T, T)) -> Message,
+    /// The message produced when the mouse is released.
+    pub on_release: &'a Option<Message>,
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`RangeSlider`]
+/// accordingly.
+pub fn update<Message, T>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+    values: &mut (T, T),
+    properties: Properties<'_, T, Message>,
+) -> event::Status
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+{
+    let Properties {
+        range,
+        step,
+        shift_step,
+        push,
+        on_change,
+        on_release,
+    } = properties;
+
+    let bounds = layout.bounds();
+
+    let mut change = |state: &mut State, values: &mut (T, T)| {
+        let thumb = if let Some(thumb) = state.dragging {
+            thumb
+        } else {
+            return;
+        };
+
+        let step = match shift_step {
+            Some(shift_step) if state.keyboard_modifiers.shift() => shift_step,
+            _ => step,
+        };
+
+        let percent = f64::from((cursor_position.x - bounds.x) / bounds.width)
+            .clamp(0.0, 1.0);
+
+        let value = if let Some(value) = T::from_f64(slider::snap(
+            percent,
+            (*range.start()).into(),
+            (*range.end()).into(),
+            step.into(),
+        )) {
+            value
+        } else {
+            return;
+        };
+
+        let (start, end) = *values;
+        let new_values = match thumb {
+            Thumb::Start if value.into() <= end.into() => (value, end),
+            Thumb::Start if push => (value, value),
+            Thumb::Start => (end, end),
+            Thumb::End if value.into() >= start.into() => (start, value),
+            Thumb::End if push => (value, value),
+            Thumb::End => (start, start),
+        };
+
+        if (start.into() - new_values.0.into()).abs() > f64::EPSILON
+            || (end.into() - new_values.1.into()).abs() > f64::EPSILON
+        {
+            shell.publish((on_change)(new_values));
+
+            *values = new_values;
+        }
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if bounds.contains(cursor_position) =>
+        {
+            let start = position(values.0, range, bounds);
+            let end = position(values.1, range, bounds);
+
+            let to_start = (cursor_position.x - start).abs();
+            let to_end = (cursor_position.x - end).abs();
+
+            // When the handles overlap, grab the one that can move
+            // towards the cursor
+            let thumb = if to_start < to_end {
+                Thumb::Start
+            } else if to_end < to_start {
+                Thumb::End
+            } else if cursor_position.x < start {
+                Thumb::Start
+            } else if cursor_position.x > end {
+                Thumb::End
+            } else if (values.1.into() - (*range.end()).into()).abs()
+                < f64::EPSILON
+            {
+                Thumb::Start
+            } else {
+                Thumb::End
+            };
+
+            state.dragging = Some(thumb);
+            change(state, values);
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. })
+            if state.dragging.is_some() =>
+        {
+            if let Some(on_release) = on_release.clone() {
+                shell.publish(on_release);
+            }
+            state.dragging = None;
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. })
+            if state.dragging.is_some() =>
+        {
+            change(state, values);
+
+            return event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.keyboard_modifiers = modifiers;
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws a [`RangeSlider`].
+pub fn draw<T>(
+    renderer: &mut impl crate::Renderer,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    state: &State,
+    values: (T, T),
+    range: &RangeInclusive<T>,
+    style_sheet: &dyn StyleSheet,
+) where
+    T: Into<f64> + Copy,
+{
+    let bounds = layout.bounds();
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    let style = if state.dragging.is_some() {
+        style_sheet.dragging()
+    } else if is_mouse_over {
+        style_sheet.hovered()
+    } else {
+        style_sheet.active()
+    };
+
+    let rail_y = bounds.y + (bounds.height / 2.0).round();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: rail_y - 1.0,
+                width: bounds.width,
+                height: 2.0,
+            },
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        style.rail_colors.0,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: rail_y + 1.0,
+                width: bounds.width,
+                height: 2.0,
+            },
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        Background::Color(style.rail_colors.1),
+    );
+
+    let start = position(values.0, range, bounds).round();
+    let end = position(values.1, range, bounds).round();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: start,
+                y: rail_y - 1.0,
+                width: end - start,
+                height: 4.0,
+            },
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        style.selection,
+    );
+
+    let (handle_width, handle_height, handle_border_radius) = match style
+        .handle
+        .shape
+    {
+        HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius),
+        HandleShape::Rectangle {
+            width,
+            border_radius,
+        } => (f32::from(width), bounds.height, border_radius),
+    };
+
+    for x in [start, end] {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: x - (handle_width / 2.0).round(),
+                    y: rail_y - handle_height / 2.0,
+                    width: handle_width,
+                    height: handle_height,
+                },
+                border_radius: handle_border_radius,
+                border_width: style.handle.border_width,
+                border_color: style.handle.border_color,
+            },
+            style.handle.color,
+        );
+    }
+}
+
+/// Computes the current [`mouse::Interaction`] of a [`RangeSlider`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    state: &State,
+) -> mouse::Interaction {
+    let bounds = layout.bounds();
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    if state.dragging.is_some() {
+        mouse::Interaction::Grabbing
+    } else if is_mouse_over {
+        mouse::Interaction::Grab
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    dragging: Option<Thumb>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits =
+            limits.width(self.width).height(Length::Units(self.height));
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            shell,
+            self.state,
+            &mut self.values,
+            Properties {
+                range: &self.range,
+                step: self.step,
+                shift_step: self.shift_step,
+                push: self.push,
+                on_change: self.on_change.as_ref(),
+                on_release: &self.on_release,
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            layout,
+            cursor_position,
+            self.state,
+            self.values,
+            &self.range,
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position, self.state)
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drag(
+        state: &mut State,
+        values: &mut (f64, f64),
+        push: bool,
+        x: f32,
+    ) -> Vec<(f64, f64)> {
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let event = if state.dragging.is_some() {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(x, 10.0),
+            })
+        } else {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        };
+
+        let _ = update(
+            event,
+            Layout::new(&node),
+            Point::new(x, 10.0),
+            &mut shell,
+            state,
+            values,
+            Properties {
+                range: &(0.0..=100.0),
+                step: 5.0,
+                shift_step: None,
+                push,
+                on_change: &|values| values,
+                on_release: &None,
+            },
+        );
+
+        messages
+    }
+
+    #[test]
+    fn handles_stop_at_each_other() {
+        let mut state = State::new();
+        let mut values = (20.0, 60.0);
+
+        assert_eq!(drag(&mut state, &mut values, false, 28.0), [(30.0, 60.0)]);
+        assert_eq!(drag(&mut state, &mut values, false, 90.0), [(60.0, 60.0)]);
+    }
+
+    #[test]
+    fn handles_push_each_other() {
+        let mut state = State::new();
+        let mut values = (20.0, 60.0);
+
+        assert!(drag(&mut state, &mut values, true, 58.0).is_empty());
+        assert_eq!(drag(&mut state, &mut values, true, 12.0), [(10.0, 10.0)]);
+    }
+
+    #[test]
+    fn overlapping_handles_grab_towards_the_cursor() {
+        let mut values = (50.0, 50.0);
+
+        let mut state = State::new();
+        let _ = drag(&mut state, &mut values, false, 45.0);
+        assert_eq!(state.dragging, Some(Thumb::Start));
+
+        let mut state = State::new();
+        let _ = drag(&mut state, &mut values, false, 55.0);
+        assert_eq!(state.dragging, Some(Thumb::End));
+    }
+}
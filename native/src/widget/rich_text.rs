@@ -0,0 +1,294 @@
+//! Write a paragraph of text with mixed styles.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+/// A run of text with its own style, part of a [`RichText`].
+///
+/// Any style that is not set is inherited from the [`RichText`].
+#[derive(Debug, Clone)]
+pub struct Span<Message, Font> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    link: Option<Message>,
+}
+
+impl<Message, Font> Span<Message, Font> {
+    /// Creates a new [`Span`] with the given contents.
+    pub fn new(content: impl Into<String>) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Turns the [`Span`] into a link, producing the given message when it
+    /// is clicked.
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+/// A paragraph made of differently styled [`Span`]s.
+///
+/// The spans are laid out as a single paragraph, wrapping across their
+/// boundaries.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::widget::rich_text::Span;
+/// # type RichText = iced_native::widget::RichText<(), iced_native::renderer::Null>;
+/// #
+/// RichText::new(vec![
+///     Span::new("error in "),
+///     Span::new("main.rs").color([1.0, 0.0, 0.0]),
+///     Span::new(":42"),
+/// ])
+/// .size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<Message, Renderer: text::Renderer> {
+    spans: Vec<Span<Message, Renderer::Font>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+}
+
+impl<Message, Renderer: text::Renderer> RichText<Message, Renderer> {
+    /// Creates a new [`RichText`] with the given [`Span`]s.
+    pub fn new(spans: Vec<Span<Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    pub fn push(mut self, span: Span<Message, Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the [`Span`]s of the [`RichText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the [`Span`]s of the [`RichText`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default font of the [`Span`]s of the [`RichText`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Resolves the style of every [`Span`], falling back to the defaults of
+    /// the [`RichText`] and the given text color.
+    fn resolve(
+        &self,
+        renderer: &Renderer,
+        text_color: Color,
+    ) -> Vec<text::Span<'_, Renderer::Font>> {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let color = self.color.unwrap_or(text_color);
+
+        self.spans
+            .iter()
+            .map(|span| text::Span {
+                content: &span.content,
+                size: f32::from(span.size.unwrap_or(size)),
+                color: span.color.unwrap_or(color),
+                font: span.font.clone().unwrap_or_else(|| self.font.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the link of the [`Span`] under the cursor, if any.
+    fn hovered_link(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+    ) -> Option<&Message> {
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position)
+            || self.spans.iter().all(|span| span.link.is_none())
+        {
+            return None;
+        }
+
+        // Colors do not affect layout
+        let spans = self.resolve(renderer, Color::BLACK);
+
+        let index = renderer.hit_test_spans(
+            &spans,
+            bounds.size(),
+            Point::new(
+                cursor_position.x - bounds.x,
+                cursor_position.y - bounds.y,
+            ),
+        )?;
+
+        self.spans[index].link.as_ref()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let spans = self.resolve(renderer, Color::BLACK);
+
+        let (width, height) = renderer.measure_spans(&spans, limits.max());
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(link) =
+                    self.hovered_link(layout, cursor_position, renderer)
+                {
+                    shell.publish(link.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let spans = self.resolve(renderer, style.text_color);
+
+        renderer.fill_rich_text(text::RichText {
+            spans: &spans,
+            bounds: layout.bounds(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .hovered_link(layout, cursor_position, renderer)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
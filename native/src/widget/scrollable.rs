@@ -1,16 +1,22 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
 use crate::widget::Column;
+use crate::window;
 use crate::{
-    Alignment, Background, Clipboard, Color, Element, Layout, Length, Padding,
-    Point, Rectangle, Shell, Size, Vector, Widget,
+    Alignment, Background, Clipboard, Color, Command, Element, Layout, Length,
+    Padding, Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::borrow::Cow;
 use std::{f32, u32};
 
 pub use iced_style::scrollable::StyleSheet;
@@ -22,18 +28,20 @@ pub mod style {
     pub use iced_style::scrollable::{Scrollbar, Scroller};
 }
 
-/// A widget that can vertically display an infinite amount of content with a
+/// A widget that can display an infinite amount of content with a
 /// scrollbar.
+///
+/// The arrow, page, home, and end keys scroll a [`Scrollable`] while the
+/// mouse is over it, unless a widget inside of it is focused.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
     height: Length,
     max_height: u32,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
+    properties: Properties,
     content: Column<'a, Message, Renderer>,
-    on_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -42,17 +50,22 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
             state,
+            id: None,
             height: Length::Shrink,
             max_height: u32::MAX,
-            scrollbar_width: 10,
-            scrollbar_margin: 0,
-            scroller_width: 10,
+            properties: Properties::default(),
             content: Column::new(),
             on_scroll: None,
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Scrollable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -102,13 +115,13 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     /// Sets the scrollbar width of the [`Scrollable`] .
     /// Silently enforces a minimum value of 1.
     pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
-        self.scrollbar_width = scrollbar_width.max(1);
+        self.properties.scrollbar_width = scrollbar_width.max(1);
         self
     }
 
     /// Sets the scrollbar margin of the [`Scrollable`] .
     pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
-        self.scrollbar_margin = scrollbar_margin;
+        self.properties.scrollbar_margin = scrollbar_margin;
         self
     }
 
@@ -116,15 +129,125 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     ///
     /// It silently enforces a minimum value of 1.
     pub fn scroller_width(mut self, scroller_width: u16) -> Self {
-        self.scroller_width = scroller_width.max(1);
+        self.properties.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets the edge of the [`Scrollable`] where its vertical scrollbar is
+    /// placed.
+    ///
+    /// [`Alignment::Start`] places it on the left edge, while any other
+    /// [`Alignment`] keeps it on the right edge, which is the default.
+    pub fn scrollbar_alignment(mut self, alignment: Alignment) -> Self {
+        self.properties.scrollbar_alignment = alignment;
+        self
+    }
+
+    /// Hides the scrollbars of the [`Scrollable`] after the given
+    /// [`Duration`] without any interaction, fading them out.
+    ///
+    /// Scrolling or hovering a scrollbar shows them again. A zero
+    /// [`Duration`] keeps them always visible, which is the default.
+    pub fn hide_scrollbar_after(mut self, duration: Duration) -> Self {
+        self.properties.hide_scrollbar_after =
+            Some(duration).filter(|d| !d.is_zero());
+        self
+    }
+
+    /// Sets the [`Direction`] in which the [`Scrollable`] can scroll.
+    ///
+    /// By default, a [`Scrollable`] only scrolls vertically.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.properties.direction = direction;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] stays anchored to the bottom of its
+    /// contents.
+    ///
+    /// When anchored, a [`Scrollable`] scrolled to the bottom keeps showing
+    /// the bottom as its contents grow. Scrolling up releases the anchor,
+    /// and scrolling back to the bottom restores it.
+    pub fn anchor_bottom(mut self, anchor_bottom: bool) -> Self {
+        self.properties.anchor_bottom = anchor_bottom;
+        self
+    }
+
+    /// Sets the amount of pixels scrolled when an arrow key is pressed.
+    ///
+    /// Page keys scroll by the height of the [`Scrollable`] minus this
+    /// amount.
+    pub fn line_step(mut self, line_step: u16) -> Self {
+        self.properties.line_step = line_step;
+        self
+    }
+
+    /// Sets the factor by which the wheel and touchpad deltas are
+    /// multiplied before scrolling, along both axes.
+    ///
+    /// By default, it is `1.0`.
+    pub fn scroll_factor(mut self, scroll_factor: f32) -> Self {
+        self.properties.scroll_factor = scroll_factor;
+        self
+    }
+
+    /// Sets the height of a line of text, in pixels, used to convert the
+    /// ticks of a mouse wheel into pixels.
+    ///
+    /// Every tick scrolls three lines.
+    pub fn scroll_line_height(mut self, scroll_line_height: u16) -> Self {
+        self.properties.scroll_line_height = scroll_line_height;
+        self
+    }
+
+    /// Sets whether a wheel event that cannot scroll the [`Scrollable`]
+    /// any further is passed on to the [`Scrollable`] containing it.
+    ///
+    /// This is known as scroll chaining and it is enabled by default. You
+    /// may want to disable it for widgets that should keep the wheel to
+    /// themselves, like embedded maps.
+    pub fn scroll_chaining(mut self, scroll_chaining: bool) -> Self {
+        self.properties.scroll_chaining = scroll_chaining;
+        self
+    }
+
+    /// Sets the friction that slows down the [`Scrollable`] after a flick
+    /// on a touch screen.
+    ///
+    /// The speed of the flick decays exponentially at this rate, per second.
+    /// By default, it is `4.0`.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.properties.friction = friction.max(0.0);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] keeps what is in view in place when
+    /// the contents above it change, like when older messages are loaded at
+    /// the top of a chat.
+    ///
+    /// The children of the contents are told apart by their height, so a
+    /// change that could have happened either above or below the viewport is
+    /// assumed to be below it. By default, scroll anchoring is disabled.
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.properties.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Sets what happens when the empty part of a scrollbar track of the
+    /// [`Scrollable`] is clicked.
+    ///
+    /// By default, it pages toward the click.
+    pub fn track_click(mut self, track_click: TrackClick) -> Self {
+        self.properties.track_click = track_click;
         self
     }
 
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
-    /// The function takes the new relative offset of the [`Scrollable`]
-    /// (e.g. `0` means top, while `1` means bottom).
-    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'static) -> Self {
+    /// The function takes the new [`Viewport`] of the [`Scrollable`]. It is
+    /// only called when the scrolling offset actually changes, including
+    /// when it is changed programmatically.
+    pub fn on_scroll(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
         self.on_scroll = Some(Box::new(f));
         self
     }
@@ -148,19 +271,294 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     }
 }
 
+/// The properties of a [`Scrollable`] that define how it scrolls and where
+/// its scrollbars are.
+///
+/// Every field is set by the builder method of the [`Scrollable`] with the
+/// same name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Properties {
+    /// The width of the scrollbars.
+    pub scrollbar_width: u16,
+    /// The margin around the scrollbars.
+    pub scrollbar_margin: u16,
+    /// The width of the scrollers.
+    pub scroller_width: u16,
+    /// The edge where the vertical scrollbar is placed.
+    pub scrollbar_alignment: Alignment,
+    /// The [`Direction`] in which the [`Scrollable`] can scroll.
+    pub direction: Direction,
+    /// Whether what is in view is kept in place when the contents above it
+    /// change.
+    pub scroll_anchoring: bool,
+    /// What happens when the empty part of a scrollbar track is clicked.
+    pub track_click: TrackClick,
+    /// The time without interaction after which the scrollbars are hidden.
+    pub hide_scrollbar_after: Option<Duration>,
+    /// Whether the [`Scrollable`] stays anchored to the bottom of its
+    /// contents.
+    pub anchor_bottom: bool,
+    /// The amount of pixels scrolled when an arrow key is pressed.
+    pub line_step: u16,
+    /// The factor by which the wheel and touchpad deltas are multiplied.
+    pub scroll_factor: f32,
+    /// The height of a line of text, used to convert wheel ticks into
+    /// pixels.
+    pub scroll_line_height: u16,
+    /// Whether wheel events that cannot scroll any further are passed on.
+    pub scroll_chaining: bool,
+    /// The rate at which the speed of a flick decays, per second.
+    pub friction: f32,
+}
+
+impl Default for Properties {
+    fn default() -> Self {
+        Self {
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            scrollbar_alignment: Alignment::End,
+            direction: Direction::default(),
+            scroll_anchoring: false,
+            track_click: TrackClick::default(),
+            hide_scrollbar_after: None,
+            anchor_bottom: false,
+            line_step: DEFAULT_LINE_STEP,
+            scroll_factor: 1.0,
+            scroll_line_height: DEFAULT_SCROLL_LINE_HEIGHT,
+            scroll_chaining: true,
+            friction: DEFAULT_FRICTION,
+        }
+    }
+}
+
+/// The directions in which a [`Scrollable`] can scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Vertical scrolling only.
+    #[default]
+    Vertical,
+    /// Horizontal scrolling only.
+    Horizontal,
+    /// Both vertical and horizontal scrolling.
+    Both,
+}
+
+impl Direction {
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+}
+
+/// What happens when the empty part of a scrollbar track of a [`Scrollable`]
+/// is clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrackClick {
+    /// Scroll by a page toward the click, repeatedly while the button is
+    /// held.
+    #[default]
+    Page,
+    /// Jump the scroller to the click and start dragging it.
+    JumpTo,
+}
+
+/// The time a click on a scrollbar track is held before paging repeats.
+const PAGE_REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// The time between the pages of a click held on a scrollbar track.
+const PAGE_REPEAT_INTERVAL: Duration = Duration::from_millis(80);
+
+/// The time it takes for hidden scrollbars to fade out.
+const FADE_OUT_DURATION: Duration = Duration::from_millis(250);
+
+/// The time between the frames of the animations of a [`Scrollable`].
+const ANIMATION_FRAME: Duration = Duration::from_millis(16);
+
+/// The default amount of pixels scrolled when an arrow key is pressed over a
+/// [`Scrollable`].
+pub const DEFAULT_LINE_STEP: u16 = 60;
+
+/// The default height of a line of text, in pixels, used to convert the
+/// ticks of a mouse wheel over a [`Scrollable`] into pixels.
+pub const DEFAULT_SCROLL_LINE_HEIGHT: u16 = 20;
+
+/// The default friction that slows down a [`Scrollable`] after a flick.
+pub const DEFAULT_FRICTION: f32 = 4.0;
+
+/// The speed, in pixels per second, below which a flick stops.
+const MIN_FLICK_VELOCITY: f32 = 20.0;
+
+/// The longest time a finger can rest before being lifted for its movement
+/// to still count as a flick.
+const MAX_FLICK_PAUSE: Duration = Duration::from_millis(100);
+
+/// The amount of lines scrolled by a tick of a mouse wheel.
+const LINES_PER_TICK: f32 = 3.0;
+
+/// The identifier of a [`Scrollable`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// An absolute scrolling offset of a [`Scrollable`], in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AbsoluteOffset {
+    /// The amount of horizontal offset.
+    pub x: f32,
+    /// The amount of vertical offset.
+    pub y: f32,
+}
+
+/// The visible region of the contents of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    offset: AbsoluteOffset,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Returns the [`AbsoluteOffset`] of the [`Viewport`].
+    pub fn absolute_offset(&self) -> AbsoluteOffset {
+        self.offset
+    }
+
+    /// Returns the relative vertical offset of the [`Viewport`].
+    ///
+    /// `0` means top, while `1` means bottom.
+    pub fn relative_offset(&self) -> f32 {
+        let hidden_content = self.content_bounds.height - self.bounds.height;
+
+        if hidden_content > 0.0 {
+            self.offset.y / hidden_content
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the bounds of the [`Scrollable`].
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the bounds of the contents of the [`Scrollable`].
+    pub fn content_bounds(&self) -> Rectangle {
+        self.content_bounds
+    }
+}
+
+/// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
+/// to the provided relative vertical position.
+///
+/// `0` represents scrollbar at the top, while `1` represents scrollbar at
+/// the bottom. Nothing happens if no [`Scrollable`] has the given [`Id`].
+pub fn snap_to<Message: 'static>(id: Id, percentage: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to(id.0, percentage))
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to the provided [`AbsoluteOffset`].
+///
+/// The offset is clamped to the bounds of the contents. Nothing happens if
+/// no [`Scrollable`] has the given [`Id`].
+pub fn scroll_to<Message: 'static>(
+    id: Id,
+    offset: AbsoluteOffset,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to(id.0, offset))
+}
+
+/// Applies an [`Operation`] to the [`State`] of a [`Scrollable`] and its
+/// contents.
+pub fn operate<Message>(
+    state: &mut State,
+    id: Option<&Id>,
+    operation: &mut dyn Operation<Message>,
+    operate_on_content: &mut dyn FnMut(&mut dyn Operation<Message>),
+) {
+    let id = id.map(|id| &id.0);
+
+    operation.scrollable(state, id);
+    operation.container(id, operate_on_content);
+}
+
+/// Returns whether a widget inside the contents of a [`Scrollable`] is
+/// focused, given a function that operates on the contents.
+pub fn is_content_focused<Message>(
+    operate_on_content: impl FnOnce(&mut dyn Operation<Message>),
+) -> bool {
+    let mut count = operation::focusable::count(|count| count);
+
+    operate_on_content(&mut operation::MapRef::new(&mut count));
+
+    matches!(
+        count.finish(),
+        operation::Outcome::Some(operation::focusable::Count {
+            focused: Some(_),
+            ..
+        })
+    )
+}
+
 /// Computes the layout of a [`Scrollable`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
     limits: &layout::Limits,
     width: Length,
     height: Length,
+    direction: Direction,
     layout_content: impl FnOnce(&Renderer, &layout::Limits) -> layout::Node,
 ) -> layout::Node {
     let limits = limits.width(width).height(height);
 
     let child_limits = layout::Limits::new(
-        Size::new(limits.min().width, 0.0),
-        Size::new(limits.max().width, f32::INFINITY),
+        Size::new(
+            if direction.is_horizontal() {
+                0.0
+            } else {
+                limits.min().width
+            },
+            if direction.is_vertical() {
+                0.0
+            } else {
+                limits.min().height
+            },
+        ),
+        Size::new(
+            if direction.is_horizontal() {
+                f32::INFINITY
+            } else {
+                limits.max().width
+            },
+            if direction.is_vertical() {
+                f32::INFINITY
+            } else {
+                limits.max().height
+            },
+        ),
     );
 
     let content = layout_content(renderer, &child_limits);
@@ -178,10 +576,9 @@ pub fn update<Message>(
     cursor_position: Point,
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
-    on_scroll: &Option<Box<dyn Fn(f32) -> Message + '_>>,
+    properties: &Properties,
+    is_content_focused: bool,
+    on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
     update_content: impl FnOnce(
         Event,
         Layout<'_>,
@@ -190,30 +587,165 @@ pub fn update<Message>(
         &mut Shell<'_, Message>,
     ) -> event::Status,
 ) -> event::Status {
+    let Properties {
+        scrollbar_width,
+        scrollbar_margin,
+        scroller_width,
+        scrollbar_alignment,
+        direction,
+        scroll_anchoring,
+        track_click,
+        hide_scrollbar_after,
+        anchor_bottom,
+        line_step,
+        scroll_factor,
+        scroll_line_height,
+        scroll_chaining,
+        friction,
+    } = *properties;
+
     let bounds = layout.bounds();
     let is_mouse_over = bounds.contains(cursor_position);
 
     let content = layout.children().next().unwrap();
     let content_bounds = content.bounds();
 
-    let scrollbar = scrollbar(
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event
+    {
+        state.keyboard_modifiers = modifiers;
+    }
+
+    // A flick keeps going on every frame until it slows down or reaches
+    // the end of the contents
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if let Some(momentum) = state.momentum {
+            let elapsed = now.duration_since(momentum.updated_at).as_secs_f32();
+            let previous_offset = state.absolute_offset(bounds, content_bounds);
+
+            state.scroll(momentum.velocity * elapsed, bounds, content_bounds);
+
+            let velocity = momentum.velocity * (-friction * elapsed).exp();
+            let is_stopped = velocity.x.hypot(velocity.y) < MIN_FLICK_VELOCITY
+                || state.absolute_offset(bounds, content_bounds)
+                    == previous_offset;
+
+            state.momentum = if is_stopped {
+                None
+            } else {
+                Some(Momentum {
+                    velocity,
+                    updated_at: now,
+                })
+            };
+        }
+    }
+
+    if state.momentum.is_some() {
+        shell.request_redraw(Instant::now() + ANIMATION_FRAME);
+    }
+
+    if scroll_anchoring {
+        state.anchor(content);
+    }
+
+    // Settle any change to the offset made outside of event processing,
+    // like a programmatic scroll
+    finish_scroll(
+        state,
+        hide_scrollbar_after,
+        anchor_bottom,
+        on_scroll,
+        bounds,
+        content_bounds,
+        shell,
+    );
+
+    let scrollbars = Scrollbars::new(
         state,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
+        scrollbar_alignment,
+        direction,
         bounds,
         content_bounds,
     );
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+    if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+        state.last_interaction = Some(Instant::now());
+    }
+
+    schedule_fade(state, hide_scrollbar_after, shell);
+
+    // A click held on a scrollbar track keeps paging until the scroller
+    // reaches the cursor
+    if let Some(paging) = state.paging {
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.paging = None;
+            }
+            Event::Window(window::Event::RedrawRequested(now))
+                if now >= paging.next_at =>
+            {
+                let direction = scrollbars
+                    .y
+                    .iter()
+                    .chain(&scrollbars.x)
+                    .find(|scrollbar| {
+                        scrollbar.orientation == paging.orientation
+                    })
+                    .and_then(|scrollbar| {
+                        scrollbar.page_direction(cursor_position)
+                    });
+
+                if direction == Some(paging.direction) {
+                    state.page(
+                        paging.orientation,
+                        paging.direction,
+                        bounds,
+                        content_bounds,
+                    );
+
+                    finish_scroll(
+                        state,
+                        hide_scrollbar_after,
+                        anchor_bottom,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+                }
+
+                state.paging = Some(Paging {
+                    next_at: now + PAGE_REPEAT_INTERVAL,
+                    ..paging
+                });
+            }
+            _ => {}
+        }
+
+        if let Some(paging) = state.paging {
+            shell.request_redraw(paging.next_at);
+        }
+    }
 
-    let event_status = {
+    // Nothing inside can be under a wheel that is outside of the viewport
+    let is_wheel_outside = !is_mouse_over
+        && matches!(event, Event::Mouse(mouse::Event::WheelScrolled { .. }));
+
+    let event_status = if is_wheel_outside {
+        event::Status::Ignored
+    } else {
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            let offset = state.offset(bounds, content_bounds);
+
             Point::new(
-                cursor_position.x,
-                cursor_position.y + state.offset(bounds, content_bounds) as f32,
+                cursor_position.x + offset.x as f32,
+                cursor_position.y + offset.y as f32,
             )
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
@@ -236,21 +768,157 @@ pub fn update<Message>(
         return event::Status::Captured;
     }
 
+    // Discards the movement along any axis that cannot be scrolled
+    let constrain = |delta: Vector| {
+        Vector::new(
+            if direction.is_horizontal() {
+                delta.x
+            } else {
+                0.0
+            },
+            if direction.is_vertical() {
+                delta.y
+            } else {
+                0.0
+            },
+        )
+    };
+
     if is_mouse_over {
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                match delta {
-                    mouse::ScrollDelta::Lines { y, .. } => {
-                        // TODO: Configurable speed (?)
-                        state.scroll(y * 60.0, bounds, content_bounds);
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        Vector::new(x, y)
+                            * (LINES_PER_TICK * f32::from(scroll_line_height))
                     }
-                    mouse::ScrollDelta::Pixels { y, .. } => {
-                        state.scroll(y, bounds, content_bounds);
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                } * scroll_factor;
+
+                // A vertical wheel scrolls horizontally while shift is held
+                // or when the content can only be scrolled horizontally.
+                // Wheels and touchpads that report horizontal movement
+                // already are left alone.
+                let delta = if delta.x == 0.0
+                    && direction.is_horizontal()
+                    && (state.keyboard_modifiers.shift()
+                        || !direction.is_vertical())
+                {
+                    Vector::new(delta.y, 0.0)
+                } else {
+                    delta
+                };
+
+                // A free-spinning wheel scrolls at most a page per event
+                let delta = Vector::new(
+                    delta.x.max(-bounds.width).min(bounds.width),
+                    delta.y.max(-bounds.height).min(bounds.height),
+                );
+
+                let previous_offset =
+                    state.absolute_offset(bounds, content_bounds);
+
+                state.scroll(constrain(delta), bounds, content_bounds);
+
+                // A wheel event that scrolls nothing is left to any
+                // scrollable containing this one
+                if scroll_chaining
+                    && state.absolute_offset(bounds, content_bounds)
+                        == previous_offset
+                {
+                    return event::Status::Ignored;
+                }
+
+                finish_scroll(
+                    state,
+                    hide_scrollbar_after,
+                    anchor_bottom,
+                    on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if !is_content_focused => {
+                let line = f32::from(line_step);
+                let page = (bounds.height - line).max(line);
+                let is_vertical = scrollbars.y.is_some();
+                let is_horizontal = scrollbars.x.is_some();
+
+                match key_code {
+                    keyboard::KeyCode::Up if is_vertical => {
+                        state.scroll(
+                            Vector::new(0.0, line),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::Down if is_vertical => {
+                        state.scroll(
+                            Vector::new(0.0, -line),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::Left if is_horizontal => {
+                        state.scroll(
+                            Vector::new(line, 0.0),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::Right if is_horizontal => {
+                        state.scroll(
+                            Vector::new(-line, 0.0),
+                            bounds,
+                            content_bounds,
+                        );
                     }
+                    keyboard::KeyCode::PageUp if is_vertical => {
+                        state.scroll(
+                            Vector::new(0.0, page),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::PageDown if is_vertical => {
+                        state.scroll(
+                            Vector::new(0.0, -page),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::Home | keyboard::KeyCode::End
+                        if is_vertical || is_horizontal =>
+                    {
+                        let percentage = if key_code == keyboard::KeyCode::Home
+                        {
+                            0.0
+                        } else {
+                            1.0
+                        };
+
+                        if is_vertical {
+                            state.scroll_to(percentage, bounds, content_bounds);
+                        } else {
+                            state.scroll_x_to(
+                                percentage,
+                                bounds,
+                                content_bounds,
+                            );
+                        }
+                    }
+                    _ => return event::Status::Ignored,
                 }
 
-                notify_on_scroll(
+                finish_scroll(
                     state,
+                    hide_scrollbar_after,
+                    anchor_bottom,
                     on_scroll,
                     bounds,
                     content_bounds,
@@ -263,20 +931,28 @@ pub fn update<Message>(
                 match event {
                     touch::Event::FingerPressed { .. } => {
                         state.scroll_box_touched_at = Some(cursor_position);
+                        state.touch_moved_at = Some(Instant::now());
+                        state.touch_velocity = Vector::new(0.0, 0.0);
+                        state.momentum = None;
                     }
                     touch::Event::FingerMoved { .. } => {
                         if let Some(scroll_box_touched_at) =
                             state.scroll_box_touched_at
                         {
-                            let delta =
-                                cursor_position.y - scroll_box_touched_at.y;
+                            let delta = constrain(Vector::new(
+                                cursor_position.x - scroll_box_touched_at.x,
+                                cursor_position.y - scroll_box_touched_at.y,
+                            ));
 
                             state.scroll(delta, bounds, content_bounds);
 
                             state.scroll_box_touched_at = Some(cursor_position);
+                            state.track_touch(delta);
 
-                            notify_on_scroll(
+                            finish_scroll(
                                 state,
+                                hide_scrollbar_after,
+                                anchor_bottom,
                                 on_scroll,
                                 bounds,
                                 content_bounds,
@@ -284,9 +960,20 @@ pub fn update<Message>(
                             );
                         }
                     }
-                    touch::Event::FingerLifted { .. }
-                    | touch::Event::FingerLost { .. } => {
+                    touch::Event::FingerLifted { .. } => {
+                        state.scroll_box_touched_at = None;
+
+                        if let Some(momentum) = state.flick() {
+                            state.momentum = Some(momentum);
+
+                            shell.request_redraw(
+                                momentum.updated_at + ANIMATION_FRAME,
+                            );
+                        }
+                    }
+                    touch::Event::FingerLost { .. } => {
                         state.scroll_box_touched_at = None;
+                        state.touch_moved_at = None;
                     }
                 }
 
@@ -302,13 +989,14 @@ pub fn update<Message>(
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
                 state.scroller_grabbed_at = None;
+                state.x_scroller_grabbed_at = None;
 
                 return event::Status::Captured;
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                    (scrollbar, state.scroller_grabbed_at)
+                    (&scrollbars.y, state.scroller_grabbed_at)
                 {
                     state.scroll_to(
                         scrollbar.scroll_percentage(
@@ -319,8 +1007,10 @@ pub fn update<Message>(
                         content_bounds,
                     );
 
-                    notify_on_scroll(
+                    finish_scroll(
                         state,
+                        hide_scrollbar_after,
+                        anchor_bottom,
                         on_scroll,
                         bounds,
                         content_bounds,
@@ -329,18 +1019,83 @@ pub fn update<Message>(
 
                     return event::Status::Captured;
                 }
-            }
-            _ => {}
-        }
-    } else if is_mouse_over_scrollbar {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if let Some(scrollbar) = scrollbar {
-                    if let Some(scroller_grabbed_at) =
-                        scrollbar.grab_scroller(cursor_position)
-                    {
-                        state.scroll_to(
+
+                if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                    (&scrollbars.x, state.x_scroller_grabbed_at)
+                {
+                    state.scroll_x_to(
+                        scrollbar.scroll_percentage(
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    finish_scroll(
+                        state,
+                        hide_scrollbar_after,
+                        anchor_bottom,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+    } else if is_mouse_over_scrollbar {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                for scrollbar in scrollbars.y.iter().chain(&scrollbars.x) {
+                    let direction = match track_click {
+                        TrackClick::Page => {
+                            scrollbar.page_direction(cursor_position)
+                        }
+                        TrackClick::JumpTo => None,
+                    };
+
+                    if let Some(direction) = direction {
+                        let next_at = Instant::now() + PAGE_REPEAT_DELAY;
+
+                        state.page(
+                            scrollbar.orientation,
+                            direction,
+                            bounds,
+                            content_bounds,
+                        );
+
+                        state.paging = Some(Paging {
+                            orientation: scrollbar.orientation,
+                            direction,
+                            next_at,
+                        });
+
+                        shell.request_redraw(next_at);
+
+                        finish_scroll(
+                            state,
+                            hide_scrollbar_after,
+                            anchor_bottom,
+                            on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(scrollbar) = &scrollbars.y {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_scroller(cursor_position)
+                    {
+                        state.scroll_to(
                             scrollbar.scroll_percentage(
                                 scroller_grabbed_at,
                                 cursor_position,
@@ -351,8 +1106,39 @@ pub fn update<Message>(
 
                         state.scroller_grabbed_at = Some(scroller_grabbed_at);
 
-                        notify_on_scroll(
+                        finish_scroll(
+                            state,
+                            hide_scrollbar_after,
+                            anchor_bottom,
+                            on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(scrollbar) = &scrollbars.x {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_scroller(cursor_position)
+                    {
+                        state.scroll_x_to(
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        state.x_scroller_grabbed_at = Some(scroller_grabbed_at);
+
+                        finish_scroll(
                             state,
+                            hide_scrollbar_after,
+                            anchor_bottom,
                             on_scroll,
                             bounds,
                             content_bounds,
@@ -375,32 +1161,47 @@ pub fn mouse_interaction(
     state: &State,
     layout: Layout<'_>,
     cursor_position: Point,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
+    properties: &Properties,
     content_interaction: impl FnOnce(
         Layout<'_>,
         Point,
         &Rectangle,
     ) -> mouse::Interaction,
 ) -> mouse::Interaction {
+    let Properties {
+        scrollbar_width,
+        scrollbar_margin,
+        scroller_width,
+        scrollbar_alignment,
+        direction,
+        scroll_anchoring,
+        ..
+    } = *properties;
+
     let bounds = layout.bounds();
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
-    let scrollbar = scrollbar(
+
+    let anchored;
+    let state = if scroll_anchoring {
+        anchored = state.anchored(content_layout);
+        &anchored
+    } else {
+        state
+    };
+    let scrollbars = Scrollbars::new(
         state,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
+        scrollbar_alignment,
+        direction,
         bounds,
         content_bounds,
     );
 
     let is_mouse_over = bounds.contains(cursor_position);
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
 
     if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
         mouse::Interaction::Idle
@@ -408,7 +1209,10 @@ pub fn mouse_interaction(
         let offset = state.offset(bounds, content_bounds);
 
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            Point::new(
+                cursor_position.x + offset.x as f32,
+                cursor_position.y + offset.y as f32,
+            )
         } else {
             Point::new(cursor_position.x, -1.0)
         };
@@ -417,7 +1221,8 @@ pub fn mouse_interaction(
             content_layout,
             cursor_position,
             &Rectangle {
-                y: bounds.y + offset as f32,
+                x: bounds.x + offset.x as f32,
+                y: bounds.y + offset.y as f32,
                 ..bounds
             },
         )
@@ -430,75 +1235,98 @@ pub fn draw<Renderer>(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     cursor_position: Point,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
+    properties: &Properties,
     style_sheet: &dyn StyleSheet,
     draw_content: impl FnOnce(&mut Renderer, Layout<'_>, Point, &Rectangle),
 ) where
     Renderer: crate::Renderer,
 {
+    let Properties {
+        scrollbar_width,
+        scrollbar_margin,
+        scroller_width,
+        scrollbar_alignment,
+        direction,
+        scroll_anchoring,
+        hide_scrollbar_after,
+        ..
+    } = *properties;
+
     let bounds = layout.bounds();
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
+
+    let anchored;
+    let state = if scroll_anchoring {
+        anchored = state.anchored(content_layout);
+        &anchored
+    } else {
+        state
+    };
     let offset = state.offset(bounds, content_bounds);
-    let scrollbar = scrollbar(
+    let scrollbars = Scrollbars::new(
         state,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
+        scrollbar_alignment,
+        direction,
         bounds,
         content_bounds,
     );
 
     let is_mouse_over = bounds.contains(cursor_position);
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
 
-    let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-        Point::new(cursor_position.x, cursor_position.y + offset as f32)
+    let content_cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+        Point::new(
+            cursor_position.x + offset.x as f32,
+            cursor_position.y + offset.y as f32,
+        )
     } else {
         Point::new(cursor_position.x, -1.0)
     };
 
-    if let Some(scrollbar) = scrollbar {
+    let viewport = Rectangle {
+        x: bounds.x + offset.x as f32,
+        y: bounds.y + offset.y as f32,
+        ..bounds
+    };
+
+    if scrollbars.y.is_some() || scrollbars.x.is_some() {
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(
-                Vector::new(0.0, -(offset as f32)),
+                Vector::new(-(offset.x as f32), -(offset.y as f32)),
                 |renderer| {
                     draw_content(
                         renderer,
                         content_layout,
-                        cursor_position,
-                        &Rectangle {
-                            y: bounds.y + offset as f32,
-                            ..bounds
-                        },
+                        content_cursor_position,
+                        &viewport,
                     );
                 },
             );
         });
 
-        let style = if state.is_scroller_grabbed() {
-            style_sheet.dragging()
-        } else if is_mouse_over_scrollbar {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let alpha = state.scrollbar_alpha(hide_scrollbar_after);
 
-        let is_scrollbar_visible =
-            style.background.is_some() || style.border_width > 0.0;
+        let draw_scrollbar =
+            |renderer: &mut Renderer, scrollbar: &Scrollbar, is_grabbed| {
+                let style = if is_grabbed {
+                    style_sheet.dragging()
+                } else if scrollbar.scroller.bounds.contains(cursor_position) {
+                    style_sheet.hovered_scroller()
+                } else if scrollbar.is_mouse_over(cursor_position) {
+                    style_sheet.hovered()
+                } else {
+                    style_sheet.active()
+                };
+
+                let style = fade(style, alpha);
+
+                let is_scrollbar_visible =
+                    style.background.is_some() || style.border_width > 0.0;
 
-        renderer.with_layer(
-            Rectangle {
-                width: bounds.width + 2.0,
-                height: bounds.height + 2.0,
-                ..bounds
-            },
-            |renderer| {
                 if is_scrollbar_visible {
                     renderer.fill_quad(
                         renderer::Quad {
@@ -514,8 +1342,9 @@ pub fn draw<Renderer>(
                 }
 
                 if is_mouse_over
-                    || state.is_scroller_grabbed()
+                    || is_grabbed
                     || is_scrollbar_visible
+                    || hide_scrollbar_after.is_some()
                 {
                     renderer.fill_quad(
                         renderer::Quad {
@@ -527,90 +1356,104 @@ pub fn draw<Renderer>(
                         style.scroller.color,
                     );
                 }
-            },
-        );
+            };
+
+        if alpha > 0.0 {
+            renderer.with_layer(
+                Rectangle {
+                    width: bounds.width + 2.0,
+                    height: bounds.height + 2.0,
+                    ..bounds
+                },
+                |renderer| {
+                    if let Some(scrollbar) = &scrollbars.y {
+                        draw_scrollbar(
+                            renderer,
+                            scrollbar,
+                            state.scroller_grabbed_at.is_some(),
+                        );
+                    }
+
+                    if let Some(scrollbar) = &scrollbars.x {
+                        draw_scrollbar(
+                            renderer,
+                            scrollbar,
+                            state.x_scroller_grabbed_at.is_some(),
+                        );
+                    }
+                },
+            );
+        }
     } else {
         draw_content(
             renderer,
             content_layout,
-            cursor_position,
-            &Rectangle {
-                y: bounds.y + offset as f32,
-                ..bounds
-            },
+            content_cursor_position,
+            &viewport,
         );
     }
 }
 
-fn scrollbar(
+/// Requests the redraws needed to fade out the scrollbars of a [`State`],
+/// if they are hidden after some time.
+fn schedule_fade<Message>(
     state: &State,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
-    bounds: Rectangle,
-    content_bounds: Rectangle,
-) -> Option<Scrollbar> {
-    let offset = state.offset(bounds, content_bounds);
-
-    if content_bounds.height > bounds.height {
-        let outer_width =
-            scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
-
-        let outer_bounds = Rectangle {
-            x: bounds.x + bounds.width - outer_width as f32,
-            y: bounds.y,
-            width: outer_width as f32,
-            height: bounds.height,
-        };
-
-        let scrollbar_bounds = Rectangle {
-            x: bounds.x + bounds.width
-                - f32::from(outer_width / 2 + scrollbar_width / 2),
-            y: bounds.y,
-            width: scrollbar_width as f32,
-            height: bounds.height,
-        };
-
-        let ratio = bounds.height / content_bounds.height;
-        let scroller_height = bounds.height * ratio;
-        let y_offset = offset as f32 * ratio;
-
-        let scroller_bounds = Rectangle {
-            x: bounds.x + bounds.width
-                - f32::from(outer_width / 2 + scroller_width / 2),
-            y: scrollbar_bounds.y + y_offset,
-            width: scroller_width as f32,
-            height: scroller_height,
-        };
+    hide_scrollbar_after: Option<Duration>,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let (Some(hide_after), Some(last_interaction)) =
+        (hide_scrollbar_after, state.last_interaction)
+    {
+        let now = Instant::now();
+        let fade_start = last_interaction + hide_after;
 
-        Some(Scrollbar {
-            outer_bounds,
-            bounds: scrollbar_bounds,
-            scroller: Scroller {
-                bounds: scroller_bounds,
-            },
-        })
-    } else {
-        None
+        if now < fade_start {
+            shell.request_redraw(fade_start);
+        } else if now < fade_start + FADE_OUT_DURATION {
+            shell.request_redraw(now + ANIMATION_FRAME);
+        }
     }
 }
 
-fn notify_on_scroll<Message>(
-    state: &State,
-    on_scroll: &Option<Box<dyn Fn(f32) -> Message + '_>>,
+/// Pins the [`State`] to the bottom of the contents if needed and notifies
+/// any change of its offset, showing the scrollbars.
+fn finish_scroll<Message>(
+    state: &mut State,
+    hide_scrollbar_after: Option<Duration>,
+    anchor_bottom: bool,
+    on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
     bounds: Rectangle,
     content_bounds: Rectangle,
     shell: &mut Shell<'_, Message>,
 ) {
-    if content_bounds.height <= bounds.height {
+    // A relative offset at the bottom stays there when the contents grow
+    if anchor_bottom {
+        let max_offset = (content_bounds.height - bounds.height).max(0.0);
+
+        if state.offset.absolute(bounds.height, content_bounds.height)
+            >= max_offset
+        {
+            state.offset = Offset::Relative(1.0);
+        }
+    }
+
+    let offset = state.absolute_offset(bounds, content_bounds);
+
+    if offset == state.last_notified {
         return;
     }
 
+    state.last_notified = offset;
+    state.last_interaction = Some(Instant::now());
+
+    schedule_fade(state, hide_scrollbar_after, shell);
+
     if let Some(on_scroll) = on_scroll {
-        shell.publish(on_scroll(
-            state.offset.absolute(bounds, content_bounds)
-                / (content_bounds.height - bounds.height),
-        ));
+        shell.publish(on_scroll(Viewport {
+            offset,
+            bounds,
+            content_bounds,
+        }));
     }
 }
 
@@ -637,6 +1480,7 @@ where
             limits,
             Widget::<Message, Renderer>::width(self),
             self.height,
+            self.properties.direction,
             |renderer, limits| self.content.layout(renderer, limits),
         )
     }
@@ -650,6 +1494,14 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let is_content_focused = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+        ) && is_content_focused(|operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation)
+        });
+
         update(
             &mut self.state,
             event,
@@ -657,9 +1509,8 @@ where
             cursor_position,
             clipboard,
             shell,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
+            is_content_focused,
             &self.on_scroll,
             |event, layout, cursor_position, clipboard, shell| {
                 self.content.on_event(
@@ -685,9 +1536,7 @@ where
             &self.state,
             layout,
             cursor_position,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
             |layout, cursor_position, viewport| {
                 self.content.mouse_interaction(
                     layout,
@@ -712,9 +1561,7 @@ where
             renderer,
             layout,
             cursor_position,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
             self.style_sheet.as_ref(),
             |renderer, layout, cursor_position, viewport| {
                 self.content.draw(
@@ -743,47 +1590,144 @@ where
                 let content_bounds = content_layout.bounds();
                 let offset = state.offset(bounds, content_bounds);
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
+                overlay.translate(Vector::new(
+                    -(offset.x as f32),
+                    -(offset.y as f32),
+                ))
             })
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operate(self.state, self.id.as_ref(), operation, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
 }
 
 /// The local state of a [`Scrollable`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct State {
     scroller_grabbed_at: Option<f32>,
+    x_scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
     offset: Offset,
+    x_offset: Offset,
+    keyboard_modifiers: keyboard::Modifiers,
+    last_notified: AbsoluteOffset,
+    last_interaction: Option<Instant>,
+    touch_moved_at: Option<Instant>,
+    touch_velocity: Vector,
+    momentum: Option<Momentum>,
+    children_bounds: Vec<Rectangle>,
+    paging: Option<Paging>,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             scroller_grabbed_at: None,
+            x_scroller_grabbed_at: None,
             scroll_box_touched_at: None,
             offset: Offset::Absolute(0.0),
+            x_offset: Offset::Absolute(0.0),
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            last_notified: AbsoluteOffset::default(),
+            last_interaction: None,
+            touch_moved_at: None,
+            touch_velocity: Vector::new(0.0, 0.0),
+            momentum: None,
+            children_bounds: Vec::new(),
+            paging: None,
         }
     }
 }
 
-/// The local state of a [`Scrollable`].
+/// Returns how far the children at the given vertical offset moved between
+/// the previous and the current bounds of the children of some contents.
+///
+/// The children are matched by their height from both ends, and the offset
+/// only moves when all of the changed children are above it.
+fn anchor_shift(
+    previous: &[Rectangle],
+    current: &[Rectangle],
+    offset: f32,
+) -> f32 {
+    let is_same =
+        |(a, b): &(&Rectangle, &Rectangle)| (a.height - b.height).abs() < 0.5;
+
+    let prefix = previous.iter().zip(current).take_while(is_same).count();
+
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(is_same)
+        .count();
+
+    if suffix == 0 {
+        return 0.0;
+    }
+
+    let before = previous[previous.len() - suffix];
+    let after = current[current.len() - suffix];
+
+    if before.y <= offset {
+        after.y - before.y
+    } else {
+        0.0
+    }
+}
+
+/// A click held on the track of a [`Scrollbar`].
+#[derive(Debug, Clone, Copy)]
+struct Paging {
+    /// The [`Orientation`] of the [`Scrollbar`].
+    orientation: Orientation,
+
+    /// The direction of the paging, either `1.0` or `-1.0`.
+    direction: f32,
+
+    /// The moment when the next page is due.
+    next_at: Instant,
+}
+
+/// The motion of a [`Scrollable`] that keeps scrolling after a flick.
+#[derive(Debug, Clone, Copy)]
+struct Momentum {
+    /// The current velocity, in pixels per second.
+    velocity: Vector,
+
+    /// The moment when the velocity was last updated.
+    updated_at: Instant,
+}
+
+/// The scrolling offset of a [`Scrollable`] along a single axis.
 #[derive(Debug, Clone, Copy)]
 enum Offset {
     Absolute(f32),
     Relative(f32),
+    /// A position of the contents to be kept in the middle of the viewport.
+    Centered(f32),
 }
 
 impl Offset {
-    fn absolute(self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+    fn absolute(self, viewport: f32, content: f32) -> f32 {
         match self {
             Self::Absolute(absolute) => {
-                let hidden_content =
-                    (content_bounds.height - bounds.height).max(0.0);
+                let hidden_content = (content - viewport).max(0.0);
 
                 absolute.min(hidden_content)
             }
             Self::Relative(percentage) => {
-                ((content_bounds.height - bounds.height) * percentage).max(0.0)
+                ((content - viewport) * percentage).max(0.0)
+            }
+            Self::Centered(position) => {
+                (position - viewport / 2.0).min(content - viewport).max(0.0)
             }
         }
     }
@@ -799,22 +1743,30 @@ impl State {
     /// the [`Scrollable`] and its contents.
     pub fn scroll(
         &mut self,
-        delta_y: f32,
+        delta: Vector<f32>,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
+        if bounds.height < content_bounds.height {
+            self.offset = Offset::Absolute(
+                (self.offset.absolute(bounds.height, content_bounds.height)
+                    - delta.y)
+                    .max(0.0)
+                    .min(content_bounds.height - bounds.height),
+            );
         }
 
-        self.offset = Offset::Absolute(
-            (self.offset.absolute(bounds, content_bounds) - delta_y)
-                .max(0.0)
-                .min((content_bounds.height - bounds.height) as f32),
-        );
+        if bounds.width < content_bounds.width {
+            self.x_offset = Offset::Absolute(
+                (self.x_offset.absolute(bounds.width, content_bounds.width)
+                    - delta.x)
+                    .max(0.0)
+                    .min(content_bounds.width - bounds.width),
+            );
+        }
     }
 
-    /// Scrolls the [`Scrollable`] to a relative amount.
+    /// Scrolls the [`Scrollable`] vertically to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
@@ -828,30 +1780,204 @@ impl State {
         self.unsnap(bounds, content_bounds);
     }
 
-    /// Snaps the scroll position to a relative amount.
+    /// Scrolls the [`Scrollable`] horizontally to a relative amount.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar
+    /// at the right.
+    pub fn scroll_x_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.snap_x_to(percentage);
+        self.unsnap(bounds, content_bounds);
+    }
+
+    /// Snaps the vertical scroll position to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
     pub fn snap_to(&mut self, percentage: f32) {
-        self.offset = Offset::Relative(percentage.max(0.0).min(1.0));
+        self.offset = Offset::Relative(percentage.clamp(0.0, 1.0));
+    }
+
+    /// Scrolls vertically so that the given position of the contents ends up
+    /// in the middle of the [`Scrollable`], as far as the contents allow.
+    ///
+    /// The offset is resolved with the bounds the [`Scrollable`] is drawn
+    /// with, so it can be requested before it is ever laid out.
+    pub fn center_on(&mut self, position: f32) {
+        self.offset = Offset::Centered(position);
+    }
+
+    /// Snaps the horizontal scroll position to a relative amount.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar
+    /// at the right.
+    pub fn snap_x_to(&mut self, percentage: f32) {
+        self.x_offset = Offset::Relative(percentage.clamp(0.0, 1.0));
     }
 
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
-        self.offset =
-            Offset::Absolute(self.offset.absolute(bounds, content_bounds));
+        self.offset = Offset::Absolute(
+            self.offset.absolute(bounds.height, content_bounds.height),
+        );
+        self.x_offset = Offset::Absolute(
+            self.x_offset.absolute(bounds.width, content_bounds.width),
+        );
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
     /// of the [`Scrollable`] and its contents.
-    pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        self.offset.absolute(bounds, content_bounds) as u32
+    pub fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        Vector::new(
+            self.x_offset.absolute(bounds.width, content_bounds.width) as u32,
+            self.offset.absolute(bounds.height, content_bounds.height) as u32,
+        )
+    }
+
+    /// Tracks the velocity of a finger that scrolled the [`State`] by the
+    /// given delta.
+    fn track_touch(&mut self, delta: Vector) {
+        let now = Instant::now();
+
+        if let Some(moved_at) = self.touch_moved_at {
+            let elapsed = now.duration_since(moved_at).as_secs_f32();
+
+            if elapsed > 0.0 {
+                // Smooth out the jitter of the touch screen
+                self.touch_velocity =
+                    delta * (0.8 / elapsed) + self.touch_velocity * 0.2;
+            }
+        }
+
+        self.touch_moved_at = Some(now);
+    }
+
+    /// Returns the [`Momentum`] of the finger that was just lifted from the
+    /// [`State`], if it was flicked.
+    fn flick(&mut self) -> Option<Momentum> {
+        let now = Instant::now();
+        let moved_at = self.touch_moved_at.take()?;
+        let velocity = self.touch_velocity;
+
+        let is_flick = now.duration_since(moved_at) <= MAX_FLICK_PAUSE
+            && velocity.x.hypot(velocity.y) >= MIN_FLICK_VELOCITY;
+
+        if is_flick {
+            Some(Momentum {
+                velocity,
+                updated_at: now,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Keeps the children of the given contents that are in view in place,
+    /// if the contents above them changed since the last time.
+    fn anchor(&mut self, content: Layout<'_>) {
+        let content_bounds = content.bounds();
+
+        let children_bounds: Vec<_> = content
+            .children()
+            .map(|child| {
+                let bounds = child.bounds();
+
+                Rectangle {
+                    x: bounds.x - content_bounds.x,
+                    y: bounds.y - content_bounds.y,
+                    ..bounds
+                }
+            })
+            .collect();
+
+        // A relative offset already follows the contents
+        if let Offset::Absolute(offset) = self.offset {
+            let shift =
+                anchor_shift(&self.children_bounds, &children_bounds, offset);
+
+            self.offset = Offset::Absolute((offset + shift).max(0.0));
+        }
+
+        self.children_bounds = children_bounds;
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns a copy of the [`State`] anchored to the given contents.
+    fn anchored(&self, content: Layout<'_>) -> Self {
+        let mut state = self.clone();
+        state.anchor(content);
+        state
+    }
+
+    /// Scrolls the [`State`] by a page along the given [`Orientation`],
+    /// forward if `direction` is positive or backward if it is negative.
+    fn page(
+        &mut self,
+        orientation: Orientation,
+        direction: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let delta = match orientation {
+            Orientation::Vertical => {
+                Vector::new(0.0, -direction * bounds.height)
+            }
+            Orientation::Horizontal => {
+                Vector::new(-direction * bounds.width, 0.0)
+            }
+        };
+
+        self.scroll(delta, bounds, content_bounds);
+    }
+
+    /// Returns the exact scrolling offset of the [`State`], in pixels.
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> AbsoluteOffset {
+        AbsoluteOffset {
+            x: self.x_offset.absolute(bounds.width, content_bounds.width),
+            y: self.offset.absolute(bounds.height, content_bounds.height),
+        }
+    }
+
+    /// Returns whether any scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
+            || self.x_scroller_grabbed_at.is_some()
+    }
+
+    /// Returns the opacity of the scrollbars, given the [`Duration`] after
+    /// which they are hidden, if any.
+    fn scrollbar_alpha(&self, hide_scrollbar_after: Option<Duration>) -> f32 {
+        let hide_after = match hide_scrollbar_after {
+            Some(hide_after) if !self.is_scroller_grabbed() => hide_after,
+            _ => return 1.0,
+        };
+
+        match self.last_interaction {
+            Some(last_interaction) => {
+                let elapsed = Instant::now().duration_since(last_interaction);
+
+                if elapsed <= hide_after {
+                    1.0
+                } else {
+                    let fading = (elapsed - hide_after).as_secs_f32();
+
+                    (1.0 - fading / FADE_OUT_DURATION.as_secs_f32()).max(0.0)
+                }
+            }
+            None => 0.0,
+        }
     }
 
     /// Returns whether the scroll box is currently touched or not.
@@ -860,9 +1986,195 @@ impl State {
     }
 }
 
+impl operation::Scrollable for State {
+    fn snap_to(&mut self, percentage: f32) {
+        State::snap_to(self, percentage);
+    }
+
+    fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.x_offset = Offset::Absolute(offset.x.max(0.0));
+        self.offset = Offset::Absolute(offset.y.max(0.0));
+    }
+}
+
+/// Multiplies the opacity of every color of the given style by `alpha`.
+fn fade(style: style::Scrollbar, alpha: f32) -> style::Scrollbar {
+    let fade = |color: Color| Color {
+        a: color.a * alpha,
+        ..color
+    };
+
+    style::Scrollbar {
+        background: style.background.map(|background| match background {
+            Background::Color(color) => Background::Color(fade(color)),
+        }),
+        border_color: fade(style.border_color),
+        scroller: style::Scroller {
+            color: fade(style.scroller.color),
+            border_color: fade(style.scroller.border_color),
+            ..style.scroller
+        },
+        ..style
+    }
+}
+
+/// The scrollbars of a [`Scrollable`].
+#[derive(Debug)]
+struct Scrollbars {
+    /// The vertical [`Scrollbar`], if the content overflows vertically.
+    y: Option<Scrollbar>,
+
+    /// The horizontal [`Scrollbar`], if the content overflows horizontally.
+    x: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn new(
+        state: &State,
+        scrollbar_width: u16,
+        scrollbar_margin: u16,
+        scroller_width: u16,
+        scrollbar_alignment: Alignment,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Self {
+        let offset = state.offset(bounds, content_bounds);
+
+        let show_y =
+            direction.is_vertical() && content_bounds.height > bounds.height;
+        let show_x =
+            direction.is_horizontal() && content_bounds.width > bounds.width;
+
+        let outer_width =
+            scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
+        let scrollbar_inset = f32::from(outer_width / 2 + scrollbar_width / 2);
+        let scroller_inset = f32::from(outer_width / 2 + scroller_width / 2);
+        let outer_width = f32::from(outer_width);
+
+        let is_left_aligned = scrollbar_alignment == Alignment::Start;
+
+        // The x coordinate of the vertical scrollbar for the given inset,
+        // measured from the edge where it is placed
+        let y_edge = |inset: f32, width: f32| {
+            if is_left_aligned {
+                bounds.x + inset - width
+            } else {
+                bounds.x + bounds.width - inset
+            }
+        };
+
+        // When both scrollbars are shown, they leave the corner where they
+        // would meet empty, so their bounds never overlap
+        let y = if show_y {
+            let height = if show_x {
+                bounds.height - outer_width
+            } else {
+                bounds.height
+            };
+
+            let ratio = height / content_bounds.height;
+
+            Some(Scrollbar {
+                orientation: Orientation::Vertical,
+                outer_bounds: Rectangle {
+                    x: y_edge(outer_width, outer_width),
+                    y: bounds.y,
+                    width: outer_width,
+                    height,
+                },
+                bounds: Rectangle {
+                    x: y_edge(scrollbar_inset, f32::from(scrollbar_width)),
+                    y: bounds.y,
+                    width: f32::from(scrollbar_width),
+                    height,
+                },
+                scroller: Scroller {
+                    bounds: Rectangle {
+                        x: y_edge(scroller_inset, f32::from(scroller_width)),
+                        y: bounds.y + offset.y as f32 * ratio,
+                        width: f32::from(scroller_width),
+                        height: bounds.height * ratio,
+                    },
+                },
+            })
+        } else {
+            None
+        };
+
+        let x = if show_x {
+            let width = if show_y {
+                bounds.width - outer_width
+            } else {
+                bounds.width
+            };
+
+            let ratio = width / content_bounds.width;
+
+            // The horizontal scrollbar starts after the empty corner when
+            // the vertical one is placed on the left
+            let x = if show_y && is_left_aligned {
+                bounds.x + outer_width
+            } else {
+                bounds.x
+            };
+
+            Some(Scrollbar {
+                orientation: Orientation::Horizontal,
+                outer_bounds: Rectangle {
+                    x,
+                    y: bounds.y + bounds.height - outer_width,
+                    width,
+                    height: outer_width,
+                },
+                bounds: Rectangle {
+                    x,
+                    y: bounds.y + bounds.height - scrollbar_inset,
+                    width,
+                    height: f32::from(scrollbar_width),
+                },
+                scroller: Scroller {
+                    bounds: Rectangle {
+                        x: x + offset.x as f32 * ratio,
+                        y: bounds.y + bounds.height - scroller_inset,
+                        width: bounds.width * ratio,
+                        height: f32::from(scroller_width),
+                    },
+                },
+            })
+        } else {
+            None
+        };
+
+        Self { y, x }
+    }
+
+    fn is_mouse_over(&self, cursor_position: Point) -> bool {
+        self.y
+            .as_ref()
+            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+            .unwrap_or(false)
+            || self
+                .x
+                .as_ref()
+                .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+                .unwrap_or(false)
+    }
+}
+
+/// The orientation of a [`Scrollbar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Orientation {
+    Vertical,
+    Horizontal,
+}
+
 /// The scrollbar of a [`Scrollable`].
 #[derive(Debug)]
 struct Scrollbar {
+    /// The [`Orientation`] of the [`Scrollbar`].
+    orientation: Orientation,
+
     /// The outer bounds of the scrollable, including the [`Scrollbar`] and
     /// [`Scroller`].
     outer_bounds: Rectangle,
@@ -879,11 +2191,38 @@ impl Scrollbar {
         self.outer_bounds.contains(cursor_position)
     }
 
+    /// Returns the direction toward the given cursor position if it is over
+    /// the empty part of the track of the [`Scrollbar`].
+    fn page_direction(&self, cursor_position: Point) -> Option<f32> {
+        if !self.outer_bounds.contains(cursor_position)
+            || self.scroller.bounds.contains(cursor_position)
+        {
+            return None;
+        }
+
+        let is_before = match self.orientation {
+            Orientation::Vertical => cursor_position.y < self.scroller.bounds.y,
+            Orientation::Horizontal => {
+                cursor_position.x < self.scroller.bounds.x
+            }
+        };
+
+        Some(if is_before { -1.0 } else { 1.0 })
+    }
+
     fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
         if self.outer_bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                match self.orientation {
+                    Orientation::Vertical => {
+                        (cursor_position.y - self.scroller.bounds.y)
+                            / self.scroller.bounds.height
+                    }
+                    Orientation::Horizontal => {
+                        (cursor_position.x - self.scroller.bounds.x)
+                            / self.scroller.bounds.width
+                    }
+                }
             } else {
                 0.5
             })
@@ -897,10 +2236,20 @@ impl Scrollbar {
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        match self.orientation {
+            Orientation::Vertical => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - self.scroller.bounds.height * grabbed_at)
+                    / (self.bounds.height - self.scroller.bounds.height)
+            }
+            Orientation::Horizontal => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - self.scroller.bounds.width * grabbed_at)
+                    / (self.bounds.width - self.scroller.bounds.width)
+            }
+        }
     }
 }
 
@@ -923,3 +2272,640 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(heights: &[f32]) -> Vec<Rectangle> {
+        let mut y = 0.0;
+
+        heights
+            .iter()
+            .map(|&height| {
+                let bounds = Rectangle {
+                    x: 0.0,
+                    y,
+                    width: 100.0,
+                    height,
+                };
+
+                y += height;
+
+                bounds
+            })
+            .collect()
+    }
+
+    /// Processes an [`Event`] over a [`Scrollable`] of 100x100 pixels with
+    /// contents of the given size, returning its status and the redraw it
+    /// requests.
+    fn update(
+        state: &mut State,
+        properties: &Properties,
+        contents: Size,
+        event: Event,
+        cursor_position: Point,
+    ) -> (event::Status, Option<Instant>) {
+        let node = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![layout::Node::new(contents)],
+        );
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = super::update(
+            state,
+            event,
+            Layout::new(&node),
+            cursor_position,
+            &mut crate::clipboard::Null,
+            &mut shell,
+            properties,
+            false,
+            &None,
+            |_, _, _, _, _| event::Status::Ignored,
+        );
+
+        (status, shell.redraw_request())
+    }
+
+    fn wheel_down() -> Event {
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+        })
+    }
+
+    fn modifiers_changed(modifiers: keyboard::Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+    }
+
+    fn wheel_up() -> Event {
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+        })
+    }
+
+    fn redraw_requested(at: Instant) -> Event {
+        Event::Window(window::Event::RedrawRequested(at))
+    }
+
+    fn cursor_moved(position: Point) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved { position })
+    }
+
+    fn content_bounds(width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(width, height))
+    }
+
+    #[test]
+    fn shift_wheel_scrolls_horizontally() {
+        let mut state = State::new();
+        let properties = Properties {
+            direction: Direction::Both,
+            ..Properties::default()
+        };
+        let contents = Size::new(400.0, 400.0);
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let content_bounds = Rectangle::new(Point::ORIGIN, contents);
+
+        let _ = update(&mut state, &properties, contents, wheel_down(), cursor);
+
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds),
+            AbsoluteOffset { x: 0.0, y: 60.0 }
+        );
+
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            modifiers_changed(keyboard::Modifiers::SHIFT),
+            cursor,
+        );
+        let (status, _) =
+            update(&mut state, &properties, contents, wheel_down(), cursor);
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds),
+            AbsoluteOffset { x: 60.0, y: 60.0 }
+        );
+
+        // Touchpads that report horizontal movement are not translated
+        let swipe = Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: -10.0, y: -5.0 },
+        });
+        let _ = update(&mut state, &properties, contents, swipe, cursor);
+
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds),
+            AbsoluteOffset { x: 70.0, y: 65.0 }
+        );
+    }
+
+    #[test]
+    fn shift_wheel_scrolls_vertical_only_contents_vertically() {
+        let mut state = State::new();
+        let properties = Properties::default();
+        let contents = Size::new(100.0, 400.0);
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let content_bounds = Rectangle::new(Point::ORIGIN, contents);
+
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            modifiers_changed(keyboard::Modifiers::SHIFT),
+            cursor,
+        );
+        let _ = update(&mut state, &properties, contents, wheel_down(), cursor);
+
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds),
+            AbsoluteOffset { x: 0.0, y: 60.0 }
+        );
+    }
+
+    #[test]
+    fn horizontal_offset_is_clamped_to_the_contents() {
+        let mut state = State::new();
+        let properties = Properties {
+            direction: Direction::Horizontal,
+            ..Properties::default()
+        };
+        let contents = Size::new(400.0, 100.0);
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+
+        for _ in 0..5 {
+            let (status, _) =
+                update(&mut state, &properties, contents, wheel_down(), cursor);
+
+            assert_eq!(status, event::Status::Captured);
+        }
+
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds(400.0, 100.0)),
+            AbsoluteOffset { x: 300.0, y: 0.0 }
+        );
+
+        // A wheel that cannot scroll any further is left to the parent
+        let (status, _) =
+            update(&mut state, &properties, contents, wheel_down(), cursor);
+
+        assert_eq!(status, event::Status::Ignored);
+
+        // Narrower contents never leave blank space on the right
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds(250.0, 100.0)),
+            AbsoluteOffset { x: 150.0, y: 0.0 }
+        );
+        assert_eq!(
+            state.absolute_offset(bounds, content_bounds(80.0, 100.0)),
+            AbsoluteOffset { x: 0.0, y: 0.0 }
+        );
+    }
+
+    #[test]
+    fn scrollbars_of_both_axes_do_not_overlap() {
+        let state = State::new();
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+
+        for alignment in [Alignment::Start, Alignment::End] {
+            let scrollbars = Scrollbars::new(
+                &state,
+                10,
+                2,
+                10,
+                alignment,
+                Direction::Both,
+                bounds,
+                content_bounds(400.0, 400.0),
+            );
+
+            let y = scrollbars.y.expect("Vertical scrollbar");
+            let x = scrollbars.x.expect("Horizontal scrollbar");
+
+            assert!(!y.outer_bounds.intersects(&x.outer_bounds));
+        }
+    }
+
+    #[test]
+    fn anchor_bottom_is_released_and_restored() {
+        let mut state = State::new();
+        let properties = Properties {
+            anchor_bottom: true,
+            ..Properties::default()
+        };
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let offset = |state: &State, height| {
+            state
+                .absolute_offset(bounds, content_bounds(100.0, height))
+                .y
+        };
+
+        for _ in 0..5 {
+            let _ = update(
+                &mut state,
+                &properties,
+                Size::new(100.0, 400.0),
+                wheel_down(),
+                cursor,
+            );
+        }
+
+        // Growing contents keep the bottom in view
+        assert_eq!(offset(&state, 400.0), 300.0);
+        assert_eq!(offset(&state, 600.0), 500.0);
+
+        let _ = update(
+            &mut state,
+            &properties,
+            Size::new(100.0, 600.0),
+            wheel_up(),
+            cursor,
+        );
+
+        // Scrolling up releases the anchor
+        assert_eq!(offset(&state, 600.0), 440.0);
+        assert_eq!(offset(&state, 800.0), 440.0);
+
+        for _ in 0..5 {
+            let _ = update(
+                &mut state,
+                &properties,
+                Size::new(100.0, 800.0),
+                wheel_down(),
+                cursor,
+            );
+        }
+
+        // Scrolling back to the bottom restores it
+        assert_eq!(offset(&state, 800.0), 700.0);
+        assert_eq!(offset(&state, 900.0), 800.0);
+    }
+
+    #[test]
+    fn hidden_scrollbars_fade_out_after_the_interaction() {
+        let hide_after = Duration::from_secs(1);
+
+        let mut state = State::new();
+        let properties = Properties {
+            hide_scrollbar_after: Some(hide_after),
+            ..Properties::default()
+        };
+        let contents = Size::new(100.0, 400.0);
+        let cursor = Point::new(50.0, 50.0);
+
+        assert_eq!(state.scrollbar_alpha(Some(hide_after)), 0.0);
+
+        let before = Instant::now();
+        let (_, redraw) =
+            update(&mut state, &properties, contents, wheel_down(), cursor);
+        let after = Instant::now();
+
+        // The fade starts once the scrollbars are left alone long enough
+        let redraw = redraw.expect("Redraw when the fade starts");
+
+        assert!(redraw >= before + hide_after && redraw <= after + hide_after);
+        assert_eq!(state.scrollbar_alpha(Some(hide_after)), 1.0);
+
+        // Halfway through the fade, every frame is redrawn
+        state.last_interaction =
+            Some(Instant::now() - hide_after - FADE_OUT_DURATION / 2);
+
+        let alpha = state.scrollbar_alpha(Some(hide_after));
+
+        assert!(alpha > 0.4 && alpha <= 0.5);
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            cursor_moved(cursor),
+            cursor,
+        );
+
+        assert!(
+            redraw.expect("Redraw of the fade")
+                <= Instant::now() + ANIMATION_FRAME
+        );
+
+        // A grabbed scroller stays visible
+        state.scroller_grabbed_at = Some(0.5);
+
+        assert_eq!(state.scrollbar_alpha(Some(hide_after)), 1.0);
+
+        state.scroller_grabbed_at = None;
+
+        // Once faded out, nothing is redrawn anymore
+        state.last_interaction =
+            Some(Instant::now() - hide_after - FADE_OUT_DURATION * 2);
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            cursor_moved(cursor),
+            cursor,
+        );
+
+        assert_eq!(state.scrollbar_alpha(Some(hide_after)), 0.0);
+        assert_eq!(redraw, None);
+    }
+
+    #[test]
+    fn momentum_decays_until_it_stops() {
+        let mut state = State::new();
+        let properties = Properties::default();
+        let contents = Size::new(100.0, 10_000.0);
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let start = Instant::now();
+
+        state.momentum = Some(Momentum {
+            velocity: Vector::new(0.0, -1000.0),
+            updated_at: start,
+        });
+
+        let mut frames = 0;
+        let mut last_offset = 0.0;
+        let mut last_speed = f32::INFINITY;
+
+        while let Some(momentum) = state.momentum {
+            assert!(frames < 100, "Momentum never stops");
+
+            let speed = momentum.velocity.y.abs();
+
+            assert!(speed < last_speed);
+            last_speed = speed;
+
+            frames += 1;
+
+            let (_, redraw) = update(
+                &mut state,
+                &properties,
+                contents,
+                redraw_requested(start + Duration::from_millis(100) * frames),
+                cursor,
+            );
+
+            let offset =
+                state.absolute_offset(bounds, content_bounds(100.0, 10_000.0));
+
+            assert!(offset.y > last_offset);
+            last_offset = offset.y;
+
+            assert_eq!(redraw.is_some(), state.momentum.is_some());
+        }
+
+        assert!(
+            last_speed * (-DEFAULT_FRICTION * 0.1).exp() < MIN_FLICK_VELOCITY
+        );
+        assert!(last_offset < 1000.0 / DEFAULT_FRICTION * 1.25);
+    }
+
+    #[test]
+    fn momentum_stops_at_the_end_of_the_contents() {
+        let mut state = State::new();
+        let properties = Properties::default();
+        let contents = Size::new(100.0, 400.0);
+        let cursor = Point::new(50.0, 50.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let start = Instant::now();
+
+        state.offset = Offset::Absolute(250.0);
+        state.momentum = Some(Momentum {
+            velocity: Vector::new(0.0, -1000.0),
+            updated_at: start,
+        });
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(start + Duration::from_millis(100)),
+            cursor,
+        );
+
+        assert_eq!(
+            state
+                .absolute_offset(bounds, content_bounds(100.0, 400.0))
+                .y,
+            300.0
+        );
+        assert!(state.momentum.is_some());
+        assert!(redraw.is_some());
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(start + Duration::from_millis(200)),
+            cursor,
+        );
+
+        assert!(state.momentum.is_none());
+        assert_eq!(redraw, None);
+    }
+
+    #[test]
+    fn held_track_click_pages_until_the_scroller_reaches_the_cursor() {
+        let mut state = State::new();
+        let properties = Properties::default();
+        let contents = Size::new(100.0, 400.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let offset = |state: &State| {
+            state
+                .absolute_offset(bounds, content_bounds(100.0, 400.0))
+                .y
+        };
+
+        // Below the scroller, on the track of the vertical scrollbar
+        let cursor = Point::new(95.0, 80.0);
+
+        let before = Instant::now();
+        let (status, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            cursor,
+        );
+        let after = Instant::now();
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(offset(&state), 100.0);
+
+        let repeat_at = redraw.expect("Redraw when paging repeats");
+
+        assert!(
+            repeat_at >= before + PAGE_REPEAT_DELAY
+                && repeat_at <= after + PAGE_REPEAT_DELAY
+        );
+
+        // Nothing happens before the delay
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(repeat_at - PAGE_REPEAT_INTERVAL),
+            cursor,
+        );
+
+        assert_eq!(offset(&state), 100.0);
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(repeat_at),
+            cursor,
+        );
+
+        assert_eq!(offset(&state), 200.0);
+        assert_eq!(redraw, Some(repeat_at + PAGE_REPEAT_INTERVAL));
+
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(repeat_at + PAGE_REPEAT_INTERVAL),
+            cursor,
+        );
+
+        assert_eq!(offset(&state), 300.0);
+
+        // The scroller is under the cursor now
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            redraw_requested(repeat_at + PAGE_REPEAT_INTERVAL * 2),
+            cursor,
+        );
+
+        assert_eq!(offset(&state), 300.0);
+
+        let (_, redraw) = update(
+            &mut state,
+            &properties,
+            contents,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            cursor,
+        );
+
+        assert!(state.paging.is_none());
+        assert_eq!(redraw, None);
+    }
+
+    #[test]
+    fn track_click_jumps_to_the_cursor_and_grabs_the_scroller() {
+        let mut state = State::new();
+        let properties = Properties {
+            track_click: TrackClick::JumpTo,
+            ..Properties::default()
+        };
+        let contents = Size::new(100.0, 400.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let offset = |state: &State| {
+            state
+                .absolute_offset(bounds, content_bounds(100.0, 400.0))
+                .y
+        };
+
+        let (status, _) = update(
+            &mut state,
+            &properties,
+            contents,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(95.0, 80.0),
+        );
+
+        // The middle of the scroller is moved to the cursor
+        assert_eq!(status, event::Status::Captured);
+        assert!((offset(&state) - 270.0).abs() < 0.01);
+        assert!(state.is_scroller_grabbed());
+        assert!(state.paging.is_none());
+
+        let _ = update(
+            &mut state,
+            &properties,
+            contents,
+            cursor_moved(Point::new(95.0, 50.0)),
+            Point::new(95.0, 50.0),
+        );
+
+        assert!((offset(&state) - 150.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn centering_is_resolved_with_the_bounds() {
+        let mut state = State::new();
+        state.center_on(150.0);
+
+        let content = Rectangle::new(Point::ORIGIN, Size::new(100.0, 400.0));
+
+        let offset = |state: &State, height| {
+            state
+                .offset(
+                    Rectangle::new(Point::ORIGIN, Size::new(100.0, height)),
+                    content,
+                )
+                .y
+        };
+
+        assert_eq!(offset(&state, 100.0), 100);
+        assert_eq!(offset(&state, 200.0), 50);
+        assert_eq!(offset(&state, 350.0), 0);
+        assert_eq!(offset(&state, 400.0), 0);
+
+        state.center_on(390.0);
+
+        assert_eq!(offset(&state, 100.0), 300);
+    }
+
+    #[test]
+    fn anchor_shift_follows_prepended_children() {
+        let previous = rows(&[10.0, 20.0, 30.0, 40.0]);
+        let current = rows(&[5.0, 15.0, 10.0, 20.0, 30.0, 40.0]);
+
+        assert_eq!(anchor_shift(&previous, &current, 30.0), 20.0);
+    }
+
+    #[test]
+    fn anchor_shift_ignores_appended_children() {
+        let previous = rows(&[10.0, 20.0, 30.0, 40.0]);
+        let current = rows(&[10.0, 20.0, 30.0, 40.0, 50.0]);
+
+        assert_eq!(anchor_shift(&previous, &current, 30.0), 0.0);
+    }
+
+    #[test]
+    fn anchor_shift_follows_children_removed_above() {
+        let previous = rows(&[10.0, 20.0, 30.0, 40.0]);
+        let current = rows(&[10.0, 30.0, 40.0]);
+
+        assert_eq!(anchor_shift(&previous, &current, 30.0), -20.0);
+    }
+
+    #[test]
+    fn anchor_shift_ignores_children_removed_below() {
+        let previous = rows(&[10.0, 20.0, 30.0, 40.0]);
+        let current = rows(&[10.0, 20.0, 40.0]);
+
+        assert_eq!(anchor_shift(&previous, &current, 0.0), 0.0);
+    }
+
+    #[test]
+    fn anchor_shift_ignores_changes_in_view() {
+        let previous = rows(&[10.0, 20.0, 30.0, 40.0]);
+        let current = rows(&[10.0, 25.0, 30.0, 40.0]);
+
+        assert_eq!(anchor_shift(&previous, &current, 15.0), 0.0);
+    }
+}
@@ -0,0 +1,606 @@
+//! Display text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::text as paragraph;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use iced_style::selectable_text::{Style, StyleSheet};
+
+/// A paragraph of text that can be selected with the mouse and copied to the
+/// [`Clipboard`].
+///
+/// Dragging selects text across wrapped lines, double clicking selects a
+/// word, triple clicking selects everything, and Ctrl+C (Cmd+C on macOS)
+/// copies the selection.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::selectable_text;
+/// #
+/// # type SelectableText<'a> =
+/// #     iced_native::widget::SelectableText<'a, iced_native::renderer::Null>;
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Error: file not found").size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer: text::Renderer> {
+    state: &'a mut State,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Renderer: text::Renderer> SelectableText<'a, Renderer> {
+    /// Creates a new [`SelectableText`] with the given [`State`] and
+    /// contents.
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> Properties<'_, Renderer::Font> {
+        Properties {
+            content: &self.content,
+            size: self.size,
+            font: &self.font,
+            color: self.color,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// The local state of a [`SelectableText`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct State {
+    anchor: usize,
+    focus: usize,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`State`], with nothing selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the selected range of characters, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        if self.anchor == self.focus {
+            None
+        } else {
+            Some((self.anchor.min(self.focus), self.anchor.max(self.focus)))
+        }
+    }
+
+    /// Selects the given range of characters.
+    pub fn select(&mut self, start: usize, end: usize) {
+        self.anchor = start;
+        self.focus = end;
+    }
+
+    /// Clears the selection.
+    pub fn clear(&mut self) {
+        self.select(0, 0);
+    }
+}
+
+/// Returns the text of the given contents that is selected in the [`State`]
+/// of a [`SelectableText`], if any.
+pub fn selected_text(state: &State, content: &str) -> Option<String> {
+    state.selection().map(|(start, end)| {
+        content.chars().skip(start).take(end - start).collect()
+    })
+}
+
+/// The properties of a [`SelectableText`] that define its contents and how
+/// they are laid out and drawn.
+///
+/// Most fields are set by the builder method of the [`SelectableText`] with
+/// the same name.
+#[derive(Debug)]
+pub struct Properties<'a, Font> {
+    /// The contents of the [`SelectableText`].
+    pub content: &'a str,
+    /// The text size, or the default size of the renderer if `None`.
+    pub size: Option<u16>,
+    /// The font of the text.
+    pub font: &'a Font,
+    /// The [`Color`] of the text, or the one of the [`StyleSheet`] if `None`.
+    pub color: Option<Color>,
+    /// The width of the [`SelectableText`] boundaries.
+    pub width: Length,
+    /// The height of the [`SelectableText`] boundaries.
+    pub height: Length,
+}
+
+/// Computes the layout of a [`SelectableText`].
+pub fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    properties: Properties<'_, Renderer::Font>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        width,
+        height,
+        ..
+    } = properties;
+
+    let limits = limits.width(width).height(height);
+
+    let size = size.unwrap_or(renderer.default_size());
+
+    let (width, height) = renderer.measure(
+        content,
+        size,
+        font.clone(),
+        limits.max(),
+        text::Spacing::default(),
+    );
+
+    layout::Node::new(limits.resolve(Size::new(width, height)))
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`SelectableText`]
+/// accordingly.
+pub fn update<'a, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    properties: Properties<'_, Renderer::Font>,
+    state: impl FnOnce() -> &'a mut State,
+) -> event::Status
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        ..
+    } = properties;
+    let bounds = layout.bounds();
+
+    let hit_test = |position: Point| {
+        let glyphs = renderer.glyphs(
+            content,
+            f32::from(size.unwrap_or(renderer.default_size())),
+            font.clone(),
+            bounds.size(),
+            text::Spacing::default(),
+        );
+
+        hit(
+            &glyphs,
+            Point::new(position.x - bounds.x, position.y - bounds.y),
+        )
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let state = state();
+
+            if !bounds.contains(cursor_position) {
+                state.clear();
+                state.is_dragging = false;
+                state.last_click = None;
+
+                return event::Status::Ignored;
+            }
+
+            let index = hit_test(cursor_position);
+            let click = mouse::Click::new(cursor_position, state.last_click);
+
+            match click.kind() {
+                mouse::click::Kind::Single => {
+                    state.select(index, index);
+                    state.is_dragging = true;
+                }
+                mouse::click::Kind::Double => {
+                    let (start, end) = word_at(content, index);
+
+                    state.select(start, end);
+                    state.is_dragging = false;
+                }
+                mouse::click::Kind::Triple => {
+                    state.select(0, content.chars().count());
+                    state.is_dragging = false;
+                }
+            }
+
+            state.last_click = Some(click);
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state().is_dragging = false;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            let state = state();
+
+            if state.is_dragging {
+                state.focus = hit_test(position);
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::C,
+            modifiers,
+        }) if modifiers.command() => {
+            if let Some(selection) = selected_text(state(), content) {
+                clipboard.write(selection);
+
+                return event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws a [`SelectableText`].
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    state: &State,
+    properties: Properties<'_, Renderer::Font>,
+    style_sheet: &dyn StyleSheet,
+) where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        content,
+        size,
+        font,
+        color,
+        ..
+    } = properties;
+    let bounds = layout.bounds();
+    let appearance = style_sheet.active();
+
+    if let Some((start, end)) = state.selection() {
+        let glyphs = renderer.glyphs(
+            content,
+            f32::from(size.unwrap_or(renderer.default_size())),
+            font.clone(),
+            bounds.size(),
+            text::Spacing::default(),
+        );
+
+        // Merge the selected glyphs of every line into a single highlight
+        let mut lines: Vec<Rectangle> = Vec::new();
+
+        for glyph in glyphs
+            .iter()
+            .filter(|glyph| start <= glyph.index && glyph.index < end)
+        {
+            match lines.last_mut() {
+                Some(line) if is_same_line(line, &glyph.bounds) => {
+                    line.width = glyph.bounds.x + glyph.bounds.width - line.x;
+                }
+                _ => lines.push(glyph.bounds),
+            }
+        }
+
+        for line in lines {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + line.x,
+                        y: bounds.y + line.y,
+                        ..line
+                    },
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.selection_color,
+            );
+        }
+    }
+
+    paragraph::draw(
+        renderer,
+        style,
+        layout,
+        content,
+        font.clone(),
+        size,
+        color.or(appearance.text_color),
+        alignment::Horizontal::Left,
+        alignment::Vertical::Top,
+        text::Spacing::default(),
+        text::Rotation::None,
+    );
+}
+
+/// Computes the current [`mouse::Interaction`] of a [`SelectableText`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> mouse::Interaction {
+    if layout.bounds().contains(cursor_position) {
+        mouse::Interaction::Text
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+/// Returns the index of the character boundary closest to the given point,
+/// relative to the origin of the laid out glyphs.
+///
+/// The closest line is found first, so dragging past the end of a line
+/// selects up to its end instead of jumping to another line.
+fn hit(glyphs: &[text::Glyph], point: Point) -> usize {
+    let vertical_distance = |bounds: &Rectangle| {
+        if point.y < bounds.y {
+            bounds.y - point.y
+        } else {
+            (point.y - (bounds.y + bounds.height)).max(0.0)
+        }
+    };
+
+    let line = match glyphs.iter().min_by(|a, b| {
+        vertical_distance(&a.bounds)
+            .partial_cmp(&vertical_distance(&b.bounds))
+            .unwrap_or(std::cmp::Ordering::Greater)
+    }) {
+        Some(glyph) => glyph.bounds,
+        None => return 0,
+    };
+
+    let mut last = 0;
+
+    for glyph in glyphs
+        .iter()
+        .filter(|glyph| is_same_line(&line, &glyph.bounds))
+    {
+        if point.x < glyph.bounds.center_x() {
+            return glyph.index;
+        }
+
+        last = glyph.index + 1;
+    }
+
+    last
+}
+
+/// Returns the range of characters of the word found at the given character
+/// index.
+fn word_at(content: &str, index: usize) -> (usize, usize) {
+    let total = content.chars().count();
+    let index = index.min(total.saturating_sub(1));
+
+    let mut start = 0;
+
+    for word in content.split_word_bounds() {
+        let end = start + word.chars().count();
+
+        if index < end {
+            return (start, end);
+        }
+
+        start = end;
+    }
+
+    (total, total)
+}
+
+fn is_same_line(a: &Rectangle, b: &Rectangle) -> bool {
+    (a.y - b.y).abs() < 0.5
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout(renderer, limits, self.properties())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            Properties {
+                content: &self.content,
+                size: self.size,
+                font: &self.font,
+                color: self.color,
+                width: self.width,
+                height: self.height,
+            },
+            || &mut *self.state,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            style,
+            layout,
+            self.state,
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph(index: usize, x: f32, y: f32) -> text::Glyph {
+        text::Glyph {
+            index,
+            bounds: Rectangle {
+                x,
+                y,
+                width: 10.0,
+                height: 20.0,
+            },
+        }
+    }
+
+    #[test]
+    fn hit_stays_in_the_closest_line() {
+        // "ab cd", wrapped after the space
+        let glyphs = [
+            glyph(0, 0.0, 0.0),
+            glyph(1, 10.0, 0.0),
+            glyph(2, 20.0, 0.0),
+            glyph(3, 0.0, 20.0),
+            glyph(4, 10.0, 20.0),
+        ];
+
+        assert_eq!(hit(&glyphs, Point::new(12.0, 5.0)), 1);
+        assert_eq!(hit(&glyphs, Point::new(100.0, 5.0)), 3);
+        assert_eq!(hit(&glyphs, Point::new(100.0, 25.0)), 5);
+        assert_eq!(hit(&glyphs, Point::new(-5.0, 100.0)), 3);
+        assert_eq!(hit(&[], Point::new(10.0, 10.0)), 0);
+    }
+
+    #[test]
+    fn word_at_selects_the_surrounding_word() {
+        assert_eq!(word_at("hello big world", 0), (0, 5));
+        assert_eq!(word_at("hello big world", 7), (6, 9));
+        assert_eq!(word_at("hello big world", 15), (10, 15));
+        assert_eq!(word_at("", 0), (0, 0));
+    }
+}
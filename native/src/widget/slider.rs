@@ -2,10 +2,13 @@
 //!
 //! A [`Slider`] has some local [`State`].
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Size, Widget,
@@ -15,6 +18,14 @@ use std::ops::RangeInclusive;
 
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 
+/// The idle time after a keyboard or mouse wheel change before the release
+/// message of a [`Slider`] is produced.
+const RELEASE_DELAY: Duration = Duration::from_millis(500);
+
+/// The maximum distance, in logical pixels, between two presses of a
+/// [`Slider`] for them to make a double click.
+const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
 ///
@@ -44,6 +55,8 @@ pub struct Slider<'a, T, Message> {
     state: &'a mut State,
     range: RangeInclusive<T>,
     step: T,
+    shift_step: Option<T>,
+    default_value: Option<T>,
     value: T,
     on_change: Box<dyn Fn(T) -> Message>,
     on_release: Option<Message>,
@@ -95,6 +108,8 @@ where
             value,
             range,
             step: T::from(1),
+            shift_step: None,
+            default_value: None,
             on_change: Box::new(on_change),
             on_release: None,
             width: Length::Fill,
@@ -136,10 +151,27 @@ where
     }
 
     /// Sets the step size of the [`Slider`].
+    ///
+    /// Values snap to multiples of the step counted from the start of the
+    /// range. If the range is not an exact multiple of the step, the last
+    /// step is shorter.
     pub fn step(mut self, step: T) -> Self {
         self.step = step;
         self
     }
+
+    /// Sets the step size of the [`Slider`] used while Shift is held, which
+    /// allows finer adjustments.
+    pub fn shift_step(mut self, shift_step: T) -> Self {
+        self.shift_step = Some(shift_step);
+        self
+    }
+
+    /// Sets the value the [`Slider`] is reset to when it is double-clicked.
+    pub fn default_value(mut self, default_value: T) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`Slider`]
@@ -153,6 +185,8 @@ pub fn update<Message, T>(
     value: &mut T,
     range: &RangeInclusive<T>,
     step: T,
+    shift_step: Option<T>,
+    default_value: Option<T>,
     on_change: &dyn Fn(T) -> Message,
     on_release: &Option<Message>,
 ) -> event::Status
@@ -161,46 +195,115 @@ where
     Message: Clone,
 {
     let is_dragging = state.is_dragging;
+    let step = |state: &State| match shift_step {
+        Some(shift_step) if state.keyboard_modifiers.shift() => shift_step,
+        _ => step,
+    };
+
+    let mut publish = |state: &mut State, value: &mut T, new_value: T| {
+        // Compare against the last published value, so moving within a
+        // step does not publish it again even if the application has not
+        // fed it back yet
+        let current = state.published.unwrap_or_else(|| (*value).into());
+
+        if (current - new_value.into()).abs() > f64::EPSILON {
+            shell.publish((on_change)(new_value));
 
-    let mut change = || {
+            *value = new_value;
+            state.published = Some(new_value.into());
+        }
+    };
+
+    let change = |state: &mut State| {
         let bounds = layout.bounds();
-        let new_value = if cursor_position.x <= bounds.x {
-            *range.start()
+
+        if cursor_position.x <= bounds.x {
+            Some(*range.start())
         } else if cursor_position.x >= bounds.x + bounds.width {
-            *range.end()
+            Some(*range.end())
         } else {
-            let step = step.into();
-            let start = (*range.start()).into();
-            let end = (*range.end()).into();
-
             let percent = f64::from(cursor_position.x - bounds.x)
                 / f64::from(bounds.width);
 
-            let steps = (percent * (end - start) / step).round();
-            let value = steps * step + start;
-
-            if let Some(value) = T::from_f64(value) {
-                value
-            } else {
-                return;
-            }
-        };
+            T::from_f64(snap(
+                percent,
+                (*range.start()).into(),
+                (*range.end()).into(),
+                step(state).into(),
+            ))
+        }
+    };
 
-        if ((*value).into() - new_value.into()).abs() > f64::EPSILON {
-            shell.publish((on_change)(new_value));
+    let nudge = |state: &mut State, value: T, increment: bool| {
+        let start = (*range.start()).into();
+        let end = (*range.end()).into();
+        let step = step(state).into();
 
-            *value = new_value;
+        if step <= 0.0 {
+            return None;
         }
+
+        // Leave the current step before moving to the next one, so values
+        // in between steps and the partial last step are not skipped
+        let steps = (value.into() - start) / step;
+        let steps = if increment {
+            (steps + 1e-6).floor() + 1.0
+        } else {
+            (steps - 1e-6).ceil() - 1.0
+        };
+
+        T::from_f64((start + steps * step).max(start).min(end))
     };
 
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             if layout.bounds().contains(cursor_position) {
-                change();
-                state.is_dragging = true;
+                // The handle is narrow, so presses that land a few pixels
+                // apart still make a double click
+                let position = match state.last_click {
+                    Some(last_click)
+                        if last_click.position().distance(cursor_position)
+                            <= DOUBLE_CLICK_DISTANCE =>
+                    {
+                        last_click.position()
+                    }
+                    _ => cursor_position,
+                };
+
+                let click = mouse::Click::new(position, state.last_click);
+
+                state.is_focused = true;
+                state.release_at = None;
+
+                match default_value {
+                    Some(default_value)
+                        if click.kind() == mouse::click::Kind::Double =>
+                    {
+                        // The first click started a drag, which is over now
+                        publish(state, value, default_value);
+
+                        if let Some(on_release) = on_release.clone() {
+                            shell.publish(on_release);
+                        }
+
+                        state.is_dragging = false;
+                        state.published = None;
+                        state.last_click = None;
+                    }
+                    _ => {
+                        if let Some(new_value) = change(state) {
+                            publish(state, value, new_value);
+                        }
+
+                        state.is_dragging = true;
+                        state.last_click = Some(click);
+                    }
+                }
 
                 return event::Status::Captured;
+            } else {
+                state.is_focused = false;
             }
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -211,6 +314,7 @@ where
                     shell.publish(on_release);
                 }
                 state.is_dragging = false;
+                state.published = None;
 
                 return event::Status::Captured;
             }
@@ -218,17 +322,103 @@ where
         Event::Mouse(mouse::Event::CursorMoved { .. })
         | Event::Touch(touch::Event::FingerMoved { .. }) => {
             if is_dragging {
-                change();
+                if let Some(new_value) = change(state) {
+                    publish(state, value, new_value);
+                }
 
                 return event::Status::Captured;
             }
         }
+        Event::Mouse(mouse::Event::WheelScrolled { delta })
+            if !is_dragging && layout.bounds().contains(cursor_position) =>
+        {
+            let y = match delta {
+                mouse::ScrollDelta::Lines { y, .. }
+                | mouse::ScrollDelta::Pixels { y, .. } => y,
+            };
+
+            if y != 0.0 {
+                let current = state.published.map_or(Some(*value), T::from_f64);
+
+                if let Some(new_value) =
+                    current.and_then(|current| nudge(state, current, y > 0.0))
+                {
+                    publish(state, value, new_value);
+                    state.release_at = Some(Instant::now() + RELEASE_DELAY);
+                    shell.request_redraw(Instant::now() + RELEASE_DELAY);
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused && !is_dragging =>
+        {
+            let increment = match key_code {
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => Some(true),
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    Some(false)
+                }
+                _ => None,
+            };
+
+            if let Some(increment) = increment {
+                let current = state.published.map_or(Some(*value), T::from_f64);
+
+                if let Some(new_value) =
+                    current.and_then(|current| nudge(state, current, increment))
+                {
+                    publish(state, value, new_value);
+                    state.release_at = Some(Instant::now() + RELEASE_DELAY);
+                    shell.request_redraw(Instant::now() + RELEASE_DELAY);
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.keyboard_modifiers = modifiers;
+        }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            if let Some(release_at) = state.release_at {
+                if now >= release_at {
+                    if let Some(on_release) = on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    state.release_at = None;
+                    state.published = None;
+                } else {
+                    shell.request_redraw(release_at);
+                }
+            }
+        }
         _ => {}
     }
 
     event::Status::Ignored
 }
 
+/// Snaps the value at the given percent of the range to the closest multiple
+/// of the step counted from the start, or to the end of the range if it is
+/// closer than the last full step.
+pub(crate) fn snap(percent: f64, start: f64, end: f64, step: f64) -> f64 {
+    let length = end - start;
+    let offset = percent * length;
+
+    if step <= 0.0 {
+        return start + offset;
+    }
+
+    let last = (length / step).floor() * step;
+
+    if offset > last && length - offset < offset - last {
+        end
+    } else {
+        start + ((offset / step).round() * step).min(length)
+    }
+}
+
 /// Draws a [`Slider`].
 pub fn draw<T>(
     renderer: &mut impl crate::Renderer,
@@ -344,9 +534,14 @@ pub fn mouse_interaction(
 }
 
 /// The local state of a [`Slider`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
+    published: Option<f64>,
+    release_at: Option<Instant>,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
 }
 
 impl State {
@@ -402,6 +597,8 @@ where
             &mut self.value,
             &self.range,
             self.step,
+            self.shift_step,
+            self.default_value,
             self.on_change.as_ref(),
             &self.on_release,
         )
@@ -448,3 +645,195 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(
+        state: &mut State,
+        value: &mut f64,
+        event: Event,
+        x: f32,
+    ) -> Vec<Option<f64>> {
+        update_with_default(state, value, event, x, None)
+    }
+
+    fn update_with_default(
+        state: &mut State,
+        value: &mut f64,
+        event: Event,
+        x: f32,
+        default_value: Option<f64>,
+    ) -> Vec<Option<f64>> {
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = super::update(
+            event,
+            Layout::new(&node),
+            Point::new(x, 10.0),
+            &mut shell,
+            state,
+            value,
+            &(0.0..=10.0),
+            3.0,
+            Some(0.5),
+            default_value,
+            &Some,
+            &Some(None),
+        );
+
+        messages
+    }
+
+    fn drag(state: &mut State, value: &mut f64, x: f32) -> Vec<Option<f64>> {
+        let event = if state.is_dragging {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(x, 10.0),
+            })
+        } else {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        };
+
+        update(state, value, event, x)
+    }
+
+    fn press(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn dragging_snaps_to_steps_from_the_start() {
+        let mut state = State::new();
+        let mut value = 0.0;
+
+        assert_eq!(drag(&mut state, &mut value, 40.0), vec![Some(3.0)]);
+        assert!(drag(&mut state, &mut value, 35.0).is_empty());
+        assert_eq!(drag(&mut state, &mut value, 94.0), vec![Some(9.0)]);
+        assert_eq!(drag(&mut state, &mut value, 96.0), vec![Some(10.0)]);
+
+        state.keyboard_modifiers = keyboard::Modifiers::SHIFT;
+
+        assert_eq!(drag(&mut state, &mut value, 41.0), vec![Some(4.0)]);
+        assert!(drag(&mut state, &mut value, 42.0).is_empty());
+    }
+
+    #[test]
+    fn moving_within_a_step_publishes_once() {
+        let mut state = State::new();
+
+        let mut value = 0.0;
+        assert_eq!(drag(&mut state, &mut value, 40.0), vec![Some(3.0)]);
+
+        // The application did not feed the value back
+        let mut value = 0.0;
+        assert!(drag(&mut state, &mut value, 35.0).is_empty());
+    }
+
+    #[test]
+    fn arrow_keys_step_a_focused_slider() {
+        let mut state = State::new();
+        let mut value = 9.0;
+
+        assert!(update(
+            &mut state,
+            &mut value,
+            press(keyboard::KeyCode::Right),
+            50.0
+        )
+        .is_empty());
+
+        let _ = drag(&mut state, &mut value, 90.0);
+        let _ = update(
+            &mut state,
+            &mut value,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            90.0,
+        );
+
+        assert_eq!(
+            update(&mut state, &mut value, press(keyboard::KeyCode::Up), 0.0),
+            vec![Some(10.0)]
+        );
+        assert_eq!(
+            update(&mut state, &mut value, press(keyboard::KeyCode::Down), 0.0),
+            vec![Some(9.0)]
+        );
+
+        let release_at = state.release_at.unwrap();
+
+        assert_eq!(
+            update(
+                &mut state,
+                &mut value,
+                Event::Window(window::Event::RedrawRequested(release_at)),
+                0.0,
+            ),
+            vec![None]
+        );
+    }
+
+    #[test]
+    fn double_click_resets_to_the_default_value() {
+        let mut state = State::new();
+        let mut value = 0.0;
+
+        let click = |state: &mut State, value: &mut f64| {
+            let pressed = update_with_default(
+                state,
+                value,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                40.0,
+                Some(6.0),
+            );
+            let released = update_with_default(
+                state,
+                value,
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                40.0,
+                Some(6.0),
+            );
+
+            (pressed, released)
+        };
+
+        assert_eq!(
+            click(&mut state, &mut value),
+            (vec![Some(3.0)], vec![None])
+        );
+        assert!(state.last_click.is_some());
+
+        assert_eq!(
+            click(&mut state, &mut value),
+            (vec![Some(6.0), None], vec![])
+        );
+        assert!(!state.is_dragging);
+    }
+
+    #[test]
+    fn double_click_tolerates_small_movements() {
+        let mut state = State::new();
+        let mut value = 0.0;
+
+        let press = |state: &mut State, value: &mut f64, x: f32| {
+            update_with_default(
+                state,
+                value,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                x,
+                Some(6.0),
+            )
+        };
+
+        assert_eq!(press(&mut state, &mut value, 40.0), vec![Some(3.0)]);
+        assert_eq!(press(&mut state, &mut value, 42.0), vec![Some(6.0), None]);
+
+        assert_eq!(press(&mut state, &mut value, 40.0), vec![Some(3.0)]);
+        assert_eq!(press(&mut state, &mut value, 90.0), vec![Some(9.0)]);
+    }
+}
@@ -3,26 +3,32 @@ use crate::layout;
 use crate::renderer;
 use crate::svg::{self, Handle};
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Color, ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
 };
 
 use std::path::PathBuf;
 
+pub use iced_style::svg::{Style, StyleSheet, Tint};
+
 /// A vector graphics image.
 ///
 /// An [`Svg`] image resizes smoothly without losing any quality.
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
-#[derive(Debug, Clone)]
-pub struct Svg {
+#[allow(missing_debug_implementations)]
+pub struct Svg<'a> {
     handle: Handle,
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    tint: Option<Tint>,
+    opacity: f32,
+    style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
-impl Svg {
+impl<'a> Svg<'a> {
     /// Creates a new [`Svg`] from the given [`Handle`].
     pub fn new(handle: impl Into<Handle>) -> Self {
         Svg {
@@ -30,6 +36,9 @@ impl Svg {
             width: Length::Fill,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            tint: None,
+            opacity: 1.0,
+            style_sheet: Default::default(),
         }
     }
 
@@ -60,9 +69,41 @@ impl Svg {
             ..self
         }
     }
+
+    /// Paints every visible part of the [`Svg`] with the given [`Color`],
+    /// like a monochrome icon.
+    ///
+    /// This is a shorthand for [`Svg::tint`] with [`Tint::Fill`].
+    pub fn color(self, color: Color) -> Self {
+        self.tint(Tint::Fill(color))
+    }
+
+    /// Sets the [`Tint`] of the [`Svg`], which overrides the one of its
+    /// style.
+    pub fn tint(mut self, tint: Tint) -> Self {
+        self.tint = Some(tint);
+        self
+    }
+
+    /// Sets the opacity of the [`Svg`], from `0.0` to `1.0`.
+    ///
+    /// A fully transparent [`Svg`] is not drawn at all.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the style of the [`Svg`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Svg
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Svg<'a>
 where
     Renderer: svg::Renderer,
 {
@@ -112,19 +153,33 @@ where
         renderer: &mut Renderer,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+
         let (width, height) = renderer.dimensions(&self.handle);
         let image_size = Size::new(width as f32, height as f32);
 
         let bounds = layout.bounds();
         let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
 
+        let tint = self.tint.or_else(|| {
+            if bounds.contains(cursor_position) {
+                self.style_sheet.hovered().tint
+            } else {
+                self.style_sheet.active().tint
+            }
+        });
+
         let render = |renderer: &mut Renderer| {
+            // Content that overflows the bounds, like with `Cover` or `None`,
+            // is centered as well and cropped evenly on both sides
             let offset = Vector::new(
-                (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
-                (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
+                (bounds.width - adjusted_fit.width) / 2.0,
+                (bounds.height - adjusted_fit.height) / 2.0,
             );
 
             let drawing_bounds = Rectangle {
@@ -133,7 +188,12 @@ where
                 ..bounds
             };
 
-            renderer.draw(self.handle.clone(), drawing_bounds + offset)
+            renderer.draw_styled(
+                self.handle.clone(),
+                tint,
+                self.opacity,
+                drawing_bounds + offset,
+            );
         };
 
         if adjusted_fit.width > bounds.width
@@ -146,11 +206,11 @@ where
     }
 }
 
-impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
+impl<'a, Message, Renderer> From<Svg<'a>> for Element<'a, Message, Renderer>
 where
     Renderer: svg::Renderer,
 {
-    fn from(icon: Svg) -> Element<'a, Message, Renderer> {
+    fn from(icon: Svg<'a>) -> Element<'a, Message, Renderer> {
         Element::new(icon)
     }
 }
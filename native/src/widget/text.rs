@@ -5,8 +5,20 @@ use crate::renderer;
 use crate::text;
 use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+pub use crate::text::{LineHeight, Rotation, Truncation, Wrap};
+
 /// A paragraph of text.
 ///
+/// # Bidirectional text
+/// The built-in renderers display every line of a [`Text`] in visual order,
+/// reordering its right-to-left runs as described in [`text::bidi`]. Glyphs
+/// are not shaped, so joining scripts like Arabic show their isolated forms.
+///
 /// # Example
 ///
 /// ```
@@ -18,7 +30,6 @@ use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
 /// ```
 ///
 /// ![Text drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text.png?raw=true)
-#[derive(Debug)]
 pub struct Text<Renderer: text::Renderer> {
     content: String,
     size: Option<u16>,
@@ -28,6 +39,20 @@ pub struct Text<Renderer: text::Renderer> {
     height: Length,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    truncation: Truncation,
+    on_truncate: Option<Rc<dyn Fn(bool)>>,
+    truncated: RefCell<Option<Truncated>>,
+    spacing: text::Spacing,
+    rotation: Rotation,
+}
+
+/// The content of a [`Text`] fitted to a width, kept between its layout and
+/// its drawing.
+#[derive(Debug, Clone)]
+struct Truncated {
+    size: u16,
+    max_width: f32,
+    content: Option<String>,
 }
 
 impl<Renderer: text::Renderer> Text<Renderer> {
@@ -42,6 +67,11 @@ impl<Renderer: text::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            truncation: Truncation::None,
+            on_truncate: None,
+            truncated: RefCell::new(None),
+            spacing: text::Spacing::default(),
+            rotation: Rotation::None,
         }
     }
 
@@ -94,6 +124,111 @@ impl<Renderer: text::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`Truncation`] of the [`Text`].
+    ///
+    /// A truncated [`Text`] is laid out in a single line, and its content is
+    /// shortened with an ellipsis only when it does not fit in its width.
+    /// You can use [`Text::on_truncate`] to know whether that is the case.
+    pub fn truncate(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Sets the function called with whether the content of the [`Text`] was
+    /// shortened, every time it is fitted to its width.
+    ///
+    /// This is useful to show the whole content somewhere else, like in a
+    /// tooltip, only when it is needed.
+    pub fn on_truncate(mut self, on_truncate: impl Fn(bool) + 'static) -> Self {
+        self.on_truncate = Some(Rc::new(on_truncate));
+        self
+    }
+
+    /// Fits the content of the [`Text`] to the given width, unless it was
+    /// already done for the same width.
+    fn fit(&self, renderer: &Renderer, size: u16, max_width: f32) {
+        let is_cached = matches!(
+            &*self.truncated.borrow(),
+            Some(truncated)
+                if truncated.size == size && truncated.max_width == max_width
+        );
+
+        if self.truncation != Truncation::None && !is_cached {
+            let content = match renderer.truncate(
+                &self.content,
+                size,
+                self.font.clone(),
+                max_width,
+                self.truncation,
+                self.spacing,
+            ) {
+                Cow::Borrowed(_) => None,
+                Cow::Owned(content) => Some(content),
+            };
+
+            if let Some(on_truncate) = &self.on_truncate {
+                on_truncate(content.is_some());
+            }
+
+            *self.truncated.borrow_mut() = Some(Truncated {
+                size,
+                max_width,
+                content,
+            });
+        }
+    }
+
+    /// Returns the content of the [`Text`] as it was last fitted.
+    fn fitted<'a>(&'a self, truncated: &'a Option<Truncated>) -> &'a str {
+        truncated
+            .as_ref()
+            .and_then(|truncated| truncated.content.as_deref())
+            .unwrap_or(&self.content)
+    }
+
+    /// Sets the [`Wrap`] mode of the [`Text`].
+    ///
+    /// A [`Text`] that does not wrap is measured as a single line and it is
+    /// clipped to its bounds when it overflows them.
+    ///
+    /// By default, lines are broken between words.
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.spacing.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// By default, the natural line height of the font is used.
+    pub fn line_height(mut self, line_height: LineHeight) -> Self {
+        self.spacing.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the amount of logical pixels added between the characters of
+    /// the [`Text`].
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.spacing.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the distance between the tab stops of the [`Text`], in spaces.
+    ///
+    /// By default, it is 4.
+    pub fn tab_width(mut self, spaces: u8) -> Self {
+        self.spacing.tab_width = spaces;
+        self
+    }
+
+    /// Sets the [`Rotation`] of the [`Text`].
+    ///
+    /// The layout of a [`Text`] rotated by 90 or 270 degrees swaps its width
+    /// and height, while its alignment applies along its rotated axes.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -117,12 +252,39 @@ where
 
         let size = self.size.unwrap_or(renderer.default_size());
 
-        let bounds = limits.max();
+        // Text is measured along its rotated axes
+        let bounds = self.rotation.rotate(limits.max());
+
+        let (width, height) = if self.truncation == Truncation::None {
+            // Text that does not wrap keeps its single line height, even
+            // when it overflows
+            let bounds = if self.spacing.wrap == Wrap::None {
+                Size::new(f32::INFINITY, bounds.height)
+            } else {
+                bounds
+            };
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+            renderer.measure(
+                &self.content,
+                size,
+                self.font.clone(),
+                bounds,
+                self.spacing,
+            )
+        } else {
+            self.fit(renderer, size, bounds.width);
 
-        let size = limits.resolve(Size::new(width, height));
+            renderer.measure(
+                self.fitted(&self.truncated.borrow()),
+                size,
+                self.font.clone(),
+                Size::new(f32::INFINITY, bounds.height),
+                self.spacing,
+            )
+        };
+
+        let size =
+            limits.resolve(self.rotation.rotate(Size::new(width, height)));
 
         layout::Node::new(size)
     }
@@ -135,17 +297,36 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        draw(
+        self.fit(
             renderer,
-            style,
-            layout,
-            &self.content,
-            self.font.clone(),
-            self.size,
-            self.color,
-            self.horizontal_alignment,
-            self.vertical_alignment,
+            self.size.unwrap_or(renderer.default_size()),
+            self.rotation.rotate(layout.bounds().size()).width,
         );
+
+        let truncated = self.truncated.borrow();
+        let content = self.fitted(&truncated);
+
+        let draw = |renderer: &mut Renderer| {
+            draw(
+                renderer,
+                style,
+                layout,
+                content,
+                self.font.clone(),
+                self.size,
+                self.color,
+                self.horizontal_alignment,
+                self.vertical_alignment,
+                self.spacing,
+                self.rotation,
+            )
+        };
+
+        if self.spacing.wrap == Wrap::None {
+            renderer.with_layer(layout.bounds(), draw);
+        } else {
+            draw(renderer);
+        }
     }
 }
 
@@ -159,6 +340,7 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * The alignment attributes apply along the axes of the [`Rotation`].
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -169,31 +351,51 @@ pub fn draw<Renderer>(
     color: Option<Color>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    spacing: text::Spacing,
+    rotation: Rotation,
 ) where
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
+    let rotated = rotation.rotate(bounds.size());
 
+    // The position of the alignment along the rotated axes of the text
     let x = match horizontal_alignment {
-        alignment::Horizontal::Left => bounds.x,
-        alignment::Horizontal::Center => bounds.center_x(),
-        alignment::Horizontal::Right => bounds.x + bounds.width,
+        alignment::Horizontal::Left => 0.0,
+        alignment::Horizontal::Center => rotated.width / 2.0,
+        alignment::Horizontal::Right => rotated.width,
     };
 
     let y = match vertical_alignment {
-        alignment::Vertical::Top => bounds.y,
-        alignment::Vertical::Center => bounds.center_y(),
-        alignment::Vertical::Bottom => bounds.y + bounds.height,
+        alignment::Vertical::Top => 0.0,
+        alignment::Vertical::Center => rotated.height / 2.0,
+        alignment::Vertical::Bottom => rotated.height,
+    };
+
+    let (x, y) = match rotation {
+        Rotation::None => (bounds.x + x, bounds.y + y),
+        Rotation::Degrees90 => (bounds.x + bounds.width - y, bounds.y + x),
+        Rotation::Degrees180 => {
+            (bounds.x + bounds.width - x, bounds.y + bounds.height - y)
+        }
+        Rotation::Degrees270 => (bounds.x + y, bounds.y + bounds.height - x),
     };
 
     renderer.fill_text(crate::text::Text {
         content,
         size: f32::from(size.unwrap_or(renderer.default_size())),
-        bounds: Rectangle { x, y, ..bounds },
+        bounds: Rectangle {
+            x,
+            y,
+            width: rotated.width,
+            height: rotated.height,
+        },
         color: color.unwrap_or(style.text_color),
         font,
         horizontal_alignment,
         vertical_alignment,
+        spacing,
+        rotation,
     });
 }
 
@@ -218,6 +420,101 @@ impl<Renderer: text::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            truncation: self.truncation,
+            on_truncate: self.on_truncate.clone(),
+            truncated: self.truncated.clone(),
+            spacing: self.spacing,
+            rotation: self.rotation,
         }
     }
 }
+
+impl<Renderer: text::Renderer> fmt::Debug for Text<Renderer>
+where
+    Renderer::Font: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Text")
+            .field("content", &self.content)
+            .field("size", &self.size)
+            .field("color", &self.color)
+            .field("font", &self.font)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("horizontal_alignment", &self.horizontal_alignment)
+            .field("vertical_alignment", &self.vertical_alignment)
+            .field("truncation", &self.truncation)
+            .field("spacing", &self.spacing)
+            .field("rotation", &self.rotation)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::{Monospace, Null};
+
+    use std::cell::Cell;
+
+    fn layout(text: Text<Null>) -> Size {
+        Widget::<(), Null>::layout(
+            &text,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 50.0)),
+        )
+        .size()
+    }
+
+    #[test]
+    fn vertical_rotations_swap_the_layout_size() {
+        let text = Text::new("Axis");
+        let size = layout(text.clone());
+
+        assert_eq!(
+            layout(text.clone().rotation(Rotation::Degrees90)),
+            Size::new(size.height, size.width)
+        );
+        assert_eq!(
+            layout(text.clone().rotation(Rotation::Degrees270)),
+            Size::new(size.height, size.width)
+        );
+        assert_eq!(layout(text.rotation(Rotation::Degrees180)), size);
+    }
+
+    fn layout_in(text: &Text<Monospace>, width: f32) -> Size {
+        Widget::<(), Monospace>::layout(
+            text,
+            &Monospace,
+            &layout::Limits::new(Size::ZERO, Size::new(width, 50.0)),
+        )
+        .size()
+    }
+
+    #[test]
+    fn truncation_is_reported_and_reused_for_the_same_width() {
+        let reports = Rc::new(Cell::new(Vec::new()));
+
+        let text = Text::new("Hello, world!")
+            .truncate(Truncation::End)
+            .on_truncate({
+                let reports = reports.clone();
+
+                move |is_truncated| {
+                    let mut list = reports.take();
+                    list.push(is_truncated);
+                    reports.set(list);
+                }
+            });
+
+        assert_eq!(layout_in(&text, 60.0), Size::new(60.0, 20.0));
+        assert_eq!(layout_in(&text, 60.0), Size::new(60.0, 20.0));
+        assert_eq!(text.fitted(&text.truncated.borrow()), "Hello\u{2026}");
+
+        assert_eq!(layout_in(&text, 200.0), Size::new(130.0, 20.0));
+        assert_eq!(text.fitted(&text.truncated.borrow()), "Hello, world!");
+
+        assert_eq!(reports.take(), vec![true, false]);
+    }
+}
@@ -0,0 +1,1027 @@
+//! Display fields that can be filled with multiple lines of text.
+//!
+//! A [`TextArea`] has some local [`State`].
+mod editor;
+
+pub mod cursor;
+
+pub use cursor::{Cursor, Position};
+
+use editor::Editor;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input::{self, Value};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::text_area::{Style, StyleSheet};
+
+/// A field that can be filled with multiple lines of text.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::text_area;
+/// #
+/// # pub type TextArea<'a, Message> = iced_native::widget::TextArea<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TextAreaChanged(String),
+/// }
+///
+/// let mut state = text_area::State::new();
+/// let value = "Some text\nspanning multiple lines";
+///
+/// let text_area = TextArea::new(
+///     &mut state,
+///     "This is the placeholder...",
+///     value,
+///     Message::TextAreaChanged,
+/// )
+/// .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextArea<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    placeholder: String,
+    lines: Vec<Value>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> TextArea<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`TextArea`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - a function that produces a message when the [`TextArea`] changes
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        TextArea {
+            state,
+            placeholder: String::from(placeholder),
+            lines: lines(value),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            size: None,
+            on_change: Box::new(on_change),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the [`Font`] of the [`TextArea`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextArea`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextArea`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextArea`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextArea`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextArea`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the current [`State`] of the [`TextArea`].
+    pub fn state(&self) -> &State {
+        self.state
+    }
+
+    fn properties(&self) -> Properties<'_, Message, Renderer::Font> {
+        Properties {
+            width: self.width,
+            height: self.height,
+            padding: self.padding,
+            size: self.size,
+            font: &self.font,
+            placeholder: &self.placeholder,
+            on_change: self.on_change.as_ref(),
+        }
+    }
+}
+
+/// Splits the given text into the lines of a [`TextArea`].
+pub fn lines(value: &str) -> Vec<Value> {
+    value
+        .split('\n')
+        .map(|line| Value::new(line.strip_suffix('\r').unwrap_or(line)))
+        .collect()
+}
+
+/// The properties of a [`TextArea`] that define how it is laid out, drawn,
+/// and how [`update`] processes events.
+///
+/// Most fields are set by the builder method of the [`TextArea`] with the
+/// same name.
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, Message, Font> {
+    /// The width of the [`TextArea`].
+    pub width: Length,
+    /// The height of the [`TextArea`].
+    pub height: Length,
+    /// The [`Padding`] of the [`TextArea`].
+    pub padding: Padding,
+    /// The text size, or the default size of the renderer if `None`.
+    pub size: Option<u16>,
+    /// The font of the text.
+    pub font: &'a Font,
+    /// The text shown while the [`TextArea`] is empty.
+    pub placeholder: &'a str,
+    /// The function producing a message when the value changes.
+    pub on_change: &'a dyn Fn(String) -> Message,
+}
+
+/// Computes the layout of a [`TextArea`].
+pub fn layout<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    properties: Properties<'_, Message, Renderer::Font>,
+    line_count: usize,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        width,
+        height,
+        padding,
+        size,
+        font,
+        ..
+    } = properties;
+
+    let text_size = size.unwrap_or(renderer.default_size());
+    let line_height = line_height(renderer, text_size, font.clone());
+
+    let limits = limits.pad(padding).width(width).height(height);
+
+    let mut text = layout::Node::new(
+        limits.resolve(Size::new(0.0, line_height * line_count as f32)),
+    );
+    text.move_to(Point::new(padding.left.into(), padding.top.into()));
+
+    layout::Node::with_children(text.size().pad(padding), vec![text])
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`TextArea`]
+/// accordingly.
+pub fn update<'a, Message, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    lines: &mut Vec<Value>,
+    properties: Properties<'_, Message, Renderer::Font>,
+    state: impl FnOnce() -> &'a mut State,
+) -> event::Status
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        size,
+        font,
+        on_change,
+        ..
+    } = properties;
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let size = size.unwrap_or(renderer.default_size());
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let state = state();
+            let is_clicked = layout.bounds().contains(cursor_position);
+
+            state.is_focused = is_clicked;
+
+            if is_clicked {
+                let line_height = line_height(renderer, size, font.clone());
+                let scroll_offset =
+                    scroll_offset(state, line_height, lines, text_bounds);
+
+                let line = ((cursor_position.y - text_bounds.y + scroll_offset)
+                    / line_height)
+                    .max(0.0) as usize;
+                let line = line.min(lines.len() - 1);
+
+                let offset = horizontal_offset(
+                    renderer,
+                    text_bounds,
+                    font.clone(),
+                    size,
+                    lines,
+                    state,
+                );
+
+                let column = renderer
+                    .hit_test(
+                        &lines[line].to_string(),
+                        size.into(),
+                        font.clone(),
+                        Size::INFINITY,
+                        Point::new(
+                            cursor_position.x - text_bounds.x + offset,
+                            line_height / 2.0,
+                        ),
+                        true,
+                        text::Spacing::default(),
+                    )
+                    .map(text::Hit::cursor)
+                    .unwrap_or(0);
+
+                if state.keyboard_modifiers.shift() {
+                    state.cursor.start_selection(lines);
+                } else {
+                    state.cursor.clear_selection();
+                }
+
+                state.cursor.move_to(Position { line, column });
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::WheelScrolled { delta })
+            if layout.bounds().contains(cursor_position) =>
+        {
+            let state = state();
+            let line_height = line_height(renderer, size, font.clone());
+
+            let delta = match delta {
+                mouse::ScrollDelta::Lines { y, .. } => y * line_height,
+                mouse::ScrollDelta::Pixels { y, .. } => y,
+            };
+
+            let max_offset = max_scroll_offset(line_height, lines, text_bounds);
+
+            state.scroll_offset =
+                (scroll_offset(state, line_height, lines, text_bounds) - delta)
+                    .clamp(0.0, max_offset);
+
+            return event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+            let state = state();
+
+            if state.is_focused
+                && state.is_pasting.is_none()
+                && !state.keyboard_modifiers.command()
+                && !c.is_control()
+            {
+                let mut editor = Editor::new(lines, &mut state.cursor);
+
+                editor.insert(c);
+
+                let message = (on_change)(editor.contents());
+                shell.publish(message);
+
+                scroll_to_cursor(
+                    renderer,
+                    text_bounds,
+                    font.clone(),
+                    size,
+                    lines,
+                    state,
+                );
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if state.is_focused {
+                let modifiers = state.keyboard_modifiers;
+
+                if is_movement(key_code) {
+                    if modifiers.shift() {
+                        state.cursor.start_selection(lines);
+                    } else {
+                        state.cursor.clear_selection();
+                    }
+                }
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        let mut editor = Editor::new(lines, &mut state.cursor);
+                        editor.enter();
+
+                        let message = (on_change)(editor.contents());
+                        shell.publish(message);
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        let mut editor = Editor::new(lines, &mut state.cursor);
+                        editor.backspace();
+
+                        let message = (on_change)(editor.contents());
+                        shell.publish(message);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        let mut editor = Editor::new(lines, &mut state.cursor);
+                        editor.delete();
+
+                        let message = (on_change)(editor.contents());
+                        shell.publish(message);
+                    }
+                    keyboard::KeyCode::Left => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            state.cursor.move_left_by_words(lines);
+                        } else {
+                            state.cursor.move_left(lines);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            state.cursor.move_right_by_words(lines);
+                        } else {
+                            state.cursor.move_right(lines);
+                        }
+                    }
+                    keyboard::KeyCode::Up => {
+                        state.cursor.move_up(lines);
+                    }
+                    keyboard::KeyCode::Down => {
+                        state.cursor.move_down(lines);
+                    }
+                    keyboard::KeyCode::Home => {
+                        if modifiers.command() {
+                            state.cursor.move_to(Position::default());
+                        } else {
+                            state.cursor.move_to_line_start(lines);
+                        }
+                    }
+                    keyboard::KeyCode::End => {
+                        if modifiers.command() {
+                            state.cursor.move_to_end(lines);
+                        } else {
+                            state.cursor.move_to_line_end(lines);
+                        }
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        state.cursor.select_all(lines);
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some((start, end)) =
+                            state.cursor.selection(lines)
+                        {
+                            clipboard
+                                .write(editor::selected(lines, start, end));
+                        }
+                    }
+                    keyboard::KeyCode::X if modifiers.command() => {
+                        if let Some((start, end)) =
+                            state.cursor.selection(lines)
+                        {
+                            clipboard
+                                .write(editor::selected(lines, start, end));
+
+                            let mut editor =
+                                Editor::new(lines, &mut state.cursor);
+                            editor.delete();
+
+                            let message = (on_change)(editor.contents());
+                            shell.publish(message);
+                        }
+                    }
+                    keyboard::KeyCode::V => {
+                        if state.keyboard_modifiers.command() {
+                            let content = match state.is_pasting.take() {
+                                Some(content) => content,
+                                None => clipboard
+                                    .read()
+                                    .unwrap_or_default()
+                                    .chars()
+                                    .filter(|c| *c == '\n' || !c.is_control())
+                                    .collect(),
+                            };
+
+                            let mut editor =
+                                Editor::new(lines, &mut state.cursor);
+
+                            editor.paste(&content);
+
+                            let message = (on_change)(editor.contents());
+                            shell.publish(message);
+
+                            state.is_pasting = Some(content);
+                        } else {
+                            state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        state.is_pasting = None;
+                        state.cursor.clear_selection();
+
+                        state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                scroll_to_cursor(
+                    renderer,
+                    text_bounds,
+                    font.clone(),
+                    size,
+                    lines,
+                    state,
+                );
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyReleased { key_code, .. }) => {
+            let state = state();
+
+            if state.is_focused {
+                match key_code {
+                    keyboard::KeyCode::V => {
+                        state.is_pasting = None;
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            let state = state();
+
+            if state.is_focused {
+                state.keyboard_modifiers = modifiers;
+            }
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws the [`TextArea`] with the given [`Renderer`].
+///
+/// [`Renderer`]: text::Renderer
+pub fn draw<Message, Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    state: &State,
+    lines: &[Value],
+    properties: Properties<'_, Message, Renderer::Font>,
+    style_sheet: &dyn StyleSheet,
+) where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        placeholder,
+        size,
+        font,
+        ..
+    } = properties;
+
+    let bounds = layout.bounds();
+    let text_bounds = layout.children().next().unwrap().bounds();
+
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    let style = if state.is_focused() {
+        style_sheet.focused()
+    } else if is_mouse_over {
+        style_sheet.hovered()
+    } else {
+        style_sheet.active()
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        },
+        style.background,
+    );
+
+    let size = size.unwrap_or(renderer.default_size());
+    let line_height = line_height(renderer, size, font.clone());
+
+    let scroll_offset = scroll_offset(state, line_height, lines, text_bounds);
+
+    let offset = horizontal_offset(
+        renderer,
+        text_bounds,
+        font.clone(),
+        size,
+        lines,
+        state,
+    );
+
+    let is_empty = lines.len() == 1 && lines[0].is_empty();
+
+    renderer.with_layer(text_bounds, |renderer| {
+        renderer.with_translation(
+            Vector::new(-offset, -scroll_offset),
+            |renderer| {
+                if is_empty {
+                    renderer.fill_text(Text {
+                        content: placeholder,
+                        color: style.placeholder_color,
+                        font: font.clone(),
+                        bounds: text_bounds,
+                        size: f32::from(size),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        spacing: text::Spacing::default(),
+                        rotation: text::Rotation::None,
+                    });
+                } else {
+                    let first = (scroll_offset / line_height) as usize;
+                    let last = (((scroll_offset + text_bounds.height)
+                        / line_height)
+                        .ceil() as usize)
+                        .min(lines.len());
+
+                    if let Some((start, end)) = state.cursor.selection(lines) {
+                        for line in start.line.max(first)..=end.line.min(last) {
+                            if line >= lines.len() {
+                                break;
+                            }
+
+                            let from = if line == start.line {
+                                start.column
+                            } else {
+                                0
+                            };
+
+                            let to = if line == end.line {
+                                end.column
+                            } else {
+                                lines[line].len()
+                            };
+
+                            let measure = |column| {
+                                text_input::measure_cursor_and_scroll_offset(
+                                    renderer,
+                                    text_bounds,
+                                    &lines[line],
+                                    size,
+                                    column,
+                                    font.clone(),
+                                    text::Spacing::default(),
+                                )
+                                .0
+                            };
+
+                            let (left, right) = (measure(from), measure(to));
+
+                            // Line breaks inside the selection are shown as
+                            // a small block after the end of the line
+                            let width = if line < end.line {
+                                right - left + f32::from(size) / 4.0
+                            } else {
+                                right - left
+                            };
+
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: text_bounds.x + left,
+                                        y: text_bounds.y
+                                            + line as f32 * line_height,
+                                        width,
+                                        height: line_height,
+                                    },
+                                    border_radius: 0.0,
+                                    border_width: 0.0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                style.selection_color,
+                            );
+                        }
+                    }
+
+                    for (i, line) in
+                        lines.iter().enumerate().take(last).skip(first)
+                    {
+                        renderer.fill_text(Text {
+                            content: &line.to_string(),
+                            color: style.value_color,
+                            font: font.clone(),
+                            bounds: Rectangle {
+                                y: text_bounds.y + i as f32 * line_height,
+                                width: f32::INFINITY,
+                                height: line_height,
+                                ..text_bounds
+                            },
+                            size: f32::from(size),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Top,
+                            spacing: text::Spacing::default(),
+                            rotation: text::Rotation::None,
+                        });
+                    }
+                }
+
+                if state.is_focused() {
+                    let Position { line, column } =
+                        state.cursor.position(lines);
+
+                    let (cursor_x, _) =
+                        text_input::measure_cursor_and_scroll_offset(
+                            renderer,
+                            text_bounds,
+                            &lines[line],
+                            size,
+                            column,
+                            font.clone(),
+                            text::Spacing::default(),
+                        );
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + cursor_x,
+                                y: text_bounds.y + line as f32 * line_height,
+                                width: 1.0,
+                                height: line_height,
+                            },
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        style.value_color,
+                    );
+                }
+            },
+        );
+    });
+}
+
+/// Computes the current [`mouse::Interaction`] of the [`TextArea`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> mouse::Interaction {
+    if layout.bounds().contains(cursor_position) {
+        mouse::Interaction::Text
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextArea<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout(renderer, limits, self.properties(), self.lines.len())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            &mut self.lines,
+            Properties {
+                width: self.width,
+                height: self.height,
+                padding: self.padding,
+                size: self.size,
+                font: &self.font,
+                placeholder: &self.placeholder,
+                on_change: self.on_change.as_ref(),
+            },
+            || &mut self.state,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            layout,
+            cursor_position,
+            self.state,
+            &self.lines,
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<TextArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        text_area: TextArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_area)
+    }
+}
+
+/// The state of a [`TextArea`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_pasting: Option<String>,
+    cursor: Cursor,
+    scroll_offset: f32,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextArea`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`TextArea`].
+    pub fn focused() -> Self {
+        Self {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the [`TextArea`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextArea`].
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Focuses the [`TextArea`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`TextArea`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Moves the [`Cursor`] of the [`TextArea`] to an arbitrary [`Position`].
+    pub fn move_cursor_to(&mut self, position: Position) {
+        self.cursor.move_to(position);
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt()
+        } else {
+            modifiers.control()
+        }
+    }
+}
+
+fn line_height<Renderer>(
+    renderer: &Renderer,
+    size: u16,
+    font: Renderer::Font,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let (_, height) = renderer.measure(
+        "M",
+        size,
+        font,
+        Size::INFINITY,
+        text::Spacing::default(),
+    );
+
+    height.max(f32::from(size))
+}
+
+fn horizontal_offset<Renderer>(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    size: u16,
+    lines: &[Value],
+    state: &State,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    if state.is_focused() {
+        let Position { line, column } = state.cursor.position(lines);
+
+        let (_, offset) = text_input::measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            &lines[line],
+            size,
+            column,
+            font,
+            text::Spacing::default(),
+        );
+
+        offset
+    } else {
+        0.0
+    }
+}
+
+/// Returns the largest scroll offset that still fills the text bounds.
+fn max_scroll_offset(
+    line_height: f32,
+    lines: &[Value],
+    text_bounds: Rectangle,
+) -> f32 {
+    (line_height * lines.len() as f32 - text_bounds.height).max(0.0)
+}
+
+/// Returns the scroll offset of the [`State`], clamped to the current
+/// contents.
+///
+/// The stored offset can go stale when lines are removed or the bounds grow,
+/// so hit-testing and drawing must both go through this.
+fn scroll_offset(
+    state: &State,
+    line_height: f32,
+    lines: &[Value],
+    text_bounds: Rectangle,
+) -> f32 {
+    state
+        .scroll_offset
+        .min(max_scroll_offset(line_height, lines, text_bounds))
+}
+
+/// Returns whether the given key moves the cursor.
+fn is_movement(key_code: keyboard::KeyCode) -> bool {
+    matches!(
+        key_code,
+        keyboard::KeyCode::Left
+            | keyboard::KeyCode::Right
+            | keyboard::KeyCode::Up
+            | keyboard::KeyCode::Down
+            | keyboard::KeyCode::Home
+            | keyboard::KeyCode::End
+    )
+}
+
+/// Adjusts the scroll offset of the [`State`] so the cursor is visible.
+fn scroll_to_cursor<Renderer>(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    size: u16,
+    lines: &[Value],
+    state: &mut State,
+) where
+    Renderer: text::Renderer,
+{
+    let line_height = line_height(renderer, size, font);
+    let Position { line, .. } = state.cursor.position(lines);
+
+    state.scroll_offset = scroll_offset(state, line_height, lines, text_bounds);
+
+    let top = line as f32 * line_height;
+    let bottom = top + line_height;
+
+    if top < state.scroll_offset {
+        state.scroll_offset = top;
+    } else if bottom > state.scroll_offset + text_bounds.height {
+        state.scroll_offset = (bottom - text_bounds.height).max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_offset_is_clamped_to_the_contents() {
+        let mut state = State::new();
+        state.scroll_offset = 500.0;
+
+        let text_bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 40.0,
+        };
+
+        assert_eq!(
+            scroll_offset(&state, 20.0, &lines("a\nb\nc\nd"), text_bounds),
+            40.0
+        );
+        assert_eq!(scroll_offset(&state, 20.0, &lines("a"), text_bounds), 0.0);
+    }
+}
@@ -0,0 +1,197 @@
+//! Track the cursor of a text area.
+use crate::widget::text_input::Value;
+
+/// A position in the contents of a text area.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    /// The index of the line
+    pub line: usize,
+    /// The index of the grapheme in the line
+    pub column: usize,
+}
+
+/// The cursor of a text area.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Cursor {
+    position: Position,
+    anchor: Option<Position>,
+    preferred_column: Option<usize>,
+}
+
+impl Cursor {
+    /// Returns the [`Position`] of the [`Cursor`] for the given lines.
+    pub fn position(&self, lines: &[Value]) -> Position {
+        let line = self.position.line.min(lines.len().saturating_sub(1));
+        let column = self
+            .position
+            .column
+            .min(lines.get(line).map(Value::len).unwrap_or(0));
+
+        Position { line, column }
+    }
+
+    /// Returns the ordered start and end [`Position`] of the current
+    /// selection, if there is any.
+    pub fn selection(&self, lines: &[Value]) -> Option<(Position, Position)> {
+        let anchor = self.anchor?;
+        let anchor = Cursor {
+            position: anchor,
+            ..Cursor::default()
+        }
+        .position(lines);
+        let position = self.position(lines);
+
+        match anchor.cmp(&position) {
+            std::cmp::Ordering::Less => Some((anchor, position)),
+            std::cmp::Ordering::Greater => Some((position, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Starts a selection at the current [`Position`], unless there is one
+    /// already.
+    pub(crate) fn start_selection(&mut self, lines: &[Value]) {
+        if self.anchor.is_none() {
+            self.anchor = Some(self.position(lines));
+        }
+    }
+
+    pub(crate) fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    pub(crate) fn select_all(&mut self, lines: &[Value]) {
+        self.anchor = Some(Position::default());
+        self.move_to_end(lines);
+    }
+
+    pub(crate) fn move_to(&mut self, position: Position) {
+        self.position = position;
+        self.preferred_column = None;
+    }
+
+    pub(crate) fn move_left(&mut self, lines: &[Value]) {
+        let Position { line, column } = self.position(lines);
+
+        if column > 0 {
+            self.move_to(Position {
+                line,
+                column: column - 1,
+            });
+        } else if line > 0 {
+            self.move_to(Position {
+                line: line - 1,
+                column: lines[line - 1].len(),
+            });
+        }
+    }
+
+    pub(crate) fn move_right(&mut self, lines: &[Value]) {
+        self.move_right_by_amount(lines, 1);
+    }
+
+    pub(crate) fn move_right_by_amount(
+        &mut self,
+        lines: &[Value],
+        amount: usize,
+    ) {
+        let Position { line, column } = self.position(lines);
+
+        if column < lines[line].len() {
+            self.move_to(Position {
+                line,
+                column: column.saturating_add(amount).min(lines[line].len()),
+            });
+        } else if line + 1 < lines.len() {
+            self.move_to(Position {
+                line: line + 1,
+                column: 0,
+            });
+        }
+    }
+
+    pub(crate) fn move_left_by_words(&mut self, lines: &[Value]) {
+        let Position { line, column } = self.position(lines);
+
+        if column == 0 {
+            self.move_left(lines);
+        } else {
+            self.move_to(Position {
+                line,
+                column: lines[line].previous_start_of_word(column),
+            });
+        }
+    }
+
+    pub(crate) fn move_right_by_words(&mut self, lines: &[Value]) {
+        let Position { line, column } = self.position(lines);
+
+        if column == lines[line].len() {
+            self.move_right(lines);
+        } else {
+            self.move_to(Position {
+                line,
+                column: lines[line].next_end_of_word(column),
+            });
+        }
+    }
+
+    pub(crate) fn move_up(&mut self, lines: &[Value]) {
+        let Position { line, column } = self.position(lines);
+        let preferred_column = self.preferred_column.unwrap_or(column);
+
+        self.position = if line > 0 {
+            Position {
+                line: line - 1,
+                column: preferred_column.min(lines[line - 1].len()),
+            }
+        } else {
+            Position { line, column: 0 }
+        };
+
+        self.preferred_column = Some(preferred_column);
+    }
+
+    pub(crate) fn move_down(&mut self, lines: &[Value]) {
+        let Position { line, column } = self.position(lines);
+        let preferred_column = self.preferred_column.unwrap_or(column);
+
+        self.position = if line + 1 < lines.len() {
+            Position {
+                line: line + 1,
+                column: preferred_column.min(lines[line + 1].len()),
+            }
+        } else {
+            Position {
+                line,
+                column: lines[line].len(),
+            }
+        };
+
+        self.preferred_column = Some(preferred_column);
+    }
+
+    pub(crate) fn move_to_line_start(&mut self, lines: &[Value]) {
+        let Position { line, .. } = self.position(lines);
+
+        self.move_to(Position { line, column: 0 });
+    }
+
+    pub(crate) fn move_to_line_end(&mut self, lines: &[Value]) {
+        let Position { line, .. } = self.position(lines);
+
+        self.move_to(Position {
+            line,
+            column: lines[line].len(),
+        });
+    }
+
+    pub(crate) fn move_to_end(&mut self, lines: &[Value]) {
+        let line = lines.len().saturating_sub(1);
+
+        self.move_to(Position {
+            line,
+            column: lines[line].len(),
+        });
+    }
+}
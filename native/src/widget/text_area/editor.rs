@@ -0,0 +1,300 @@
+use crate::widget::text_area::{Cursor, Position};
+use crate::widget::text_input::Value;
+
+pub struct Editor<'a> {
+    lines: &'a mut Vec<Value>,
+    cursor: &'a mut Cursor,
+}
+
+impl<'a> Editor<'a> {
+    pub fn new(
+        lines: &'a mut Vec<Value>,
+        cursor: &'a mut Cursor,
+    ) -> Editor<'a> {
+        Editor { lines, cursor }
+    }
+
+    pub fn contents(&self) -> String {
+        contents(self.lines)
+    }
+
+    pub fn insert(&mut self, character: char) {
+        let _ = self.delete_selection();
+
+        let Position { line, column } = self.cursor.position(self.lines);
+
+        self.lines[line].insert(column, character);
+        self.cursor.move_right(self.lines);
+    }
+
+    pub fn paste(&mut self, content: &str) {
+        let _ = self.delete_selection();
+
+        for (i, part) in content.split('\n').enumerate() {
+            if i > 0 {
+                self.enter();
+            }
+
+            let value = Value::new(part);
+            let length = value.len();
+
+            let Position { line, column } = self.cursor.position(self.lines);
+
+            self.lines[line].insert_many(column, value);
+            self.cursor.move_right_by_amount(self.lines, length);
+        }
+    }
+
+    pub fn enter(&mut self) {
+        let _ = self.delete_selection();
+
+        let Position { line, column } = self.cursor.position(self.lines);
+
+        let rest = self.lines[line].select(column, self.lines[line].len());
+        self.lines[line] = self.lines[line].until(column);
+        self.lines.insert(line + 1, rest);
+
+        self.cursor.move_to(Position {
+            line: line + 1,
+            column: 0,
+        });
+    }
+
+    pub fn backspace(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+
+        let Position { line, column } = self.cursor.position(self.lines);
+
+        if column > 0 {
+            self.cursor.move_left(self.lines);
+            self.lines[line].remove(column - 1);
+        } else if line > 0 {
+            let current = self.lines.remove(line);
+            let previous_length = self.lines[line - 1].len();
+
+            self.lines[line - 1].insert_many(previous_length, current);
+
+            self.cursor.move_to(Position {
+                line: line - 1,
+                column: previous_length,
+            });
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+
+        let Position { line, column } = self.cursor.position(self.lines);
+        let length = self.lines[line].len();
+
+        if column < length {
+            self.lines[line].remove(column);
+        } else if line + 1 < self.lines.len() {
+            let next = self.lines.remove(line + 1);
+
+            self.lines[line].insert_many(length, next);
+        }
+    }
+
+    /// Removes the selected text, if any, and returns whether there was a
+    /// selection to remove.
+    fn delete_selection(&mut self) -> bool {
+        let (start, end) = match self.cursor.selection(self.lines) {
+            Some(selection) => selection,
+            None => {
+                self.cursor.clear_selection();
+                return false;
+            }
+        };
+
+        let rest =
+            self.lines[end.line].select(end.column, self.lines[end.line].len());
+
+        self.lines[start.line] = self.lines[start.line].until(start.column);
+        self.lines[start.line].insert_many(start.column, rest);
+        let _ = self.lines.drain(start.line + 1..=end.line);
+
+        self.cursor.clear_selection();
+        self.cursor.move_to(start);
+
+        true
+    }
+}
+
+/// Joins the given lines into a single `String`.
+pub fn contents(lines: &[Value]) -> String {
+    lines
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Joins the lines between the given positions into a single `String`.
+pub fn selected(lines: &[Value], start: Position, end: Position) -> String {
+    if start.line == end.line {
+        return lines[start.line]
+            .select(start.column, end.column)
+            .to_string();
+    }
+
+    let first = lines[start.line].select(start.column, lines[start.line].len());
+    let last = lines[end.line].until(end.column);
+
+    std::iter::once(first)
+        .chain(lines[start.line + 1..end.line].iter().cloned())
+        .chain(std::iter::once(last))
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::text_area;
+
+    fn editing(text: &str, position: Position) -> (Vec<Value>, Cursor) {
+        let mut cursor = Cursor::default();
+        cursor.move_to(position);
+
+        (text_area::lines(text), cursor)
+    }
+
+    #[test]
+    fn up_and_down_keep_the_preferred_column() {
+        let (lines, mut cursor) = editing(
+            "long line\nab\nlonger line",
+            Position { line: 0, column: 7 },
+        );
+
+        cursor.move_down(&lines);
+        assert_eq!(cursor.position(&lines), Position { line: 1, column: 2 });
+
+        cursor.move_down(&lines);
+        assert_eq!(cursor.position(&lines), Position { line: 2, column: 7 });
+
+        cursor.move_up(&lines);
+        cursor.move_up(&lines);
+        assert_eq!(cursor.position(&lines), Position { line: 0, column: 7 });
+    }
+
+    #[test]
+    fn left_and_right_wrap_around_line_boundaries() {
+        let (lines, mut cursor) =
+            editing("ab\ncd", Position { line: 1, column: 0 });
+
+        cursor.move_left(&lines);
+        assert_eq!(cursor.position(&lines), Position { line: 0, column: 2 });
+
+        cursor.move_right(&lines);
+        assert_eq!(cursor.position(&lines), Position { line: 1, column: 0 });
+    }
+
+    #[test]
+    fn selection_spans_multiple_lines() {
+        let (lines, mut cursor) =
+            editing("first\nsecond\nthird", Position { line: 0, column: 2 });
+
+        cursor.start_selection(&lines);
+        cursor.move_down(&lines);
+        cursor.move_down(&lines);
+
+        let (start, end) = cursor.selection(&lines).unwrap();
+
+        assert_eq!(start, Position { line: 0, column: 2 });
+        assert_eq!(end, Position { line: 2, column: 2 });
+        assert_eq!(selected(&lines, start, end), "rst\nsecond\nth");
+    }
+
+    #[test]
+    fn selection_is_ordered_when_made_backwards() {
+        let (lines, mut cursor) =
+            editing("first\nsecond", Position { line: 1, column: 3 });
+
+        cursor.start_selection(&lines);
+        cursor.move_up(&lines);
+
+        assert_eq!(
+            cursor.selection(&lines),
+            Some((
+                Position { line: 0, column: 3 },
+                Position { line: 1, column: 3 }
+            ))
+        );
+    }
+
+    #[test]
+    fn typing_replaces_a_selection_across_lines() {
+        let (mut lines, mut cursor) =
+            editing("first\nsecond\nthird", Position { line: 0, column: 2 });
+
+        cursor.start_selection(&lines);
+        cursor.move_to(Position { line: 2, column: 2 });
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.insert('x');
+
+        assert_eq!(editor.contents(), "fixird");
+        assert_eq!(cursor.position(&lines), Position { line: 0, column: 3 });
+        assert_eq!(cursor.selection(&lines), None);
+    }
+
+    #[test]
+    fn backspace_at_line_start_joins_with_previous_line() {
+        let (mut lines, mut cursor) =
+            editing("ab\ncd", Position { line: 1, column: 0 });
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.backspace();
+
+        assert_eq!(editor.contents(), "abcd");
+        assert_eq!(cursor.position(&lines), Position { line: 0, column: 2 });
+    }
+
+    #[test]
+    fn delete_at_line_end_joins_with_next_line() {
+        let (mut lines, mut cursor) =
+            editing("ab\ncd", Position { line: 0, column: 2 });
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.delete();
+
+        assert_eq!(editor.contents(), "abcd");
+        assert_eq!(cursor.position(&lines), Position { line: 0, column: 2 });
+    }
+
+    #[test]
+    fn backspace_and_delete_at_the_edges_do_nothing() {
+        let (mut lines, mut cursor) =
+            editing("ab\ncd", Position { line: 0, column: 0 });
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.backspace();
+        assert_eq!(editor.contents(), "ab\ncd");
+
+        cursor.move_to_end(&lines);
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.delete();
+        assert_eq!(editor.contents(), "ab\ncd");
+    }
+
+    #[test]
+    fn enter_and_paste_split_lines_at_the_cursor() {
+        let (mut lines, mut cursor) =
+            editing("abcd", Position { line: 0, column: 2 });
+
+        let mut editor = Editor::new(&mut lines, &mut cursor);
+        editor.enter();
+        assert_eq!(editor.contents(), "ab\ncd");
+
+        editor.paste("x\ny");
+        assert_eq!(editor.contents(), "ab\nx\nycd");
+        assert_eq!(cursor.position(&lines), Position { line: 2, column: 1 });
+    }
+}
@@ -2,14 +2,18 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
+mod numeric;
 mod value;
 
 pub mod cursor;
 
 pub use cursor::Cursor;
+pub use numeric::NumericOptions;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::alignment;
 use crate::event::{self, Event};
@@ -19,15 +23,27 @@ use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
 use crate::{
-    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
-    Shell, Size, Vector, Widget,
+    Clipboard, Color, Command, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::borrow::Cow;
+use std::ops::Range;
+
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
 ///
+/// # Bidirectional text
+/// The cursor of a [`TextInput`] moves over grapheme clusters in logical
+/// order. Over right-to-left text, the Left and Right keys move it
+/// backwards and forwards in the value, not in the visual direction.
+/// However, the cursor is drawn, and placed by clicks, where the text is
+/// displayed.
+///
 /// # Example
 /// ```
 /// # use iced_native::renderer::Null;
@@ -54,15 +70,36 @@ pub use iced_style::text_input::{Style, StyleSheet};
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: text::Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
     placeholder: String,
     value: Value,
     is_secure: bool,
+    secure_character: char,
+    is_disabled: bool,
+    is_error: bool,
     font: Renderer::Font,
+    spacing: text::Spacing,
     width: Length,
     padding: Padding,
     size: Option<u16>,
+    leading_icon: Option<Icon<Renderer::Font>>,
+    trailing_icon: Option<Icon<Renderer::Font>>,
     on_change: Box<dyn Fn(String) -> Message + 'a>,
     on_submit: Option<Message>,
+    on_submit_with: Option<Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+    on_icon_press: Option<Message>,
+    on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_cursor_move: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    #[allow(clippy::type_complexity)]
+    on_change_parsed: Option<Box<dyn Fn(Result<f64, String>) -> Message + 'a>>,
+    paste_filter: Option<Box<dyn Fn(String) -> String + 'a>>,
+    max_length: Option<usize>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    numeric: Option<NumericOptions>,
+    history_depth: usize,
+    primary_selection: bool,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -89,25 +126,77 @@ where
     {
         TextInput {
             state,
+            id: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            secure_character: DEFAULT_SECURE_CHARACTER,
+            is_disabled: false,
+            is_error: false,
             font: Default::default(),
+            spacing: text::Spacing::default(),
             width: Length::Fill,
             padding: Padding::ZERO,
             size: None,
+            leading_icon: None,
+            trailing_icon: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            on_submit_with: None,
+            on_focus: None,
+            on_blur: None,
+            on_icon_press: None,
+            on_paste: None,
+            on_cursor_move: None,
+            on_change_parsed: None,
+            paste_filter: None,
+            max_length: None,
+            filter: None,
+            numeric: None,
+            history_depth: DEFAULT_HISTORY_DEPTH,
+            primary_selection: false,
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
         self
     }
 
+    /// Sets the character used to obscure the value of the [`TextInput`]
+    /// when it is a secure password input.
+    ///
+    /// By default, the dot ('•') character is used.
+    pub fn secure_character(mut self, character: char) -> Self {
+        self.secure_character = character;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] is disabled.
+    ///
+    /// A disabled [`TextInput`] ignores any interaction and loses its focus.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] has an error.
+    ///
+    /// A [`TextInput`] with an error is drawn with the
+    /// [`StyleSheet::errored`] style.
+    pub fn error(mut self, is_error: bool) -> Self {
+        self.is_error = is_error;
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: crate::text::Renderer::Font
@@ -133,10 +222,193 @@ where
         self
     }
 
+    /// Sets the [`LineHeight`] of the [`TextInput`], which determines the
+    /// height of its text area.
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn line_height(mut self, line_height: text::LineHeight) -> Self {
+        self.spacing.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the amount of logical pixels added between the characters of
+    /// the [`TextInput`].
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.spacing.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the distance between the tab stops of the [`TextInput`], in
+    /// spaces.
+    ///
+    /// By default, it is 4.
+    pub fn tab_width(mut self, spaces: u8) -> Self {
+        self.spacing.tab_width = spaces;
+        self
+    }
+
+    /// Adds an [`Icon`] to the [`TextInput`].
+    ///
+    /// A [`TextInput`] can have an [`Icon`] on each [`Side`], which shrinks
+    /// the area used to display its value.
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        match icon.side {
+            Side::Leading => self.leading_icon = Some(icon),
+            Side::Trailing => self.trailing_icon = Some(icon),
+        }
+
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
+    ///
+    /// It replaces any function set with [`on_submit_with`].
+    ///
+    /// [`on_submit_with`]: Self::on_submit_with
     pub fn on_submit(mut self, message: Message) -> Self {
         self.on_submit = Some(message);
+        self.on_submit_with = None;
+        self
+    }
+
+    /// Sets the function that produces a message when the [`TextInput`] is
+    /// focused and the enter key is pressed.
+    ///
+    /// The function receives the keyboard modifiers that were held when the
+    /// enter key was pressed. It replaces any message set with
+    /// [`on_submit`].
+    ///
+    /// [`on_submit`]: Self::on_submit
+    pub fn on_submit_with(
+        mut self,
+        on_submit: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_submit_with = Some(Box::new(on_submit));
+        self.on_submit = None;
+        self
+    }
+
+    /// Sets the message that should be produced when the trailing icon of
+    /// the [`TextInput`] is pressed.
+    pub fn on_icon_press(mut self, message: Message) -> Self {
+        self.on_icon_press = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`]
+    /// gains focus.
+    ///
+    /// When the focus is given by an operation, like [`focus`], the message
+    /// is produced with the next event processed by the [`TextInput`].
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`]
+    /// loses focus.
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor of the
+    /// [`TextInput`] moves.
+    ///
+    /// The message receives the new position of the cursor, as a grapheme
+    /// index into the value.
+    pub fn on_cursor_move(
+        mut self,
+        on_cursor_move: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_cursor_move = Some(Box::new(on_cursor_move));
+        self
+    }
+
+    /// Sets the message that should be produced when the value of the
+    /// [`TextInput`] changes, parsed as a number.
+    ///
+    /// The value is parsed following the [`NumericOptions`] of the
+    /// [`TextInput`], if any. On failure, the message receives the raw
+    /// value instead.
+    pub fn on_change_parsed(
+        mut self,
+        on_change_parsed: impl Fn(Result<f64, String>) -> Message + 'a,
+    ) -> Self {
+        self.on_change_parsed = Some(Box::new(on_change_parsed));
+        self
+    }
+
+    /// Sets the message that should be produced when content is pasted
+    /// into the [`TextInput`].
+    ///
+    /// When set, the message receives the contents of the clipboard and the
+    /// [`TextInput`] does not insert them by itself.
+    pub fn on_paste(
+        mut self,
+        on_paste: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_paste = Some(Box::new(on_paste));
+        self
+    }
+
+    /// Sets the function used to transform the contents of the clipboard
+    /// before they are pasted into the [`TextInput`].
+    pub fn paste_filter(
+        mut self,
+        paste_filter: impl Fn(String) -> String + 'a,
+    ) -> Self {
+        self.paste_filter = Some(Box::new(paste_filter));
+        self
+    }
+
+    /// Sets the maximum amount of graphemes that the [`TextInput`] can
+    /// contain.
+    ///
+    /// Any input that would exceed this length is discarded.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the filter that decides which characters are accepted by the
+    /// [`TextInput`].
+    ///
+    /// Rejected characters are neither typed nor pasted.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Restricts the [`TextInput`] to numbers, following the given
+    /// [`NumericOptions`].
+    ///
+    /// Only digits, a single decimal separator, and a leading sign are
+    /// accepted. The arrow keys step the value up and down.
+    pub fn numeric(mut self, options: NumericOptions) -> Self {
+        self.numeric = Some(options);
+        self
+    }
+
+    /// Sets the maximum amount of edits that can be undone in the
+    /// [`TextInput`].
+    ///
+    /// By default, the last 100 edits are kept.
+    pub fn history_depth(mut self, depth: usize) -> Self {
+        self.history_depth = depth;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] uses the primary selection of the
+    /// [`Clipboard`], where available.
+    ///
+    /// When enabled, selected text is written to the primary selection and
+    /// a middle click pastes it. It is disabled by default.
+    ///
+    /// [`Clipboard`]: crate::Clipboard
+    pub fn primary_selection(mut self, primary_selection: bool) -> Self {
+        self.primary_selection = primary_selection;
         self
     }
 
@@ -154,6 +426,28 @@ where
         self.state
     }
 
+    fn mask(&self) -> Option<char> {
+        if self.is_secure {
+            Some(self.secure_character)
+        } else {
+            None
+        }
+    }
+
+    /// Measures the horizontal distance, in logical pixels, between the start
+    /// of the text of the [`TextInput`] and its cursor.
+    pub fn cursor_offset(&self, renderer: &Renderer) -> f32 {
+        cursor_offset(
+            renderer,
+            &self.value,
+            self.size,
+            &self.font,
+            self.spacing,
+            self.mask(),
+            self.state,
+        )
+    }
+
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
@@ -174,12 +468,157 @@ where
             &self.placeholder,
             self.size,
             &self.font,
-            self.is_secure,
+            self.spacing,
+            self.mask(),
+            self.is_disabled,
+            self.is_error,
+            self.leading_icon.as_ref(),
+            self.trailing_icon.as_ref(),
             self.style_sheet.as_ref(),
         )
     }
 }
 
+/// Measures the horizontal distance, in logical pixels, between the start of
+/// the text of a [`TextInput`] and its cursor.
+///
+/// The scrolling offset of the [`TextInput`] is not taken into account.
+pub fn cursor_offset<Renderer>(
+    renderer: &Renderer,
+    value: &Value,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    spacing: text::Spacing,
+    secure_character: Option<char>,
+    state: &State,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let value = displayed(value, secure_character);
+
+    let text_before_cursor =
+        value.until(state.cursor_position(&value)).to_string();
+
+    let width = renderer.measure_width(
+        &text_before_cursor,
+        size.unwrap_or_else(|| renderer.default_size()),
+        font.clone(),
+        spacing,
+    );
+
+    if text_before_cursor.is_empty() {
+        width
+    } else {
+        width + spacing.letter_spacing
+    }
+}
+
+/// The identifier of a [`TextInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// An icon displayed at one of the sides of a [`TextInput`].
+#[derive(Debug, Clone)]
+pub struct Icon<Font> {
+    /// The font used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point displayed as the icon.
+    pub code_point: char,
+    /// The font size of the icon.
+    pub size: Option<u16>,
+    /// The spacing between the icon and the text.
+    pub spacing: u16,
+    /// The [`Side`] of the [`TextInput`] where the icon is displayed.
+    pub side: Side,
+}
+
+impl<Font: Clone> Icon<Font> {
+    fn width<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        renderer.measure_width(
+            &self.code_point.to_string(),
+            self.size.unwrap_or_else(|| renderer.default_size()),
+            self.font.clone(),
+            text::Spacing::default(),
+        )
+    }
+}
+
+/// The side of a [`TextInput`] where an [`Icon`] is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Before the text.
+    Leading,
+    /// After the text.
+    Trailing,
+}
+
+/// Produces a [`Command`] that focuses the [`TextInput`] with the given [`Id`].
+///
+/// Every other [`TextInput`] is unfocused. Nothing happens if no
+/// [`TextInput`] has the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the end.
+pub fn move_cursor_to_end<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to_end(id.0))
+}
+
+/// Produces a [`Command`] that selects all the content of the [`TextInput`]
+/// with the given [`Id`].
+pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::select_all(id.0))
+}
+
+/// Applies an [`Operation`] to the [`State`] of a [`TextInput`].
+///
+/// A disabled [`TextInput`] cannot be focused, so it is left out of the
+/// operations on focusable widgets.
+pub fn operate<Message>(
+    state: &mut State,
+    id: Option<&Id>,
+    is_disabled: bool,
+    operation: &mut dyn Operation<Message>,
+) {
+    if !is_disabled {
+        operation.focusable(state, id.map(|id| &id.0));
+    }
+
+    operation.text_input(state, id.map(|id| &id.0));
+}
+
+/// The default character used to obscure a secure [`TextInput`].
+pub const DEFAULT_SECURE_CHARACTER: char = '•';
+
+/// The default amount of edits that can be undone in a [`TextInput`].
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
 /// Computes the layout of a [`TextInput`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -187,25 +626,120 @@ pub fn layout<Renderer>(
     width: Length,
     padding: Padding,
     size: Option<u16>,
+    spacing: text::Spacing,
+    leading_icon: Option<&Icon<Renderer::Font>>,
+    trailing_icon: Option<&Icon<Renderer::Font>>,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
 {
     let text_size = size.unwrap_or(renderer.default_size());
 
+    let text_height = match spacing.line_height {
+        Some(line_height) => {
+            line_height.to_absolute(f32::from(text_size)).round() as u16
+        }
+        None => text_size,
+    };
+
     let limits = limits
         .pad(padding)
         .width(width)
-        .height(Length::Units(text_size));
+        .height(Length::Units(text_height));
+
+    let bounds = limits.resolve(Size::ZERO);
+
+    let mut text_x = f32::from(padding.left);
+    let mut text_width = bounds.width;
+    let mut icons = Vec::new();
+
+    if let Some(icon) = leading_icon {
+        let icon_width = icon.width(renderer);
+
+        let mut node = layout::Node::new(Size::new(icon_width, bounds.height));
+        node.move_to(Point::new(text_x, padding.top.into()));
+        icons.push(node);
+
+        text_x += icon_width + f32::from(icon.spacing);
+        text_width -= icon_width + f32::from(icon.spacing);
+    }
+
+    if let Some(icon) = trailing_icon {
+        let icon_width = icon.width(renderer);
+
+        let mut node = layout::Node::new(Size::new(icon_width, bounds.height));
+        node.move_to(Point::new(
+            f32::from(padding.left) + bounds.width - icon_width,
+            padding.top.into(),
+        ));
+        icons.push(node);
+
+        text_width -= icon_width + f32::from(icon.spacing);
+    }
+
+    let mut text =
+        layout::Node::new(Size::new(text_width.max(0.0), bounds.height));
+    text.move_to(Point::new(text_x, padding.top.into()));
+
+    let mut children = vec![text];
+    children.extend(icons);
 
-    let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-    text.move_to(Point::new(padding.left.into(), padding.top.into()));
+    layout::Node::with_children(bounds.pad(padding), children)
+}
 
-    layout::Node::with_children(text.size().pad(padding), vec![text])
+/// The properties of a [`TextInput`] that define how [`update`] processes
+/// events.
+///
+/// Most fields are set by the builder method of the [`TextInput`] with the
+/// same name.
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, Message, Font> {
+    /// The text size, or the default size of the renderer if `None`.
+    pub size: Option<u16>,
+    /// The font of the text.
+    pub font: &'a Font,
+    /// The [`Spacing`] of the text.
+    ///
+    /// [`Spacing`]: text::Spacing
+    pub spacing: text::Spacing,
+    /// The character obscuring the value of a secure [`TextInput`].
+    pub secure_character: Option<char>,
+    /// Whether the [`TextInput`] is disabled.
+    pub is_disabled: bool,
+    /// The function producing a message when the value changes.
+    pub on_change: &'a dyn Fn(String) -> Message,
+    /// The message produced when the enter key is pressed.
+    pub on_submit: &'a Option<Message>,
+    /// The function producing a message when the enter key is pressed,
+    /// instead of [`on_submit`](Self::on_submit).
+    pub on_submit_with: Option<&'a dyn Fn(keyboard::Modifiers) -> Message>,
+    /// The message produced when the trailing icon is pressed.
+    pub on_icon_press: &'a Option<Message>,
+    /// The function producing a message when content is pasted.
+    pub on_paste: Option<&'a dyn Fn(String) -> Message>,
+    /// The function transforming the contents of the clipboard.
+    pub paste_filter: Option<&'a dyn Fn(String) -> String>,
+    /// The maximum amount of graphemes of the value.
+    pub max_length: Option<usize>,
+    /// The filter deciding which characters are accepted.
+    pub filter: Option<&'a dyn Fn(char) -> bool>,
+    /// The [`NumericOptions`] of a numeric [`TextInput`].
+    pub numeric: Option<&'a NumericOptions>,
+    /// The maximum amount of edits that can be undone.
+    pub history_depth: usize,
+    /// Whether the primary selection of the [`Clipboard`] is used.
+    pub primary_selection: bool,
+    /// The message produced when the [`TextInput`] gains focus.
+    pub on_focus: &'a Option<Message>,
+    /// The message produced when the [`TextInput`] loses focus.
+    pub on_blur: &'a Option<Message>,
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`TextInput`]
 /// accordingly.
+///
+/// Any change of focus since the last call is reported, including the ones
+/// made by an [`Operation`] like [`focus`].
 pub fn update<'a, Message, Renderer>(
     event: Event,
     layout: Layout<'_>,
@@ -214,27 +748,146 @@ pub fn update<'a, Message, Renderer>(
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
     value: &mut Value,
-    size: Option<u16>,
-    font: &Renderer::Font,
-    is_secure: bool,
-    on_change: &dyn Fn(String) -> Message,
-    on_submit: &Option<Message>,
+    properties: Properties<'_, Message, Renderer::Font>,
+    state: impl FnOnce() -> &'a mut State,
+) -> event::Status
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    let state = state();
+    let (on_focus, on_blur) = (properties.on_focus, properties.on_blur);
+    let (font, spacing, size, secure_character) = (
+        properties.font.clone(),
+        properties.spacing,
+        properties.size,
+        properties.secure_character,
+    );
+
+    let borrowed = &mut *state;
+
+    let status = process(
+        event,
+        layout,
+        cursor_position,
+        renderer,
+        clipboard,
+        shell,
+        value,
+        properties,
+        move || borrowed,
+    );
+
+    if state.is_focused != state.is_focus_reported {
+        state.is_focus_reported = state.is_focused;
+
+        let message = if state.is_focused { on_focus } else { on_blur };
+
+        if let Some(message) = message.clone() {
+            shell.publish(message);
+        }
+    }
+
+    // The input method editor follows the cursor of the focused input
+    if state.is_focused {
+        let text_layout = layout.children().next().unwrap();
+
+        shell.request_ime_position(ime_position(
+            renderer,
+            text_layout.bounds(),
+            font,
+            spacing,
+            size,
+            value,
+            secure_character,
+            state,
+        ));
+    }
+
+    status
+}
+
+fn process<'a, Message, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    value: &mut Value,
+    properties: Properties<'_, Message, Renderer::Font>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status
 where
     Message: Clone,
     Renderer: text::Renderer,
 {
+    let Properties {
+        size,
+        font,
+        spacing,
+        secure_character,
+        is_disabled,
+        on_change,
+        on_submit,
+        on_submit_with,
+        on_icon_press,
+        on_paste,
+        paste_filter,
+        max_length,
+        filter,
+        numeric,
+        history_depth,
+        primary_selection,
+        on_focus: _,
+        on_blur: _,
+    } = properties;
+
+    let is_secure = secure_character.is_some();
+
+    if is_disabled {
+        let state = state();
+
+        if state.is_focused {
+            state.unfocus();
+        }
+
+        state.is_dragging = false;
+
+        return event::Status::Ignored;
+    }
+
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
             let is_clicked = layout.bounds().contains(cursor_position);
 
+            if state.is_focused != is_clicked {
+                state.last_click = None;
+            }
+
             state.is_focused = is_clicked;
 
             if is_clicked {
+                state.history.seal();
+
                 let text_layout = layout.children().next().unwrap();
+
+                // Clicking an icon does not move the cursor
+                if let Some(icon_layout) = layout
+                    .children()
+                    .skip(1)
+                    .find(|icon| icon.bounds().contains(cursor_position))
+                {
+                    if icon_layout.bounds().x > text_layout.bounds().x {
+                        if let Some(on_icon_press) = on_icon_press.clone() {
+                            shell.publish(on_icon_press);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
                 let target = cursor_position.x - text_layout.bounds().x;
 
                 let click =
@@ -243,16 +896,13 @@ where
                 match click.kind() {
                     click::Kind::Single => {
                         let position = if target > 0.0 {
-                            let value = if is_secure {
-                                value.secure()
-                            } else {
-                                value.clone()
-                            };
+                            let value = displayed(value, secure_character);
 
                             find_cursor_position(
                                 renderer,
                                 text_layout.bounds(),
                                 font.clone(),
+                                spacing,
                                 size,
                                 &value,
                                 state,
@@ -262,17 +912,30 @@ where
                             None
                         };
 
-                        state.cursor.move_to(position.unwrap_or(0));
+                        let position = position.unwrap_or(0);
+
+                        if state.keyboard_modifiers.shift() {
+                            state.cursor.select_range(
+                                state.cursor.start(value),
+                                position,
+                            );
+                        } else {
+                            state.cursor.move_to(position);
+                        }
+
                         state.is_dragging = true;
+                        state.dragged_word = None;
                     }
                     click::Kind::Double => {
                         if is_secure {
                             state.cursor.select_all(value);
+                            state.is_dragging = false;
                         } else {
                             let position = find_cursor_position(
                                 renderer,
                                 text_layout.bounds(),
                                 font.clone(),
+                                spacing,
                                 size,
                                 value,
                                 state,
@@ -280,48 +943,132 @@ where
                             )
                             .unwrap_or(0);
 
-                            state.cursor.select_range(
-                                value.previous_start_of_word(position),
-                                value.next_end_of_word(position),
-                            );
-                        }
+                            let start = value.previous_start_of_word(position);
+                            let end = value.next_end_of_word(position);
 
-                        state.is_dragging = false;
+                            state.cursor.select_range(start, end);
+                            state.is_dragging = true;
+                            state.dragged_word = Some((start, end));
+                        }
                     }
                     click::Kind::Triple => {
                         state.cursor.select_all(value);
                         state.is_dragging = false;
+                        state.dragged_word = None;
                     }
                 }
 
                 state.last_click = Some(click);
 
+                // A selection being dragged is only complete when released
+                if !state.is_dragging {
+                    update_primary(
+                        clipboard,
+                        value,
+                        state,
+                        is_secure || !primary_selection,
+                    );
+                }
+
                 return event::Status::Captured;
             }
         }
-        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerLifted { .. })
-        | Event::Touch(touch::Event::FingerLost { .. }) => {
-            state().is_dragging = false;
-        }
-        Event::Mouse(mouse::Event::CursorMoved { position })
-        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle))
+            if primary_selection =>
+        {
             let state = state();
 
-            if state.is_dragging {
+            if layout.bounds().contains(cursor_position) {
                 let text_layout = layout.children().next().unwrap();
-                let target = position.x - text_layout.bounds().x;
+                let target = cursor_position.x - text_layout.bounds().x;
+
+                state.is_focused = true;
+                state.is_dragging = false;
+                state.dragged_word = None;
+                state.last_click = None;
+                state.history.seal();
 
-                let value = if is_secure {
-                    value.secure()
+                let position = if target > 0.0 {
+                    let value = displayed(value, secure_character);
+
+                    find_cursor_position(
+                        renderer,
+                        text_layout.bounds(),
+                        font.clone(),
+                        spacing,
+                        size,
+                        &value,
+                        state,
+                        target,
+                    )
                 } else {
-                    value.clone()
+                    None
                 };
 
+                state.cursor.move_to(position.unwrap_or(0));
+
+                // Pasting the primary selection moves the cursor, like
+                // most Linux applications do
+                if let Some(content) = clipboard.read_primary() {
+                    if let Some(on_paste) = on_paste {
+                        shell.publish((on_paste)(content));
+                    } else if let Some(content) = prepare_paste(
+                        content,
+                        value,
+                        state,
+                        paste_filter,
+                        filter,
+                        numeric,
+                    ) {
+                        paste(
+                            &content,
+                            value,
+                            state,
+                            shell,
+                            on_change,
+                            max_length,
+                            history_depth,
+                        );
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            let state = state();
+
+            if state.is_dragging {
+                update_primary(
+                    clipboard,
+                    value,
+                    state,
+                    is_secure || !primary_selection,
+                );
+            }
+
+            state.is_dragging = false;
+            state.dragged_word = None;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            let state = state();
+
+            if state.is_dragging {
+                let text_layout = layout.children().next().unwrap();
+                let target = position.x - text_layout.bounds().x;
+
+                let value = displayed(value, secure_character);
+
+                let text_bounds = text_layout.bounds();
+
                 let position = find_cursor_position(
                     renderer,
-                    text_layout.bounds(),
+                    text_bounds,
                     font.clone(),
+                    spacing,
                     size,
                     &value,
                     state,
@@ -329,6 +1076,36 @@ where
                 )
                 .unwrap_or(0);
 
+                // After a double click, the selection grows word by word
+                // while keeping the original word selected
+                if let Some((start, end)) = state.dragged_word {
+                    if position < start {
+                        state.cursor.select_range(
+                            end,
+                            value.previous_start_of_word(position),
+                        );
+                    } else {
+                        state.cursor.select_range(
+                            start,
+                            value.next_end_of_word(position),
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                // Keep moving the selection while the cursor is dragged past
+                // the visible edges, so the text scrolls along with it
+                let end = state.cursor.end(&value);
+
+                let position = if target < 0.0 {
+                    position.min(end.saturating_sub(1))
+                } else if target > text_bounds.width {
+                    position.max(end + 1).min(value.len())
+                } else {
+                    position
+                };
+
                 state
                     .cursor
                     .select_range(state.cursor.start(&value), position);
@@ -344,26 +1121,70 @@ where
                 && !state.keyboard_modifiers.command()
                 && !c.is_control()
             {
+                state.last_click = None;
+
+                let length = available_length(value, &state.cursor, max_length);
+
+                if !is_accepted(filter, c) || length == 0 {
+                    return event::Status::Captured;
+                }
+
+                let content = match numeric {
+                    Some(numeric) => {
+                        match numeric.accept(
+                            value,
+                            &state.cursor,
+                            &c.to_string(),
+                        ) {
+                            Some(content) if content.len() <= length => {
+                                Some(content)
+                            }
+                            _ => return event::Status::Captured,
+                        }
+                    }
+                    None => None,
+                };
+
+                state.history.record(
+                    history::Kind::Insert,
+                    value,
+                    state.cursor,
+                    history_depth,
+                );
+
                 let mut editor = Editor::new(value, &mut state.cursor);
 
-                editor.insert(c);
+                match content {
+                    Some(content) => editor.paste(content),
+                    None => editor.insert(c),
+                }
 
                 let message = (on_change)(editor.contents());
                 shell.publish(message);
 
+                state.history.commit(value);
+
                 return event::Status::Captured;
             }
         }
-        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) => {
             let state = state();
 
             if state.is_focused {
-                let modifiers = state.keyboard_modifiers;
+                state.keyboard_modifiers = modifiers;
+                state.last_click = None;
+
+                let selection = state.cursor.selection(value);
 
                 match key_code {
                     keyboard::KeyCode::Enter
                     | keyboard::KeyCode::NumpadEnter => {
-                        if let Some(on_submit) = on_submit.clone() {
+                        if let Some(on_submit_with) = on_submit_with {
+                            shell.publish((on_submit_with)(modifiers));
+                        } else if let Some(on_submit) = on_submit.clone() {
                             shell.publish(on_submit);
                         }
                     }
@@ -379,11 +1200,26 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.backspace();
+                        // Without a selection, there may be nothing to remove
+                        if state.cursor.selection(value).is_some()
+                            || state.cursor.start(value) > 0
+                        {
+                            state.history.record(
+                                history::Kind::Delete,
+                                value,
+                                state.cursor,
+                                history_depth,
+                            );
+
+                            let mut editor =
+                                Editor::new(value, &mut state.cursor);
+                            editor.backspace();
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                            let message = (on_change)(editor.contents());
+                            shell.publish(message);
+
+                            state.history.commit(value);
+                        }
                     }
                     keyboard::KeyCode::Delete => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -399,13 +1235,30 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.delete();
+                        // Without a selection, there may be nothing to remove
+                        if state.cursor.selection(value).is_some()
+                            || state.cursor.end(value) < value.len()
+                        {
+                            state.history.record(
+                                history::Kind::Delete,
+                                value,
+                                state.cursor,
+                                history_depth,
+                            );
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                            let mut editor =
+                                Editor::new(value, &mut state.cursor);
+                            editor.delete();
+
+                            let message = (on_change)(editor.contents());
+                            shell.publish(message);
+
+                            state.history.commit(value);
+                        }
                     }
                     keyboard::KeyCode::Left => {
+                        state.history.seal();
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && !is_secure
                         {
@@ -421,6 +1274,8 @@ where
                         }
                     }
                     keyboard::KeyCode::Right => {
+                        state.history.seal();
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && !is_secure
                         {
@@ -436,6 +1291,8 @@ where
                         }
                     }
                     keyboard::KeyCode::Home => {
+                        state.history.seal();
+
                         if modifiers.shift() {
                             state
                                 .cursor
@@ -445,6 +1302,8 @@ where
                         }
                     }
                     keyboard::KeyCode::End => {
+                        state.history.seal();
+
                         if modifiers.shift() {
                             state.cursor.select_range(
                                 state.cursor.start(value),
@@ -455,64 +1314,102 @@ where
                         }
                     }
                     keyboard::KeyCode::C
-                        if state.keyboard_modifiers.command() =>
+                        if state.keyboard_modifiers.command() && !is_secure =>
                     {
-                        match state.cursor.selection(value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
+                        if let Some((start, end)) =
+                            state.cursor.selection(value)
+                        {
+                            clipboard
+                                .write(value.select(start, end).to_string());
                         }
                     }
                     keyboard::KeyCode::X
-                        if state.keyboard_modifiers.command() =>
+                        if state.keyboard_modifiers.command() && !is_secure =>
                     {
-                        match state.cursor.selection(value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
-                        }
+                        // Without a selection, there is nothing to cut
+                        if let Some((start, end)) =
+                            state.cursor.selection(value)
+                        {
+                            clipboard
+                                .write(value.select(start, end).to_string());
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.delete();
+                            state.history.record(
+                                history::Kind::Delete,
+                                value,
+                                state.cursor,
+                                history_depth,
+                            );
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                            let mut editor =
+                                Editor::new(value, &mut state.cursor);
+                            editor.delete();
+
+                            let message = (on_change)(editor.contents());
+                            shell.publish(message);
+
+                            state.history.commit(value);
+                        }
                     }
                     keyboard::KeyCode::V => {
                         if state.keyboard_modifiers.command() {
-                            let content = match state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .read()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
+                            if let Some(on_paste) = on_paste {
+                                if let Some(content) = clipboard.read() {
+                                    shell.publish((on_paste)(content));
                                 }
-                            };
 
-                            let mut editor =
-                                Editor::new(value, &mut state.cursor);
+                                return event::Status::Captured;
+                            }
 
-                            editor.paste(content.clone());
+                            let content = match state.is_pasting.take() {
+                                Some(content) => content,
+                                None => match prepare_paste(
+                                    clipboard.read().unwrap_or_default(),
+                                    value,
+                                    state,
+                                    paste_filter,
+                                    filter,
+                                    numeric,
+                                ) {
+                                    Some(content) => content,
+                                    None => return event::Status::Captured,
+                                },
+                            };
 
-                            let message = (on_change)(editor.contents());
-                            shell.publish(message);
+                            paste(
+                                &content,
+                                value,
+                                state,
+                                shell,
+                                on_change,
+                                max_length,
+                                history_depth,
+                            );
 
                             state.is_pasting = Some(content);
                         } else {
                             state.is_pasting = None;
                         }
                     }
+                    keyboard::KeyCode::Z | keyboard::KeyCode::Y
+                        if state.keyboard_modifiers.command() =>
+                    {
+                        let is_redo = key_code == keyboard::KeyCode::Y
+                            || state.keyboard_modifiers.shift();
+
+                        let entry = if is_redo {
+                            state.history.redo(value, state.cursor)
+                        } else {
+                            state.history.undo(value, state.cursor)
+                        };
+
+                        if let Some(entry) = entry {
+                            *value = entry.value;
+                            state.cursor = entry.cursor;
+
+                            let message = (on_change)(value.to_string());
+                            shell.publish(message);
+                        }
+                    }
                     keyboard::KeyCode::A
                         if state.keyboard_modifiers.command() =>
                     {
@@ -521,19 +1418,57 @@ where
                     keyboard::KeyCode::Escape => {
                         state.is_focused = false;
                         state.is_dragging = false;
+                        state.dragged_word = None;
                         state.is_pasting = None;
 
                         state.keyboard_modifiers =
                             keyboard::Modifiers::default();
                     }
-                    keyboard::KeyCode::Tab
-                    | keyboard::KeyCode::Up
-                    | keyboard::KeyCode::Down => {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let numeric = match numeric {
+                            Some(numeric) => numeric,
+                            None => return event::Status::Ignored,
+                        };
+
+                        let stepped = Value::new(&numeric.step_by(
+                            &value.to_string(),
+                            key_code == keyboard::KeyCode::Up,
+                        ));
+
+                        if stepped.len() > max_length.unwrap_or(usize::MAX) {
+                            return event::Status::Captured;
+                        }
+
+                        state.history.record(
+                            history::Kind::Paste,
+                            value,
+                            state.cursor,
+                            history_depth,
+                        );
+
+                        *value = stepped;
+                        state.cursor.move_to(value.len());
+
+                        let message = (on_change)(value.to_string());
+                        shell.publish(message);
+
+                        state.history.commit(value);
+                    }
+                    keyboard::KeyCode::Tab => {
                         return event::Status::Ignored;
                     }
                     _ => {}
                 }
 
+                if state.cursor.selection(value) != selection {
+                    update_primary(
+                        clipboard,
+                        value,
+                        state,
+                        is_secure || !primary_selection,
+                    );
+                }
+
                 return event::Status::Captured;
             }
         }
@@ -545,6 +1480,8 @@ where
                     keyboard::KeyCode::V => {
                         state.is_pasting = None;
                     }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                        if numeric.is_some() => {}
                     keyboard::KeyCode::Tab
                     | keyboard::KeyCode::Up
                     | keyboard::KeyCode::Down => {
@@ -582,20 +1519,30 @@ pub fn draw<Renderer>(
     placeholder: &str,
     size: Option<u16>,
     font: &Renderer::Font,
-    is_secure: bool,
+    spacing: text::Spacing,
+    secure_character: Option<char>,
+    is_disabled: bool,
+    is_error: bool,
+    leading_icon: Option<&Icon<Renderer::Font>>,
+    trailing_icon: Option<&Icon<Renderer::Font>>,
     style_sheet: &dyn StyleSheet,
 ) where
     Renderer: text::Renderer,
 {
-    let secure_value = is_secure.then(|| value.secure());
-    let value = secure_value.as_ref().unwrap_or(&value);
+    let value = displayed(value, secure_character);
+    let value = value.as_ref();
 
     let bounds = layout.bounds();
     let text_bounds = layout.children().next().unwrap().bounds();
 
     let is_mouse_over = bounds.contains(cursor_position);
+    let is_focused = state.is_focused() && !is_disabled;
 
-    let style = if state.is_focused() {
+    let style = if is_disabled {
+        style_sheet.disabled()
+    } else if is_error {
+        style_sheet.errored()
+    } else if is_focused {
         style_sheet.focused()
     } else if is_mouse_over {
         style_sheet.hovered()
@@ -613,21 +1560,48 @@ pub fn draw<Renderer>(
         style.background,
     );
 
+    let icon_layouts = layout.children().skip(1);
+
+    for (icon, icon_layout) in leading_icon
+        .into_iter()
+        .chain(trailing_icon)
+        .zip(icon_layouts)
+    {
+        let icon_bounds = icon_layout.bounds();
+
+        renderer.fill_text(Text {
+            content: &icon.code_point.to_string(),
+            color: style.icon_color,
+            font: icon.font.clone(),
+            bounds: Rectangle {
+                y: icon_bounds.center_y(),
+                ..icon_bounds
+            },
+            size: f32::from(
+                icon.size.unwrap_or_else(|| renderer.default_size()),
+            ),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            spacing: text::Spacing::default(),
+            rotation: text::Rotation::None,
+        });
+    }
+
     let text = value.to_string();
     let size = size.unwrap_or(renderer.default_size());
 
-    let (cursor, offset) = if state.is_focused() {
-        match state.cursor.state(&value) {
+    let (cursor, offset) = if is_focused {
+        let (cursor, position) = match state.cursor.state(value) {
             cursor::State::Index(position) => {
-                let (text_value_width, offset) =
-                    measure_cursor_and_scroll_offset(
-                        renderer,
-                        text_bounds,
-                        &value,
-                        size,
-                        position,
-                        font.clone(),
-                    );
+                let (text_value_width, _) = measure_cursor_and_scroll_offset(
+                    renderer,
+                    text_bounds,
+                    &value,
+                    size,
+                    position,
+                    font.clone(),
+                    spacing,
+                );
 
                 (
                     Some((
@@ -642,34 +1616,34 @@ pub fn draw<Renderer>(
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
                         },
-                        style_sheet.value_color(),
+                        style.value_color,
                     )),
-                    offset,
+                    position,
                 )
             }
             cursor::State::Selection { start, end } => {
                 let left = start.min(end);
                 let right = end.max(start);
 
-                let (left_position, left_offset) =
-                    measure_cursor_and_scroll_offset(
-                        renderer,
-                        text_bounds,
-                        &value,
-                        size,
-                        left,
-                        font.clone(),
-                    );
+                let (left_position, _) = measure_cursor_and_scroll_offset(
+                    renderer,
+                    text_bounds,
+                    &value,
+                    size,
+                    left,
+                    font.clone(),
+                    spacing,
+                );
 
-                let (right_position, right_offset) =
-                    measure_cursor_and_scroll_offset(
-                        renderer,
-                        text_bounds,
-                        &value,
-                        size,
-                        right,
-                        font.clone(),
-                    );
+                let (right_position, _) = measure_cursor_and_scroll_offset(
+                    renderer,
+                    text_bounds,
+                    &value,
+                    size,
+                    right,
+                    font.clone(),
+                    spacing,
+                );
 
                 let width = right_position - left_position;
 
@@ -686,16 +1660,25 @@ pub fn draw<Renderer>(
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
                         },
-                        style_sheet.selection_color(),
+                        style.selection_color,
                     )),
-                    if end == right {
-                        right_offset
-                    } else {
-                        left_offset
-                    },
+                    end,
                 )
             }
-        }
+        };
+
+        let offset = scroll_offset(
+            renderer,
+            text_bounds,
+            font.clone(),
+            spacing,
+            size,
+            value,
+            position,
+            state.scroll_offset,
+        );
+
+        (cursor, offset)
     } else {
         (None, 0.0)
     };
@@ -704,6 +1687,7 @@ pub fn draw<Renderer>(
         if text.is_empty() { placeholder } else { &text },
         size,
         font.clone(),
+        spacing,
     );
 
     let render = |renderer: &mut Renderer| {
@@ -714,9 +1698,9 @@ pub fn draw<Renderer>(
         renderer.fill_text(Text {
             content: if text.is_empty() { placeholder } else { &text },
             color: if text.is_empty() {
-                style_sheet.placeholder_color()
+                style.placeholder_color
             } else {
-                style_sheet.value_color()
+                style.value_color
             },
             font: font.clone(),
             bounds: Rectangle {
@@ -727,6 +1711,8 @@ pub fn draw<Renderer>(
             size: f32::from(size),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            spacing,
+            rotation: text::Rotation::None,
         });
     };
 
@@ -743,8 +1729,9 @@ pub fn draw<Renderer>(
 pub fn mouse_interaction(
     layout: Layout<'_>,
     cursor_position: Point,
+    is_disabled: bool,
 ) -> mouse::Interaction {
-    if layout.bounds().contains(cursor_position) {
+    if !is_disabled && layout.bounds().contains(cursor_position) {
         mouse::Interaction::Text
     } else {
         mouse::Interaction::default()
@@ -770,7 +1757,16 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(renderer, limits, self.width, self.padding, self.size)
+        layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+            self.spacing,
+            self.leading_icon.as_ref(),
+            self.trailing_icon.as_ref(),
+        )
     }
 
     fn on_event(
@@ -782,7 +1778,13 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        update(
+        let previous_position = self.state.cursor_position(&self.value);
+
+        let secure_character = self.mask();
+        let previous_value =
+            self.on_change_parsed.as_ref().map(|_| self.value.clone());
+
+        let status = update(
             event,
             layout,
             cursor_position,
@@ -790,13 +1792,64 @@ where
             clipboard,
             shell,
             &mut self.value,
+            Properties {
+                size: self.size,
+                font: &self.font,
+                spacing: self.spacing,
+                secure_character,
+                is_disabled: self.is_disabled,
+                on_change: self.on_change.as_ref(),
+                on_submit: &self.on_submit,
+                on_submit_with: self.on_submit_with.as_deref(),
+                on_icon_press: &self.on_icon_press,
+                on_paste: self.on_paste.as_deref(),
+                paste_filter: self.paste_filter.as_deref(),
+                max_length: self.max_length,
+                filter: self.filter.as_deref(),
+                numeric: self.numeric.as_ref(),
+                history_depth: self.history_depth,
+                primary_selection: self.primary_selection,
+                on_focus: &self.on_focus,
+                on_blur: &self.on_blur,
+            },
+            || &mut self.state,
+        );
+
+        scroll_to_cursor(
+            renderer,
+            layout,
+            &self.value,
             self.size,
             &self.font,
-            self.is_secure,
-            self.on_change.as_ref(),
-            &self.on_submit,
-            || &mut self.state,
-        )
+            self.spacing,
+            secure_character,
+            self.state,
+        );
+
+        if let Some(on_cursor_move) = &self.on_cursor_move {
+            let new_position = self.state.cursor_position(&self.value);
+
+            if new_position != previous_position {
+                shell.publish((on_cursor_move)(new_position));
+            }
+        }
+
+        if let (Some(on_change_parsed), Some(previous_value)) =
+            (&self.on_change_parsed, previous_value)
+        {
+            if self.value != previous_value {
+                let value = self.value.to_string();
+
+                let parsed = match &self.numeric {
+                    Some(numeric) => numeric.parse(&value),
+                    None => NumericOptions::default().parse(&value),
+                };
+
+                shell.publish((on_change_parsed)(parsed));
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -806,7 +1859,7 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position)
+        mouse_interaction(layout, cursor_position, self.is_disabled)
     }
 
     fn draw(
@@ -819,6 +1872,14 @@ where
     ) {
         self.draw(renderer, layout, cursor_position, None)
     }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operate(self.state, self.id.as_ref(), self.is_disabled, operation)
+    }
 }
 
 impl<'a, Message, Renderer> From<TextInput<'a, Message, Renderer>>
@@ -838,12 +1899,15 @@ where
 #[derive(Debug, Default, Clone)]
 pub struct State {
     is_focused: bool,
+    is_focus_reported: bool,
     is_dragging: bool,
+    dragged_word: Option<(usize, usize)>,
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
-    // TODO: Add stateful horizontal scrolling offset
+    scroll_offset: f32,
 }
 
 impl State {
@@ -856,11 +1920,15 @@ impl State {
     pub fn focused() -> Self {
         Self {
             is_focused: true,
+            is_focus_reported: true,
             is_dragging: false,
+            dragged_word: None,
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            scroll_offset: 0.0,
         }
     }
 
@@ -874,14 +1942,30 @@ impl State {
         self.cursor
     }
 
+    /// Returns the position of the cursor of the [`TextInput`] for the given
+    /// [`Value`], as a grapheme index.
+    ///
+    /// When there is a selection, this is the end that moves while selecting.
+    pub fn cursor_position(&self, value: &Value) -> usize {
+        self.cursor.end(value)
+    }
+
+    /// Returns the range of graphemes currently selected in the [`TextInput`]
+    /// for the given [`Value`], if any.
+    pub fn selection(&self, value: &Value) -> Option<Range<usize>> {
+        self.cursor.selection(value).map(|(start, end)| start..end)
+    }
+
     /// Focuses the [`TextInput`].
     pub fn focus(&mut self) {
         self.is_focused = true;
+        self.last_click = None;
     }
 
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = false;
+        self.last_click = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -905,6 +1989,38 @@ impl State {
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+impl operation::TextInput for State {
+    fn move_cursor_to_front(&mut self) {
+        State::move_cursor_to_front(self)
+    }
+
+    fn move_cursor_to_end(&mut self) {
+        State::move_cursor_to_end(self)
+    }
+
+    fn move_cursor_to(&mut self, position: usize) {
+        State::move_cursor_to(self, position)
+    }
+
+    fn select_all(&mut self) {
+        State::select_all(self)
+    }
+}
+
 mod platform {
     use crate::keyboard;
 
@@ -921,6 +2037,7 @@ fn offset<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
     font: Renderer::Font,
+    spacing: text::Spacing,
     size: u16,
     value: &Value,
     state: &State,
@@ -929,55 +2046,291 @@ where
     Renderer: text::Renderer,
 {
     if state.is_focused() {
-        let cursor = state.cursor();
-
-        let focus_position = match cursor.state(value) {
-            cursor::State::Index(i) => i,
-            cursor::State::Selection { end, .. } => end,
-        };
-
-        let (_, offset) = measure_cursor_and_scroll_offset(
+        scroll_offset(
             renderer,
             text_bounds,
-            value,
-            size,
-            focus_position,
             font,
-        );
-
-        offset
+            spacing,
+            size,
+            value,
+            state.cursor.end(value),
+            state.scroll_offset,
+        )
     } else {
         0.0
     }
 }
 
-fn measure_cursor_and_scroll_offset<Renderer>(
+/// The space, in logical pixels, kept between the cursor of a [`TextInput`]
+/// and the edges of its text bounds.
+const CURSOR_MARGIN: f32 = 5.0;
+
+/// Computes the scrolling offset of a [`TextInput`] that keeps the cursor
+/// visible, moving the `current` offset as little as possible.
+fn scroll_offset<Renderer>(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    spacing: text::Spacing,
+    size: u16,
+    value: &Value,
+    cursor_index: usize,
+    current: f32,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let (cursor_x, _) = measure_cursor_and_scroll_offset(
+        renderer,
+        text_bounds,
+        value,
+        size,
+        cursor_index,
+        font.clone(),
+        spacing,
+    );
+
+    let text_width =
+        renderer.measure_width(&value.to_string(), size, font, spacing);
+
+    // Never leave blank space on the right while text is hidden on the left
+    let max_offset = (text_width + CURSOR_MARGIN - text_bounds.width).max(0.0);
+
+    current
+        .max(cursor_x + CURSOR_MARGIN - text_bounds.width)
+        .min(cursor_x - CURSOR_MARGIN)
+        .min(max_offset)
+        .max(0.0)
+}
+
+/// Updates the scrolling offset of the [`State`] of a [`TextInput`], so its
+/// cursor stays visible.
+///
+/// This should be called after processing an event with [`update`].
+pub fn scroll_to_cursor<Renderer>(
+    renderer: &Renderer,
+    layout: Layout<'_>,
+    value: &Value,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    spacing: text::Spacing,
+    secure_character: Option<char>,
+    state: &mut State,
+) where
+    Renderer: text::Renderer,
+{
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let value = displayed(value, secure_character);
+    let size = size.unwrap_or(renderer.default_size());
+
+    state.scroll_offset = offset(
+        renderer,
+        text_bounds,
+        font.clone(),
+        spacing,
+        size,
+        &value,
+        state,
+    );
+}
+
+pub(crate) fn measure_cursor_and_scroll_offset<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
     value: &Value,
     size: u16,
     cursor_index: usize,
     font: Renderer::Font,
+    spacing: text::Spacing,
 ) -> (f32, f32)
 where
     Renderer: text::Renderer,
 {
-    let text_before_cursor = value.until(cursor_index).to_string();
-
-    let text_value_width =
-        renderer.measure_width(&text_before_cursor, size, font);
+    // The caret is placed where the text is displayed, so right-to-left
+    // runs are taken into account
+    let value = value.to_string();
+    let text_before_cursor = text::bidi::before_caret(&value, cursor_index);
+
+    let mut text_value_width =
+        renderer.measure_width(&text_before_cursor, size, font, spacing);
+
+    // Letter spacing is only added between characters, but the cursor
+    // should be placed after the spacing of the last one
+    if !text_before_cursor.is_empty() {
+        text_value_width += spacing.letter_spacing;
+    }
 
-    let offset = ((text_value_width + 5.0) - text_bounds.width).max(0.0);
+    let offset =
+        ((text_value_width + CURSOR_MARGIN) - text_bounds.width).max(0.0);
 
     (text_value_width, offset)
 }
 
+/// Replaces every line break in the given text with a space, so it can be
+/// inserted in a single-line [`TextInput`].
+fn single_line(text: &str) -> String {
+    text.replace("\r\n", " ").replace(['\r', '\n'], " ")
+}
+
+/// Returns the [`Value`] to display, obscured with the `secure_character` if
+/// there is one.
+fn displayed(value: &Value, secure_character: Option<char>) -> Cow<'_, Value> {
+    match secure_character {
+        Some(character) => Cow::Owned(value.secure_with(character)),
+        None => Cow::Borrowed(value),
+    }
+}
+
+/// Computes the position where the input method editor of the platform
+/// should be placed for the given [`State`].
+fn ime_position<Renderer>(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    spacing: text::Spacing,
+    size: Option<u16>,
+    value: &Value,
+    secure_character: Option<char>,
+    state: &State,
+) -> Point
+where
+    Renderer: text::Renderer,
+{
+    let value = displayed(value, secure_character);
+    let value = value.as_ref();
+    let size = size.unwrap_or(renderer.default_size());
+
+    let (cursor_x, _) = measure_cursor_and_scroll_offset(
+        renderer,
+        text_bounds,
+        value,
+        size,
+        state.cursor.end(value),
+        font.clone(),
+        spacing,
+    );
+
+    let offset =
+        offset(renderer, text_bounds, font, spacing, size, value, state);
+
+    Point::new(
+        text_bounds.x + cursor_x - offset,
+        text_bounds.y + text_bounds.height,
+    )
+}
+
+/// Returns whether the given character passes the `filter`, if any.
+fn is_accepted(filter: Option<&dyn Fn(char) -> bool>, c: char) -> bool {
+    match filter {
+        Some(filter) => filter(c),
+        None => true,
+    }
+}
+
+/// Turns the given clipboard `content` into the [`Value`] to be pasted in a
+/// [`TextInput`], or returns `None` if it should not be pasted at all.
+fn prepare_paste(
+    content: String,
+    value: &Value,
+    state: &State,
+    paste_filter: Option<&dyn Fn(String) -> String>,
+    filter: Option<&dyn Fn(char) -> bool>,
+    numeric: Option<&NumericOptions>,
+) -> Option<Value> {
+    let content = match paste_filter {
+        Some(paste_filter) => (paste_filter)(content),
+        None => content,
+    };
+
+    let content: String = single_line(&content)
+        .chars()
+        .filter(|c| !c.is_control() && is_accepted(filter, *c))
+        .collect();
+
+    match numeric {
+        Some(numeric) => numeric.accept(value, &state.cursor, &content),
+        None => Some(Value::new(&content)),
+    }
+}
+
+/// Pastes the given `content` in place of the current selection of a
+/// [`TextInput`], as much of it as the `max_length` allows.
+///
+/// When none of it is allowed, nothing is recorded or published.
+fn paste<Message>(
+    content: &Value,
+    value: &mut Value,
+    state: &mut State,
+    shell: &mut Shell<'_, Message>,
+    on_change: &dyn Fn(String) -> Message,
+    max_length: Option<usize>,
+    history_depth: usize,
+) {
+    let length = available_length(value, &state.cursor, max_length);
+    let content = content.select(0, length);
+
+    if content.is_empty() {
+        return;
+    }
+
+    state.history.record(
+        history::Kind::Paste,
+        value,
+        state.cursor,
+        history_depth,
+    );
+
+    let mut editor = Editor::new(value, &mut state.cursor);
+
+    editor.paste(content);
+
+    let message = (on_change)(editor.contents());
+    shell.publish(message);
+
+    state.history.commit(value);
+}
+
+/// Writes the current selection of a [`TextInput`] to the primary selection
+/// of the [`Clipboard`], unless it is `is_private`.
+fn update_primary(
+    clipboard: &mut dyn Clipboard,
+    value: &Value,
+    state: &State,
+    is_private: bool,
+) {
+    if is_private {
+        return;
+    }
+
+    if let Some((start, end)) = state.cursor.selection(value) {
+        clipboard.write_primary(value.select(start, end).to_string());
+    }
+}
+
+/// Returns the amount of graphemes that can be inserted in place of the
+/// current selection without exceeding the `max_length`.
+fn available_length(
+    value: &Value,
+    cursor: &Cursor,
+    max_length: Option<usize>,
+) -> usize {
+    let selected = cursor
+        .selection(value)
+        .map(|(start, end)| end - start)
+        .unwrap_or(0);
+
+    max_length
+        .map(|max_length| max_length.saturating_sub(value.len() - selected))
+        .unwrap_or(usize::MAX)
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
     font: Renderer::Font,
+    spacing: text::Spacing,
     size: Option<u16>,
     value: &Value,
     state: &State,
@@ -988,8 +2341,15 @@ where
 {
     let size = size.unwrap_or(renderer.default_size());
 
-    let offset =
-        offset(renderer, text_bounds, font.clone(), size, &value, &state);
+    let offset = offset(
+        renderer,
+        text_bounds,
+        font.clone(),
+        spacing,
+        size,
+        &value,
+        &state,
+    );
 
     renderer
         .hit_test(
@@ -999,6 +2359,433 @@ where
             Size::INFINITY,
             Point::new(x + offset, text_bounds.height / 2.0),
             true,
+            spacing,
         )
         .map(text::Hit::cursor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Monospace;
+    use crate::Font;
+
+    fn icon(side: Side) -> Icon<Font> {
+        Icon {
+            font: Font::Default,
+            code_point: '×',
+            size: None,
+            spacing: 10,
+            side,
+        }
+    }
+
+    fn children(
+        leading_icon: Option<&Icon<Font>>,
+        trailing_icon: Option<&Icon<Font>>,
+    ) -> Vec<Rectangle> {
+        let node = layout(
+            &Monospace,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            Length::Fill,
+            Padding::new(5),
+            None,
+            text::Spacing::default(),
+            leading_icon,
+            trailing_icon,
+        );
+
+        assert_eq!(node.bounds().width, 200.0);
+
+        node.children().iter().map(layout::Node::bounds).collect()
+    }
+
+    fn focus(state: &mut State, id: &Id, is_disabled: bool) {
+        let mut operation = operation::focusable::focus(id.0.clone());
+
+        operate::<()>(state, Some(id), is_disabled, &mut operation);
+
+        if let operation::Outcome::Chain(mut next) = operation.finish() {
+            operate(state, Some(id), is_disabled, next.as_mut());
+        }
+    }
+
+    #[test]
+    fn cursor_is_measured_where_right_to_left_text_is_displayed() {
+        // "ab" followed by the Hebrew letters alef, bet and gimel, which
+        // are displayed in reverse
+        let value = Value::new("ab\u{5D0}\u{5D1}\u{5D2}");
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 20.0));
+
+        let cursor = |index| {
+            measure_cursor_and_scroll_offset(
+                &Monospace,
+                bounds,
+                &value,
+                20,
+                index,
+                Font::Default,
+                text::Spacing::default(),
+            )
+            .0
+        };
+
+        assert_eq!(cursor(1), 10.0);
+        assert_eq!(cursor(2), 50.0);
+        assert_eq!(cursor(3), 40.0);
+        assert_eq!(cursor(5), 20.0);
+    }
+
+    #[test]
+    fn focus_operation_focuses_enabled_input() {
+        let id = Id::new("input");
+        let mut state = State::new();
+
+        focus(&mut state, &id, false);
+
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn focus_operation_skips_disabled_input() {
+        let id = Id::new("input");
+        let mut state = State::new();
+
+        focus(&mut state, &id, true);
+
+        assert!(!state.is_focused());
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(String),
+        Focused,
+        Blurred,
+    }
+
+    struct Input {
+        value: Value,
+        state: State,
+        max_length: Option<usize>,
+        primary_selection: bool,
+        ime_position: Option<Point>,
+    }
+
+    impl Input {
+        fn new(value: &str) -> Self {
+            Self {
+                value: Value::new(value),
+                state: State::new(),
+                max_length: None,
+                primary_selection: false,
+                ime_position: None,
+            }
+        }
+
+        fn update(
+            &mut self,
+            event: Event,
+            cursor_position: Point,
+            clipboard: &mut dyn Clipboard,
+        ) -> Vec<Message> {
+            let node = layout(
+                &Monospace,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+                Length::Fill,
+                Padding::new(5),
+                None,
+                text::Spacing::default(),
+                None,
+                None,
+            );
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = update(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &Monospace,
+                clipboard,
+                &mut shell,
+                &mut self.value,
+                Properties {
+                    size: None,
+                    font: &Font::Default,
+                    spacing: text::Spacing::default(),
+                    secure_character: None,
+                    is_disabled: false,
+                    on_change: &Message::Changed,
+                    on_submit: &None,
+                    on_submit_with: None,
+                    on_icon_press: &None,
+                    on_paste: None,
+                    paste_filter: None,
+                    max_length: self.max_length,
+                    filter: None,
+                    numeric: None,
+                    history_depth: DEFAULT_HISTORY_DEPTH,
+                    primary_selection: self.primary_selection,
+                    on_focus: &Some(Message::Focused),
+                    on_blur: &Some(Message::Blurred),
+                },
+                || &mut self.state,
+            );
+
+            self.ime_position = shell.ime_position();
+
+            messages
+        }
+
+        fn event(&mut self, event: Event) -> Vec<Message> {
+            self.update(event, Point::new(-1.0, -1.0), &mut clipboard::Null)
+        }
+    }
+
+    fn cursor_moved() -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        })
+    }
+
+    #[test]
+    fn last_submit_setter_wins() {
+        let mut state = State::new();
+
+        let input = TextInput::<_, Monospace>::new(
+            &mut state,
+            "",
+            "",
+            Message::Changed,
+        )
+        .on_submit_with(|_| Message::Focused)
+        .on_submit(Message::Blurred);
+
+        assert_eq!(input.on_submit, Some(Message::Blurred));
+        assert!(input.on_submit_with.is_none());
+
+        let input = input.on_submit_with(|_| Message::Focused);
+
+        assert_eq!(input.on_submit, None);
+        assert!(input.on_submit_with.is_some());
+    }
+
+    #[test]
+    fn focus_given_by_operation_is_reported() {
+        let id = Id::new("input");
+        let mut input = Input::new("");
+
+        focus(&mut input.state, &id, false);
+
+        assert_eq!(input.event(cursor_moved()), vec![Message::Focused]);
+        assert_eq!(input.event(cursor_moved()), vec![]);
+
+        let click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(input.event(click), vec![Message::Blurred]);
+    }
+
+    fn key_pressed(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn removing_nothing_is_not_an_edit() {
+        let mut input = Input::new("abc");
+        input.state = State::focused();
+
+        input.state.move_cursor_to_front();
+        assert_eq!(
+            input.event(key_pressed(keyboard::KeyCode::Backspace)),
+            vec![]
+        );
+
+        input.state.move_cursor_to_end();
+        assert_eq!(input.event(key_pressed(keyboard::KeyCode::Delete)), vec![]);
+
+        assert_eq!(input.value.to_string(), "abc");
+        assert!(input
+            .state
+            .history
+            .undo(&input.value, input.state.cursor)
+            .is_none());
+
+        assert_eq!(
+            input.event(key_pressed(keyboard::KeyCode::Backspace)),
+            vec![Message::Changed(String::from("ab"))]
+        );
+    }
+
+    #[test]
+    fn ime_position_is_only_requested_while_focused() {
+        let mut input = Input::new("abc");
+
+        let _ = input.event(cursor_moved());
+        assert_eq!(input.ime_position, None);
+
+        input.state = State::focused();
+        input.state.move_cursor_to_end();
+
+        let _ = input.event(cursor_moved());
+        assert!(input.ime_position.is_some());
+
+        let click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(input.event(click), vec![Message::Blurred]);
+        assert_eq!(input.ime_position, None);
+    }
+
+    struct Contents(String);
+
+    impl Clipboard for Contents {
+        fn read(&self) -> Option<String> {
+            Some(self.0.clone())
+        }
+
+        fn write(&mut self, contents: String) {
+            self.0 = contents;
+        }
+    }
+
+    #[test]
+    fn pasting_at_max_length_is_not_an_edit() {
+        let mut input = Input::new("abc");
+        input.state = State::focused();
+        input.state.move_cursor_to_end();
+        input.max_length = Some(3);
+
+        let paste = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::V,
+            modifiers: keyboard::Modifiers::COMMAND,
+        });
+
+        let mut clipboard = Contents(String::from("def"));
+
+        let _ = input.update(
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+            Point::ORIGIN,
+            &mut clipboard,
+        );
+
+        assert_eq!(
+            input.update(paste.clone(), Point::ORIGIN, &mut clipboard),
+            vec![]
+        );
+        assert_eq!(input.value.to_string(), "abc");
+        assert!(input
+            .state
+            .history
+            .undo(&input.value, input.state.cursor)
+            .is_none());
+
+        input.max_length = Some(4);
+
+        assert_eq!(
+            input.update(paste, Point::ORIGIN, &mut clipboard),
+            vec![Message::Changed(String::from("abcd"))]
+        );
+    }
+
+    #[derive(Default)]
+    struct Primary(Option<String>);
+
+    impl Clipboard for Primary {
+        fn read(&self) -> Option<String> {
+            None
+        }
+
+        fn write(&mut self, _contents: String) {}
+
+        fn read_primary(&self) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn write_primary(&mut self, contents: String) {
+            self.0 = Some(contents);
+        }
+    }
+
+    #[test]
+    fn primary_selection_is_disabled_by_default() {
+        let mut input = Input::new("abc");
+        let mut primary = Primary(Some(String::from("def")));
+
+        let middle_click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle));
+
+        assert_eq!(
+            input.update(
+                middle_click.clone(),
+                Point::new(10.0, 10.0),
+                &mut primary
+            ),
+            vec![]
+        );
+
+        input.primary_selection = true;
+
+        assert_eq!(
+            input.update(middle_click, Point::new(10.0, 10.0), &mut primary),
+            vec![Message::Changed(String::from("defabc")), Message::Focused]
+        );
+    }
+
+    #[test]
+    fn selecting_writes_the_primary_selection_only_when_enabled() {
+        let mut input = Input::new("abc");
+        input.state = State::focused();
+
+        let select_all = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::A,
+            modifiers: keyboard::Modifiers::COMMAND,
+        });
+
+        let command = Event::Keyboard(keyboard::Event::ModifiersChanged(
+            keyboard::Modifiers::COMMAND,
+        ));
+
+        let mut primary = Primary::default();
+
+        let _ = input.update(command.clone(), Point::ORIGIN, &mut primary);
+        let _ = input.update(select_all.clone(), Point::ORIGIN, &mut primary);
+
+        assert_eq!(primary.0, None);
+
+        input.state = State::focused();
+        input.primary_selection = true;
+
+        let _ = input.update(command, Point::ORIGIN, &mut primary);
+        let _ = input.update(select_all, Point::ORIGIN, &mut primary);
+
+        assert_eq!(primary.0.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn leading_icon_shrinks_text() {
+        let bounds = children(Some(&icon(Side::Leading)), None);
+
+        assert_eq!(bounds[0].x, 25.0);
+        assert_eq!(bounds[0].width, 170.0);
+        assert_eq!(bounds[1].x, 5.0);
+        assert_eq!(bounds[1].width, 10.0);
+    }
+
+    #[test]
+    fn trailing_icon_shrinks_text() {
+        let bounds = children(None, Some(&icon(Side::Trailing)));
+
+        assert_eq!(bounds[0].x, 5.0);
+        assert_eq!(bounds[0].width, 170.0);
+        assert_eq!(bounds[1].x, 185.0);
+        assert_eq!(bounds[1].width, 10.0);
+    }
+}
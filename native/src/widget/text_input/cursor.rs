@@ -2,6 +2,9 @@
 use crate::widget::text_input::Value;
 
 /// The cursor of a text input.
+///
+/// The indices of a [`Cursor`] refer to grapheme clusters of a [`Value`] in
+/// logical order, regardless of the direction of the text.
 #[derive(Debug, Copy, Clone)]
 pub struct Cursor {
     state: State,
@@ -23,13 +23,18 @@ impl<'a> Editor<'a> {
             _ => {}
         }
 
+        let length = self.value.len();
+
         self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+
+        // The character may have been merged with its neighbors
+        self.cursor.move_right_by_amount(
+            self.value,
+            self.value.len().saturating_sub(length),
+        );
     }
 
     pub fn paste(&mut self, content: Value) {
-        let length = content.len();
-
         match self.cursor.selection(self.value) {
             Some((left, right)) => {
                 self.cursor.move_left(self.value);
@@ -38,9 +43,14 @@ impl<'a> Editor<'a> {
             _ => {}
         }
 
+        let length = self.value.len();
+
         self.value.insert_many(self.cursor.end(self.value), content);
 
-        self.cursor.move_right_by_amount(self.value, length);
+        self.cursor.move_right_by_amount(
+            self.value,
+            self.value.len().saturating_sub(length),
+        );
     }
 
     pub fn backspace(&mut self) {
@@ -75,3 +85,58 @@ impl<'a> Editor<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backspace(text: &str) -> String {
+        let mut value = Value::new(text);
+        let mut cursor = Cursor::default();
+
+        cursor.move_to(value.len());
+        Editor::new(&mut value, &mut cursor).backspace();
+
+        value.to_string()
+    }
+
+    fn type_in(text: &str) -> (Value, Cursor) {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for c in text.chars() {
+            Editor::new(&mut value, &mut cursor).insert(c);
+        }
+
+        (value, cursor)
+    }
+
+    #[test]
+    fn backspace_removes_zwj_sequences() {
+        assert_eq!(
+            backspace("a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            "a"
+        );
+    }
+
+    #[test]
+    fn backspace_removes_flags() {
+        assert_eq!(
+            backspace("\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}"),
+            "\u{1F1FA}\u{1F1F8}"
+        );
+    }
+
+    #[test]
+    fn backspace_removes_combining_marks() {
+        assert_eq!(backspace("cafe\u{301}"), "caf");
+    }
+
+    #[test]
+    fn typing_merges_graphemes() {
+        let (value, cursor) = type_in("e\u{301}\u{1F1FA}\u{1F1F8}x");
+
+        assert_eq!(value.len(), 3);
+        assert_eq!(cursor.end(&value), 3);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::widget::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The edit history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    last_kind: Option<Kind>,
+    current: Option<Value>,
+}
+
+/// A snapshot of the contents of a text input.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub value: Value,
+    pub cursor: Cursor,
+}
+
+/// The kind of an edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert,
+    Delete,
+    Paste,
+}
+
+impl History {
+    /// Records the given `value` and `cursor` before an edit of the given
+    /// [`Kind`] takes place.
+    ///
+    /// Consecutive insertions are grouped together in a single entry.
+    pub fn record(
+        &mut self,
+        kind: Kind,
+        value: &Value,
+        cursor: Cursor,
+        depth: usize,
+    ) {
+        self.sync(value);
+
+        let is_grouped =
+            kind == Kind::Insert && self.last_kind == Some(Kind::Insert);
+
+        if !is_grouped {
+            self.undo.push_back(Entry {
+                value: value.clone(),
+                cursor,
+            });
+
+            while self.undo.len() > depth {
+                let _ = self.undo.pop_front();
+            }
+        }
+
+        self.redo.clear();
+        self.last_kind = Some(kind);
+    }
+
+    /// Stores the resulting `value` of an edit.
+    pub fn commit(&mut self, value: &Value) {
+        self.current = Some(value.clone());
+    }
+
+    /// Stops grouping consecutive insertions with the previous ones.
+    pub fn seal(&mut self) {
+        self.last_kind = None;
+    }
+
+    /// Reverts the last edit, returning the [`Entry`] to restore.
+    pub fn undo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        self.sync(value);
+
+        let entry = self.undo.pop_back()?;
+
+        self.redo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        self.restore(entry)
+    }
+
+    /// Reapplies the last reverted edit, returning the [`Entry`] to restore.
+    pub fn redo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        self.sync(value);
+
+        let entry = self.redo.pop()?;
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        self.restore(entry)
+    }
+
+    fn restore(&mut self, entry: Entry) -> Option<Entry> {
+        self.last_kind = None;
+        self.current = Some(entry.value.clone());
+
+        Some(entry)
+    }
+
+    /// Clears the history if the `value` was changed by someone else since
+    /// the last edit.
+    fn sync(&mut self, value: &Value) {
+        if let Some(current) = &self.current {
+            if current != value {
+                *self = Self::default();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(history: &mut History, kind: Kind, before: &str, after: &str) {
+        history.record(kind, &Value::new(before), Cursor::default(), 10);
+        history.commit(&Value::new(after));
+    }
+
+    #[test]
+    fn groups_consecutive_insertions() {
+        let mut history = History::default();
+
+        edit(&mut history, Kind::Insert, "", "a");
+        edit(&mut history, Kind::Insert, "a", "ab");
+        edit(&mut history, Kind::Delete, "ab", "a");
+
+        let entry = history.undo(&Value::new("a"), Cursor::default());
+        assert_eq!(entry.unwrap().value, Value::new("ab"));
+
+        let entry = history.undo(&Value::new("ab"), Cursor::default());
+        assert_eq!(entry.unwrap().value, Value::new(""));
+
+        let entry = history.redo(&Value::new(""), Cursor::default());
+        assert_eq!(entry.unwrap().value, Value::new("ab"));
+    }
+
+    #[test]
+    fn clears_on_external_change() {
+        let mut history = History::default();
+
+        edit(&mut history, Kind::Insert, "", "a");
+
+        assert!(history
+            .undo(&Value::new("changed"), Cursor::default())
+            .is_none());
+    }
+
+    #[test]
+    fn respects_depth() {
+        let mut history = History::default();
+
+        for i in 0..20 {
+            history.record(
+                Kind::Delete,
+                &Value::new(&i.to_string()),
+                Cursor::default(),
+                5,
+            );
+        }
+
+        assert_eq!(history.undo.len(), 5);
+    }
+}
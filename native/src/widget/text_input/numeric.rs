@@ -0,0 +1,241 @@
+//! Restrict a text input to numbers.
+use crate::widget::text_input::{Cursor, Value};
+
+/// The options of a numeric text input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericOptions {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    allow_negative: bool,
+    step: f64,
+}
+
+impl NumericOptions {
+    /// Creates new [`NumericOptions`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the character used to separate the integer part of a number
+    /// from its fractional part.
+    ///
+    /// By default, the dot ('.') character is used. If the thousands
+    /// separator is the same character, it becomes the previous decimal
+    /// separator, so `decimal_separator(',')` alone groups thousands with
+    /// dots.
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        if self.thousands_separator == Some(separator) {
+            self.thousands_separator = Some(self.decimal_separator)
+                .filter(|previous| *previous != separator);
+        }
+
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the character used to group the thousands of a number, if any.
+    ///
+    /// Thousands separators are stripped from any pasted content. By
+    /// default, the comma (',') character is used. If the decimal separator
+    /// is the same character, it becomes the previous thousands separator,
+    /// or the thousands are not grouped when there was none.
+    pub fn thousands_separator(mut self, separator: Option<char>) -> Self {
+        if separator == Some(self.decimal_separator) {
+            match self.thousands_separator {
+                Some(previous) if Some(previous) != separator => {
+                    self.decimal_separator = previous;
+                }
+                _ => {
+                    self.thousands_separator = None;
+                    return self;
+                }
+            }
+        }
+
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Sets whether negative numbers are accepted.
+    pub fn allow_negative(mut self, allow_negative: bool) -> Self {
+        self.allow_negative = allow_negative;
+        self
+    }
+
+    /// Sets the amount added or subtracted when the arrow keys are pressed.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Parses the given text as a number, following these options.
+    ///
+    /// On failure, the original text is returned.
+    pub fn parse(&self, text: &str) -> Result<f64, String> {
+        let normalized: String = text
+            .chars()
+            .filter(|c| Some(*c) != self.thousands_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+
+        normalized.trim().parse().map_err(|_| String::from(text))
+    }
+
+    /// Returns the contents that should be inserted in place of the current
+    /// selection when the given `input` is entered, or `None` if the
+    /// resulting value would not be a number.
+    pub(crate) fn accept(
+        &self,
+        value: &Value,
+        cursor: &Cursor,
+        input: &str,
+    ) -> Option<Value> {
+        let (left, right) = cursor.selection(value).unwrap_or_else(|| {
+            let index = cursor.end(value);
+
+            (index, index)
+        });
+
+        let before = value.select(0, left).to_string();
+        let after = value.select(right, value.len()).to_string();
+
+        let mut content: String = input
+            .chars()
+            .filter(|c| Some(*c) != self.thousands_separator)
+            .collect();
+
+        if content.starts_with(self.decimal_separator)
+            && matches!(before.as_str(), "" | "-" | "+")
+        {
+            content.insert(0, '0');
+        }
+
+        if self.is_partial(&format!("{}{}{}", before, content, after)) {
+            Some(Value::new(&content))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the text that results from stepping the given one up or
+    /// down, keeping its amount of decimals.
+    pub(crate) fn step_by(&self, text: &str, is_increment: bool) -> String {
+        let decimals = |text: &str, separator: char| {
+            text.split_once(separator)
+                .map(|(_, fraction)| fraction.len())
+                .unwrap_or(0)
+        };
+
+        let precision = decimals(text, self.decimal_separator)
+            .max(decimals(&self.step.to_string(), '.'));
+
+        let current = self.parse(text).unwrap_or(0.0);
+
+        let mut stepped = if is_increment {
+            current + self.step
+        } else {
+            current - self.step
+        };
+
+        if !self.allow_negative {
+            stepped = stepped.max(0.0);
+        }
+
+        // Adding zero turns a negative zero into a positive one
+        format!("{:.*}", precision, stepped + 0.0)
+            .replace('.', &self.decimal_separator.to_string())
+    }
+
+    /// Returns whether the given text is a number, or the beginning of one.
+    fn is_partial(&self, text: &str) -> bool {
+        let unsigned = match text.chars().next() {
+            Some('-') if self.allow_negative => &text[1..],
+            Some('+') => &text[1..],
+            _ => text,
+        };
+
+        let (integer, fraction) = unsigned
+            .split_once(self.decimal_separator)
+            .unwrap_or((unsigned, ""));
+
+        integer.chars().all(|c| c.is_ascii_digit())
+            && fraction.chars().all(|c| c.is_ascii_digit())
+    }
+}
+
+impl Default for NumericOptions {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: Some(','),
+            allow_negative: true,
+            step: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_in(options: &NumericOptions, text: &str) -> String {
+        let mut value = Value::new("");
+
+        for c in text.chars() {
+            let mut cursor = Cursor::default();
+            cursor.move_to(value.len());
+
+            if let Some(content) =
+                options.accept(&value, &cursor, &c.to_string())
+            {
+                value = Value::new(&format!(
+                    "{}{}",
+                    value.to_string(),
+                    content.to_string()
+                ));
+            }
+        }
+
+        value.to_string()
+    }
+
+    #[test]
+    fn default_separators_round_trip() {
+        let options = NumericOptions::new();
+
+        assert_eq!(options.parse("1,234.5"), Ok(1234.5));
+        assert_eq!(type_in(&options, "1,234.5"), "1234.5");
+        assert_eq!(options.step_by("1234.5", true), "1235.5");
+        assert_eq!(options.parse(&options.step_by("1234.5", true)), Ok(1235.5));
+    }
+
+    #[test]
+    fn comma_decimal_separator_groups_thousands_with_dots() {
+        let options = NumericOptions::new().decimal_separator(',');
+
+        assert_eq!(options.thousands_separator, Some('.'));
+        assert_eq!(options.parse("1.234,5"), Ok(1234.5));
+        assert_eq!(type_in(&options, "1.234,5"), "1234,5");
+        assert_eq!(options.step_by("1234,5", false), "1233,5");
+        assert_eq!(
+            options.parse(&options.step_by("1234,5", false)),
+            Ok(1233.5)
+        );
+    }
+
+    #[test]
+    fn colliding_thousands_separator_keeps_separators_distinct() {
+        let swapped = NumericOptions::new().thousands_separator(Some('.'));
+
+        assert_eq!(swapped.decimal_separator, ',');
+        assert_eq!(swapped.parse("1.234,5"), Ok(1234.5));
+
+        let ungrouped = NumericOptions::new()
+            .thousands_separator(None)
+            .thousands_separator(Some('.'));
+
+        assert_eq!(ungrouped.decimal_separator, '.');
+        assert_eq!(ungrouped.thousands_separator, None);
+        assert_eq!(ungrouped.parse("1234.5"), Ok(1234.5));
+    }
+}
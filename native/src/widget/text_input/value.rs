@@ -4,7 +4,7 @@ use unicode_segmentation::UnicodeSegmentation;
 ///
 /// [`TextInput`]: crate::widget::TextInput
 // TODO: Reduce allocations, cache results (?)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Value {
     graphemes: Vec<String>,
 }
@@ -96,20 +96,24 @@ impl Value {
     }
 
     /// Inserts a new `char` at the given grapheme `index`.
+    ///
+    /// The character may be merged with its neighbors into a single
+    /// grapheme, like a combining mark or the second half of a flag.
     pub fn insert(&mut self, index: usize, c: char) {
         self.graphemes.insert(index, c.to_string());
-
-        self.graphemes =
-            UnicodeSegmentation::graphemes(&self.to_string() as &str, true)
-                .map(String::from)
-                .collect();
+        self.segment();
     }
 
     /// Inserts a bunch of graphemes at the given grapheme `index`.
+    ///
+    /// Like [`Value::insert`], the graphemes may be merged with their
+    /// neighbors.
     pub fn insert_many(&mut self, index: usize, mut value: Value) {
         let _ = self
             .graphemes
             .splice(index..index, value.graphemes.drain(..));
+
+        self.segment();
     }
 
     /// Removes the grapheme at the given `index`.
@@ -125,10 +129,23 @@ impl Value {
     /// Returns a new [`Value`] with all its graphemes replaced with the
     /// dot ('•') character.
     pub fn secure(&self) -> Self {
+        self.secure_with('•')
+    }
+
+    /// Returns a new [`Value`] with all its graphemes replaced with the
+    /// given character.
+    pub fn secure_with(&self, character: char) -> Self {
         Self {
-            graphemes: std::iter::repeat(String::from("•"))
-                .take(self.graphemes.len())
-                .collect(),
+            graphemes: vec![character.to_string(); self.graphemes.len()],
         }
     }
+
+    /// Splits the contents of the [`Value`] into extended grapheme clusters
+    /// again.
+    fn segment(&mut self) {
+        self.graphemes =
+            UnicodeSegmentation::graphemes(&self.to_string() as &str, true)
+                .map(String::from)
+                .collect();
+    }
 }
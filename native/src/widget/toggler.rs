@@ -5,14 +5,23 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
+use crate::time::{Duration, Instant};
 use crate::widget::{Row, Text};
+use crate::window;
 use crate::{
     Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
     Shell, Widget,
 };
 
+pub use crate::text::Position;
 pub use iced_style::toggler::{Style, StyleSheet};
 
+/// The duration of the movement of the knob of a [`Toggler`].
+const TRANSITION: Duration = Duration::from_millis(120);
+
+/// The time between the frames of the movement of the knob of a [`Toggler`].
+const ANIMATION_FRAME: Duration = Duration::from_millis(16);
+
 /// A toggler widget.
 ///
 /// # Example
@@ -30,6 +39,7 @@ pub use iced_style::toggler::{Style, StyleSheet};
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Toggler<'a, Message, Renderer: text::Renderer> {
+    state: Option<&'a mut State>,
     is_active: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: Option<String>,
@@ -37,6 +47,7 @@ pub struct Toggler<'a, Message, Renderer: text::Renderer> {
     size: u16,
     text_size: Option<u16>,
     text_alignment: alignment::Horizontal,
+    text_position: Position,
     spacing: u16,
     font: Renderer::Font,
     style_sheet: Box<dyn StyleSheet + 'a>,
@@ -63,6 +74,7 @@ impl<'a, Message, Renderer: text::Renderer> Toggler<'a, Message, Renderer> {
         F: 'a + Fn(bool) -> Message,
     {
         Toggler {
+            state: None,
             is_active,
             on_toggle: Box::new(f),
             label: label.into(),
@@ -70,12 +82,22 @@ impl<'a, Message, Renderer: text::Renderer> Toggler<'a, Message, Renderer> {
             size: Self::DEFAULT_SIZE,
             text_size: None,
             text_alignment: alignment::Horizontal::Left,
+            text_position: Position::Left,
             spacing: 0,
             font: Renderer::Font::default(),
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the local [`State`] of the [`Toggler`], which lets it animate
+    /// its knob when it is toggled.
+    ///
+    /// Without a [`State`], the knob jumps to its new position.
+    pub fn state(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
     /// Sets the size of the [`Toggler`].
     pub fn size(mut self, size: u16) -> Self {
         self.size = size;
@@ -100,6 +122,15 @@ impl<'a, Message, Renderer: text::Renderer> Toggler<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the side of the [`Toggler`] where its text is placed.
+    ///
+    /// By default, the text is placed to the left of the [`Toggler`], which
+    /// sits at the far end when it fills its width.
+    pub fn text_position(mut self, position: Position) -> Self {
+        self.text_position = position;
+        self
+    }
+
     /// Sets the spacing between the [`Toggler`] and the text.
     pub fn spacing(mut self, spacing: u16) -> Self {
         self.spacing = spacing;
@@ -122,6 +153,362 @@ impl<'a, Message, Renderer: text::Renderer> Toggler<'a, Message, Renderer> {
         self.style_sheet = style_sheet.into();
         self
     }
+
+    fn properties(&self) -> Properties<'_, Renderer::Font> {
+        Properties {
+            is_active: self.is_active,
+            label: self.label.as_deref(),
+            width: self.width,
+            size: self.size,
+            text_size: self.text_size,
+            text_alignment: self.text_alignment,
+            text_position: self.text_position,
+            spacing: self.spacing,
+            font: &self.font,
+        }
+    }
+}
+
+/// The local state of a [`Toggler`], which animates its knob when it is
+/// toggled.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_active: Option<bool>,
+    from: f32,
+    started_at: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the position of the knob at the given moment, from `0.0` when
+    /// inactive to `1.0` when active.
+    ///
+    /// A value the [`State`] has not been told about yet is shown as is.
+    fn position(&self, is_active: bool, now: Instant) -> f32 {
+        if self.is_active == Some(is_active) {
+            self.knob(now)
+        } else if is_active {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn knob(&self, now: Instant) -> f32 {
+        let target = if self.is_active == Some(true) {
+            1.0
+        } else {
+            0.0
+        };
+
+        match self.started_at {
+            Some(started_at) => {
+                let progress = now.duration_since(started_at).as_secs_f32()
+                    / TRANSITION.as_secs_f32();
+
+                if progress >= 1.0 {
+                    target
+                } else {
+                    self.from + (target - self.from) * ease(progress)
+                }
+            }
+            None => target,
+        }
+    }
+
+    /// Moves the knob to the given value right away, if it changed without
+    /// the [`Toggler`] being toggled.
+    pub fn sync(&mut self, is_active: bool) {
+        if self.is_active != Some(is_active) {
+            self.is_active = Some(is_active);
+            self.started_at = None;
+        }
+    }
+
+    /// Moves the knob towards the given value, starting from wherever it is
+    /// at the given moment.
+    fn retarget(&mut self, is_active: bool, now: Instant) {
+        if self.is_active != Some(is_active) {
+            self.from = self.knob(now);
+            self.is_active = Some(is_active);
+            self.started_at = Some(now);
+        }
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.started_at
+            .map(|started_at| now < started_at + TRANSITION)
+            .unwrap_or(false)
+    }
+}
+
+/// An ease-in-out cubic curve.
+fn ease(t: f32) -> f32 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// The properties of a [`Toggler`] that define how it is laid out and
+/// drawn.
+///
+/// Most fields are set by the builder method of the [`Toggler`] with the
+/// same name.
+#[derive(Debug)]
+pub struct Properties<'a, Font> {
+    /// Whether the [`Toggler`] is toggled on.
+    pub is_active: bool,
+    /// The label of the [`Toggler`], if any.
+    pub label: Option<&'a str>,
+    /// The width of the [`Toggler`], including its label.
+    pub width: Length,
+    /// The size of the toggler itself.
+    pub size: u16,
+    /// The text size of the label, or the default size of the renderer if
+    /// `None`.
+    pub text_size: Option<u16>,
+    /// The horizontal alignment of the label.
+    pub text_alignment: alignment::Horizontal,
+    /// The side of the toggler where the label is placed.
+    pub text_position: Position,
+    /// The space between the toggler and its label.
+    pub spacing: u16,
+    /// The font of the label.
+    pub font: &'a Font,
+}
+
+/// Computes the layout of a [`Toggler`].
+pub fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    properties: Properties<'_, Renderer::Font>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        label,
+        width,
+        size,
+        text_size,
+        text_alignment,
+        text_position,
+        spacing,
+        font,
+        ..
+    } = properties;
+
+    let row = Row::<(), Renderer>::new()
+        .width(width)
+        .spacing(spacing)
+        .align_items(Alignment::Center);
+
+    let toggler = Row::new()
+        .width(Length::Units(2 * size))
+        .height(Length::Units(size));
+
+    let label = match label {
+        Some(label) => label,
+        None => return row.push(toggler).layout(renderer, limits),
+    };
+
+    // The label takes whatever room the toggler leaves, which pushes the
+    // toggler to the far end when the label comes first
+    let label = Text::new(label)
+        .horizontal_alignment(text_alignment)
+        .font(font.clone())
+        .width(match width {
+            Length::Shrink => Length::Shrink,
+            _ => Length::Fill,
+        })
+        .size(text_size.unwrap_or(renderer.default_size()));
+
+    match text_position {
+        Position::Left => row.push(label).push(toggler),
+        Position::Right => row.push(toggler).push(label),
+    }
+    .layout(renderer, limits)
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`Toggler`], if
+/// any, accordingly.
+pub fn update<Message>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    shell: &mut Shell<'_, Message>,
+    is_active: bool,
+    on_toggle: &dyn Fn(bool) -> Message,
+    state: Option<&mut State>,
+) -> event::Status {
+    let now = match event {
+        Event::Window(window::Event::RedrawRequested(now)) => now,
+        _ => Instant::now(),
+    };
+
+    let mut state = state;
+
+    if let Some(state) = state.as_deref_mut() {
+        state.sync(is_active);
+    }
+
+    let status = match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            if layout.bounds().contains(cursor_position) =>
+        {
+            shell.publish((on_toggle)(!is_active));
+
+            // Start moving right away, without waiting for the application
+            // to rebuild the widget
+            if let Some(state) = state.as_deref_mut() {
+                state.retarget(!is_active, now);
+            }
+
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
+    };
+
+    if let Some(state) = state {
+        if state.is_animating(now) {
+            shell.request_redraw(now + ANIMATION_FRAME);
+        }
+    }
+
+    status
+}
+
+/// Draws a [`Toggler`].
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    properties: Properties<'_, Renderer::Font>,
+    style_sheet: &dyn StyleSheet,
+    state: Option<&State>,
+) where
+    Renderer: text::Renderer,
+{
+    let Properties {
+        is_active,
+        label,
+        text_size,
+        text_alignment,
+        text_position,
+        font,
+        ..
+    } = properties;
+
+    /// Makes sure that the border radius of the toggler looks good at every size.
+    const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
+
+    /// The space ratio between the background Quad and the Toggler bounds, and
+    /// between the background Quad and foreground Quad.
+    const SPACE_RATIO: f32 = 0.05;
+
+    let mut children = layout.children();
+    let first = children.next().unwrap();
+
+    let toggler_layout = match (label, children.next()) {
+        (Some(label), Some(second)) => {
+            let (label_layout, toggler_layout) = match text_position {
+                Position::Left => (first, second),
+                Position::Right => (second, first),
+            };
+
+            crate::widget::text::draw(
+                renderer,
+                style,
+                label_layout,
+                label,
+                font.clone(),
+                text_size,
+                None,
+                text_alignment,
+                alignment::Vertical::Center,
+                text::Spacing::default(),
+                text::Rotation::None,
+            );
+
+            toggler_layout
+        }
+        _ => first,
+    };
+
+    let bounds = toggler_layout.bounds();
+
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    let style = if is_mouse_over {
+        style_sheet.hovered(is_active)
+    } else {
+        style_sheet.active(is_active)
+    };
+
+    let border_radius = bounds.height as f32 / BORDER_RADIUS_RATIO;
+    let space = SPACE_RATIO * bounds.height as f32;
+
+    let toggler_background_bounds = Rectangle {
+        x: bounds.x + space,
+        y: bounds.y + space,
+        width: bounds.width - (2.0 * space),
+        height: bounds.height - (2.0 * space),
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: toggler_background_bounds,
+            border_radius,
+            border_width: 1.0,
+            border_color: style.background_border.unwrap_or(style.background),
+        },
+        style.background,
+    );
+
+    let position = state
+        .map(|state| state.position(is_active, Instant::now()))
+        .unwrap_or(if is_active { 1.0 } else { 0.0 });
+
+    let travel = bounds.width
+        - 2.0 * space
+        - (bounds.height - (4.0 * space))
+        - 2.0 * space;
+
+    let toggler_foreground_bounds = Rectangle {
+        x: bounds.x + 2.0 * space + travel * position,
+        y: bounds.y + (2.0 * space),
+        width: bounds.height - (4.0 * space),
+        height: bounds.height - (4.0 * space),
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: toggler_foreground_bounds,
+            border_radius,
+            border_width: 1.0,
+            border_color: style.foreground_border.unwrap_or(style.foreground),
+        },
+        style.foreground,
+    );
+}
+
+/// Returns the [`mouse::Interaction`] of a [`Toggler`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> mouse::Interaction {
+    if layout.bounds().contains(cursor_position) {
+        mouse::Interaction::Pointer
+    } else {
+        mouse::Interaction::default()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -142,28 +529,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let mut row = Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center);
-
-        if let Some(label) = &self.label {
-            row = row.push(
-                Text::new(label)
-                    .horizontal_alignment(self.text_alignment)
-                    .font(self.font.clone())
-                    .width(self.width)
-                    .size(self.text_size.unwrap_or(renderer.default_size())),
-            );
-        }
-
-        row = row.push(
-            Row::new()
-                .width(Length::Units(2 * self.size))
-                .height(Length::Units(self.size)),
-        );
-
-        row.layout(renderer, limits)
+        layout(renderer, limits, self.properties())
     }
 
     fn on_event(
@@ -175,20 +541,15 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
-
-                if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_active));
-
-                    event::Status::Captured
-                } else {
-                    event::Status::Ignored
-                }
-            }
-            _ => event::Status::Ignored,
-        }
+        update(
+            event,
+            layout,
+            cursor_position,
+            shell,
+            self.is_active,
+            self.on_toggle.as_ref(),
+            self.state.as_deref_mut(),
+        )
     }
 
     fn mouse_interaction(
@@ -198,11 +559,7 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        }
+        mouse_interaction(layout, cursor_position)
     }
 
     fn draw(
@@ -213,87 +570,15 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        /// Makes sure that the border radius of the toggler looks good at every size.
-        const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
-
-        /// The space ratio between the background Quad and the Toggler bounds, and
-        /// between the background Quad and foreground Quad.
-        const SPACE_RATIO: f32 = 0.05;
-
-        let mut children = layout.children();
-
-        if let Some(label) = &self.label {
-            let label_layout = children.next().unwrap();
-
-            crate::widget::text::draw(
-                renderer,
-                style,
-                label_layout,
-                &label,
-                self.font.clone(),
-                self.text_size,
-                None,
-                self.text_alignment,
-                alignment::Vertical::Center,
-            );
-        }
-
-        let toggler_layout = children.next().unwrap();
-        let bounds = toggler_layout.bounds();
-
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        let style = if is_mouse_over {
-            self.style_sheet.hovered(self.is_active)
-        } else {
-            self.style_sheet.active(self.is_active)
-        };
-
-        let border_radius = bounds.height as f32 / BORDER_RADIUS_RATIO;
-        let space = SPACE_RATIO * bounds.height as f32;
-
-        let toggler_background_bounds = Rectangle {
-            x: bounds.x + space,
-            y: bounds.y + space,
-            width: bounds.width - (2.0 * space),
-            height: bounds.height - (2.0 * space),
-        };
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: toggler_background_bounds,
-                border_radius,
-                border_width: 1.0,
-                border_color: style
-                    .background_border
-                    .unwrap_or(style.background),
-            },
-            style.background,
-        );
-
-        let toggler_foreground_bounds = Rectangle {
-            x: bounds.x
-                + if self.is_active {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
-            y: bounds.y + (2.0 * space),
-            width: bounds.height - (4.0 * space),
-            height: bounds.height - (4.0 * space),
-        };
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: toggler_foreground_bounds,
-                border_radius,
-                border_width: 1.0,
-                border_color: style
-                    .foreground_border
-                    .unwrap_or(style.foreground),
-            },
-            style.foreground,
-        );
+        draw(
+            renderer,
+            style,
+            layout,
+            cursor_position,
+            self.properties(),
+            self.style_sheet.as_ref(),
+            self.state.as_deref(),
+        )
     }
 }
 
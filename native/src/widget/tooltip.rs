@@ -6,6 +6,7 @@ use crate::renderer;
 use crate::text;
 use crate::widget::container;
 use crate::widget::text::Text;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle,
     Shell, Size, Vector, Widget,
@@ -307,6 +308,14 @@ where
             },
         )
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, operation)
+    }
 }
 
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
@@ -1,6 +1,23 @@
 //! Build window-based GUI applications.
+pub mod icon;
+
 mod action;
+mod cursor_grab;
 mod event;
+mod id;
+mod level;
+mod mode;
+mod screenshot;
+mod settings;
+mod user_attention;
 
 pub use action::Action;
+pub use cursor_grab::CursorGrab;
 pub use event::Event;
+pub use icon::Icon;
+pub use id::Id;
+pub use level::Level;
+pub use mode::Mode;
+pub use screenshot::Screenshot;
+pub use settings::Settings;
+pub use user_attention::UserAttention;
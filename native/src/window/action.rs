@@ -1,6 +1,23 @@
+use crate::window::{
+    CursorGrab, Icon, Id, Level, Mode, Screenshot, Settings, UserAttention,
+};
+use crate::{Point, Size};
+
+use std::fmt;
+
 /// An operation to be performed on some window.
-#[derive(Debug)]
-pub enum Action {
+///
+/// Actions are performed on the main window, unless they are wrapped in a
+/// [`Target`].
+///
+/// [`Target`]: Self::Target
+pub enum Action<T> {
+    /// Open a new window with the given [`Id`] and [`Settings`].
+    ///
+    /// Only runtimes with support for multiple windows can open it.
+    Spawn(Id, Settings),
+    /// Perform an [`Action`] on the window with the given [`Id`].
+    Target(Id, Box<Action<T>>),
     /// Resize the window.
     Resize {
         /// The new logical width of the window
@@ -9,10 +26,204 @@ pub enum Action {
         height: u32,
     },
     /// Move the window.
+    ///
+    /// The coordinates are those of the outer position of the window. It has
+    /// no effect in platforms that do not let windows position themselves,
+    /// like Wayland.
     Move {
         /// The new logical x location of the window
         x: i32,
         /// The new logical y location of the window
         y: i32,
     },
+    /// Move the window to the center of its current monitor.
+    ///
+    /// Like a [`Move`], it has no effect in platforms that do not let windows
+    /// position themselves.
+    ///
+    /// [`Move`]: Self::Move
+    Center,
+    /// Set the minimum logical size of the window, or remove it if `None`.
+    SetMinSize(Option<Size<u32>>),
+    /// Set the maximum logical size of the window, or remove it if `None`.
+    SetMaxSize(Option<Size<u32>>),
+    /// Set the logical increments the window is resized in, or remove them
+    /// if `None`.
+    SetResizeIncrements(Option<Size<u32>>),
+    /// Set the ratio between the width and the height the window keeps
+    /// while resized, or remove it if `None`.
+    SetAspectRatio(Option<f32>),
+    /// Close the window and exit the application.
+    ///
+    /// Combined with disabling `exit_on_close_request` in the settings, it
+    /// lets the application confirm a close request before closing.
+    Close,
+    /// Start moving the window with the mouse, until its button is released.
+    ///
+    /// It must be performed in response to the press of a mouse button,
+    /// while the button is still down, or platforms will ignore it.
+    Drag,
+    /// Minimize the window, or restore it if `false`.
+    Minimize(bool),
+    /// Maximize the window, or restore it if `false`.
+    Maximize(bool),
+    /// Set the title of the window.
+    ///
+    /// The title stays until the one returned by the application changes.
+    SetTitle(String),
+    /// Set the icon of the window.
+    SetIcon(Icon),
+    /// Change the [`Mode`] of the window.
+    ///
+    /// The [`Mode`] stays until the one returned by the application changes.
+    ChangeMode(Mode),
+    /// Request the attention of the user to the window, or cancel a previous
+    /// request if `None`.
+    ///
+    /// It has no effect if the window is already focused.
+    RequestUserAttention(Option<UserAttention>),
+    /// Change how the cursor is grabbed by the window.
+    ///
+    /// Platforms that do not support the [`CursorGrab`] fall back to the one
+    /// they support, and the one that is applied produces `T`, if any
+    /// closure is given.
+    ///
+    /// The cursor is released while the window is unfocused.
+    ChangeCursorGrab(CursorGrab, Option<Box<dyn FnOnce(CursorGrab) -> T>>),
+    /// Show or hide the cursor over the window.
+    ///
+    /// Mouse events are still produced while the cursor is hidden, and the
+    /// cursor is shown while the window is unfocused.
+    SetCursorVisible(bool),
+    /// Change the [`Level`] of the window.
+    ChangeLevel(Level),
+    /// Fetch the current [`Mode`] of the window and produce `T` with it.
+    FetchMode(Box<dyn FnOnce(Mode) -> T + 'static>),
+    /// Fetch the current logical size of the window and produce `T` with it.
+    FetchSize(Box<dyn FnOnce(Size<u32>) -> T + 'static>),
+    /// Fetch the current logical position of the window and produce `T` with
+    /// it.
+    ///
+    /// The position is `None` in platforms that do not expose it, like
+    /// Wayland.
+    FetchPosition(Box<dyn FnOnce(Option<Point>) -> T + 'static>),
+    /// Capture the next frame of the window, as the user sees it, and
+    /// produce `T` with its [`Screenshot`].
+    Screenshot(Box<dyn FnOnce(Screenshot) -> T + 'static>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a window [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Spawn(id, settings) => Action::Spawn(id, settings),
+            Self::Target(id, action) => {
+                Action::Target(id, Box::new((*action).map(f)))
+            }
+            Self::Resize { width, height } => Action::Resize { width, height },
+            Self::Move { x, y } => Action::Move { x, y },
+            Self::Center => Action::Center,
+            Self::SetMinSize(size) => Action::SetMinSize(size),
+            Self::SetMaxSize(size) => Action::SetMaxSize(size),
+            Self::SetResizeIncrements(increments) => {
+                Action::SetResizeIncrements(increments)
+            }
+            Self::SetAspectRatio(ratio) => Action::SetAspectRatio(ratio),
+            Self::Close => Action::Close,
+            Self::Drag => Action::Drag,
+            Self::Minimize(minimized) => Action::Minimize(minimized),
+            Self::Maximize(maximized) => Action::Maximize(maximized),
+            Self::SetTitle(title) => Action::SetTitle(title),
+            Self::SetIcon(icon) => Action::SetIcon(icon),
+            Self::ChangeMode(mode) => Action::ChangeMode(mode),
+            Self::ChangeCursorGrab(grab, o) => Action::ChangeCursorGrab(
+                grab,
+                o.map(|o| {
+                    Box::new(move |s| f(o(s)))
+                        as Box<dyn FnOnce(CursorGrab) -> A>
+                }),
+            ),
+            Self::SetCursorVisible(visible) => {
+                Action::SetCursorVisible(visible)
+            }
+            Self::ChangeLevel(level) => Action::ChangeLevel(level),
+            Self::RequestUserAttention(attention) => {
+                Action::RequestUserAttention(attention)
+            }
+            Self::FetchMode(o) => Action::FetchMode(Box::new(move |s| f(o(s)))),
+            Self::FetchSize(o) => Action::FetchSize(Box::new(move |s| f(o(s)))),
+            Self::FetchPosition(o) => {
+                Action::FetchPosition(Box::new(move |s| f(o(s))))
+            }
+            Self::Screenshot(o) => {
+                Action::Screenshot(Box::new(move |s| f(o(s))))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn(id, settings) => {
+                write!(f, "Action::Spawn({:?}, {:?})", id, settings)
+            }
+            Self::Target(id, action) => {
+                write!(f, "Action::Target({:?}, {:?})", id, action)
+            }
+            Self::Resize { width, height } => write!(
+                f,
+                "Action::Resize {{ width: {}, height: {} }}",
+                width, height
+            ),
+            Self::Move { x, y } => {
+                write!(f, "Action::Move {{ x: {}, y: {} }}", x, y)
+            }
+            Self::Center => write!(f, "Action::Center"),
+            Self::SetMinSize(size) => {
+                write!(f, "Action::SetMinSize({:?})", size)
+            }
+            Self::SetMaxSize(size) => {
+                write!(f, "Action::SetMaxSize({:?})", size)
+            }
+            Self::SetResizeIncrements(increments) => {
+                write!(f, "Action::SetResizeIncrements({:?})", increments)
+            }
+            Self::SetAspectRatio(ratio) => {
+                write!(f, "Action::SetAspectRatio({:?})", ratio)
+            }
+            Self::Close => write!(f, "Action::Close"),
+            Self::Drag => write!(f, "Action::Drag"),
+            Self::Minimize(minimized) => {
+                write!(f, "Action::Minimize({})", minimized)
+            }
+            Self::Maximize(maximized) => {
+                write!(f, "Action::Maximize({})", maximized)
+            }
+            Self::SetTitle(title) => write!(f, "Action::SetTitle({:?})", title),
+            Self::SetIcon(icon) => write!(f, "Action::SetIcon({:?})", icon),
+            Self::ChangeMode(mode) => {
+                write!(f, "Action::ChangeMode({:?})", mode)
+            }
+            Self::ChangeCursorGrab(grab, _) => {
+                write!(f, "Action::ChangeCursorGrab({:?})", grab)
+            }
+            Self::SetCursorVisible(visible) => {
+                write!(f, "Action::SetCursorVisible({})", visible)
+            }
+            Self::ChangeLevel(level) => {
+                write!(f, "Action::ChangeLevel({:?})", level)
+            }
+            Self::RequestUserAttention(attention) => {
+                write!(f, "Action::RequestUserAttention({:?})", attention)
+            }
+            Self::FetchMode(_) => write!(f, "Action::FetchMode"),
+            Self::FetchSize(_) => write!(f, "Action::FetchSize"),
+            Self::FetchPosition(_) => write!(f, "Action::FetchPosition"),
+            Self::Screenshot(_) => write!(f, "Action::Screenshot"),
+        }
+    }
 }
@@ -0,0 +1,13 @@
+/// How the cursor is grabbed by a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorGrab {
+    /// The cursor moves freely.
+    #[default]
+    None,
+
+    /// The cursor is confined to the window area.
+    Confined,
+
+    /// The cursor is locked in place, inside the window.
+    Locked,
+}
@@ -1,3 +1,5 @@
+use crate::time::Instant;
+
 use std::path::PathBuf;
 
 /// A window-related event.
@@ -19,12 +21,29 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window was maximized, or restored from being maximized.
+    Maximized(bool),
+
+    /// A window was asked to draw a new frame.
+    ///
+    /// This event is produced when the time of a redraw requested through
+    /// a [`Shell`] is reached, with the current [`Instant`].
+    ///
+    /// [`Shell`]: crate::Shell
+    RedrawRequested(Instant),
+
     /// The user has requested for the window to close.
     ///
     /// Usually, you will want to terminate the execution whenever this event
     /// occurs.
     CloseRequested,
 
+    /// A window was closed.
+    ///
+    /// Only the secondary windows of a multi-window application produce this
+    /// event, since closing the main window exits the application.
+    Closed,
+
     /// A window was focused.
     Focused,
 
@@ -0,0 +1,119 @@
+//! Change the icon of a window.
+use std::fmt;
+
+/// The icon of a window, as 32bpp RGBA pixels.
+#[derive(Clone)]
+pub struct Icon {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from 32bpp RGBA data.
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, Error> {
+        if !rgba.len().is_multiple_of(4) {
+            return Err(Error::InvalidData {
+                byte_count: rgba.len(),
+            });
+        }
+
+        let pixel_count = rgba.len() / 4;
+
+        if pixel_count as u64 != u64::from(width) * u64::from(height) {
+            return Err(Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            });
+        }
+
+        Ok(Icon {
+            rgba,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the 32bpp RGBA data of the [`Icon`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the width of the [`Icon`], in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Icon`], in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the 32bpp RGBA data of the [`Icon`], along with its width and
+    /// height.
+    pub fn into_raw(self) -> (Vec<u8>, u32, u32) {
+        (self.rgba, self.width, self.height)
+    }
+}
+
+impl fmt::Debug for Icon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Icon")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// An error produced when using [`Icon::from_rgba`] with invalid arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The provided RGBA data isn't divisible by 4.
+    ///
+    /// Therefore, it cannot be safely interpreted as 32bpp RGBA pixels.
+    InvalidData {
+        /// The length of the provided RGBA data.
+        byte_count: usize,
+    },
+
+    /// The number of RGBA pixels does not match the provided dimensions.
+    DimensionsMismatch {
+        /// The provided width.
+        width: u32,
+        /// The provided height.
+        height: u32,
+        /// The amount of pixels of the provided RGBA data.
+        pixel_count: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidData { byte_count } => write!(
+                f,
+                "The provided RGBA data (with length {:?}) isn't divisible by \
+                4. Therefore, it cannot be safely interpreted as 32bpp RGBA \
+                pixels.",
+                byte_count,
+            ),
+            Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => write!(
+                f,
+                "The number of RGBA pixels ({:?}) does not match the provided \
+                dimensions ({:?}x{:?}).",
+                pixel_count, width, height,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
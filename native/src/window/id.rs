@@ -0,0 +1,19 @@
+use std::sync::atomic::{self, AtomicU64};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The identifier of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl Id {
+    /// The [`Id`] of the main window of an application.
+    pub const MAIN: Id = Id(0);
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Id(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
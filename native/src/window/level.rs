@@ -0,0 +1,13 @@
+/// The level of a window, relative to other windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Level {
+    /// The window is ordered like any other window.
+    #[default]
+    Normal,
+
+    /// The window is always on top of other windows.
+    AlwaysOnTop,
+
+    /// The window is always below other windows.
+    AlwaysOnBottom,
+}
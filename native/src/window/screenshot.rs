@@ -0,0 +1,60 @@
+use std::fmt;
+
+/// The contents of a window, as 32bpp RGBA pixels.
+///
+/// The pixels are stored row by row, starting from the top-left corner of the
+/// window, without any padding between rows. Transparent windows have their
+/// colors premultiplied by their alpha.
+#[derive(Clone)]
+pub struct Screenshot {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`] from 32bpp RGBA pixels with the given
+    /// physical dimensions.
+    pub fn new(rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        debug_assert_eq!(
+            rgba.len() as u64,
+            u64::from(width) * u64::from(height) * 4,
+        );
+
+        Self {
+            rgba,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the 32bpp RGBA data of the [`Screenshot`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the width of the [`Screenshot`], in physical pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Screenshot`], in physical pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the 32bpp RGBA data of the [`Screenshot`], along with its
+    /// width and height.
+    pub fn into_raw(self) -> (Vec<u8>, u32, u32) {
+        (self.rgba, self.width, self.height)
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Screenshot")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::window::{Icon, Level};
+use crate::{Point, Size};
+
+/// The settings of a new window.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The initial logical size of the window.
+    pub size: Size<u32>,
+
+    /// The initial logical position of the window.
+    ///
+    /// The platform chooses it if `None`.
+    pub position: Option<Point>,
+
+    /// The minimum logical size of the window.
+    pub min_size: Option<Size<u32>>,
+
+    /// The maximum logical size of the window.
+    pub max_size: Option<Size<u32>>,
+
+    /// The logical increments the window is resized in, if any.
+    pub resize_increments: Option<Size<u32>>,
+
+    /// The ratio between the width and the height the window keeps while
+    /// resized, if any.
+    pub aspect_ratio: Option<f32>,
+
+    /// Whether the window should be resizable or not.
+    pub resizable: bool,
+
+    /// Whether the window should have a border, a title bar, etc.
+    pub decorations: bool,
+
+    /// Whether the window should be transparent.
+    ///
+    /// Only renderers that composite the background color with the desktop
+    /// make the window see-through; `wgpu` presents it opaque for now.
+    pub transparent: bool,
+
+    /// The [`Level`] of the window.
+    pub level: Level,
+
+    /// The [`Icon`] of the window.
+    pub icon: Option<Icon>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            size: Size::new(1024, 768),
+            position: None,
+            min_size: None,
+            max_size: None,
+            resize_increments: None,
+            aspect_ratio: None,
+            resizable: true,
+            decorations: true,
+            transparent: false,
+            level: Level::Normal,
+            icon: None,
+        }
+    }
+}
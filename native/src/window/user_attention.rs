@@ -0,0 +1,11 @@
+/// The type of user attention to request from the platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAttention {
+    /// Requests attention until the window is focused, like bouncing the
+    /// dock icon on macOS or flashing the taskbar button on Windows.
+    Critical,
+
+    /// Requests attention briefly, like bouncing the dock icon once on macOS
+    /// or flashing the taskbar button until the window is focused on Windows.
+    Informational,
+}
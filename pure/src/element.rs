@@ -6,6 +6,7 @@ use iced_native::event::{self, Event};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::widget::operation::{self, Operation};
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
 use std::borrow::Borrow;
@@ -322,6 +323,19 @@ where
             .overlay(tree, layout, renderer)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<B>,
+    ) {
+        self.widget.operate(
+            tree,
+            layout,
+            &mut operation::MapRef::new(operation),
+        );
+    }
 }
 
 impl<'a, Message, Renderer> Borrow<dyn Widget<Message, Renderer> + 'a>
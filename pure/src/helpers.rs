@@ -79,6 +79,39 @@ where
     widget::Text::new(text)
 }
 
+/// Creates a new [`RichText`] with the given spans.
+///
+/// [`RichText`]: widget::RichText
+pub fn rich_text<Message, Renderer>(
+    spans: Vec<widget::rich_text::Span<Message, Renderer::Font>>,
+) -> widget::RichText<Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    widget::RichText::new(spans)
+}
+
+/// Creates a new [`Span`] of rich text.
+///
+/// [`Span`]: widget::rich_text::Span
+pub fn span<Message, Font>(
+    content: impl Into<String>,
+) -> widget::rich_text::Span<Message, Font> {
+    widget::rich_text::Span::new(content)
+}
+
+/// Creates a new [`Link`] with the given text.
+///
+/// [`Link`]: widget::Link
+pub fn link<'a, Message, Renderer>(
+    content: impl Into<String>,
+) -> widget::Link<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    widget::Link::new(content)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: widget::Checkbox
@@ -139,6 +172,32 @@ where
     widget::TextInput::new(placeholder, value, on_change)
 }
 
+/// Creates a new [`TextArea`].
+///
+/// [`TextArea`]: widget::TextArea
+pub fn text_area<'a, Message, Renderer>(
+    placeholder: &str,
+    value: &str,
+    on_change: impl Fn(String) -> Message + 'a,
+) -> widget::TextArea<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    widget::TextArea::new(placeholder, value, on_change)
+}
+
+/// Creates a new [`SelectableText`].
+///
+/// [`SelectableText`]: widget::SelectableText
+pub fn selectable_text<'a, Renderer>(
+    content: impl Into<String>,
+) -> widget::SelectableText<'a, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    widget::SelectableText::new(content)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: widget::Slider
@@ -154,6 +213,21 @@ where
     widget::Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// [`RangeSlider`]: widget::RangeSlider
+pub fn range_slider<'a, Message, T>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> widget::RangeSlider<'a, T, Message>
+where
+    Message: Clone,
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+{
+    widget::RangeSlider::new(range, values, on_change)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// [`PickList`]: widget::PickList
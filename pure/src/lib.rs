@@ -102,6 +102,7 @@ use iced_native::event::{self, Event};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
 /// A bridge between impure and pure widgets.
@@ -266,6 +267,18 @@ where
             renderer,
         )
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.element.as_widget().operate(
+            &mut self.state.state_tree,
+            layout,
+            operation,
+        )
+    }
 }
 
 impl<'a, Message, Renderer> Into<iced_native::Element<'a, Message, Renderer>>
@@ -3,14 +3,25 @@ pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod image;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod lazy_image;
+pub mod link;
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod svg;
+pub mod text_area;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
@@ -26,17 +37,26 @@ pub use checkbox::Checkbox;
 pub use column::Column;
 pub use container::Container;
 pub use image::Image;
+#[cfg(feature = "image")]
+pub use lazy_image::LazyImage;
+pub use link::Link;
+#[cfg(feature = "markdown")]
+pub use markdown::Markdown;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use range_slider::RangeSlider;
+pub use rich_text::RichText;
 pub use row::Row;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
+pub use selectable_text::SelectableText;
 pub use slider::Slider;
 pub use space::Space;
 pub use svg::Svg;
 pub use text::Text;
+pub use text_area::TextArea;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::{Position, Tooltip};
@@ -47,6 +67,7 @@ use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
 /// A component that displays information and allows interaction.
@@ -142,4 +163,15 @@ pub trait Widget<Message, Renderer> {
     ) -> Option<overlay::Element<'a, Message, Renderer>> {
         None
     }
+
+    /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// By default, it does nothing.
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        _layout: Layout<'_>,
+        _operation: &mut dyn Operation<Message>,
+    ) {
+    }
 }
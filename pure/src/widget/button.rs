@@ -7,7 +7,9 @@ use iced_native::event::{self, Event};
 use iced_native::layout;
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::time::Duration;
 use iced_native::widget::button;
+use iced_native::widget::Operation;
 use iced_native::{
     Clipboard, Layout, Length, Padding, Point, Rectangle, Shell,
 };
@@ -53,6 +55,9 @@ use button::State;
 pub struct Button<'a, Message, Renderer> {
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    on_right_press: Option<Message>,
+    on_middle_press: Option<Message>,
+    repeat: Option<(Duration, Duration)>,
     style_sheet: Box<dyn StyleSheet + 'a>,
     width: Length,
     height: Length,
@@ -65,6 +70,9 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
         Button {
             content: content.into(),
             on_press: None,
+            on_right_press: None,
+            on_middle_press: None,
+            repeat: None,
             style_sheet: Default::default(),
             width: Length::Shrink,
             height: Length::Shrink,
@@ -98,6 +106,48 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is pressed,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Button`] will be disabled.
+    pub fn on_press_maybe(mut self, msg: Option<Message>) -> Self {
+        self.on_press = msg;
+        self
+    }
+
+    /// Makes the [`Button`] produce its [`Button::on_press`] message as soon
+    /// as it is pressed, and then repeatedly while it is held: first after
+    /// the given initial delay, and then at the given interval.
+    ///
+    /// The repetition pauses while the cursor is outside of the [`Button`],
+    /// and no message is produced when it is released.
+    pub fn on_press_repeat(
+        mut self,
+        initial_delay: Duration,
+        interval: Duration,
+    ) -> Self {
+        self.repeat = Some((initial_delay, interval));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    ///
+    /// Unlike [`Button::on_press`], it does not enable the [`Button`].
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the middle mouse button.
+    ///
+    /// Unlike [`Button::on_press`], it does not enable the [`Button`].
+    pub fn on_middle_press(mut self, msg: Message) -> Self {
+        self.on_middle_press = Some(msg);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     pub fn style(
         mut self,
@@ -183,6 +233,9 @@ where
             cursor_position,
             shell,
             &self.on_press,
+            &self.on_right_press,
+            &self.on_middle_press,
+            self.repeat,
             || tree.state.downcast_mut::<State>(),
         )
     }
@@ -247,6 +300,21 @@ where
             renderer,
         )
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                operation,
+            );
+        });
+    }
 }
 
 impl<'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
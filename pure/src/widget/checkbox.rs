@@ -9,7 +9,9 @@ use iced_native::renderer;
 use iced_native::text;
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
-pub use iced_native::widget::checkbox::{Checkbox, Style, StyleSheet};
+pub use iced_native::widget::checkbox::{
+    Checkbox, Position, Style, StyleSheet,
+};
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Checkbox<'a, Message, Renderer>
@@ -9,6 +9,7 @@ use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::widget::container;
+use iced_native::widget::Operation;
 use iced_native::{
     Clipboard, Layout, Length, Padding, Point, Rectangle, Shell,
 };
@@ -236,6 +237,21 @@ where
             renderer,
         )
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                operation,
+            );
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<Container<'a, Message, Renderer>>
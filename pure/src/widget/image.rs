@@ -1,17 +1,184 @@
 //! Display images in your user interface.
-use crate::widget::{Tree, Widget};
+use crate::widget::tree::{self, Tree};
+use crate::widget::Widget;
 use crate::Element;
 
+use iced_native::event::{self, Event};
+use iced_native::image::FilterMethod;
 use iced_native::layout::{self, Layout};
 use iced_native::renderer;
+use iced_native::time::Duration;
 use iced_native::widget::image;
-use iced_native::{Length, Point, Rectangle};
+use iced_native::{Clipboard, ContentFit, Length, Point, Rectangle, Shell};
 
 use std::hash::Hash;
 
-pub use image::Image;
+pub use image::{NineSlice, State};
+
+/// A frame that displays an image while keeping aspect ratio.
+///
+/// Animated images, like GIFs, are played if the renderer supports them.
+#[derive(Debug)]
+pub struct Image<Handle> {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    paused: bool,
+    opacity: f32,
+    fade_in: Option<Duration>,
+}
+
+impl<Handle> Image<Handle> {
+    /// Creates a new [`Image`] with the given path.
+    pub fn new<T: Into<Handle>>(handle: T) -> Self {
+        Image {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            paused: false,
+            opacity: 1.0,
+            fade_in: None,
+        }
+    }
+
+    /// Sets the width of the [`Image`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Image`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] is paused, if it has one.
+    ///
+    /// A paused [`Image`] keeps showing its current frame.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], from `0.0` to `1.0`.
+    ///
+    /// A fully transparent [`Image`] is not drawn at all.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`Duration`] of the fade-in of the [`Image`], which animates
+    /// its opacity from `0.0` when it is first shown, and every time its
+    /// image changes.
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+}
 
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
+where
+    Handle: Clone + Hash,
+    Renderer: iced_native::image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        image::layout(
+            renderer,
+            limits,
+            &self.handle,
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        image::update(
+            &event,
+            renderer,
+            shell,
+            &self.handle,
+            self.paused,
+            self.fade_in,
+            Some(tree.state.downcast_mut::<State>()),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        image::draw(
+            renderer,
+            layout,
+            &self.handle,
+            self.content_fit,
+            self.filter_method,
+            self.paused,
+            self.opacity,
+            self.fade_in,
+            Some(tree.state.downcast_ref::<State>()),
+        )
+    }
+}
+
+impl<Message, Renderer, Handle> Widget<Message, Renderer> for NineSlice<Handle>
 where
     Handle: Clone + Hash,
     Renderer: iced_native::image::Renderer<Handle = Handle>,
@@ -54,6 +221,18 @@ where
     }
 }
 
+impl<'a, Message, Renderer, Handle> From<NineSlice<Handle>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced_native::image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(nine_slice: NineSlice<Handle>) -> Element<'a, Message, Renderer> {
+        Element::new(nine_slice)
+    }
+}
+
 impl<'a, Message, Renderer, Handle> Into<Element<'a, Message, Renderer>>
     for Image<Handle>
 where
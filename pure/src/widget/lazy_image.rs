@@ -0,0 +1,320 @@
+//! Load images in the background, showing a placeholder meanwhile.
+use crate::widget::tree::{self, Tree};
+use crate::widget::Space;
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::image::{self, FilterMethod, Source, Status};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::time::Duration;
+use iced_native::widget::{image as native_image, lazy_image};
+use iced_native::{Clipboard, ContentFit, Length, Point, Rectangle, Shell};
+
+pub use lazy_image::State;
+
+/// An image that is loaded in the background, instead of when it is drawn
+/// for the first time.
+///
+/// A placeholder is shown while the image is loading, and an error element
+/// if it fails to load. The loading is cancelled if the [`LazyImage`]
+/// disappears before it finishes, unless something else is waiting for the
+/// same image.
+#[allow(missing_debug_implementations)]
+pub struct LazyImage<'a, Message, Renderer> {
+    source: Source,
+    status: Status,
+    placeholder: Element<'a, Message, Renderer>,
+    error: Element<'a, Message, Renderer>,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    opacity: f32,
+    fade_in: Option<Duration>,
+}
+
+impl<'a, Message, Renderer> LazyImage<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    /// Creates a new [`LazyImage`] that loads the image of the given
+    /// [`Source`].
+    pub fn new(source: impl Into<Source>) -> Self {
+        let source = source.into();
+
+        LazyImage {
+            status: image::cached(&source).unwrap_or(Status::Loading),
+            source,
+            placeholder: Space::new(Length::Shrink, Length::Shrink).into(),
+            error: Space::new(Length::Shrink, Length::Shrink).into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+            fade_in: None,
+        }
+    }
+
+    /// Sets the element shown while the image of the [`LazyImage`] is
+    /// loading.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the element shown if the image of the [`LazyImage`] fails to
+    /// load.
+    pub fn error(
+        mut self,
+        error: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.error = error.into();
+        self
+    }
+
+    /// Sets the width of the [`LazyImage`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LazyImage`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the image of the [`LazyImage`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the image of the [`LazyImage`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the image of the [`LazyImage`], from `0.0` to
+    /// `1.0`.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`Duration`] of the fade-in of the [`LazyImage`], which
+    /// animates the opacity of its image from `0.0` once it is loaded.
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> LazyImage<'a, Message, Renderer> {
+    /// Returns the index of the child shown while the image is not loaded.
+    fn index(&self) -> usize {
+        match self.status {
+            Status::Failed(_) => 1,
+            _ => 0,
+        }
+    }
+
+    fn child(&self, index: usize) -> &Element<'a, Message, Renderer> {
+        if index == 0 {
+            &self.placeholder
+        } else {
+            &self.error
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LazyImage<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::new();
+        state.load(self.source.clone());
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.placeholder), Tree::new(&self.error)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().load(self.source.clone());
+
+        tree.diff_children(&[&self.placeholder, &self.error]);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match &self.status {
+            Status::Loaded(handle) => native_image::layout(
+                renderer,
+                limits,
+                handle,
+                self.width,
+                self.height,
+                self.content_fit,
+            ),
+            _ => self.child(self.index()).as_widget().layout(
+                renderer,
+                &limits.width(self.width).height(self.height),
+            ),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The content is laid out for the status before the poll
+        let index = self.index();
+
+        let status = match &self.status {
+            Status::Loaded(handle) => native_image::update(
+                &event,
+                renderer,
+                shell,
+                handle,
+                false,
+                self.fade_in,
+                Some(tree.state.downcast_mut::<State>().image_mut()),
+            ),
+            _ => {
+                let child = if index == 0 {
+                    &mut self.placeholder
+                } else {
+                    &mut self.error
+                };
+
+                child.as_widget_mut().on_event(
+                    &mut tree.children[index],
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            }
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        lazy_image::update(&event, shell, state);
+
+        // The widget is rebuilt with every view, so it only learns that its
+        // image finished loading from its state
+        if !matches!(state.status(), Status::Loading)
+            && std::mem::discriminant(state.status())
+                != std::mem::discriminant(&self.status)
+        {
+            self.status = state.status().clone();
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        match &self.status {
+            Status::Loaded(handle) => native_image::draw(
+                renderer,
+                layout,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                false,
+                self.opacity,
+                self.fade_in,
+                Some(tree.state.downcast_ref::<State>().image()),
+            ),
+            _ => self.child(self.index()).as_widget().draw(
+                &tree.children[self.index()],
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            ),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.status {
+            Status::Loaded(_) => mouse::Interaction::default(),
+            _ => self.child(self.index()).as_widget().mouse_interaction(
+                &tree.children[self.index()],
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<LazyImage<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(lazy_image: LazyImage<'a, Message, Renderer>) -> Self {
+        Element::new(lazy_image)
+    }
+}
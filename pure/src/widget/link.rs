@@ -0,0 +1,224 @@
+//! Display clickable text, like a hyperlink.
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::link;
+use iced_native::{Clipboard, Layout, Length, Point, Rectangle, Shell};
+
+pub use iced_style::link::{Style, StyleSheet};
+
+use link::State;
+
+/// Some text that produces a message when clicked, like a hyperlink.
+///
+/// Only the text itself reacts to the mouse, not the rest of the bounds of
+/// the [`Link`].
+///
+/// # Example
+/// ```
+/// # type Link<'a, Message> =
+/// #     iced_pure::widget::Link<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     OpenDocs,
+/// }
+///
+/// let link = Link::new("Read the docs").on_press(Message::OpenDocs);
+/// ```
+///
+/// If [`Link::on_press`] is not set, the [`Link`] is disabled.
+#[allow(missing_debug_implementations)]
+pub struct Link<'a, Message, Renderer: text::Renderer> {
+    content: String,
+    on_press: Option<Message>,
+    size: Option<u16>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    underline: bool,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Link<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Link`] with the given text.
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Link {
+            content: content.into(),
+            on_press: None,
+            size: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            underline: true,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Link`] is clicked.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the text size of the [`Link`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Link`].
+    ///
+    /// [`Font`]: iced_native::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`Link`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Link`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the text of the [`Link`] is underlined.
+    ///
+    /// By default, it is.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets the style of the [`Link`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> link::Properties<'_, Message, Renderer::Font> {
+        link::Properties {
+            content: &self.content,
+            size: self.size,
+            font: &self.font,
+            width: self.width,
+            height: self.height,
+            underline: self.underline,
+            on_press: &self.on_press,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Link<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        link::layout(renderer, limits, self.properties())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        link::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            shell,
+            self.properties(),
+            || tree.state.downcast_mut::<State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        link::draw(
+            renderer,
+            layout,
+            cursor_position,
+            tree.state.downcast_ref::<State>(),
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        link::mouse_interaction(
+            layout,
+            cursor_position,
+            renderer,
+            self.properties(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Link<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(link: Link<'a, Message, Renderer>) -> Self {
+        Element::new(link)
+    }
+}
@@ -0,0 +1,183 @@
+//! Display Markdown content.
+use crate::widget::{Column, Container, Row, Rule, Text};
+use crate::Element;
+
+use iced_native::text;
+use iced_native::Length;
+
+pub use iced_native::widget::markdown::{
+    bullet, heading_size, parse, rich_text, Block, CodeBlock, Inline, Style,
+    StyleSheet, Url,
+};
+
+/// Some Markdown content displayed with the built-in widgets.
+///
+/// # Example
+/// ```
+/// # type Markdown<'a, Message> =
+/// #     iced_pure::widget::Markdown<'a, Message, iced_native::renderer::Null>;
+/// # type Element<'a, Message> =
+/// #     iced_pure::Element<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let changelog: Element<Message> = Markdown::new(
+///     "# 1.0\n\n- First release, see [the docs](https://docs.rs)",
+///     Message::LinkClicked,
+/// )
+/// .into();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Markdown<'a, Message, Renderer: text::Renderer> {
+    blocks: Vec<Block>,
+    on_link: Box<dyn Fn(Url) -> Message + 'a>,
+    text_size: u16,
+    code_font: Renderer::Font,
+    spacing: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Markdown`] with the given content and a function
+    /// that produces a message when a link is clicked.
+    pub fn new(content: &str, on_link: impl Fn(Url) -> Message + 'a) -> Self {
+        Self::with_blocks(parse(content), on_link)
+    }
+
+    /// Creates a new [`Markdown`] with some already parsed [`Block`]s.
+    pub fn with_blocks(
+        blocks: Vec<Block>,
+        on_link: impl Fn(Url) -> Message + 'a,
+    ) -> Self {
+        Markdown {
+            blocks,
+            on_link: Box::new(on_link),
+            text_size: 20,
+            code_font: Default::default(),
+            spacing: 10,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the text of the [`Markdown`]. Headings are scaled
+    /// relative to it.
+    ///
+    /// By default, it is 20.
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font used to display code, which should be monospaced.
+    pub fn code_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.code_font = font.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the blocks of the [`Markdown`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the style of the [`Markdown`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn blocks(
+        &self,
+        blocks: &[Block],
+        style: &Style,
+    ) -> Element<'a, Message, Renderer> {
+        blocks
+            .iter()
+            .fold(
+                Column::new().spacing(self.spacing).width(Length::Fill),
+                |column, block| column.push(self.block(block, style)),
+            )
+            .into()
+    }
+
+    fn block(
+        &self,
+        block: &Block,
+        style: &Style,
+    ) -> Element<'a, Message, Renderer> {
+        match block {
+            Block::Heading(level, inlines) => {
+                rich_text(inlines, &self.code_font, style, &self.on_link)
+                    .size(heading_size(self.text_size, *level))
+                    .into()
+            }
+            Block::Paragraph(inlines) => {
+                rich_text(inlines, &self.code_font, style, &self.on_link)
+                    .size(self.text_size)
+                    .into()
+            }
+            Block::List { start, items } => items
+                .iter()
+                .enumerate()
+                .fold(
+                    Column::new().spacing(self.spacing / 2),
+                    |column, (i, item)| {
+                        column.push(
+                            Row::new()
+                                .spacing(self.spacing / 2)
+                                .padding([0, 0, 0, self.spacing])
+                                .push(
+                                    Text::new(bullet(*start, i))
+                                        .size(self.text_size),
+                                )
+                                .push(self.blocks(item, style)),
+                        )
+                    },
+                )
+                .into(),
+            Block::Code(code) => Container::new(
+                Text::new(code.as_str())
+                    .size(self.text_size)
+                    .font(self.code_font.clone()),
+            )
+            .width(Length::Fill)
+            .padding(self.spacing)
+            .style(CodeBlock(*style))
+            .into(),
+            Block::Quote(blocks) => Container::new(self.blocks(blocks, style))
+                .padding([0, 0, 0, self.spacing * 2])
+                .into(),
+            Block::Rule => Rule::horizontal(self.spacing).into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Markdown<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        markdown: Markdown<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        let style = markdown.style_sheet.style();
+
+        markdown.blocks(&markdown.blocks, &style)
+    }
+}
@@ -28,6 +28,7 @@ use iced_native::mouse;
 use iced_native::renderer;
 use iced_native::widget::pane_grid;
 use iced_native::widget::pane_grid::state;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Layout, Length, Point, Rectangle, Shell};
 
 pub use iced_style::pane_grid::{Line, StyleSheet};
@@ -384,6 +385,23 @@ where
             })
             .next()
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.elements
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|(((_, pane), tree), layout)| {
+                    pane.operate(tree, layout, operation);
+                })
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<PaneGrid<'a, Message, Renderer>>
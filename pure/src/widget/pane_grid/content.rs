@@ -9,6 +9,7 @@ use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::widget::container;
 use iced_native::widget::pane_grid::Draggable;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Layout, Point, Rectangle, Shell, Size};
 
 /// The content of a [`Pane`].
@@ -222,6 +223,33 @@ where
         event_status.merge(body_status)
     }
 
+    pub(crate) fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let body_layout = if let Some(title_bar) = &self.title_bar {
+            let mut children = layout.children();
+
+            title_bar.operate(
+                &mut tree.children[1],
+                children.next().unwrap(),
+                operation,
+            );
+
+            children.next().unwrap()
+        } else {
+            layout
+        };
+
+        self.body.as_widget().operate(
+            &mut tree.children[0],
+            body_layout,
+            operation,
+        );
+    }
+
     pub(crate) fn mouse_interaction(
         &self,
         tree: &Tree,
@@ -7,6 +7,7 @@ use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::widget::container;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Layout, Padding, Point, Rectangle, Shell, Size};
 
 /// The title bar of a [`Pane`].
@@ -339,6 +340,35 @@ where
         }
     }
 
+    pub(crate) fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            title_layout,
+            operation,
+        );
+
+        if let Some(controls) = &self.controls {
+            if let Some(controls_layout) = children.next() {
+                controls.as_widget().operate(
+                    &mut tree.children[1],
+                    controls_layout,
+                    operation,
+                );
+            }
+        }
+    }
+
     pub(crate) fn overlay<'b>(
         &'b self,
         tree: &'b mut Tree,
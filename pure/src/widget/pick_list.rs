@@ -8,6 +8,7 @@ use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::text;
+use iced_native::widget::operation::Operation;
 use iced_native::widget::pick_list;
 use iced_native::{
     Clipboard, Layout, Length, Padding, Point, Rectangle, Shell,
@@ -15,6 +16,7 @@ use iced_native::{
 
 use std::borrow::Cow;
 
+pub use iced_native::widget::pick_list::{open, Id};
 pub use iced_style::pick_list::{Style, StyleSheet};
 
 /// A widget for selecting a single value from a list of options.
@@ -23,14 +25,21 @@ pub struct PickList<'a, T, Message, Renderer: text::Renderer>
 where
     [T]: ToOwned<Owned = Vec<T>>,
 {
+    id: Option<Id>,
     on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_open: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn() -> Message + 'a>>,
     options: Cow<'a, [T]>,
     placeholder: Option<String>,
     selected: Option<T>,
     width: Length,
     padding: Padding,
     text_size: Option<u16>,
+    menu_height: Option<u16>,
     font: Renderer::Font,
+    is_enabled: Box<dyn Fn(&T) -> bool + 'a>,
+    separators_after: Vec<usize>,
+    searchable: bool,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -51,18 +60,56 @@ where
         on_selected: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
+            id: None,
             on_selected: Box::new(on_selected),
+            on_open: None,
+            on_close: None,
             options: options.into(),
             placeholder: None,
             selected,
             width: Length::Shrink,
             text_size: None,
+            menu_height: None,
             padding: Self::DEFAULT_PADDING,
             font: Default::default(),
+            is_enabled: Box::new(|_| true),
+            separators_after: Vec::new(),
+            searchable: false,
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the message that should be produced when the menu of the
+    /// [`PickList`] opens.
+    ///
+    /// When the menu is opened by an operation, like [`open`], the message
+    /// is produced with the next event processed by the [`PickList`].
+    ///
+    /// [`open`]: iced_native::widget::pick_list::open
+    pub fn on_open(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_open = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the menu of the
+    /// [`PickList`] closes, be it because an option was selected or not.
+    pub fn on_close(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_close = Some(Box::new(move || message.clone()));
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -87,12 +134,49 @@ where
         self
     }
 
+    /// Sets the maximum height of the menu of the [`PickList`]. Its options
+    /// can be scrolled when they do not fit.
+    ///
+    /// By default, the menu is only limited by the size of the window.
+    pub fn menu_height(mut self, height: u16) -> Self {
+        self.menu_height = Some(height);
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: Renderer::Font) -> Self {
         self.font = font;
         self
     }
 
+    /// Sets the function that decides which options of the [`PickList`] can
+    /// be chosen.
+    ///
+    /// Disabled options are displayed with a distinct style in the menu, but
+    /// they cannot be selected with the mouse or the keyboard.
+    pub fn is_enabled(mut self, is_enabled: impl Fn(&T) -> bool + 'a) -> Self {
+        self.is_enabled = Box::new(is_enabled);
+        self
+    }
+
+    /// Sets the indices of the options of the [`PickList`] that are followed
+    /// by a separator in the menu.
+    pub fn separators_after(mut self, indices: impl Into<Vec<usize>>) -> Self {
+        self.separators_after = indices.into();
+        self
+    }
+
+    /// Sets whether the options of the [`PickList`] can be filtered by
+    /// typing while its menu is open.
+    ///
+    /// The typed query replaces the selected value in the [`PickList`].
+    /// Up and Down move through the matching options, Enter selects one, and
+    /// Escape clears the query or closes the menu.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     pub fn style(
         mut self,
@@ -160,8 +244,12 @@ where
             cursor_position,
             shell,
             self.on_selected.as_ref(),
+            self.on_open.as_deref(),
+            self.on_close.as_deref(),
             self.selected.as_ref(),
             &self.options,
+            self.is_enabled.as_ref(),
+            self.searchable,
             || tree.state.downcast_mut::<pick_list::State<T>>(),
         )
     }
@@ -179,7 +267,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         _style: &renderer::Style,
         layout: Layout<'_>,
@@ -195,15 +283,31 @@ where
             &self.font,
             self.placeholder.as_ref().map(String::as_str),
             self.selected.as_ref(),
+            tree.state.downcast_ref::<pick_list::State<T>>().search(),
             self.style_sheet.as_ref(),
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        pick_list::operate(
+            tree.state.downcast_mut::<pick_list::State<T>>(),
+            self.id.as_ref(),
+            self.selected.as_ref(),
+            &self.options,
+            operation,
+        )
+    }
+
     fn overlay<'b>(
         &'b self,
         tree: &'b mut Tree,
         layout: Layout<'_>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         let state = tree.state.downcast_mut::<pick_list::State<T>>();
 
@@ -211,9 +315,13 @@ where
             layout,
             state,
             self.padding,
-            self.text_size,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.menu_height,
             self.font.clone(),
             &self.options,
+            self.is_enabled.as_ref(),
+            &self.separators_after,
+            self.searchable,
             self.style_sheet.as_ref(),
         )
     }
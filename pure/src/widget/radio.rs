@@ -9,6 +9,9 @@ use iced_native::renderer;
 use iced_native::text;
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
+pub mod group;
+
+pub use group::{group, Group};
 pub use iced_native::widget::radio::{Radio, Style, StyleSheet};
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
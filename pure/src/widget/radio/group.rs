@@ -0,0 +1,266 @@
+//! Lay out a set of radio buttons that share their appearance.
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::radio::{self, StyleSheet};
+use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
+
+pub use iced_native::widget::radio::group::State;
+
+/// A set of [`Radio`] buttons, one for every option, that share their size,
+/// spacing, and style.
+///
+/// Once one of its buttons is clicked, the arrow keys cycle the selection
+/// through the options of the [`Group`].
+///
+/// [`Radio`]: radio::Radio
+#[allow(missing_debug_implementations)]
+pub struct Group<'a, V, Message, Renderer: text::Renderer> {
+    options: Vec<V>,
+    selected: Option<V>,
+    on_select: Box<dyn Fn(V) -> Message + 'a>,
+    is_horizontal: bool,
+    width: Length,
+    spacing: u16,
+    size: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// Creates a new [`Group`] with a [`Radio`] button for every option, the
+/// current selected option, and a function that produces a message when an
+/// option is selected.
+///
+/// [`Radio`]: radio::Radio
+pub fn group<'a, V, Message, Renderer>(
+    options: impl IntoIterator<Item = V>,
+    selected: Option<V>,
+    on_select: impl Fn(V) -> Message + 'a,
+) -> Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    Group::new(options, selected, on_select)
+}
+
+impl<'a, V, Message, Renderer> Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    /// The default spacing between the [`Radio`] buttons of a [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub const DEFAULT_SPACING: u16 = 10;
+
+    /// Creates a new [`Group`] with a [`Radio`] button for every option, the
+    /// current selected option, and a function that produces a message when
+    /// an option is selected.
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn new(
+        options: impl IntoIterator<Item = V>,
+        selected: Option<V>,
+        on_select: impl Fn(V) -> Message + 'a,
+    ) -> Self {
+        Group {
+            options: options.into_iter().collect(),
+            selected,
+            on_select: Box::new(on_select),
+            is_horizontal: false,
+            width: Length::Shrink,
+            spacing: Self::DEFAULT_SPACING,
+            size: radio::Radio::<Message, Renderer>::DEFAULT_SIZE,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Lays the [`Radio`] buttons of the [`Group`] out in a row, instead of a
+    /// column.
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn horizontal(mut self) -> Self {
+        self.is_horizontal = true;
+        self
+    }
+
+    /// Sets the width of the [`Group`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the [`Radio`] buttons of the [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of the [`Radio`] buttons of the [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the text size of the [`Radio`] buttons of the [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the text font of the [`Radio`] buttons of the [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Radio`] buttons of the [`Group`].
+    ///
+    /// [`Radio`]: radio::Radio
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn content(&self) -> iced_native::Element<'_, Message, Renderer> {
+        radio::group::content(
+            radio::group::Properties {
+                options: &self.options,
+                selected: self.selected,
+                on_select: self.on_select.as_ref(),
+                is_horizontal: self.is_horizontal,
+                width: self.width,
+                spacing: self.spacing,
+                size: self.size,
+                text_size: self.text_size,
+                font: &self.font,
+            },
+            self.style_sheet.as_ref(),
+        )
+    }
+}
+
+impl<'a, V, Message, Renderer> Widget<Message, Renderer>
+    for Group<'a, V, Message, Renderer>
+where
+    V: Copy + Eq + ToString,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content().on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        radio::group::update(
+            &event,
+            status,
+            shell,
+            tree.state.downcast_mut::<State>(),
+            &self.options,
+            self.selected,
+            self.on_select.as_ref(),
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content()
+            .draw(renderer, style, layout, cursor_position, viewport)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content().mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+impl<'a, V, Message, Renderer> From<Group<'a, V, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    V: 'a + Copy + Eq + ToString,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(group: Group<'a, V, Message, Renderer>) -> Self {
+        Element::new(group)
+    }
+}
@@ -0,0 +1,272 @@
+//! Display an interactive selector of a range of values.
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::widget::range_slider;
+use iced_native::{Clipboard, Layout, Length, Point, Rectangle, Shell, Size};
+
+use std::ops::RangeInclusive;
+
+pub use iced_style::range_slider::{Handle, HandleShape, Style, StyleSheet};
+
+/// An horizontal bar with two handles that select a range of values between
+/// some bounds.
+///
+/// A [`RangeSlider`] will try to fill the horizontal space of its container.
+///
+/// The handles cannot cross each other. By default, a dragged handle stops at
+/// the other one, but it can also push it instead.
+///
+/// # Example
+/// ```
+/// # use iced_pure::widget::RangeSlider;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceChanged((f32, f32)),
+/// }
+///
+/// let price = (20.0, 80.0);
+///
+/// RangeSlider::new(0.0..=100.0, price, Message::PriceChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message> {
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    values: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    push: bool,
+    width: Length,
+    height: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: u16 = 22;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`RangeSlider`]
+    ///   * a function that will be called when a handle is dragged.
+    ///     It receives the new start and end values of the [`RangeSlider`]
+    ///     and must produce a `Message`.
+    pub fn new<F>(
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+        let values = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            values,
+            range,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            push: false,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets whether a dragged handle pushes the other one instead of
+    /// stopping at it.
+    ///
+    /// By default, it stops.
+    pub fn push(mut self, push: bool) -> Self {
+        self.push = push;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    ///
+    /// It snaps values like the step of a [`Slider`](crate::widget::Slider)
+    /// does.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`] used while Shift is held,
+    /// which allows finer adjustments.
+    pub fn shift_step(mut self, shift_step: T) -> Self {
+        self.shift_step = Some(shift_step);
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<range_slider::State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(range_slider::State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits =
+            limits.width(self.width).height(Length::Units(self.height));
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        range_slider::update(
+            event,
+            layout,
+            cursor_position,
+            shell,
+            tree.state.downcast_mut::<range_slider::State>(),
+            &mut self.values,
+            range_slider::Properties {
+                range: &self.range,
+                step: self.step,
+                shift_step: self.shift_step,
+                push: self.push,
+                on_change: self.on_change.as_ref(),
+                on_release: &self.on_release,
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        range_slider::draw(
+            renderer,
+            layout,
+            cursor_position,
+            tree.state.downcast_ref::<range_slider::State>(),
+            self.values,
+            &self.range,
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        range_slider::mouse_interaction(
+            layout,
+            cursor_position,
+            tree.state.downcast_ref::<range_slider::State>(),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
@@ -0,0 +1,107 @@
+//! Write a paragraph of text with mixed styles.
+use crate::widget::Tree;
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
+
+pub use iced_native::widget::rich_text::{RichText, Span};
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        <Self as iced_native::Widget<Message, Renderer>>::width(self)
+    }
+
+    fn height(&self) -> Length {
+        <Self as iced_native::Widget<Message, Renderer>>::height(self)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        <Self as iced_native::Widget<Message, Renderer>>::layout(
+            self, renderer, limits,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        <Self as iced_native::Widget<Message, Renderer>>::on_event(
+            self,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        <Self as iced_native::Widget<Message, Renderer>>::draw(
+            self,
+            renderer,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        <Self as iced_native::Widget<Message, Renderer>>::mouse_interaction(
+            self,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -7,6 +7,7 @@ use iced_native::event::{self, Event};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::widget::Operation;
 use iced_native::{
     Alignment, Clipboard, Length, Padding, Point, Rectangle, Shell,
 };
@@ -164,6 +165,7 @@ where
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
             .map(|((child, state), layout)| {
                 child.as_widget().mouse_interaction(
                     state,
@@ -191,6 +193,7 @@ where
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
         {
             child.as_widget().draw(
                 state,
@@ -211,6 +214,23 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         overlay::from_children(&self.children, tree, layout, renderer)
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child.as_widget().operate(state, layout, operation);
+                })
+        });
+    }
 }
 
 impl<'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
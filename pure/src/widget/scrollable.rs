@@ -4,23 +4,33 @@ use crate::widget::tree::{self, Tree};
 use crate::{Element, Widget};
 
 use iced_native::event::{self, Event};
+use iced_native::keyboard;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::time::Duration;
 use iced_native::widget::scrollable;
-use iced_native::{Clipboard, Length, Point, Rectangle, Shell, Vector};
+use iced_native::widget::Operation;
+use iced_native::{
+    Alignment, Clipboard, Length, Point, Rectangle, Shell, Vector,
+};
 
+pub use iced_native::widget::scrollable::{
+    scroll_to, snap_to, AbsoluteOffset, Direction, Id, TrackClick, Viewport,
+};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content with a
+/// A widget that can display an infinite amount of content with a
 /// scrollbar.
+///
+/// The arrow, page, home, and end keys scroll a [`Scrollable`] while the
+/// mouse is over it, unless a widget inside of it is focused.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
+    id: Option<Id>,
     height: Length,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
-    on_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    properties: scrollable::Properties,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     style_sheet: Box<dyn StyleSheet + 'a>,
     content: Element<'a, Message, Renderer>,
 }
@@ -31,16 +41,21 @@ impl<'a, Message, Renderer: iced_native::Renderer>
     /// Creates a new [`Scrollable`].
     pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Scrollable {
+            id: None,
             height: Length::Shrink,
-            scrollbar_width: 10,
-            scrollbar_margin: 0,
-            scroller_width: 10,
+            properties: scrollable::Properties::default(),
             on_scroll: None,
             style_sheet: Default::default(),
             content: content.into(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Scrollable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the height of the [`Scrollable`].
     pub fn height(mut self, height: Length) -> Self {
         self.height = height;
@@ -50,13 +65,13 @@ impl<'a, Message, Renderer: iced_native::Renderer>
     /// Sets the scrollbar width of the [`Scrollable`] .
     /// Silently enforces a minimum value of 1.
     pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
-        self.scrollbar_width = scrollbar_width.max(1);
+        self.properties.scrollbar_width = scrollbar_width.max(1);
         self
     }
 
     /// Sets the scrollbar margin of the [`Scrollable`] .
     pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
-        self.scrollbar_margin = scrollbar_margin;
+        self.properties.scrollbar_margin = scrollbar_margin;
         self
     }
 
@@ -64,15 +79,125 @@ impl<'a, Message, Renderer: iced_native::Renderer>
     ///
     /// It silently enforces a minimum value of 1.
     pub fn scroller_width(mut self, scroller_width: u16) -> Self {
-        self.scroller_width = scroller_width.max(1);
+        self.properties.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets the edge of the [`Scrollable`] where its vertical scrollbar is
+    /// placed.
+    ///
+    /// [`Alignment::Start`] places it on the left edge, while any other
+    /// [`Alignment`] keeps it on the right edge, which is the default.
+    pub fn scrollbar_alignment(mut self, alignment: Alignment) -> Self {
+        self.properties.scrollbar_alignment = alignment;
+        self
+    }
+
+    /// Hides the scrollbars of the [`Scrollable`] after the given
+    /// [`Duration`] without any interaction, fading them out.
+    ///
+    /// Scrolling or hovering a scrollbar shows them again. A zero
+    /// [`Duration`] keeps them always visible, which is the default.
+    pub fn hide_scrollbar_after(mut self, duration: Duration) -> Self {
+        self.properties.hide_scrollbar_after =
+            Some(duration).filter(|d| !d.is_zero());
+        self
+    }
+
+    /// Sets the [`Direction`] in which the [`Scrollable`] can scroll.
+    ///
+    /// By default, a [`Scrollable`] only scrolls vertically.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.properties.direction = direction;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] stays anchored to the bottom of its
+    /// contents.
+    ///
+    /// When anchored, a [`Scrollable`] scrolled to the bottom keeps showing
+    /// the bottom as its contents grow. Scrolling up releases the anchor,
+    /// and scrolling back to the bottom restores it.
+    pub fn anchor_bottom(mut self, anchor_bottom: bool) -> Self {
+        self.properties.anchor_bottom = anchor_bottom;
+        self
+    }
+
+    /// Sets the amount of pixels scrolled when an arrow key is pressed.
+    ///
+    /// Page keys scroll by the height of the [`Scrollable`] minus this
+    /// amount.
+    pub fn line_step(mut self, line_step: u16) -> Self {
+        self.properties.line_step = line_step;
+        self
+    }
+
+    /// Sets the factor by which the wheel and touchpad deltas are
+    /// multiplied before scrolling, along both axes.
+    ///
+    /// By default, it is `1.0`.
+    pub fn scroll_factor(mut self, scroll_factor: f32) -> Self {
+        self.properties.scroll_factor = scroll_factor;
+        self
+    }
+
+    /// Sets the height of a line of text, in pixels, used to convert the
+    /// ticks of a mouse wheel into pixels.
+    ///
+    /// Every tick scrolls three lines.
+    pub fn scroll_line_height(mut self, scroll_line_height: u16) -> Self {
+        self.properties.scroll_line_height = scroll_line_height;
+        self
+    }
+
+    /// Sets whether a wheel event that cannot scroll the [`Scrollable`]
+    /// any further is passed on to the [`Scrollable`] containing it.
+    ///
+    /// This is known as scroll chaining and it is enabled by default. You
+    /// may want to disable it for widgets that should keep the wheel to
+    /// themselves, like embedded maps.
+    pub fn scroll_chaining(mut self, scroll_chaining: bool) -> Self {
+        self.properties.scroll_chaining = scroll_chaining;
+        self
+    }
+
+    /// Sets the friction that slows down the [`Scrollable`] after a flick
+    /// on a touch screen.
+    ///
+    /// The speed of the flick decays exponentially at this rate, per second.
+    /// By default, it is `4.0`.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.properties.friction = friction.max(0.0);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] keeps what is in view in place when
+    /// the contents above it change, like when older messages are loaded at
+    /// the top of a chat.
+    ///
+    /// The children of the contents are told apart by their height, so a
+    /// change that could have happened either above or below the viewport is
+    /// assumed to be below it. By default, scroll anchoring is disabled.
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.properties.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Sets what happens when the empty part of a scrollbar track of the
+    /// [`Scrollable`] is clicked.
+    ///
+    /// By default, it pages toward the click.
+    pub fn track_click(mut self, track_click: TrackClick) -> Self {
+        self.properties.track_click = track_click;
         self
     }
 
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
-    /// The function takes the new relative offset of the [`Scrollable`]
-    /// (e.g. `0` means top, while `1` means bottom).
-    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'a) -> Self {
+    /// The function takes the new [`Viewport`] of the [`Scrollable`]. It is
+    /// only called when the scrolling offset actually changes, including
+    /// when it is changed programmatically.
+    pub fn on_scroll(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
         self.on_scroll = Some(Box::new(f));
         self
     }
@@ -126,6 +251,7 @@ where
             limits,
             Widget::<Message, Renderer>::width(self),
             self.height,
+            self.properties.direction,
             |renderer, limits| {
                 self.content.as_widget().layout(renderer, limits)
             },
@@ -142,6 +268,18 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let is_content_focused =
+            matches!(
+                event,
+                Event::Keyboard(keyboard::Event::KeyPressed { .. })
+            ) && scrollable::is_content_focused(|operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    operation,
+                )
+            });
+
         scrollable::update(
             tree.state.downcast_mut::<scrollable::State>(),
             event,
@@ -149,9 +287,8 @@ where
             cursor_position,
             clipboard,
             shell,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
+            is_content_focused,
             &self.on_scroll,
             |event, layout, cursor_position, clipboard, shell| {
                 self.content.as_widget_mut().on_event(
@@ -181,9 +318,7 @@ where
             renderer,
             layout,
             cursor_position,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
             self.style_sheet.as_ref(),
             |renderer, layout, cursor_position, viewport| {
                 self.content.as_widget().draw(
@@ -210,9 +345,7 @@ where
             tree.state.downcast_ref::<scrollable::State>(),
             layout,
             cursor_position,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
+            &self.properties,
             |layout, cursor_position, viewport| {
                 self.content.as_widget().mouse_interaction(
                     &tree.children[0],
@@ -247,9 +380,32 @@ where
                     .downcast_ref::<scrollable::State>()
                     .offset(bounds, content_bounds);
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
+                overlay.translate(Vector::new(
+                    -(offset.x as f32),
+                    -(offset.y as f32),
+                ))
             })
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        scrollable::operate(
+            tree.state.downcast_mut::<scrollable::State>(),
+            self.id.as_ref(),
+            operation,
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    operation,
+                );
+            },
+        );
+    }
 }
 
 impl<'a, Message, Renderer> From<Scrollable<'a, Message, Renderer>>
@@ -0,0 +1,193 @@
+//! Display text that can be selected and copied.
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::selectable_text;
+use iced_native::{Clipboard, Color, Length, Point, Rectangle, Shell};
+
+pub use iced_style::selectable_text::{Style, StyleSheet};
+
+/// A paragraph of text that can be selected with the mouse and copied to the
+/// [`Clipboard`].
+///
+/// # Example
+/// ```
+/// # type SelectableText<'a> =
+/// #     iced_pure::widget::SelectableText<'a, iced_native::renderer::Null>;
+/// SelectableText::new("Error: file not found").size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer: text::Renderer> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Renderer: text::Renderer> SelectableText<'a, Renderer> {
+    /// Creates a new [`SelectableText`] with the given contents.
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        SelectableText {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`Font`]: iced_native::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> selectable_text::Properties<'_, Renderer::Font> {
+        selectable_text::Properties {
+            content: &self.content,
+            size: self.size,
+            font: &self.font,
+            color: self.color,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<selectable_text::State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(selectable_text::State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        selectable_text::layout(renderer, limits, self.properties())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        selectable_text::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            self.properties(),
+            || tree.state.downcast_mut::<selectable_text::State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        selectable_text::draw(
+            renderer,
+            style,
+            layout,
+            tree.state.downcast_ref::<selectable_text::State>(),
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        selectable_text::mouse_interaction(layout, cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
@@ -40,6 +40,8 @@ pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 pub struct Slider<'a, T, Message> {
     range: RangeInclusive<T>,
     step: T,
+    shift_step: Option<T>,
+    default_value: Option<T>,
     value: T,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
@@ -84,6 +86,8 @@ where
             value,
             range,
             step: T::from(1),
+            shift_step: None,
+            default_value: None,
             on_change: Box::new(on_change),
             on_release: None,
             width: Length::Fill,
@@ -125,10 +129,27 @@ where
     }
 
     /// Sets the step size of the [`Slider`].
+    ///
+    /// Values snap to multiples of the step counted from the start of the
+    /// range. If the range is not an exact multiple of the step, the last
+    /// step is shorter.
     pub fn step(mut self, step: T) -> Self {
         self.step = step;
         self
     }
+
+    /// Sets the step size of the [`Slider`] used while Shift is held, which
+    /// allows finer adjustments.
+    pub fn shift_step(mut self, shift_step: T) -> Self {
+        self.shift_step = Some(shift_step);
+        self
+    }
+
+    /// Sets the value the [`Slider`] is reset to when it is double-clicked.
+    pub fn default_value(mut self, default_value: T) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -186,6 +207,8 @@ where
             &mut self.value,
             &self.range,
             self.step,
+            self.shift_step,
+            self.default_value,
             self.on_change.as_ref(),
             &self.on_release,
         )
@@ -8,9 +8,9 @@ use iced_native::widget::svg;
 use iced_native::{Length, Point, Rectangle};
 
 pub use iced_native::svg::Handle;
-pub use svg::Svg;
+pub use svg::{Style, StyleSheet, Svg, Tint};
 
-impl<Message, Renderer> Widget<Message, Renderer> for Svg
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Svg<'a>
 where
     Renderer: iced_native::svg::Renderer,
 {
@@ -52,7 +52,7 @@ where
     }
 }
 
-impl<'a, Message, Renderer> Into<Element<'a, Message, Renderer>> for Svg
+impl<'a, Message, Renderer> Into<Element<'a, Message, Renderer>> for Svg<'a>
 where
     Message: Clone + 'a,
     Renderer: iced_native::svg::Renderer + 'a,
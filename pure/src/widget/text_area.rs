@@ -0,0 +1,232 @@
+//! Display fields that can be filled with multiple lines of text.
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::text_area;
+use iced_native::widget::text_input::Value;
+use iced_native::{Clipboard, Length, Padding, Point, Rectangle, Shell};
+
+pub use iced_style::text_area::{Style, StyleSheet};
+
+/// A field that can be filled with multiple lines of text.
+///
+/// # Example
+/// ```
+/// # pub type TextArea<'a, Message> = iced_pure::widget::TextArea<'a, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TextAreaChanged(String),
+/// }
+///
+/// let value = "Some text\nspanning multiple lines";
+///
+/// let text_area = TextArea::new(
+///     "This is the placeholder...",
+///     value,
+///     Message::TextAreaChanged,
+/// )
+/// .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextArea<'a, Message, Renderer: text::Renderer> {
+    placeholder: String,
+    lines: Vec<Value>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> TextArea<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`TextArea`].
+    ///
+    /// It expects:
+    /// - a placeholder,
+    /// - the current value, and
+    /// - a function that produces a message when the [`TextArea`] changes.
+    pub fn new<F>(placeholder: &str, value: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        TextArea {
+            placeholder: String::from(placeholder),
+            lines: text_area::lines(value),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            size: None,
+            on_change: Box::new(on_change),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the [`Font`] of the [`TextArea`].
+    ///
+    /// [`Font`]: iced_native::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextArea`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextArea`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextArea`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextArea`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextArea`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> text_area::Properties<'_, Message, Renderer::Font> {
+        text_area::Properties {
+            width: self.width,
+            height: self.height,
+            padding: self.padding,
+            size: self.size,
+            font: &self.font,
+            placeholder: &self.placeholder,
+            on_change: self.on_change.as_ref(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextArea<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<text_area::State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(text_area::State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_area::layout(renderer, limits, self.properties(), self.lines.len())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        text_area::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            &mut self.lines,
+            text_area::Properties {
+                width: self.width,
+                height: self.height,
+                padding: self.padding,
+                size: self.size,
+                font: &self.font,
+                placeholder: &self.placeholder,
+                on_change: self.on_change.as_ref(),
+            },
+            || tree.state.downcast_mut::<text_area::State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        text_area::draw(
+            renderer,
+            layout,
+            cursor_position,
+            tree.state.downcast_ref::<text_area::State>(),
+            &self.lines,
+            self.properties(),
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_area::mouse_interaction(layout, cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> From<TextArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        text_area: TextArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_area)
+    }
+}
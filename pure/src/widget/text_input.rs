@@ -3,17 +3,30 @@ use crate::widget::tree::{self, Tree};
 use crate::{Element, Widget};
 
 use iced_native::event::{self, Event};
+use iced_native::keyboard;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
 use iced_native::text;
 use iced_native::widget::text_input;
+use iced_native::widget::Operation;
 use iced_native::{Clipboard, Length, Padding, Point, Rectangle, Shell};
 
+pub use iced_native::widget::text_input::{
+    cursor_offset, focus, move_cursor_to_end, select_all, Icon, Id,
+    NumericOptions, Side,
+};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
 ///
+/// # Bidirectional text
+/// The cursor of a [`TextInput`] moves over grapheme clusters in logical
+/// order. Over right-to-left text, the Left and Right keys move it
+/// backwards and forwards in the value, not in the visual direction.
+/// However, the cursor is drawn, and placed by clicks, where the text is
+/// displayed.
+///
 /// # Example
 /// ```
 /// # pub type TextInput<'a, Message> = iced_pure::widget::TextInput<'a, Message, iced_native::renderer::Null>;
@@ -34,15 +47,36 @@ pub use iced_style::text_input::{Style, StyleSheet};
 /// ![Text input drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text_input.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: text::Renderer> {
+    id: Option<Id>,
     placeholder: String,
     value: text_input::Value,
     is_secure: bool,
+    secure_character: char,
+    is_disabled: bool,
+    is_error: bool,
     font: Renderer::Font,
+    spacing: text::Spacing,
     width: Length,
     padding: Padding,
     size: Option<u16>,
+    leading_icon: Option<Icon<Renderer::Font>>,
+    trailing_icon: Option<Icon<Renderer::Font>>,
     on_change: Box<dyn Fn(String) -> Message + 'a>,
     on_submit: Option<Message>,
+    on_submit_with: Option<Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+    on_icon_press: Option<Message>,
+    on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_cursor_move: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    #[allow(clippy::type_complexity)]
+    on_change_parsed: Option<Box<dyn Fn(Result<f64, String>) -> Message + 'a>>,
+    paste_filter: Option<Box<dyn Fn(String) -> String + 'a>>,
+    max_length: Option<usize>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    numeric: Option<NumericOptions>,
+    history_depth: usize,
+    primary_selection: bool,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -62,25 +96,77 @@ where
         F: 'a + Fn(String) -> Message,
     {
         TextInput {
+            id: None,
             placeholder: String::from(placeholder),
             value: text_input::Value::new(value),
             is_secure: false,
+            secure_character: text_input::DEFAULT_SECURE_CHARACTER,
+            is_disabled: false,
+            is_error: false,
             font: Default::default(),
+            spacing: text::Spacing::default(),
             width: Length::Fill,
             padding: Padding::ZERO,
             size: None,
+            leading_icon: None,
+            trailing_icon: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            on_submit_with: None,
+            on_focus: None,
+            on_blur: None,
+            on_icon_press: None,
+            on_paste: None,
+            on_cursor_move: None,
+            on_change_parsed: None,
+            paste_filter: None,
+            max_length: None,
+            filter: None,
+            numeric: None,
+            history_depth: text_input::DEFAULT_HISTORY_DEPTH,
+            primary_selection: false,
             style_sheet: Default::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
         self
     }
 
+    /// Sets the character used to obscure the value of the [`TextInput`]
+    /// when it is a secure password input.
+    ///
+    /// By default, the dot ('•') character is used.
+    pub fn secure_character(mut self, character: char) -> Self {
+        self.secure_character = character;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] is disabled.
+    ///
+    /// A disabled [`TextInput`] ignores any interaction and loses its focus.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] has an error.
+    ///
+    /// A [`TextInput`] with an error is drawn with the
+    /// [`StyleSheet::errored`] style.
+    pub fn error(mut self, is_error: bool) -> Self {
+        self.is_error = is_error;
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: iced_native::text::Renderer::Font
@@ -106,10 +192,195 @@ where
         self
     }
 
+    /// Sets the [`LineHeight`] of the [`TextInput`], which determines the
+    /// height of its text area.
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn line_height(mut self, line_height: text::LineHeight) -> Self {
+        self.spacing.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the amount of logical pixels added between the characters of
+    /// the [`TextInput`].
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.spacing.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the distance between the tab stops of the [`TextInput`], in
+    /// spaces.
+    ///
+    /// By default, it is 4.
+    pub fn tab_width(mut self, spaces: u8) -> Self {
+        self.spacing.tab_width = spaces;
+        self
+    }
+
+    /// Adds an [`Icon`] to the [`TextInput`].
+    ///
+    /// A [`TextInput`] can have an [`Icon`] on each [`Side`], which shrinks
+    /// the area used to display its value.
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        match icon.side {
+            Side::Leading => self.leading_icon = Some(icon),
+            Side::Trailing => self.trailing_icon = Some(icon),
+        }
+
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
+    ///
+    /// It replaces any function set with [`on_submit_with`].
+    ///
+    /// [`on_submit_with`]: Self::on_submit_with
     pub fn on_submit(mut self, message: Message) -> Self {
         self.on_submit = Some(message);
+        self.on_submit_with = None;
+        self
+    }
+
+    /// Sets the function that produces a message when the [`TextInput`] is
+    /// focused and the enter key is pressed.
+    ///
+    /// The function receives the keyboard modifiers that were held when the
+    /// enter key was pressed. It replaces any message set with
+    /// [`on_submit`].
+    ///
+    /// [`on_submit`]: Self::on_submit
+    pub fn on_submit_with(
+        mut self,
+        on_submit: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_submit_with = Some(Box::new(on_submit));
+        self.on_submit = None;
+        self
+    }
+
+    /// Sets the message that should be produced when the trailing icon of
+    /// the [`TextInput`] is pressed.
+    pub fn on_icon_press(mut self, message: Message) -> Self {
+        self.on_icon_press = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`]
+    /// gains focus.
+    ///
+    /// When the focus is given by an operation, like [`focus`], the message
+    /// is produced with the next event processed by the [`TextInput`].
+    ///
+    /// [`focus`]: iced_native::widget::text_input::focus
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`]
+    /// loses focus.
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor of the
+    /// [`TextInput`] moves.
+    ///
+    /// The message receives the new position of the cursor, as a grapheme
+    /// index into the value.
+    pub fn on_cursor_move(
+        mut self,
+        on_cursor_move: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_cursor_move = Some(Box::new(on_cursor_move));
+        self
+    }
+
+    /// Sets the message that should be produced when the value of the
+    /// [`TextInput`] changes, parsed as a number.
+    ///
+    /// The value is parsed following the [`NumericOptions`] of the
+    /// [`TextInput`], if any. On failure, the message receives the raw
+    /// value instead.
+    pub fn on_change_parsed(
+        mut self,
+        on_change_parsed: impl Fn(Result<f64, String>) -> Message + 'a,
+    ) -> Self {
+        self.on_change_parsed = Some(Box::new(on_change_parsed));
+        self
+    }
+
+    /// Sets the message that should be produced when content is pasted
+    /// into the [`TextInput`].
+    ///
+    /// When set, the message receives the contents of the clipboard and the
+    /// [`TextInput`] does not insert them by itself.
+    pub fn on_paste(
+        mut self,
+        on_paste: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_paste = Some(Box::new(on_paste));
+        self
+    }
+
+    /// Sets the function used to transform the contents of the clipboard
+    /// before they are pasted into the [`TextInput`].
+    pub fn paste_filter(
+        mut self,
+        paste_filter: impl Fn(String) -> String + 'a,
+    ) -> Self {
+        self.paste_filter = Some(Box::new(paste_filter));
+        self
+    }
+
+    /// Sets the maximum amount of graphemes that the [`TextInput`] can
+    /// contain.
+    ///
+    /// Any input that would exceed this length is discarded.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the filter that decides which characters are accepted by the
+    /// [`TextInput`].
+    ///
+    /// Rejected characters are neither typed nor pasted.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Restricts the [`TextInput`] to numbers, following the given
+    /// [`NumericOptions`].
+    ///
+    /// Only digits, a single decimal separator, and a leading sign are
+    /// accepted. The arrow keys step the value up and down.
+    pub fn numeric(mut self, options: NumericOptions) -> Self {
+        self.numeric = Some(options);
+        self
+    }
+
+    /// Sets the maximum amount of edits that can be undone in the
+    /// [`TextInput`].
+    ///
+    /// By default, the last 100 edits are kept.
+    pub fn history_depth(mut self, depth: usize) -> Self {
+        self.history_depth = depth;
+        self
+    }
+
+    /// Sets whether the [`TextInput`] uses the primary selection of the
+    /// [`Clipboard`], where available.
+    ///
+    /// When enabled, selected text is written to the primary selection and
+    /// a middle click pastes it. It is disabled by default.
+    ///
+    /// [`Clipboard`]: iced_native::Clipboard
+    pub fn primary_selection(mut self, primary_selection: bool) -> Self {
+        self.primary_selection = primary_selection;
         self
     }
 
@@ -121,6 +392,14 @@ where
         self.style_sheet = style_sheet.into();
         self
     }
+
+    fn mask(&self) -> Option<char> {
+        if self.is_secure {
+            Some(self.secure_character)
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -156,6 +435,9 @@ where
             self.width,
             self.padding,
             self.size,
+            self.spacing,
+            self.leading_icon.as_ref(),
+            self.trailing_icon.as_ref(),
         )
     }
 
@@ -169,7 +451,16 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        text_input::update(
+        let previous_position = tree
+            .state
+            .downcast_ref::<text_input::State>()
+            .cursor_position(&self.value);
+
+        let secure_character = self.mask();
+        let previous_value =
+            self.on_change_parsed.as_ref().map(|_| self.value.clone());
+
+        let status = text_input::update(
             event,
             layout,
             cursor_position,
@@ -177,13 +468,67 @@ where
             clipboard,
             shell,
             &mut self.value,
+            text_input::Properties {
+                size: self.size,
+                font: &self.font,
+                spacing: self.spacing,
+                secure_character,
+                is_disabled: self.is_disabled,
+                on_change: self.on_change.as_ref(),
+                on_submit: &self.on_submit,
+                on_submit_with: self.on_submit_with.as_deref(),
+                on_icon_press: &self.on_icon_press,
+                on_paste: self.on_paste.as_deref(),
+                paste_filter: self.paste_filter.as_deref(),
+                max_length: self.max_length,
+                filter: self.filter.as_deref(),
+                numeric: self.numeric.as_ref(),
+                history_depth: self.history_depth,
+                primary_selection: self.primary_selection,
+                on_focus: &self.on_focus,
+                on_blur: &self.on_blur,
+            },
+            || tree.state.downcast_mut::<text_input::State>(),
+        );
+
+        text_input::scroll_to_cursor(
+            renderer,
+            layout,
+            &self.value,
             self.size,
             &self.font,
-            self.is_secure,
-            self.on_change.as_ref(),
-            &self.on_submit,
-            || tree.state.downcast_mut::<text_input::State>(),
-        )
+            self.spacing,
+            secure_character,
+            tree.state.downcast_mut::<text_input::State>(),
+        );
+
+        if let Some(on_cursor_move) = &self.on_cursor_move {
+            let new_position = tree
+                .state
+                .downcast_ref::<text_input::State>()
+                .cursor_position(&self.value);
+
+            if new_position != previous_position {
+                shell.publish((on_cursor_move)(new_position));
+            }
+        }
+
+        if let (Some(on_change_parsed), Some(previous_value)) =
+            (&self.on_change_parsed, previous_value)
+        {
+            if self.value != previous_value {
+                let value = self.value.to_string();
+
+                let parsed = match &self.numeric {
+                    Some(numeric) => numeric.parse(&value),
+                    None => NumericOptions::default().parse(&value),
+                };
+
+                shell.publish((on_change_parsed)(parsed));
+            }
+        }
+
+        status
     }
 
     fn draw(
@@ -204,7 +549,12 @@ where
             &self.placeholder,
             self.size,
             &self.font,
-            self.is_secure,
+            self.spacing,
+            self.mask(),
+            self.is_disabled,
+            self.is_error,
+            self.leading_icon.as_ref(),
+            self.trailing_icon.as_ref(),
             self.style_sheet.as_ref(),
         )
     }
@@ -217,7 +567,21 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        text_input::mouse_interaction(layout, cursor_position)
+        text_input::mouse_interaction(layout, cursor_position, self.is_disabled)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        text_input::operate(
+            tree.state.downcast_mut::<text_input::State>(),
+            self.id.as_ref(),
+            self.is_disabled,
+            operation,
+        )
     }
 }
 
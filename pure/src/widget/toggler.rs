@@ -1,27 +1,182 @@
 //! Show toggle controls using togglers.
-use crate::widget::{Tree, Widget};
-use crate::Element;
+use crate::widget::tree::{self, Tree};
+use crate::{Element, Widget};
 
+use iced_native::alignment;
 use iced_native::event::{self, Event};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
 use iced_native::text;
+use iced_native::widget::toggler;
 use iced_native::{Clipboard, Length, Point, Rectangle, Shell};
 
-pub use iced_native::widget::toggler::{Style, StyleSheet, Toggler};
+pub use iced_native::widget::toggler::{Position, State, Style, StyleSheet};
+
+/// A toggler widget.
+///
+/// The knob of a [`Toggler`] moves smoothly whenever it is toggled.
+///
+/// # Example
+///
+/// ```
+/// # type Toggler<'a, Message> = iced_pure::widget::Toggler<'a, Message, iced_native::renderer::Null>;
+/// #
+/// pub enum Message {
+///     TogglerToggled(bool),
+/// }
+///
+/// let is_active = true;
+///
+/// Toggler::new(is_active, String::from("Toggle me!"), |b| Message::TogglerToggled(b));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toggler<'a, Message, Renderer: text::Renderer> {
+    is_active: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
+    label: Option<String>,
+    width: Length,
+    size: u16,
+    text_size: Option<u16>,
+    text_alignment: alignment::Horizontal,
+    text_position: Position,
+    spacing: u16,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer: text::Renderer> Toggler<'a, Message, Renderer> {
+    /// The default size of a [`Toggler`].
+    pub const DEFAULT_SIZE: u16 = 20;
+
+    /// Creates a new [`Toggler`].
+    ///
+    /// It expects:
+    ///   * a boolean describing whether the [`Toggler`] is checked or not
+    ///   * An optional label for the [`Toggler`]
+    ///   * a function that will be called when the [`Toggler`] is toggled. It
+    ///     will receive the new state of the [`Toggler`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(
+        is_active: bool,
+        label: impl Into<Option<String>>,
+        f: F,
+    ) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        Toggler {
+            is_active,
+            on_toggle: Box::new(f),
+            label: label.into(),
+            width: Length::Fill,
+            size: Self::DEFAULT_SIZE,
+            text_size: None,
+            text_alignment: alignment::Horizontal::Left,
+            text_position: Position::Left,
+            spacing: 0,
+            font: Renderer::Font::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`Toggler`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the [`Toggler`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the text size o the [`Toggler`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the horizontal alignment of the text of the [`Toggler`]
+    pub fn text_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    /// Sets the side of the [`Toggler`] where its text is placed.
+    ///
+    /// By default, the text is placed to the left of the [`Toggler`], which
+    /// sits at the far end when it fills its width.
+    pub fn text_position(mut self, position: Position) -> Self {
+        self.text_position = position;
+        self
+    }
+
+    /// Sets the spacing between the [`Toggler`] and the text.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the [`Font`] of the text of the [`Toggler`]
+    ///
+    /// [`Font`]: iced_native::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Toggler`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn properties(&self) -> toggler::Properties<'_, Renderer::Font> {
+        toggler::Properties {
+            is_active: self.is_active,
+            label: self.label.as_deref(),
+            width: self.width,
+            size: self.size,
+            text_size: self.text_size,
+            text_alignment: self.text_alignment,
+            text_position: self.text_position,
+            spacing: self.spacing,
+            font: &self.font,
+        }
+    }
+}
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Toggler<'a, Message, Renderer>
 where
     Renderer: text::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::new();
+        state.sync(self.is_active);
+
+        tree::State::new(state)
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().sync(self.is_active);
+    }
+
     fn width(&self) -> Length {
-        <Self as iced_native::Widget<Message, Renderer>>::width(self)
+        self.width
     }
 
     fn height(&self) -> Length {
-        <Self as iced_native::Widget<Message, Renderer>>::height(self)
+        Length::Shrink
     }
 
     fn layout(
@@ -29,76 +184,69 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        <Self as iced_native::Widget<Message, Renderer>>::layout(
-            self, renderer, limits,
+        toggler::layout(renderer, limits, self.properties())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        toggler::update(
+            event,
+            layout,
+            cursor_position,
+            shell,
+            self.is_active,
+            self.on_toggle.as_ref(),
+            Some(tree.state.downcast_mut::<State>()),
         )
     }
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         style: &renderer::Style,
         layout: Layout<'_>,
         cursor_position: Point,
-        viewport: &Rectangle,
+        _viewport: &Rectangle,
     ) {
-        <Self as iced_native::Widget<Message, Renderer>>::draw(
-            self,
+        toggler::draw(
             renderer,
             style,
             layout,
             cursor_position,
-            viewport,
+            self.properties(),
+            self.style_sheet.as_ref(),
+            Some(tree.state.downcast_ref::<State>()),
         )
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        _tree: &Tree,
         layout: Layout<'_>,
         cursor_position: Point,
-        viewport: &Rectangle,
-        renderer: &Renderer,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
     ) -> mouse::Interaction {
-        <Self as iced_native::Widget<Message, Renderer>>::mouse_interaction(
-            self,
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
-    }
-
-    fn on_event(
-        &mut self,
-        _state: &mut Tree,
-        event: Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<'_, Message>,
-    ) -> event::Status {
-        <Self as iced_native::Widget<Message, Renderer>>::on_event(
-            self,
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        )
+        toggler::mouse_interaction(layout, cursor_position)
     }
 }
 
-impl<'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
-    for Toggler<'a, Message, Renderer>
+impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
 where
     Message: 'a,
     Renderer: text::Renderer + 'a,
 {
-    fn into(self) -> Element<'a, Message, Renderer> {
-        Element::new(self)
+    fn from(toggler: Toggler<'a, Message, Renderer>) -> Self {
+        Element::new(toggler)
     }
 }
@@ -8,6 +8,7 @@ use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::text;
 use iced_native::widget::tooltip;
+use iced_native::widget::Operation;
 use iced_native::widget::Text;
 use iced_native::{Clipboard, Layout, Length, Point, Rectangle, Shell};
 
@@ -212,6 +213,19 @@ where
             renderer,
         )
     }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
 }
 
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
@@ -160,6 +160,12 @@ pub trait Application: Sized {
 
     /// Returns the background color of the [`Application`].
     ///
+    /// A color with some transparency lets the desktop show through a
+    /// transparent window. See [`window::Settings::transparent`].
+    ///
+    /// The `wgpu` renderer always presents opaque windows for now, so the
+    /// transparency of the color only has an effect with `glow`.
+    ///
     /// By default, it returns [`Color::WHITE`].
     fn background_color(&self) -> Color {
         Color::WHITE
@@ -200,6 +206,7 @@ pub trait Application: Sized {
     {
         let renderer_settings = crate::renderer::Settings {
             default_font: settings.default_font,
+            fallback_fonts: settings.fallback_fonts,
             default_text_size: settings.default_text_size,
             text_multithreading: settings.text_multithreading,
             antialiasing: if settings.antialiasing {
@@ -253,11 +260,7 @@ where
     }
 
     fn mode(&self) -> iced_winit::Mode {
-        match self.0.mode() {
-            window::Mode::Windowed => iced_winit::Mode::Windowed,
-            window::Mode::Fullscreen => iced_winit::Mode::Fullscreen,
-            window::Mode::Hidden => iced_winit::Mode::Hidden,
-        }
+        self.0.mode()
     }
 
     fn subscription(&self) -> Subscription<Self::Message> {
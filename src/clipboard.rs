@@ -1,3 +1,6 @@
 //! Access the clipboard.
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::clipboard::{read, write};
+pub use crate::runtime::clipboard::{
+    read, read_data, read_image, read_primary, write, write_data, write_image,
+    write_primary, ImageData, MimeType,
+};
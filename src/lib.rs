@@ -199,6 +199,10 @@ pub mod window;
 #[cfg_attr(docsrs, doc(cfg(feature = "pure")))]
 pub mod pure;
 
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+pub mod multi_window;
+
 #[cfg(all(not(feature = "glow"), feature = "wgpu"))]
 use iced_winit as runtime;
 
@@ -0,0 +1,182 @@
+//! Create applications with multiple windows.
+use crate::window;
+use crate::{Color, Command, Element, Executor, Settings, Subscription};
+
+/// An interactive cross-platform application with multiple windows.
+///
+/// It works like an [`Application`], but every window is identified by a
+/// [`window::Id`] and has its own title and view. The application starts
+/// with the [`window::Id::MAIN`] window, and it can open more of them with
+/// [`window::spawn`]. Window commands affect the main window, unless they are
+/// wrapped with [`window::target`].
+///
+/// Closing the main window exits the application. When any other window is
+/// closed, either by the user or with [`window::close`], a
+/// [`window::Event::Closed`] is produced for it, which can be listened to
+/// with [`subscription::events_by_window`].
+///
+/// Only the `wgpu` renderer supports multiple windows for now.
+///
+/// # Examples
+/// The [`multi_window`] example opens a new window for every press of a
+/// button and keeps track of the ones that are closed.
+///
+/// [`Application`]: crate::Application
+/// [`window::Event::Closed`]: iced_native::window::Event::Closed
+/// [`subscription::events_by_window`]: iced_native::subscription::events_by_window
+/// [`multi_window`]: https://github.com/iced-rs/iced/tree/0.4/examples/multi_window
+pub trait Application: Sized {
+    /// The [`Executor`] that will run commands and subscriptions.
+    ///
+    /// The [default executor] can be a good starting point!
+    ///
+    /// [`Executor`]: Self::Executor
+    /// [default executor]: crate::executor::Default
+    type Executor: Executor;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// [`run`]: Self::run
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the event [`Subscription`] for the current state of the
+    /// application.
+    ///
+    /// By default, this method returns an empty [`Subscription`].
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message>;
+
+    /// Returns the current [`window::Mode`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, windows run in windowed mode.
+    fn mode(&self, _window: window::Id) -> window::Mode {
+        window::Mode::Windowed
+    }
+
+    /// Returns the background color of the window with the given
+    /// [`window::Id`].
+    ///
+    /// The `wgpu` renderer always presents opaque windows for now, so any
+    /// transparency of the color is ignored.
+    ///
+    /// By default, it returns [`Color::WHITE`].
+    fn background_color(&self, _window: window::Id) -> Color {
+        Color::WHITE
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Runs the [`Application`].
+    ///
+    /// This method will take control of the current thread until the
+    /// [`Application`] exits. The window settings are used for the main
+    /// window.
+    fn run(settings: Settings<Self::Flags>) -> crate::Result
+    where
+        Self: 'static,
+    {
+        let renderer_settings = crate::renderer::Settings {
+            default_font: settings.default_font,
+            fallback_fonts: settings.fallback_fonts,
+            default_text_size: settings.default_text_size,
+            text_multithreading: settings.text_multithreading,
+            antialiasing: if settings.antialiasing {
+                Some(crate::renderer::settings::Antialiasing::MSAAx4)
+            } else {
+                None
+            },
+            ..crate::renderer::Settings::from_env()
+        };
+
+        Ok(crate::runtime::multi_window::run::<
+            Instance<Self>,
+            Self::Executor,
+            crate::renderer::window::Compositor,
+        >(settings.into(), renderer_settings)?)
+    }
+}
+
+struct Instance<A: Application>(A);
+
+impl<A> crate::runtime::multi_window::Application for Instance<A>
+where
+    A: Application,
+{
+    type Renderer = crate::renderer::Renderer;
+    type Message = A::Message;
+    type Flags = A::Flags;
+
+    fn new(flags: Self::Flags) -> (Self, Command<A::Message>) {
+        let (app, command) = A::new(flags);
+
+        (Instance(app), command)
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        self.0.title(window)
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        self.0.update(message)
+    }
+
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message> {
+        self.0.view(window)
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        self.0.subscription()
+    }
+
+    fn mode(&self, window: window::Id) -> iced_winit::Mode {
+        self.0.mode(window)
+    }
+
+    fn background_color(&self, window: window::Id) -> Color {
+        self.0.background_color(window)
+    }
+
+    fn scale_factor(&self, window: window::Id) -> f64 {
+        self.0.scale_factor(window)
+    }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+}
@@ -82,6 +82,12 @@ pub trait Application: Sized {
 
     /// Returns the background color of the [`Application`].
     ///
+    /// A color with some transparency lets the desktop show through a
+    /// transparent window. See [`window::Settings::transparent`].
+    ///
+    /// The `wgpu` renderer always presents opaque windows for now, so the
+    /// transparency of the color only has an effect with `glow`.
+    ///
     /// By default, it returns [`Color::WHITE`].
     fn background_color(&self) -> Color {
         Color::WHITE
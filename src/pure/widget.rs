@@ -8,8 +8,15 @@ pub type Column<'a, Message> =
 pub type Row<'a, Message> =
     iced_pure::widget::Row<'a, Message, crate::Renderer>;
 
-/// A paragraph of text.
-pub type Text = iced_pure::widget::Text<crate::Renderer>;
+pub mod text {
+    //! Write some text for your users to read.
+    pub use iced_native::widget::text::{
+        LineHeight, Rotation, Truncation, Wrap,
+    };
+
+    /// A paragraph of text.
+    pub type Text = iced_pure::widget::Text<crate::Renderer>;
+}
 
 pub mod button {
     //! Allow your users to perform actions by pressing a button.
@@ -22,7 +29,7 @@ pub mod button {
 
 pub mod checkbox {
     //! Show toggle controls using checkboxes.
-    pub use iced_pure::widget::checkbox::{Style, StyleSheet};
+    pub use iced_pure::widget::checkbox::{Position, Style, StyleSheet};
 
     /// A box that can be checked.
     pub type Checkbox<'a, Message> =
@@ -38,6 +45,15 @@ pub mod container {
         iced_pure::widget::Container<'a, Message, crate::Renderer>;
 }
 
+pub mod link {
+    //! Display clickable text, like a hyperlink.
+    pub use iced_pure::widget::link::{Style, StyleSheet};
+
+    /// Some text that produces a message when clicked, like a hyperlink.
+    pub type Link<'a, Message> =
+        iced_pure::widget::Link<'a, Message, crate::Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -72,7 +88,7 @@ pub mod pane_grid {
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_pure::overlay::menu::Style as Menu;
-    pub use iced_pure::widget::pick_list::{Style, StyleSheet};
+    pub use iced_pure::widget::pick_list::{open, Id, Style, StyleSheet};
 
     /// A widget allowing the selection of a single value from a list of options.
     pub type PickList<'a, T, Message> =
@@ -81,37 +97,79 @@ pub mod pick_list {
 
 pub mod radio {
     //! Create choices using radio buttons.
-    pub use iced_pure::widget::radio::{Style, StyleSheet};
+    pub use iced_pure::widget::radio::{group, Style, StyleSheet};
 
     /// A circular button representing a choice.
     pub type Radio<'a, Message> =
         iced_pure::widget::Radio<'a, Message, crate::Renderer>;
+
+    /// A set of radio buttons that share their appearance.
+    pub type Group<'a, V, Message> =
+        iced_pure::widget::radio::Group<'a, V, Message, crate::Renderer>;
+}
+
+pub mod rich_text {
+    //! Write a paragraph of text with mixed styles.
+
+    /// A run of text with its own style, part of a [`RichText`].
+    pub type Span<Message> =
+        iced_pure::widget::rich_text::Span<Message, crate::Font>;
+
+    /// A paragraph made of differently styled spans.
+    pub type RichText<Message> =
+        iced_pure::widget::RichText<Message, crate::Renderer>;
 }
 
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
-    pub use iced_pure::widget::scrollable::{Scrollbar, Scroller, StyleSheet};
+    pub use iced_pure::widget::scrollable::{
+        scroll_to, snap_to, AbsoluteOffset, Direction, Id, Scrollbar, Scroller,
+        StyleSheet, TrackClick, Viewport,
+    };
 
-    /// A widget that can vertically display an infinite amount of content
-    /// with a scrollbar.
+    /// A widget that can display an infinite amount of content with a
+    /// scrollbar.
     pub type Scrollable<'a, Message> =
         iced_pure::widget::Scrollable<'a, Message, crate::Renderer>;
 }
 
+pub mod selectable_text {
+    //! Display text that can be selected and copied.
+    pub use iced_pure::widget::selectable_text::{Style, StyleSheet};
+
+    /// A paragraph of text that can be selected and copied.
+    pub type SelectableText<'a> =
+        iced_pure::widget::SelectableText<'a, crate::Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
-    pub use iced_pure::widget::toggler::{Style, StyleSheet};
+    pub use iced_pure::widget::toggler::{Position, Style, StyleSheet};
 
     /// A toggler widget.
     pub type Toggler<'a, Message> =
         iced_pure::widget::Toggler<'a, Message, crate::Renderer>;
 }
 
+pub mod text_area {
+    //! Display fields that can be filled with multiple lines of text.
+    use crate::Renderer;
+
+    pub use iced_pure::widget::text_area::{Style, StyleSheet};
+
+    /// A field that can be filled with multiple lines of text.
+    pub type TextArea<'a, Message> =
+        iced_pure::widget::TextArea<'a, Message, Renderer>;
+}
+
 pub mod text_input {
     //! Display fields that can be filled with text.
     use crate::Renderer;
 
-    pub use iced_pure::widget::text_input::{Style, StyleSheet};
+    pub use iced_pure::widget::text_input::{
+        cursor_offset, focus, move_cursor_to_end, select_all, Icon, Id,
+        NumericOptions, Side, Style, StyleSheet,
+    };
 
     /// A field that can be filled with text.
     pub type TextInput<'a, Message> =
@@ -128,6 +186,7 @@ pub mod tooltip {
 }
 
 pub use iced_pure::widget::progress_bar;
+pub use iced_pure::widget::range_slider;
 pub use iced_pure::widget::rule;
 pub use iced_pure::widget::slider;
 pub use iced_pure::widget::Space;
@@ -135,13 +194,19 @@ pub use iced_pure::widget::Space;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use container::Container;
+pub use link::Link;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use range_slider::RangeSlider;
+pub use rich_text::RichText;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
+pub use selectable_text::SelectableText;
 pub use slider::Slider;
+pub use text::Text;
+pub use text_area::TextArea;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
@@ -152,13 +217,38 @@ pub use iced_graphics::widget::pure::canvas;
 #[cfg(feature = "qr_code")]
 pub use iced_graphics::widget::pure::qr_code;
 
+#[cfg(feature = "markdown")]
+pub mod markdown {
+    //! Display Markdown content.
+    pub use iced_pure::widget::markdown::{
+        parse, Block, Inline, Style, StyleSheet, Url,
+    };
+
+    /// Some Markdown content displayed with the built-in widgets.
+    pub type Markdown<'a, Message> =
+        iced_pure::widget::Markdown<'a, Message, crate::Renderer>;
+}
+
 #[cfg(feature = "image")]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_native::image::{load, Error, Format, Handle, Source, Status};
 
     /// A frame that displays an image.
     pub type Image = iced_pure::widget::Image<Handle>;
+
+    /// An image that stretches with fixed corners.
+    pub type NineSlice = iced_pure::widget::image::NineSlice<Handle>;
+}
+
+#[cfg(feature = "image")]
+pub mod lazy_image {
+    //! Load images in the background, showing a placeholder meanwhile.
+    pub use iced_pure::widget::lazy_image::State;
+
+    /// An image that is loaded in the background.
+    pub type LazyImage<'a, Message> =
+        iced_pure::widget::LazyImage<'a, Message, crate::Renderer>;
 }
 
 #[cfg(feature = "svg")]
@@ -170,8 +260,14 @@ pub use canvas::Canvas;
 #[cfg(feature = "qr_code")]
 pub use qr_code::QRCode;
 
+#[cfg(feature = "markdown")]
+pub use markdown::Markdown;
+
 #[cfg(feature = "image")]
 pub use image::Image;
 
+#[cfg(feature = "image")]
+pub use lazy_image::LazyImage;
+
 #[cfg(feature = "svg")]
 pub use svg::Svg;
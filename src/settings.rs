@@ -26,6 +26,13 @@ pub struct Settings<Flags> {
     // TODO: Add `name` for web compatibility
     pub default_font: Option<&'static [u8]>,
 
+    /// The bytes of the fonts used, in order, to display the characters
+    /// that are missing from the default font, like CJK characters or
+    /// emoji.
+    ///
+    /// By default, it is empty.
+    pub fallback_fonts: &'static [&'static [u8]],
+
     /// The text size that will be used by default.
     ///
     /// The default value is 20.
@@ -51,8 +58,15 @@ pub struct Settings<Flags> {
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
+    /// When disabled, every close request produces a
+    /// [`window::Event::CloseRequested`] instead, and the [`Application`] can
+    /// exit later with [`window::close`].
+    ///
     /// By default, it is enabled.
     ///
+    /// [`window::Event::CloseRequested`]: iced_native::window::Event::CloseRequested
+    /// [`window::close`]: crate::window::close
+    ///
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
 
@@ -78,6 +92,7 @@ impl<Flags> Settings<Flags> {
             id: default_settings.id,
             window: default_settings.window,
             default_font: default_settings.default_font,
+            fallback_fonts: default_settings.fallback_fonts,
             default_text_size: default_settings.default_text_size,
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
@@ -97,6 +112,7 @@ where
             window: Default::default(),
             flags: Default::default(),
             default_font: Default::default(),
+            fallback_fonts: &[],
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: false,
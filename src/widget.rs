@@ -22,8 +22,15 @@ pub type Column<'a, Message> =
 pub type Row<'a, Message> =
     iced_native::widget::Row<'a, Message, crate::Renderer>;
 
-/// A paragraph of text.
-pub type Text = iced_native::widget::Text<crate::Renderer>;
+pub mod text {
+    //! Write some text for your users to read.
+    pub use iced_native::widget::text::{
+        LineHeight, Rotation, Truncation, Wrap,
+    };
+
+    /// A paragraph of text.
+    pub type Text = iced_native::widget::Text<crate::Renderer>;
+}
 
 pub mod button {
     //! Allow your users to perform actions by pressing a button.
@@ -38,7 +45,7 @@ pub mod button {
 
 pub mod checkbox {
     //! Show toggle controls using checkboxes.
-    pub use iced_native::widget::checkbox::{Style, StyleSheet};
+    pub use iced_native::widget::checkbox::{Position, Style, StyleSheet};
 
     /// A box that can be checked.
     pub type Checkbox<'a, Message> =
@@ -54,6 +61,17 @@ pub mod container {
         iced_native::widget::Container<'a, Message, crate::Renderer>;
 }
 
+pub mod link {
+    //! Display clickable text, like a hyperlink.
+    //!
+    //! A [`Link`] has some local [`State`].
+    pub use iced_native::widget::link::{State, Style, StyleSheet};
+
+    /// Some text that produces a message when clicked, like a hyperlink.
+    pub type Link<'a, Message> =
+        iced_native::widget::Link<'a, Message, crate::Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -88,7 +106,9 @@ pub mod pane_grid {
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::overlay::menu::Style as Menu;
-    pub use iced_native::widget::pick_list::{State, Style, StyleSheet};
+    pub use iced_native::widget::pick_list::{
+        open, Id, State, Style, StyleSheet,
+    };
 
     /// A widget allowing the selection of a single value from a list of options.
     pub type PickList<'a, T, Message> =
@@ -97,41 +117,89 @@ pub mod pick_list {
 
 pub mod radio {
     //! Create choices using radio buttons.
-    pub use iced_native::widget::radio::{Style, StyleSheet};
+    pub use iced_native::widget::radio::{group, Style, StyleSheet};
 
     /// A circular button representing a choice.
     pub type Radio<'a, Message> =
         iced_native::widget::Radio<'a, Message, crate::Renderer>;
+
+    /// A set of radio buttons that share their appearance.
+    pub type Group<'a, V, Message> =
+        iced_native::widget::radio::Group<'a, V, Message, crate::Renderer>;
+}
+
+pub mod rich_text {
+    //! Write a paragraph of text with mixed styles.
+
+    /// A run of text with its own style, part of a [`RichText`].
+    pub type Span<Message> =
+        iced_native::widget::rich_text::Span<Message, crate::Font>;
+
+    /// A paragraph made of differently styled spans.
+    pub type RichText<Message> =
+        iced_native::widget::RichText<Message, crate::Renderer>;
 }
 
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
-        style::Scrollbar, style::Scroller, State, StyleSheet,
+        scroll_to, snap_to, style::Scrollbar, style::Scroller, AbsoluteOffset,
+        Direction, Id, State, StyleSheet, TrackClick, Viewport,
     };
 
-    /// A widget that can vertically display an infinite amount of content
-    /// with a scrollbar.
+    /// A widget that can display an infinite amount of content with a
+    /// scrollbar.
     pub type Scrollable<'a, Message> =
         iced_native::widget::Scrollable<'a, Message, crate::Renderer>;
 }
 
+pub mod selectable_text {
+    //! Display text that can be selected and copied.
+    //!
+    //! A [`SelectableText`] has some local [`State`].
+    pub use iced_native::widget::selectable_text::{
+        selected_text, State, Style, StyleSheet,
+    };
+
+    /// A paragraph of text that can be selected and copied.
+    pub type SelectableText<'a> =
+        iced_native::widget::SelectableText<'a, crate::Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
-    pub use iced_native::widget::toggler::{Style, StyleSheet};
+    pub use iced_native::widget::toggler::{
+        Position, State, Style, StyleSheet,
+    };
 
     /// A toggler widget.
     pub type Toggler<'a, Message> =
         iced_native::widget::Toggler<'a, Message, crate::Renderer>;
 }
 
+pub mod text_area {
+    //! Display fields that can be filled with multiple lines of text.
+    //!
+    //! A [`TextArea`] has some local [`State`].
+    use crate::Renderer;
+
+    pub use iced_native::widget::text_area::{State, Style, StyleSheet};
+
+    /// A field that can be filled with multiple lines of text.
+    pub type TextArea<'a, Message> =
+        iced_native::widget::TextArea<'a, Message, Renderer>;
+}
+
 pub mod text_input {
     //! Display fields that can be filled with text.
     //!
     //! A [`TextInput`] has some local [`State`].
     use crate::Renderer;
 
-    pub use iced_native::widget::text_input::{State, Style, StyleSheet};
+    pub use iced_native::widget::text_input::{
+        cursor_offset, focus, move_cursor_to_end, select_all, Icon, Id,
+        NumericOptions, Side, State, Style, StyleSheet, Value,
+    };
 
     /// A field that can be filled with text.
     pub type TextInput<'a, Message> =
@@ -148,6 +216,7 @@ pub mod tooltip {
 }
 
 pub use iced_native::widget::progress_bar;
+pub use iced_native::widget::range_slider;
 pub use iced_native::widget::rule;
 pub use iced_native::widget::slider;
 pub use iced_native::widget::Space;
@@ -155,13 +224,19 @@ pub use iced_native::widget::Space;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use container::Container;
+pub use link::Link;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use range_slider::RangeSlider;
+pub use rich_text::RichText;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
+pub use selectable_text::SelectableText;
 pub use slider::Slider;
+pub use text::Text;
+pub use text_area::TextArea;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
@@ -174,15 +249,44 @@ pub use iced_graphics::widget::canvas;
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_native::image::{load, Error, Format, Handle, Source, Status};
 
     /// A frame that displays an image.
     pub type Image = iced_native::widget::Image<Handle>;
 
+    /// An image that stretches with fixed corners.
+    pub type NineSlice = iced_native::widget::image::NineSlice<Handle>;
+
     pub use iced_native::widget::image::viewer;
     pub use viewer::Viewer;
 }
 
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod lazy_image {
+    //! Load images in the background, showing a placeholder meanwhile.
+    //!
+    //! A [`LazyImage`] has some local [`State`].
+    pub use iced_native::widget::lazy_image::State;
+
+    /// An image that is loaded in the background.
+    pub type LazyImage<'a, Message> =
+        iced_native::widget::LazyImage<'a, Message, crate::Renderer>;
+}
+
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown {
+    //! Display Markdown content.
+    pub use iced_native::widget::markdown::{
+        parse, Block, Inline, Style, StyleSheet, Url,
+    };
+
+    /// Some Markdown content displayed with the built-in widgets.
+    pub type Markdown<'a, Message> =
+        iced_native::widget::Markdown<'a, Message, crate::Renderer>;
+}
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub use iced_graphics::widget::qr_code;
@@ -192,6 +296,7 @@ pub use iced_graphics::widget::qr_code;
 pub mod svg {
     //! Display vector graphics in your application.
     pub use iced_native::svg::Handle;
+    pub use iced_native::widget::svg::{Style, StyleSheet, Tint};
     pub use iced_native::widget::Svg;
 }
 
@@ -203,6 +308,14 @@ pub use canvas::Canvas;
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub use image::Image;
 
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub use lazy_image::LazyImage;
+
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub use markdown::Markdown;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub use qr_code::QRCode;
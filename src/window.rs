@@ -1,14 +1,43 @@
 //! Configure the window of your application in native platforms.
-mod mode;
 mod position;
 mod settings;
 
 pub mod icon;
 
+pub use crate::runtime::window::{
+    CursorGrab, Event, Id, Level, Mode, Screenshot, UserAttention,
+};
 pub use icon::Icon;
-pub use mode::Mode;
 pub use position::Position;
 pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::window::{move_to, resize};
+pub use crate::runtime::window::{
+    center, change_cursor_grab, change_cursor_grab_with, change_level,
+    change_mode, close, drag, fetch_mode, fetch_position, fetch_size, maximize,
+    minimize, move_to, request_user_attention, resize, screenshot,
+    set_aspect_ratio, set_cursor_visible, set_max_size, set_min_size,
+    set_resize_increments, set_title, target,
+};
+
+/// Sets the icon of the window.
+///
+/// The [`Icon`] is validated when it is created, so any error left, like
+/// the OS failing to create it, is logged instead.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_icon<Message>(icon: Icon) -> crate::Command<Message> {
+    crate::runtime::window::set_icon(icon.into())
+}
+
+/// Opens a new window with the given [`Id`] and [`Settings`].
+///
+/// Only a [`multi_window::Application`] can open new windows. A
+/// [`Position::Centered`] window is placed by the platform, like a
+/// [`Position::Default`] one, but it can be centered once opened with
+/// [`target`] and [`center`].
+///
+/// [`multi_window::Application`]: crate::multi_window::Application
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn<Message>(id: Id, settings: Settings) -> crate::Command<Message> {
+    crate::runtime::window::spawn(id, settings.into())
+}
@@ -4,7 +4,10 @@ use std::io;
 
 /// The icon of a window.
 #[derive(Debug, Clone)]
-pub struct Icon(iced_winit::winit::window::Icon);
+pub struct Icon {
+    raw: iced_winit::winit::window::Icon,
+    native: iced_winit::window::Icon,
+}
 
 impl Icon {
     /// Creates an icon from 32bpp RGBA data.
@@ -13,10 +16,13 @@ impl Icon {
         width: u32,
         height: u32,
     ) -> Result<Self, Error> {
+        let native =
+            iced_winit::window::Icon::from_rgba(rgba.clone(), width, height)?;
+
         let raw =
             iced_winit::winit::window::Icon::from_rgba(rgba, width, height)?;
 
-        Ok(Icon(raw))
+        Ok(Icon { raw, native })
     }
 }
 
@@ -68,9 +74,36 @@ impl From<iced_winit::winit::window::BadIcon> for Error {
     }
 }
 
+impl From<iced_winit::window::icon::Error> for Error {
+    fn from(error: iced_winit::window::icon::Error) -> Self {
+        use iced_winit::window::icon;
+
+        match error {
+            icon::Error::InvalidData { byte_count } => {
+                Error::InvalidData { byte_count }
+            }
+            icon::Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            },
+        }
+    }
+}
+
 impl From<Icon> for iced_winit::winit::window::Icon {
     fn from(icon: Icon) -> Self {
-        icon.0
+        icon.raw
+    }
+}
+
+impl From<Icon> for iced_winit::window::Icon {
+    fn from(icon: Icon) -> Self {
+        icon.native
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::window::{Icon, Position};
+use crate::{Point, Size};
 
 /// The window settings of an application.
 #[derive(Debug, Clone)]
@@ -15,6 +16,13 @@ pub struct Settings {
     /// The maximum size of the window.
     pub max_size: Option<(u32, u32)>,
 
+    /// The logical increments the window is resized in, if any.
+    pub resize_increments: Option<Size<u32>>,
+
+    /// The ratio between the width and the height the window keeps while
+    /// resized, if any.
+    pub aspect_ratio: Option<f32>,
+
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
@@ -22,6 +30,15 @@ pub struct Settings {
     pub decorations: bool,
 
     /// Whether the window should be transparent.
+    ///
+    /// The background color of the application is composited with the
+    /// desktop behind the window, where supported. Only the `glow` renderer
+    /// composites transparent windows for now; with `wgpu`, and in platforms
+    /// without compositing, the window stays opaque.
+    ///
+    /// The `wgpu` renderer cannot choose how the alpha of its surface is
+    /// composited yet, so it always presents opaque windows, regardless of
+    /// the background color.
     pub transparent: bool,
 
     /// Whether the window will always be on top of other windows.
@@ -38,6 +55,8 @@ impl Default for Settings {
             position: Position::default(),
             min_size: None,
             max_size: None,
+            resize_increments: None,
+            aspect_ratio: None,
             resizable: true,
             decorations: true,
             transparent: false,
@@ -54,6 +73,8 @@ impl From<Settings> for iced_winit::settings::Window {
             position: iced_winit::Position::from(settings.position),
             min_size: settings.min_size,
             max_size: settings.max_size,
+            resize_increments: settings.resize_increments,
+            aspect_ratio: settings.aspect_ratio,
             resizable: settings.resizable,
             decorations: settings.decorations,
             transparent: settings.transparent,
@@ -63,3 +84,32 @@ impl From<Settings> for iced_winit::settings::Window {
         }
     }
 }
+
+impl From<Settings> for iced_winit::window::Settings {
+    fn from(settings: Settings) -> Self {
+        let to_size = |(width, height): (u32, u32)| Size::new(width, height);
+
+        Self {
+            size: to_size(settings.size),
+            position: match settings.position {
+                Position::Default | Position::Centered => None,
+                Position::Specific(x, y) => {
+                    Some(Point::new(x as f32, y as f32))
+                }
+            },
+            min_size: settings.min_size.map(to_size),
+            max_size: settings.max_size.map(to_size),
+            resize_increments: settings.resize_increments,
+            aspect_ratio: settings.aspect_ratio,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            level: if settings.always_on_top {
+                iced_winit::window::Level::AlwaysOnTop
+            } else {
+                iced_winit::window::Level::Normal
+            },
+            icon: settings.icon.map(Icon::into),
+        }
+    }
+}
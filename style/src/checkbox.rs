@@ -17,6 +17,16 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the style of an indeterminate checkbox, whose checkmark is
+    /// replaced by a dash.
+    fn indeterminate(&self, is_hovered: bool) -> Style {
+        if is_hovered {
+            self.hovered(true)
+        } else {
+            self.active(true)
+        }
+    }
 }
 
 struct Default;
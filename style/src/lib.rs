@@ -12,13 +12,19 @@ pub use iced_core::{Background, Color};
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod link;
+pub mod markdown;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod svg;
+pub mod text_area;
 pub mod text_input;
 pub mod toggler;
@@ -0,0 +1,69 @@
+//! Display clickable text, like a hyperlink.
+use iced_core::Color;
+
+/// The appearance of a link.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.2, 0.4, 0.8),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a link.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    fn pressed(&self) -> Style {
+        self.hovered()
+    }
+
+    fn disabled(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            color: Color {
+                a: active.color.a * 0.5,
+                ..active.color
+            },
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            color: Color::from_rgb(0.1, 0.3, 0.7),
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
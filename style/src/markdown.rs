@@ -0,0 +1,53 @@
+//! Display Markdown content.
+use iced_core::{Background, Color};
+
+/// The appearance of some Markdown content.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub link_color: Color,
+    pub code_color: Option<Color>,
+    pub code_background: Option<Background>,
+    pub code_border_radius: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            link_color: Color::from_rgb(0.2, 0.4, 0.8),
+            code_color: None,
+            code_background: Some(Background::Color(Color::from_rgb(
+                0.95, 0.95, 0.95,
+            ))),
+            code_border_radius: 4.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of some Markdown content.
+pub trait StyleSheet {
+    /// Produces the style of some Markdown content.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
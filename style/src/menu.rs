@@ -9,6 +9,8 @@ pub struct Style {
     pub border_color: Color,
     pub selected_text_color: Color,
     pub selected_background: Background,
+    pub disabled_text_color: Color,
+    pub separator_color: Color,
 }
 
 impl std::default::Default for Style {
@@ -20,6 +22,8 @@ impl std::default::Default for Style {
             border_color: [0.7, 0.7, 0.7].into(),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            disabled_text_color: [0.6, 0.6, 0.6].into(),
+            separator_color: [0.7, 0.7, 0.7].into(),
         }
     }
 }
@@ -1,6 +1,8 @@
 //! Provide progress feedback to your users.
 use iced_core::{Background, Color};
 
+use std::time::Duration;
+
 /// The appearance of a progress bar.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
@@ -9,9 +11,28 @@ pub struct Style {
     pub border_radius: f32,
 }
 
+/// The appearance of the segment that sweeps back and forth across an
+/// indeterminate progress bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Indeterminate {
+    pub segment: Background,
+    /// The width of the segment, relative to the width of the bar.
+    pub segment_width: f32,
+    /// The time the segment takes to sweep there and back again.
+    pub period: Duration,
+}
+
 /// A set of rules that dictate the style of a progress bar.
 pub trait StyleSheet {
     fn style(&self) -> Style;
+
+    fn indeterminate(&self) -> Indeterminate {
+        Indeterminate {
+            segment: self.style().bar,
+            segment_width: 0.3,
+            period: Duration::from_millis(2000),
+        }
+    }
 }
 
 struct Default;
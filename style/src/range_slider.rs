@@ -0,0 +1,83 @@
+//! Display an interactive selector of a range of values.
+use iced_core::{Background, Color};
+
+pub use crate::slider::{Handle, HandleShape};
+
+/// The appearance of a range slider.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub rail_colors: (Color, Color),
+    pub selection: Background,
+    pub handle: Handle,
+}
+
+/// A set of rules that dictate the style of a range slider.
+pub trait StyleSheet {
+    /// Produces the style of an active range slider.
+    fn active(&self) -> Style;
+
+    /// Produces the style of an hovered range slider.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a range slider that is being dragged.
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), Color::WHITE),
+            selection: Background::Color(Color::from_rgb(0.5, 0.5, 0.5)),
+            handle: Handle {
+                shape: HandleShape::Rectangle {
+                    width: 8,
+                    border_radius: 4.0,
+                },
+                color: Color::from_rgb(0.95, 0.95, 0.95),
+                border_color: Color::from_rgb(0.6, 0.6, 0.6),
+                border_width: 1.0,
+            },
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            handle: Handle {
+                color: Color::from_rgb(0.90, 0.90, 0.90),
+                ..active.handle
+            },
+            ..active
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            handle: Handle {
+                color: Color::from_rgb(0.85, 0.85, 0.85),
+                ..active.handle
+            },
+            ..active
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -28,6 +28,13 @@ pub trait StyleSheet {
     /// Produces the style of an hovered scrollbar.
     fn hovered(&self) -> Scrollbar;
 
+    /// Produces the style of a scrollbar whose scroller is hovered.
+    ///
+    /// By default, it is the same as the [`hovered`](Self::hovered) style.
+    fn hovered_scroller(&self) -> Scrollbar {
+        self.hovered()
+    }
+
     /// Produces the style of a scrollbar that is being dragged.
     fn dragging(&self) -> Scrollbar {
         self.hovered()
@@ -0,0 +1,47 @@
+//! Display text that can be selected and copied.
+use iced_core::Color;
+
+/// The appearance of some selectable text.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Option<Color>,
+    pub selection_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            selection_color: Color::from_rgb(0.8, 0.8, 1.0),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of some selectable text.
+pub trait StyleSheet {
+    /// Produces the style of some selectable text.
+    fn active(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -0,0 +1,53 @@
+//! Change the appearance of vector graphics.
+use iced_core::Color;
+
+/// A color applied to vector graphics when they are rasterized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tint {
+    /// Every visible part is painted with the color, keeping its opacity.
+    ///
+    /// This suits monochrome icons.
+    Fill(Color),
+
+    /// The colors are multiplied by the color, which keeps the shading of
+    /// multicolored graphics.
+    Multiply(Color),
+}
+
+/// The appearance of some vector graphics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Style {
+    pub tint: Option<Tint>,
+}
+
+/// A set of rules that dictate the style of some vector graphics.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -8,6 +8,10 @@ pub struct Style {
     pub border_radius: f32,
     pub border_width: f32,
     pub border_color: Color,
+    pub icon_color: Color,
+    pub placeholder_color: Color,
+    pub value_color: Color,
+    pub selection_color: Color,
 }
 
 impl std::default::Default for Style {
@@ -17,6 +21,10 @@ impl std::default::Default for Style {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            icon_color: Color::BLACK,
+            placeholder_color: Color::from_rgb(0.7, 0.7, 0.7),
+            value_color: Color::BLACK,
+            selection_color: Color::from_rgb(0.8, 0.8, 1.0),
         }
     }
 }
@@ -29,16 +37,29 @@ pub trait StyleSheet {
     /// Produces the style of a focused text input.
     fn focused(&self) -> Style;
 
-    fn placeholder_color(&self) -> Color;
-
-    fn value_color(&self) -> Color;
-
-    fn selection_color(&self) -> Color;
-
     /// Produces the style of an hovered text input.
     fn hovered(&self) -> Style {
         self.focused()
     }
+
+    /// Produces the style of a text input with an error.
+    fn errored(&self) -> Style {
+        Style {
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active()
+        }
+    }
+
+    /// Produces the style of a disabled text input.
+    fn disabled(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            value_color: active.placeholder_color,
+            ..active
+        }
+    }
 }
 
 struct Default;
@@ -50,6 +71,10 @@ impl StyleSheet for Default {
             border_radius: 5.0,
             border_width: 1.0,
             border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            icon_color: Color::from_rgb(0.3, 0.3, 0.3),
+            placeholder_color: Color::from_rgb(0.7, 0.7, 0.7),
+            value_color: Color::from_rgb(0.3, 0.3, 0.3),
+            selection_color: Color::from_rgb(0.8, 0.8, 1.0),
         }
     }
 
@@ -60,16 +85,13 @@ impl StyleSheet for Default {
         }
     }
 
-    fn placeholder_color(&self) -> Color {
-        Color::from_rgb(0.7, 0.7, 0.7)
-    }
-
-    fn value_color(&self) -> Color {
-        Color::from_rgb(0.3, 0.3, 0.3)
-    }
-
-    fn selection_color(&self) -> Color {
-        Color::from_rgb(0.8, 0.8, 1.0)
+    fn disabled(&self) -> Style {
+        Style {
+            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
+            border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            value_color: Color::from_rgb(0.7, 0.7, 0.7),
+            ..self.active()
+        }
     }
 }
 
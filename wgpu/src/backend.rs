@@ -40,6 +40,7 @@ impl Backend {
             device,
             format,
             settings.default_font,
+            settings.fallback_fonts,
             settings.text_multithreading,
         );
 
@@ -170,10 +171,12 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
+            let mut rotated = Vec::new();
+
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
+                let section = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -207,52 +210,122 @@ impl Backend {
                             z: 0.0,
                         },
                     }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    ..Default::default()
+                };
+
+                let layout = text::Spaced::new(
+                    section_layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
+                    text.spacing,
+                    text.size,
+                    scale_factor,
+                );
+
+                // Rotated text is drawn on its own, turned around its
+                // position
+                if text.rotation != 0.0 {
+                    rotated.push((section, layout, text.rotation));
+                    continue;
+                }
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
+            }
+
+            for text in layer.rich_text.iter() {
+                let section = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| wgpu_glyph::Text {
+                            text: span.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(span.font),
+                            extra: wgpu_glyph::Extra {
+                                color: span.color,
+                                z: 0.0,
+                            },
                         })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let layout = text::Spaced::from(section_layout(
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                ));
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
             }
 
+            let region = || wgpu_glyph::Region {
+                x: bounds.x,
+                y: bounds.y,
+                width: bounds.width,
+                height: bounds.height,
+            };
+
             self.text_pipeline.draw_queued(
                 device,
                 staging_belt,
                 encoder,
                 target,
                 transformation,
-                wgpu_glyph::Region {
-                    x: bounds.x,
-                    y: bounds.y,
-                    width: bounds.width,
-                    height: bounds.height,
-                },
+                region(),
             );
+
+            for (section, layout, rotation) in rotated {
+                let (x, y) = section.screen_position;
+
+                self.text_pipeline.queue_custom_layout(section, &layout);
+
+                self.text_pipeline.draw_queued(
+                    device,
+                    staging_belt,
+                    encoder,
+                    target,
+                    transformation
+                        * Transformation::translate(x, y)
+                        * Transformation::rotate(rotation)
+                        * Transformation::translate(-x, -y),
+                    region(),
+                );
+            }
         }
     }
 }
 
+fn section_layout(
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> wgpu_glyph::Layout<wgpu_glyph::BuiltInLineBreaker> {
+    wgpu_glyph::Layout::default()
+        .h_align(match horizontal_alignment {
+            alignment::Horizontal::Left => wgpu_glyph::HorizontalAlign::Left,
+            alignment::Horizontal::Center => {
+                wgpu_glyph::HorizontalAlign::Center
+            }
+            alignment::Horizontal::Right => wgpu_glyph::HorizontalAlign::Right,
+        })
+        .v_align(match vertical_alignment {
+            alignment::Vertical::Top => wgpu_glyph::VerticalAlign::Top,
+            alignment::Vertical::Center => wgpu_glyph::VerticalAlign::Center,
+            alignment::Vertical::Bottom => wgpu_glyph::VerticalAlign::Bottom,
+        })
+}
+
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
@@ -274,8 +347,10 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: text::Spacing,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .measure(contents, size, font, bounds, spacing)
     }
 
     fn hit_test(
@@ -286,6 +361,7 @@ impl backend::Text for Backend {
         bounds: Size,
         point: iced_native::Point,
         nearest_only: bool,
+        spacing: text::Spacing,
     ) -> Option<text::Hit> {
         self.text_pipeline.hit_test(
             contents,
@@ -294,8 +370,41 @@ impl backend::Text for Backend {
             bounds,
             point,
             nearest_only,
+            spacing,
         )
     }
+
+    fn glyphs(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        spacing: text::Spacing,
+    ) -> Vec<text::Glyph> {
+        self.text_pipeline
+            .glyphs(contents, size, font, bounds, spacing)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn span_glyphs(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<text::Glyph> {
+        self.text_pipeline.span_glyphs(spans, bounds)
+    }
+
+    fn measurer(&self) -> Box<dyn backend::Measurer> {
+        Box::new(self.text_pipeline.measurer())
+    }
 }
 
 #[cfg(feature = "image_rs")]
@@ -303,6 +412,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn animation(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<iced_native::image::Animation> {
+        self.image_pipeline.animation(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -10,6 +10,7 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image::FilterMethod;
 use iced_native::Rectangle;
 use std::cell::RefCell;
 use std::mem;
@@ -35,6 +36,7 @@ pub struct Pipeline {
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -45,15 +47,17 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let sampler = |filter_mode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
+                ..Default::default()
+            })
+        };
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -89,7 +93,9 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        // Images with different filter methods can be mixed in the same
+        // frame, so every sampler gets its own bind group
+        let constant_bind_group = |filter_mode| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -106,10 +112,16 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(&sampler(
+                            filter_mode,
+                        )),
                     },
                 ],
-            });
+            })
+        };
+
+        let constants = constant_bind_group(wgpu::FilterMode::Linear);
+        let nearest_constants = constant_bind_group(wgpu::FilterMode::Nearest);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -169,6 +181,7 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32,
                             ),
                         },
                     ],
@@ -253,7 +266,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -269,6 +283,17 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image_rs")]
+    pub fn animation(
+        &self,
+        handle: &image::Handle,
+    ) -> Option<image::Animation> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.animation()
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -289,6 +314,7 @@ impl Pipeline {
         _scale: f32,
     ) {
         let instances: &mut Vec<Instance> = &mut Vec::new();
+        let filter_methods: &mut Vec<FilterMethod> = &mut Vec::new();
 
         #[cfg(feature = "image_rs")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -299,9 +325,21 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image_rs")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    frame,
+                    filter_method,
+                    opacity,
+                    region,
+                    bounds,
+                } => {
+                    if *opacity <= 0.0 {
+                        continue;
+                    }
+
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
+                        *frame,
                         device,
                         encoder,
                         &mut self.texture_atlas,
@@ -309,20 +347,35 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *region,
                             atlas_entry,
+                            *filter_method,
+                            *opacity,
                             instances,
                         );
+
+                        filter_methods.resize(instances.len(), *filter_method);
                     }
                 }
                 #[cfg(not(feature = "image_rs"))]
                 layer::Image::Raster { .. } => {}
 
                 #[cfg(feature = "svg")]
-                layer::Image::Vector { handle, bounds } => {
+                layer::Image::Vector {
+                    handle,
+                    tint,
+                    opacity,
+                    bounds,
+                } => {
+                    if *opacity <= 0.0 {
+                        continue;
+                    }
+
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
                         handle,
+                        *tint,
                         size,
                         _scale,
                         device,
@@ -332,9 +385,15 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            None,
                             atlas_entry,
+                            FilterMethod::Linear,
+                            *opacity,
                             instances,
                         );
+
+                        filter_methods
+                            .resize(instances.len(), FilterMethod::Linear);
                     }
                 }
                 #[cfg(not(feature = "svg"))]
@@ -385,7 +444,14 @@ impl Pipeline {
         let total = instances.len();
 
         while i < total {
+            // Consecutive images with the same filter method are drawn
+            // together, which keeps the order of the images intact
+            let filter_method = filter_methods[i];
             let end = (i + Instance::MAX).min(total);
+            let end = filter_methods[i..end]
+                .iter()
+                .position(|other| *other != filter_method)
+                .map_or(end, |amount| i + amount);
             let amount = end - i;
 
             let mut instances_buffer = staging_belt.write_buffer(
@@ -418,7 +484,14 @@ impl Pipeline {
                 });
 
             render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(
+                0,
+                match filter_method {
+                    FilterMethod::Linear => &self.constants,
+                    FilterMethod::Nearest => &self.nearest_constants,
+                },
+                &[],
+            );
             render_pass.set_bind_group(1, &self.texture, &[]);
             render_pass.set_index_buffer(
                 self.indices.slice(..),
@@ -440,7 +513,7 @@ impl Pipeline {
                 0..amount as u32,
             );
 
-            i += Instance::MAX;
+            i = end;
         }
     }
 
@@ -484,6 +557,7 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _opacity: f32,
 }
 
 impl Instance {
@@ -499,35 +573,72 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    region: Option<Rectangle>,
     entry: &atlas::Entry,
+    filter_method: FilterMethod,
+    opacity: f32,
     instances: &mut Vec<Instance>,
 ) {
-    match entry {
-        atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
-        }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.0 as f32;
-            let scaling_y = image_size[1] / size.1 as f32;
+    let (width, height) = entry.size();
 
-            for fragment in fragments {
-                let allocation = &fragment.allocation;
+    let region = region.unwrap_or(Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: width as f32,
+        height: height as f32,
+    });
+
+    if region.width <= 0.0 || region.height <= 0.0 {
+        return;
+    }
 
-                let [x, y] = image_position;
-                let (fragment_x, fragment_y) = fragment.position;
-                let (fragment_width, fragment_height) = allocation.size();
+    let scaling_x = image_size[0] / region.width;
+    let scaling_y = image_size[1] / region.height;
 
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
+    let mut add_fragment =
+        |position: (u32, u32), allocation: &atlas::Allocation| {
+            let (fragment_width, fragment_height) = allocation.size();
 
-                let size = [
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
+            let fragment = Rectangle {
+                x: position.0 as f32,
+                y: position.1 as f32,
+                width: fragment_width as f32,
+                height: fragment_height as f32,
+            };
 
-                add_instance(position, size, allocation, instances);
+            // Only the part of the fragment inside the region is drawn
+            let visible = match fragment.intersection(&region) {
+                Some(visible) => visible,
+                None => return,
+            };
+
+            let [x, y] = image_position;
+
+            add_instance(
+                [
+                    x + (visible.x - region.x) * scaling_x,
+                    y + (visible.y - region.y) * scaling_y,
+                ],
+                [visible.width * scaling_x, visible.height * scaling_y],
+                Rectangle {
+                    x: visible.x - fragment.x,
+                    y: visible.y - fragment.y,
+                    ..visible
+                },
+                allocation,
+                filter_method,
+                opacity,
+                instances,
+            );
+        };
+
+    match entry {
+        atlas::Entry::Contiguous(allocation) => {
+            add_fragment((0, 0), allocation);
+        }
+        atlas::Entry::Fragmented { fragments, .. } => {
+            for fragment in fragments {
+                add_fragment(fragment.position, &fragment.allocation);
             }
         }
     }
@@ -537,25 +648,39 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    source: Rectangle,
     allocation: &atlas::Allocation,
+    filter_method: FilterMethod,
+    opacity: f32,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let (width, height) = allocation.size();
     let layer = allocation.layer();
 
+    // Linear filtering samples from the centers of the texels on the edges,
+    // so it never blends in the neighbors of an image in the atlas. Nearest
+    // filtering needs every texel in full, so it only stays clear of the
+    // edges by a tiny inset.
+    let inset = match filter_method {
+        FilterMethod::Linear => 0.5_f32,
+        FilterMethod::Nearest => 0.01,
+    }
+    .min(source.width / 2.0)
+    .min(source.height / 2.0);
+
     let instance = Instance {
         _position: position,
         _size: size,
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + source.x + inset) / atlas::SIZE as f32,
+            (y as f32 + source.y + inset) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (source.width - 2.0 * inset) / atlas::SIZE as f32,
+            (source.height - 2.0 * inset) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _opacity: opacity,
     };
 
     instances.push(instance);
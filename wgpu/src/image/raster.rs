@@ -1,13 +1,18 @@
 use crate::image::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
 
 use bitflags::bitflags;
 
+type Buffer = ::image_rs::ImageBuffer<::image_rs::Bgra<u8>, Vec<u8>>;
+
 #[derive(Debug)]
 pub enum Memory {
-    Host(::image_rs::ImageBuffer<::image_rs::Bgra<u8>, Vec<u8>>),
+    Host(Buffer),
     Device(atlas::Entry),
+    Animated(Animation),
     NotFound,
     Invalid,
 }
@@ -17,10 +22,41 @@ impl Memory {
         match self {
             Memory::Host(image) => image.dimensions(),
             Memory::Device(entry) => entry.size(),
+            Memory::Animated(animation) => {
+                animation.frames[0].image.dimensions()
+            }
             Memory::NotFound => (1, 1),
             Memory::Invalid => (1, 1),
         }
     }
+
+    pub fn animation(&self) -> Option<image::Animation> {
+        match self {
+            Memory::Animated(animation) => Some(image::Animation {
+                delays: animation.delays.clone(),
+                repetitions: animation.repetitions,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The decoded frames of an animated image.
+///
+/// Only the frames drawn since the last trim of the [`Cache`] are kept in
+/// the atlas, so long animations do not fill it up.
+#[derive(Debug)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    delays: Arc<[Duration]>,
+    repetitions: Option<u32>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    image: Buffer,
+    entry: Option<atlas::Entry>,
+    is_drawn: bool,
 }
 
 #[derive(Debug)]
@@ -44,7 +80,13 @@ impl Cache {
 
         let memory = match handle.data() {
             image::Data::Path(path) => {
-                if let Ok(image) = image_rs::open(path) {
+                let animation = std::fs::read(path)
+                    .ok()
+                    .and_then(|bytes| load_animation(&bytes));
+
+                if let Some(animation) = animation {
+                    Memory::Animated(animation)
+                } else if let Ok(image) = image_rs::open(path) {
                     let operation = std::fs::File::open(path)
                         .ok()
                         .map(std::io::BufReader::new)
@@ -59,7 +101,9 @@ impl Cache {
                 }
             }
             image::Data::Bytes(bytes) => {
-                if let Ok(image) = image_rs::load_from_memory(&bytes) {
+                if let Some(animation) = load_animation(bytes) {
+                    Memory::Animated(animation)
+                } else if let Ok(image) = image_rs::load_from_memory(&bytes) {
                     let operation =
                         Operation::from_exif(&mut std::io::Cursor::new(bytes))
                             .ok()
@@ -94,12 +138,28 @@ impl Cache {
     pub fn upload(
         &mut self,
         handle: &image::Handle,
+        frame: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
         let memory = self.load(handle);
 
+        if let Memory::Animated(animation) = memory {
+            let frame = animation.frames.get_mut(frame)?;
+
+            if frame.entry.is_none() {
+                let (width, height) = frame.image.dimensions();
+
+                frame.entry =
+                    atlas.upload(width, height, &frame.image, device, encoder);
+            }
+
+            frame.is_drawn = true;
+
+            return frame.entry.as_ref();
+        }
+
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
 
@@ -121,10 +181,24 @@ impl Cache {
         self.map.retain(|k, memory| {
             let retain = hits.contains(k);
 
-            if !retain {
-                if let Memory::Device(entry) = memory {
+            match memory {
+                Memory::Device(entry) if !retain => {
                     atlas.remove(entry);
                 }
+                Memory::Animated(animation) => {
+                    // The frames that were not drawn are uploaded again
+                    // when they come back around
+                    for frame in &mut animation.frames {
+                        if !retain || !frame.is_drawn {
+                            if let Some(entry) = frame.entry.take() {
+                                atlas.remove(&entry);
+                            }
+                        }
+
+                        frame.is_drawn = false;
+                    }
+                }
+                _ => {}
             }
 
             retain
@@ -148,6 +222,113 @@ impl Cache {
     }
 }
 
+/// Decodes every frame of an animated GIF or APNG.
+///
+/// Images with a single frame are left to the regular decoders.
+fn load_animation(bytes: &[u8]) -> Option<Animation> {
+    use image_rs::AnimationDecoder;
+    use std::io::Cursor;
+
+    let frames = match image_rs::guess_format(bytes).ok()? {
+        #[cfg(feature = "gif")]
+        image_rs::ImageFormat::Gif => {
+            image_rs::codecs::gif::GifDecoder::new(Cursor::new(bytes))
+                .ok()?
+                .into_frames()
+                .collect_frames()
+                .ok()?
+        }
+        #[cfg(feature = "png")]
+        image_rs::ImageFormat::Png => {
+            let decoder =
+                image_rs::codecs::png::PngDecoder::new(Cursor::new(bytes))
+                    .ok()?;
+
+            if !decoder.is_apng() {
+                return None;
+            }
+
+            decoder.apng().into_frames().collect_frames().ok()?
+        }
+        _ => return None,
+    };
+
+    if frames.len() < 2 {
+        return None;
+    }
+
+    let delays = frames
+        .iter()
+        .map(|frame| {
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+            let delay = Duration::from_secs_f64(
+                f64::from(numerator) / f64::from(denominator.max(1)) / 1000.0,
+            );
+
+            // Browsers slow down frames that are too fast to be meant, which
+            // many files out there rely on
+            if delay < Duration::from_millis(20) {
+                Duration::from_millis(100)
+            } else {
+                delay
+            }
+        })
+        .collect();
+
+    let frames = frames
+        .into_iter()
+        .map(|frame| Frame {
+            image: image_rs::DynamicImage::ImageRgba8(frame.into_buffer())
+                .to_bgra8(),
+            entry: None,
+            is_drawn: false,
+        })
+        .collect();
+
+    Some(Animation {
+        frames,
+        delays,
+        repetitions: repetitions(bytes),
+    })
+}
+
+/// Reads how many times an animated GIF or APNG is played, which `image`
+/// does not expose. `None` means it loops forever.
+fn repetitions(bytes: &[u8]) -> Option<u32> {
+    let find = |needle: &[u8]| {
+        bytes
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .map(|start| &bytes[start + needle.len()..])
+    };
+
+    if bytes.starts_with(b"GIF") {
+        // The loop count of the NETSCAPE2.0 extension does not include the
+        // first play, and files without it are played once
+        return match find(b"NETSCAPE2.0") {
+            Some([3, 1, low, high, ..]) => {
+                match u16::from_le_bytes([*low, *high]) {
+                    0 => None,
+                    loops => Some(u32::from(loops) + 1),
+                }
+            }
+            _ => Some(1),
+        };
+    }
+
+    // The animation control chunk of an APNG stores the amount of frames,
+    // followed by the amount of plays
+    match find(b"acTL") {
+        Some([_, _, _, _, a, b, c, d, ..]) => {
+            match u32::from_be_bytes([*a, *b, *c, *d]) {
+                0 => None,
+                plays => Some(plays),
+            }
+        }
+        _ => None,
+    }
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
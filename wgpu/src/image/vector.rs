@@ -5,6 +5,12 @@ use iced_native::svg;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 
+/// An SVG rasterized at some size and with some tint.
+type Key = (u64, u32, u32, Option<TintKey>);
+
+/// A [`svg::Tint`] that can be hashed, using the bits of its color.
+type TintKey = (bool, [u32; 4]);
+
 pub enum Svg {
     Loaded(usvg::Tree),
     NotFound,
@@ -26,9 +32,9 @@ impl Svg {
 #[derive(Debug)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), atlas::Entry>,
+    rasterized: HashMap<Key, atlas::Entry>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    rasterized_hits: HashSet<Key>,
 }
 
 impl Cache {
@@ -76,6 +82,7 @@ impl Cache {
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        tint: Option<svg::Tint>,
         [width, height]: [f32; 2],
         scale: f32,
         device: &wgpu::Device,
@@ -89,15 +96,19 @@ impl Cache {
             (scale * height).ceil() as u32,
         );
 
+        // Differently tinted instances of the same SVG are rasterized
+        // separately
+        let key = (id, width, height, tint.map(tint_key));
+
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
-        if self.rasterized.contains_key(&(id, width, height)) {
+        if self.rasterized.contains_key(&key) {
             let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+            let _ = self.rasterized_hits.insert(key);
 
-            return self.rasterized.get(&(id, width, height));
+            return self.rasterized.get(&key);
         }
 
         match self.load(handle) {
@@ -123,6 +134,11 @@ impl Cache {
                 )?;
 
                 let mut rgba = img.take();
+
+                if let Some(tint) = tint {
+                    apply_tint(&mut rgba, tint);
+                }
+
                 rgba.chunks_exact_mut(4).for_each(|rgba| rgba.swap(0, 2));
 
                 let allocation = texture_atlas.upload(
@@ -135,10 +151,10 @@ impl Cache {
                 log::debug!("allocating {} {}x{}", id, width, height);
 
                 let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
-                let _ = self.rasterized.insert((id, width, height), allocation);
+                let _ = self.rasterized_hits.insert(key);
+                let _ = self.rasterized.insert(key, allocation);
 
-                self.rasterized.get(&(id, width, height))
+                self.rasterized.get(&key)
             }
             Svg::NotFound => None,
         }
@@ -163,6 +179,57 @@ impl Cache {
     }
 }
 
+fn tint_key(tint: svg::Tint) -> TintKey {
+    let (is_multiply, color) = match tint {
+        svg::Tint::Fill(color) => (false, color),
+        svg::Tint::Multiply(color) => (true, color),
+    };
+
+    (
+        is_multiply,
+        [
+            color.r.to_bits(),
+            color.g.to_bits(),
+            color.b.to_bits(),
+            color.a.to_bits(),
+        ],
+    )
+}
+
+/// Tints the premultiplied RGBA pixels of a rasterized SVG.
+fn apply_tint(rgba: &mut [u8], tint: svg::Tint) {
+    for pixel in rgba.chunks_exact_mut(4) {
+        let alpha = f32::from(pixel[3]) / 255.0;
+
+        let [r, g, b, a] = match tint {
+            svg::Tint::Fill(color) => {
+                let alpha = alpha * color.a;
+
+                [color.r * alpha, color.g * alpha, color.b * alpha, alpha]
+            }
+            svg::Tint::Multiply(color) => {
+                let channel = |value: u8, factor: f32| {
+                    f32::from(value) / 255.0 * factor * color.a
+                };
+
+                [
+                    channel(pixel[0], color.r),
+                    channel(pixel[1], color.g),
+                    channel(pixel[2], color.b),
+                    alpha * color.a,
+                ]
+            }
+        };
+
+        pixel.copy_from_slice(&[
+            (r * 255.0).round() as u8,
+            (g * 255.0).round() as u8,
+            (b * 255.0).round() as u8,
+            (a * 255.0).round() as u8,
+        ]);
+    }
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
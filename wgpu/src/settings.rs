@@ -19,6 +19,12 @@ pub struct Settings {
     /// If `None` is provided, a default system font will be chosen.
     pub default_font: Option<&'static [u8]>,
 
+    /// The bytes of the fonts used, in order, to display the characters
+    /// that are missing from the default font.
+    ///
+    /// By default, it is empty.
+    pub fallback_fonts: &'static [&'static [u8]],
+
     /// The default size of text.
     ///
     /// By default, it will be set to 20.
@@ -66,6 +72,7 @@ impl Default for Settings {
             present_mode: wgpu::PresentMode::Mailbox,
             internal_backend: wgpu::Backends::all(),
             default_font: None,
+            fallback_fonts: &[],
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
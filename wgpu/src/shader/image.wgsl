@@ -13,12 +13,14 @@ struct VertexInput {
     [[location(3)]] atlas_pos: vec2<f32>;
     [[location(4)]] atlas_scale: vec2<f32>;
     [[location(5)]] layer: i32;
+    [[location(6)]] opacity: f32;
 };
 
 struct VertexOutput {
     [[builtin(position)]] position: vec4<f32>;
     [[location(0)]] uv: vec2<f32>;
     [[location(1)]] layer: f32; // this should be an i32, but naga currently reads that as requiring interpolation.
+    [[location(2)]] opacity: f32;
 };
 
 [[stage(vertex)]]
@@ -27,6 +29,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.opacity = input.opacity;
 
     var transform: mat4x4<f32> = mat4x4<f32>(
         vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
@@ -42,5 +45,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
 [[stage(fragment)]]
 fn fs_main(input: VertexOutput) -> [[location(0)]] vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    let color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    return vec4<f32>(color.rgb, color.a * input.opacity);
 }
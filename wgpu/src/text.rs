@@ -1,17 +1,19 @@
 use crate::Transformation;
 
 use iced_graphics::font;
+use iced_graphics::text::{self, Measurer};
 
 use std::{cell::RefCell, collections::HashMap};
 use wgpu_glyph::ab_glyph;
 
-pub use iced_native::text::Hit;
+pub use iced_graphics::text::Spaced;
+pub use iced_native::text::{Glyph, Hit, Spacing, Span};
 
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<()>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
-    measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    measure_brush: RefCell<text::Brush>,
 }
 
 impl Pipeline {
@@ -19,6 +21,7 @@ impl Pipeline {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         default_font: Option<&[u8]>,
+        fallback_fonts: &[&[u8]],
         multithreading: bool,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
@@ -48,8 +51,20 @@ impl Pipeline {
                     .expect("Load fallback font")
             });
 
+        let fonts: Vec<_> = std::iter::once(font)
+            .chain(fallback_fonts.iter().filter_map(|bytes| {
+                ab_glyph::FontArc::try_from_vec(bytes.to_vec())
+                    .map_err(|_| {
+                        log::warn!("Fallback font failed to load. Skipping...")
+                    })
+                    .ok()
+            }))
+            .collect();
+
+        let fallbacks = fonts.len() - 1;
+
         let draw_brush_builder =
-            wgpu_glyph::GlyphBrushBuilder::using_font(font.clone())
+            wgpu_glyph::GlyphBrushBuilder::using_fonts(fonts.clone())
                 .initial_cache_size((2048, 2048))
                 .draw_cache_multithread(multithreading);
 
@@ -58,8 +73,7 @@ impl Pipeline {
 
         let draw_brush = draw_brush_builder.build(device, format);
 
-        let measure_brush =
-            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+        let measure_brush = text::Brush::new(fonts, fallbacks);
 
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
@@ -68,8 +82,16 @@ impl Pipeline {
         }
     }
 
-    pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue_custom_layout(
+        &mut self,
+        section: wgpu_glyph::Section<'_>,
+        layout: &Spaced,
+    ) {
+        let fallbacks = self.measure_brush.borrow().fallbacks();
+
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &layout.fallbacks(fallbacks));
     }
 
     pub fn draw_queued(
@@ -100,29 +122,24 @@ impl Pipeline {
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        spacing: Spacing,
     ) -> (f32, f32) {
-        use wgpu_glyph::GlyphCruncher;
-
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+        let section =
+            text::section(content, size, self.find_font(font), bounds);
 
-        let section = wgpu_glyph::Section {
-            bounds: (bounds.width, bounds.height),
-            text: vec![wgpu_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: wgpu_glyph::FontId(font_id),
-                extra: wgpu_glyph::Extra::default(),
-            }],
-            ..Default::default()
-        };
+        self.measure_brush.borrow_mut().measure(
+            &section,
+            &Spaced::new(wgpu_glyph::Layout::default(), spacing, size, 1.0),
+        )
+    }
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
-        {
-            (bounds.width().ceil(), bounds.height().ceil())
-        } else {
-            (0.0, 0.0)
-        }
+    /// Returns a [`Measurer`] with the fonts currently known by the
+    /// [`Pipeline`].
+    pub fn measurer(&self) -> Measurer {
+        Measurer::new(
+            &self.measure_brush.borrow(),
+            self.draw_font_map.borrow().clone(),
+        )
     }
 
     pub fn hit_test(
@@ -133,108 +150,80 @@ impl Pipeline {
         bounds: iced_native::Size,
         point: iced_native::Point,
         nearest_only: bool,
+        spacing: Spacing,
     ) -> Option<Hit> {
-        use wgpu_glyph::GlyphCruncher;
+        let section =
+            text::section(content, size, self.find_font(font), bounds);
+
+        self.measure_brush.borrow_mut().hit_test(
+            &section,
+            &Spaced::new(wgpu_glyph::Layout::default(), spacing, size, 1.0),
+            point,
+            nearest_only,
+        )
+    }
 
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+    pub fn glyphs(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        spacing: Spacing,
+    ) -> Vec<Glyph> {
+        let section =
+            text::section(content, size, self.find_font(font), bounds);
+
+        self.measure_brush.borrow_mut().glyphs(
+            &section,
+            &Spaced::new(wgpu_glyph::Layout::default(), spacing, size, 1.0),
+        )
+    }
 
-        let section = wgpu_glyph::Section {
-            bounds: (bounds.width, bounds.height),
-            text: vec![wgpu_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: wgpu_glyph::FontId(font_id),
-                extra: wgpu_glyph::Extra::default(),
-            }],
-            ..Default::default()
-        };
+    pub fn measure_spans(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        self.measure_brush.borrow_mut().measure(
+            &self.spans_section(spans, bounds),
+            &Spaced::from(wgpu_glyph::Layout::default()),
+        )
+    }
 
-        let mut mb = self.measure_brush.borrow_mut();
-
-        // The underlying type is FontArc, so clones are cheap.
-        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
-        let font = mb.fonts()[font_id].clone().into_scaled(size);
-
-        // Implements an iterator over the glyph bounding boxes.
-        let bounds = mb.glyphs(section).map(
-            |wgpu_glyph::SectionGlyph {
-                 byte_index, glyph, ..
-             }| {
-                (
-                    *byte_index,
-                    iced_native::Rectangle::new(
-                        iced_native::Point::new(
-                            glyph.position.x - font.h_side_bearing(glyph.id),
-                            glyph.position.y - font.ascent(),
-                        ),
-                        iced_native::Size::new(
-                            font.h_advance(glyph.id),
-                            font.ascent() - font.descent(),
-                        ),
-                    ),
-                )
-            },
-        );
-
-        // Implements computation of the character index based on the byte index
-        // within the input string.
-        let char_index = |byte_index| {
-            let mut b_count = 0;
-            for (i, utf8_len) in
-                content.chars().map(|c| c.len_utf8()).enumerate()
-            {
-                if byte_index < (b_count + utf8_len) {
-                    return i;
-                }
-                b_count += utf8_len;
-            }
-            return byte_index;
-        };
+    pub fn span_glyphs(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> Vec<Glyph> {
+        self.measure_brush.borrow_mut().glyphs(
+            &self.spans_section(spans, bounds),
+            &Spaced::from(wgpu_glyph::Layout::default()),
+        )
+    }
 
-        if !nearest_only {
-            for (idx, bounds) in bounds.clone() {
-                if bounds.contains(point) {
-                    return Some(Hit::CharOffset(char_index(idx)));
-                }
-            }
+    fn spans_section<'a>(
+        &self,
+        spans: &[Span<'a, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> wgpu_glyph::Section<'a> {
+        wgpu_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text: spans
+                .iter()
+                .map(|span| wgpu_glyph::Text {
+                    text: span.content,
+                    scale: span.size.into(),
+                    font_id: self.find_font(span.font),
+                    extra: wgpu_glyph::Extra::default(),
+                })
+                .collect(),
+            ..Default::default()
         }
-
-        let nearest = bounds
-            .map(|(index, bounds)| (index, bounds.center()))
-            .min_by(|(_, center_a), (_, center_b)| {
-                center_a
-                    .distance(point)
-                    .partial_cmp(&center_b.distance(point))
-                    .unwrap_or(std::cmp::Ordering::Greater)
-            });
-
-        nearest.map(|(idx, center)| {
-            Hit::NearestCharOffset(char_index(idx), point - center)
-        })
     }
 
     pub fn trim_measurement_cache(&mut self) {
-        // TODO: We should probably use a `GlyphCalculator` for this. However,
-        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
-        // This makes stuff quite inconvenient. A manual method for trimming the
-        // cache would make our lives easier.
-        loop {
-            let action = self
-                .measure_brush
-                .borrow_mut()
-                .process_queued(|_, _| {}, |_| {});
-
-            match action {
-                Ok(_) => break,
-                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
-                    let (width, height) = suggested;
-
-                    self.measure_brush
-                        .borrow_mut()
-                        .resize_texture(width, height);
-                }
-            }
-        }
+        self.measure_brush.borrow_mut().trim();
     }
 
     pub fn find_font(&self, font: iced_native::Font) -> wgpu_glyph::FontId {
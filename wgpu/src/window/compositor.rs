@@ -7,6 +7,8 @@ use iced_graphics::compositor;
 use iced_native::futures;
 use raw_window_handle::HasRawWindowHandle;
 
+use std::num::NonZeroU32;
+
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
@@ -116,6 +118,71 @@ impl Compositor {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Records the drawing of the primitives of the [`Renderer`] into the
+    /// given texture view.
+    fn draw<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> wgpu::CommandEncoder {
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu encoder"),
+            },
+        );
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::window::Compositor render pass"),
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear({
+                        let [r, g, b, a] = background_color.into_linear();
+
+                        // Premultiplied, like the alpha of transparent
+                        // windows is composited
+                        wgpu::Color {
+                            r: f64::from(r * a),
+                            g: f64::from(g * a),
+                            b: f64::from(b * a),
+                            a: f64::from(a),
+                        }
+                    }),
+                    store: true,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &self.device,
+                &mut self.staging_belt,
+                &mut encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+
+        encoder
+    }
+
+    /// Recalls the staging buffers used by the last submitted work.
+    fn recall_staging_belt(&mut self) {
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
+    }
 }
 
 impl iced_graphics::window::Compositor for Compositor {
@@ -154,6 +221,8 @@ impl iced_graphics::window::Compositor for Compositor {
         width: u32,
         height: u32,
     ) {
+        // The composite alpha mode of a surface cannot be chosen in this
+        // version of `wgpu`, so transparent windows are presented opaque
         surface.configure(
             &self.device,
             &wgpu::SurfaceConfiguration {
@@ -185,53 +254,17 @@ impl iced_graphics::window::Compositor for Compositor {
     ) -> Result<(), compositor::SurfaceError> {
         match surface.get_current_texture() {
             Ok(frame) => {
-                let mut encoder = self.device.create_command_encoder(
-                    &wgpu::CommandEncoderDescriptor {
-                        label: Some("iced_wgpu encoder"),
-                    },
-                );
-
                 let view = &frame
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[wgpu::RenderPassColorAttachment {
-                            view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear({
-                                    let [r, g, b, a] =
-                                        background_color.into_linear();
-
-                                    wgpu::Color {
-                                        r: f64::from(r),
-                                        g: f64::from(g),
-                                        b: f64::from(b),
-                                        a: f64::from(a),
-                                    }
-                                }),
-                                store: true,
-                            },
-                        }],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &mut self.device,
-                        &mut self.staging_belt,
-                        &mut encoder,
-                        view,
-                        primitives,
-                        viewport,
-                        overlay,
-                    );
-                });
+                let encoder = self.draw(
+                    renderer,
+                    view,
+                    viewport,
+                    background_color,
+                    overlay,
+                );
 
                 // Submit work
                 self.staging_belt.finish();
@@ -239,12 +272,7 @@ impl iced_graphics::window::Compositor for Compositor {
                 frame.present();
 
                 // Recall staging buffers
-                self.local_pool
-                    .spawner()
-                    .spawn(self.staging_belt.recall())
-                    .expect("Recall staging belt");
-
-                self.local_pool.run_until_stalled();
+                self.recall_staging_belt();
 
                 Ok(())
             }
@@ -262,4 +290,151 @@ impl iced_graphics::window::Compositor for Compositor {
             },
         }
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let physical_size = viewport.physical_size();
+
+        if physical_size.width == 0 || physical_size.height == 0 {
+            return Vec::new();
+        }
+
+        let extent = wgpu::Extent3d {
+            width: physical_size.width,
+            height: physical_size.height,
+            depth_or_array_layers: 1,
+        };
+
+        // The texture has the format of the surfaces, so the pipelines of
+        // the backend can draw into it
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder =
+            self.draw(renderer, &view, viewport, background_color, overlay);
+
+        // Rows of a texture copy must be aligned
+        let bytes_per_row = physical_size.width * 4;
+        let padded_bytes_per_row =
+            bytes_per_row.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot buffer"),
+            size: u64::from(padded_bytes_per_row)
+                * u64::from(physical_size.height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        self.staging_belt.finish();
+        self.queue.submit(Some(encoder.finish()));
+
+        self.recall_staging_belt();
+
+        // Mapping is only driven by polling the device, which the runtime
+        // never does between frames. Screenshots are rare and explicit
+        // requests that are answered right away, so waiting for the copy we
+        // just submitted here is simpler than keeping the buffer around
+        // until a later frame finds it mapped.
+        let slice = buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+
+        self.device.poll(wgpu::Maintain::Wait);
+
+        if let Err(error) = futures::executor::block_on(mapping) {
+            log::error!("Screenshot readback failed: {:?}", error);
+
+            return vec![0; bytes_per_row as usize * extent.height as usize];
+        }
+
+        let rgba = unpad_rows(
+            &slice.get_mapped_range(),
+            bytes_per_row as usize,
+            padded_bytes_per_row as usize,
+            matches!(
+                self.format,
+                wgpu::TextureFormat::Bgra8Unorm
+                    | wgpu::TextureFormat::Bgra8UnormSrgb
+            ),
+        );
+
+        buffer.unmap();
+
+        rgba
+    }
+}
+
+/// Removes the padding at the end of every row of the given pixels, which
+/// are converted from BGRA to RGBA if `is_bgra`.
+fn unpad_rows(
+    pixels: &[u8],
+    bytes_per_row: usize,
+    padded_bytes_per_row: usize,
+    is_bgra: bool,
+) -> Vec<u8> {
+    let mut rgba =
+        Vec::with_capacity(pixels.len() / padded_bytes_per_row * bytes_per_row);
+
+    for row in pixels.chunks_exact(padded_bytes_per_row) {
+        rgba.extend_from_slice(&row[..bytes_per_row]);
+    }
+
+    if is_bgra {
+        for pixel in rgba.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+
+    rgba
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unpad_rows;
+
+    // Two rows of one pixel each, padded to 8 bytes
+    const PIXELS: [u8; 16] = [1, 2, 3, 4, 0, 0, 0, 0, 5, 6, 7, 8, 0, 0, 0, 0];
+
+    #[test]
+    fn unpad_rows_removes_padding() {
+        assert_eq!(unpad_rows(&PIXELS, 4, 8, false), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn unpad_rows_swaps_bgra_to_rgba() {
+        assert_eq!(unpad_rows(&PIXELS, 4, 8, true), [3, 2, 1, 4, 7, 6, 5, 8]);
+    }
 }
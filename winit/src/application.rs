@@ -7,8 +7,8 @@ use crate::clipboard::{self, Clipboard};
 use crate::conversion;
 use crate::mouse;
 use crate::{
-    Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime, Settings,
-    Size, Subscription,
+    Color, Command, Debug, Error, Executor, Mode, Point, Position, Proxy,
+    Runtime, Settings, Size, Subscription,
 };
 
 use iced_futures::futures;
@@ -16,6 +16,7 @@ use iced_futures::futures::channel::mpsc;
 use iced_graphics::compositor;
 use iced_graphics::window;
 use iced_native::program::Program;
+use iced_native::time::Instant;
 use iced_native::user_interface::{self, UserInterface};
 
 use std::mem::ManuallyDrop;
@@ -49,6 +50,10 @@ pub trait Application: Program {
     ///
     /// This title can be dynamic! The runtime will automatically update the
     /// title of your application when necessary.
+    ///
+    /// A title set with [`window::set_title`] is kept until this one changes.
+    ///
+    /// [`window::set_title`]: crate::window::set_title
     fn title(&self) -> String;
 
     /// Returns the event `Subscription` for the current state of the
@@ -76,6 +81,12 @@ pub trait Application: Program {
 
     /// Returns the background [`Color`] of the [`Application`].
     ///
+    /// A color with some transparency lets the desktop show through a
+    /// transparent window.
+    ///
+    /// The `wgpu` renderer always presents opaque windows for now, so the
+    /// transparency of the color only has an effect with `glow`.
+    ///
     /// By default, it returns [`Color::WHITE`].
     fn background_color(&self) -> Color {
         Color::WHITE
@@ -121,7 +132,7 @@ where
     debug.startup_started();
 
     let event_loop = EventLoop::with_user_event();
-    let mut proxy = event_loop.create_proxy();
+    let proxy = event_loop.create_proxy();
 
     let mut runtime = {
         let proxy = Proxy::new(event_loop.create_proxy());
@@ -136,7 +147,12 @@ where
         runtime.enter(|| A::new(flags))
     };
 
-    let subscription = application.subscription();
+    // The runtime keeps the size constraints of the window from now on
+    let init_command = Command::batch([
+        crate::window::set_resize_increments(settings.window.resize_increments),
+        crate::window::set_aspect_ratio(settings.window.aspect_ratio),
+        init_command,
+    ]);
 
     let builder = settings.window.into_builder(
         &application.title(),
@@ -166,21 +182,12 @@ where
             .expect("Append canvas to HTML body");
     }
 
-    let mut clipboard = Clipboard::connect(&window);
+    let clipboard = Clipboard::connect(&window);
 
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
-    run_command(
-        init_command,
-        &mut runtime,
-        &mut clipboard,
-        &mut proxy,
-        &window,
-        || compositor.fetch_information(),
-    );
-    runtime.track(subscription);
-
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -191,6 +198,8 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
+        init_command,
         window,
         settings.exit_on_close_request,
     ));
@@ -224,9 +233,15 @@ where
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
+            match poll {
+                task::Poll::Pending => {
+                    while let Ok(Some(flow)) = control_receiver.try_next() {
+                        *control_flow = flow;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    *control_flow = ControlFlow::Exit;
+                }
             };
         }
     })
@@ -241,6 +256,8 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
+    init_command: Command<A::Message>,
     window: winit::window::Window,
     exit_on_close_request: bool,
 ) where
@@ -264,9 +281,26 @@ async fn run_instance<A, E, C>(
         physical_size.height,
     );
 
+    let mut cache = user_interface::Cache::default();
+
+    run_command(
+        &mut application,
+        &mut cache,
+        &mut state,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &mut debug,
+        &window,
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &mut application,
-        user_interface::Cache::default(),
+        cache,
         &mut renderer,
         state.logical_size(),
         &mut debug,
@@ -275,6 +309,9 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut redraw_request = None;
+
+    let _ = control_sender.start_send(control_flow(redraw_request));
 
     debug.startup_finished();
 
@@ -297,8 +334,24 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                if let Some(position) = user_interface.ime_position() {
+                    window.set_ime_position(conversion::ime_position(
+                        position,
+                        state.scale_factor(),
+                    ));
+                }
+
+                redraw_request = user_interface.redraw_request();
+                let _ = control_sender.start_send(control_flow(redraw_request));
+
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    runtime.broadcast((
+                        iced_native::window::Id::MAIN,
+                        event,
+                        status,
+                    ));
                 }
 
                 if !messages.is_empty()
@@ -307,12 +360,15 @@ async fn run_instance<A, E, C>(
                         user_interface::State::Outdated,
                     )
                 {
-                    let cache =
+                    let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
                     update(
                         &mut application,
+                        &mut cache,
+                        &mut state,
+                        &mut renderer,
                         &mut runtime,
                         &mut clipboard,
                         &mut proxy,
@@ -325,7 +381,8 @@ async fn run_instance<A, E, C>(
                     // Update window
                     state.synchronize(&application, &window);
 
-                    let should_exit = application.should_exit();
+                    let should_exit =
+                        application.should_exit() || state.should_close();
 
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &mut application,
@@ -355,6 +412,19 @@ async fn run_instance<A, E, C>(
 
                 window.request_redraw();
             }
+            event::Event::NewEvents(_) => {
+                if let Some(at) = redraw_request {
+                    let now = Instant::now();
+
+                    if now >= at {
+                        redraw_request = None;
+
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::RedrawRequested(now),
+                        ));
+                    }
+                }
+            }
             event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
                 event::MacOS::ReceivedUrl(url),
             )) => {
@@ -418,10 +488,24 @@ async fn run_instance<A, E, C>(
                     &debug.overlay(),
                 ) {
                     Ok(()) => {
-                        debug.render_finished();
+                        if state.is_screenshot_requested() {
+                            let rgba = compositor.screenshot(
+                                &mut renderer,
+                                state.viewport(),
+                                state.background_color(),
+                                &debug.overlay(),
+                            );
+
+                            state.resolve_screenshot_requests(
+                                iced_native::window::Screenshot::new(
+                                    rgba,
+                                    physical_size.width,
+                                    physical_size.height,
+                                ),
+                            );
+                        }
 
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
+                        debug.render_finished();
                     }
                     Err(error) => match error {
                         // This is an unrecoverable error.
@@ -441,10 +525,21 @@ async fn run_instance<A, E, C>(
                 event: window_event,
                 ..
             } => {
-                if requests_exit(&window_event, state.modifiers())
-                    && exit_on_close_request
-                {
-                    break;
+                if requests_exit(&window_event, state.modifiers()) {
+                    if exit_on_close_request {
+                        break;
+                    }
+
+                    // Any other way to request closing reaches the application
+                    // like the close button of the window
+                    if !matches!(
+                        window_event,
+                        winit::event::WindowEvent::CloseRequested
+                    ) {
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::CloseRequested,
+                        ));
+                    }
                 }
 
                 state.update(&window, &window_event, &mut debug);
@@ -456,6 +551,15 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let winit::event::WindowEvent::Resized(_) = window_event {
+                    if let Some(is_maximized) = state.update_maximized(&window)
+                    {
+                        events.push(iced_native::Event::Window(
+                            iced_native::window::Event::Maximized(is_maximized),
+                        ));
+                    }
+                }
             }
             _ => {}
         }
@@ -465,6 +569,26 @@ async fn run_instance<A, E, C>(
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Returns the [`ControlFlow`] of the event loop that honors the given
+/// redraw request of the widgets, if any.
+///
+/// [`ControlFlow`]: winit::event_loop::ControlFlow
+pub fn control_flow(
+    redraw_request: Option<Instant>,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+
+    match redraw_request {
+        #[cfg(not(target_arch = "wasm32"))]
+        Some(at) => ControlFlow::WaitUntil(at),
+        // The event loop of the web measures time with its own `Instant`,
+        // so it polls until the request is reached instead
+        #[cfg(target_arch = "wasm32")]
+        Some(_) => ControlFlow::Poll,
+        None => ControlFlow::Wait,
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -513,6 +637,9 @@ pub fn build_user_interface<'a, A: Application>(
 /// resulting [`Command`], and tracking its [`Subscription`].
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
+    cache: &mut user_interface::Cache,
+    state: &mut State<A>,
+    renderer: &mut A::Renderer,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
@@ -528,7 +655,19 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        run_command(command, runtime, clipboard, proxy, window, graphics_info);
+        run_command(
+            application,
+            cache,
+            state,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            debug,
+            window,
+            graphics_info,
+        );
     }
 
     let subscription = application.subscription();
@@ -536,17 +675,25 @@ pub fn update<A: Application, E: Executor>(
 }
 
 /// Runs the actions of a [`Command`].
-pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
-    command: Command<Message>,
-    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+pub fn run_command<A, E>(
+    application: &mut A,
+    cache: &mut user_interface::Cache,
+    state: &mut State<A>,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
-    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
     window: &winit::window::Window,
     _graphics_info: impl FnOnce() -> compositor::Information + Copy,
-) {
+) where
+    A: Application,
+    E: Executor,
+{
     use iced_native::command;
     use iced_native::system;
-    use iced_native::window;
+    use iced_native::widget::operation;
 
     for action in command.actions() {
         match action {
@@ -554,31 +701,46 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
                 runtime.spawn(future);
             }
             command::Action::Clipboard(action) => match action {
-                clipboard::Action::Read(tag) => {
-                    let message = tag(clipboard.read());
+                clipboard::Action::Read(tag, kind) => {
+                    let message = tag(match kind {
+                        clipboard::Kind::Standard => clipboard.read(),
+                        clipboard::Kind::Primary => clipboard.read_primary(),
+                    });
 
                     proxy
                         .send_event(message)
                         .expect("Send message to event loop");
                 }
-                clipboard::Action::Write(contents) => {
-                    clipboard.write(contents);
+                clipboard::Action::Write(contents, kind) => match kind {
+                    clipboard::Kind::Standard => clipboard.write(contents),
+                    clipboard::Kind::Primary => {
+                        clipboard.write_primary(contents)
+                    }
+                },
+                clipboard::Action::ReadData(mime_type, tag) => {
+                    let message = tag(clipboard.read_data(&mime_type));
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
                 }
-            },
-            command::Action::Window(action) => match action {
-                window::Action::Resize { width, height } => {
-                    window.set_inner_size(winit::dpi::LogicalSize {
-                        width,
-                        height,
-                    });
+                clipboard::Action::WriteData(data) => {
+                    clipboard.write_data(data);
                 }
-                window::Action::Move { x, y } => {
-                    window.set_outer_position(winit::dpi::LogicalPosition {
-                        x,
-                        y,
-                    });
+                clipboard::Action::ReadImage(tag) => {
+                    let message = tag(clipboard.read_image());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteImage(image) => {
+                    clipboard.write_image(image);
                 }
             },
+            command::Action::Window(action) => {
+                run_window_action(action, state, window, proxy);
+            }
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
                     #[cfg(feature = "system")]
@@ -599,12 +761,208 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
                     }
                 }
             },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action.into_operation());
+
+                let mut user_interface = build_user_interface(
+                    application,
+                    std::mem::take(cache),
+                    renderer,
+                    state.logical_size(),
+                    debug,
+                );
+
+                while let Some(mut operation) = current_operation.take() {
+                    user_interface.operate(operation.as_mut());
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+
+                *cache = user_interface.into_cache();
+            }
+        }
+    }
+}
+
+/// Runs a window [`Action`] on the given window.
+///
+/// [`Action`]: iced_native::window::Action
+pub(crate) fn run_window_action<A, Message: 'static>(
+    action: iced_native::window::Action<Message>,
+    state: &mut State<A>,
+    window: &winit::window::Window,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) {
+    use iced_native::window;
+
+    match action {
+        window::Action::Spawn(id, _settings) => {
+            log::warn!(
+                "window {:?} was not opened: an `Application` only has one \
+                window",
+                id
+            );
+        }
+        window::Action::Target(id, action) => {
+            if id == window::Id::MAIN {
+                run_window_action(*action, state, window, proxy);
+            } else {
+                log::warn!("window {:?} does not exist", id);
+            }
+        }
+        window::Action::Resize { width, height } => {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+        window::Action::Move { x, y } => {
+            window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+        }
+        window::Action::Center => {
+            let size =
+                window.outer_size().to_logical::<u32>(window.scale_factor());
+
+            if let Some(position) = conversion::position(
+                window.current_monitor().as_ref(),
+                (size.width, size.height),
+                Position::Centered,
+            ) {
+                window.set_outer_position(position);
+            }
+        }
+        window::Action::SetMinSize(size) => {
+            // The window is resized if it does not fit anymore, which
+            // produces a `Resized` event as usual
+            window.set_min_inner_size(size.map(|size| {
+                winit::dpi::LogicalSize {
+                    width: size.width,
+                    height: size.height,
+                }
+            }));
+        }
+        window::Action::SetMaxSize(size) => {
+            window.set_max_inner_size(size.map(|size| {
+                winit::dpi::LogicalSize {
+                    width: size.width,
+                    height: size.height,
+                }
+            }));
+        }
+        window::Action::SetResizeIncrements(increments) => {
+            state.set_resize_increments(window, increments);
+        }
+        window::Action::SetAspectRatio(ratio) => {
+            state.set_aspect_ratio(window, ratio);
+        }
+        window::Action::Close => {
+            state.close();
+        }
+        window::Action::Drag => {
+            if let Err(error) = window.drag_window() {
+                log::debug!("error dragging window: {}", error);
+            }
+        }
+        window::Action::Minimize(minimized) => {
+            window.set_minimized(minimized);
+        }
+        window::Action::Maximize(maximized) => {
+            window.set_maximized(maximized);
+        }
+        window::Action::SetTitle(title) => {
+            // The title of the `State` is left untouched, so the one of
+            // the application replaces it once it changes
+            window.set_title(&title);
+        }
+        window::Action::SetIcon(icon) => {
+            let (rgba, width, height) = icon.into_raw();
+
+            match winit::window::Icon::from_rgba(rgba, width, height) {
+                Ok(icon) => window.set_window_icon(Some(icon)),
+                Err(error) => {
+                    log::warn!("error setting window icon: {}", error)
+                }
+            }
+        }
+        window::Action::ChangeMode(mode) => {
+            state.change_mode(window, mode);
+        }
+        window::Action::ChangeCursorGrab(grab, tag) => {
+            let grab = state.change_cursor_grab(window, grab);
+
+            if let Some(tag) = tag {
+                proxy
+                    .send_event(tag(grab))
+                    .expect("Send message to event loop");
+            }
+        }
+        window::Action::SetCursorVisible(is_visible) => {
+            state.set_cursor_visible(window, is_visible);
+        }
+        window::Action::ChangeLevel(level) => match level {
+            window::Level::Normal => window.set_always_on_top(false),
+            window::Level::AlwaysOnTop => window.set_always_on_top(true),
+            window::Level::AlwaysOnBottom => {
+                log::debug!("always on bottom windows are not supported")
+            }
+        },
+        window::Action::RequestUserAttention(attention) => {
+            window.request_user_attention(
+                attention.map(conversion::user_attention),
+            );
+        }
+        window::Action::Screenshot(tag) => {
+            let proxy = proxy.clone();
+
+            state.request_screenshot(window, move |screenshot| {
+                proxy
+                    .send_event(tag(screenshot))
+                    .expect("Send message to event loop");
+            });
+        }
+        window::Action::FetchMode(tag) => {
+            let message = tag(state.window_mode());
+
+            proxy
+                .send_event(message)
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchSize(tag) => {
+            let size =
+                window.inner_size().to_logical::<u32>(window.scale_factor());
+
+            let message = tag(Size::new(size.width, size.height));
+
+            proxy
+                .send_event(message)
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchPosition(tag) => {
+            let position = window.outer_position().ok().map(|position| {
+                let position =
+                    position.to_logical::<f32>(window.scale_factor());
+
+                Point::new(position.x, position.y)
+            });
+
+            let message = tag(position);
+
+            proxy
+                .send_event(message)
+                .expect("Send message to event loop");
         }
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         mut event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -626,7 +984,7 @@ mod platform {
 }
 
 #[cfg(target_arch = "wasm32")]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -1,32 +1,80 @@
 use crate::conversion;
 use crate::{Application, Color, Debug, Mode, Point, Size, Viewport};
 
+use iced_native::window::{CursorGrab, Screenshot};
+use std::fmt;
 use std::marker::PhantomData;
+use winit::dpi::{LogicalSize, PhysicalSize};
 use winit::event::{Touch, WindowEvent};
 use winit::window::Window;
 
 /// The state of a windowed [`Application`].
-#[derive(Debug, Clone)]
-pub struct State<A: Application> {
+#[derive(Debug)]
+pub struct State<A> {
     title: String,
     mode: Mode,
+    window_mode: Mode,
+    windowed_bounds: Option<(
+        winit::dpi::PhysicalPosition<i32>,
+        winit::dpi::PhysicalSize<u32>,
+    )>,
     background_color: Color,
     scale_factor: f64,
     viewport: Viewport,
     viewport_version: usize,
     cursor_position: winit::dpi::PhysicalPosition<f64>,
     modifiers: winit::event::ModifiersState,
+    is_maximized: bool,
+    should_close: bool,
+    cursor_grab: CursorGrab,
+    is_cursor_visible: bool,
+    resize_increments: Option<Size<u32>>,
+    aspect_ratio: Option<f32>,
+    requested_size: Option<PhysicalSize<u32>>,
+    screenshot_requests: ScreenshotRequests,
     application: PhantomData<A>,
 }
 
 impl<A: Application> State<A> {
     /// Creates a new [`State`] for the provided [`Application`] and window.
     pub fn new(application: &A, window: &Window) -> Self {
-        let title = application.title();
-        let mode = application.mode();
-        let background_color = application.background_color();
-        let scale_factor = application.scale_factor();
+        Self::with_parts(
+            application.title(),
+            application.mode(),
+            application.background_color(),
+            application.scale_factor(),
+            window,
+        )
+    }
+
+    /// Synchronizes the [`State`] with its [`Application`] and its respective
+    /// window.
+    ///
+    /// Normally an [`Application`] should be synchronized with its [`State`]
+    /// and window after calling [`Application::update`].
+    ///
+    /// [`Application::update`]: crate::Program::update
+    pub fn synchronize(&mut self, application: &A, window: &Window) {
+        self.synchronize_parts(
+            application.title(),
+            application.mode(),
+            application.background_color(),
+            application.scale_factor(),
+            window,
+        );
+    }
+}
 
+impl<A> State<A> {
+    /// Creates a new [`State`] for a window with the given title, [`Mode`],
+    /// background [`Color`], and scale factor.
+    pub(crate) fn with_parts(
+        title: String,
+        mode: Mode,
+        background_color: Color,
+        scale_factor: f64,
+        window: &Window,
+    ) -> Self {
         let viewport = {
             let physical_size = window.inner_size();
 
@@ -39,6 +87,8 @@ impl<A: Application> State<A> {
         Self {
             title,
             mode,
+            window_mode: mode,
+            windowed_bounds: None,
             background_color,
             scale_factor,
             viewport,
@@ -46,10 +96,199 @@ impl<A: Application> State<A> {
             // TODO: Encode cursor availability in the type-system
             cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
             modifiers: winit::event::ModifiersState::default(),
+            is_maximized: window.is_maximized(),
+            should_close: false,
+            cursor_grab: CursorGrab::None,
+            is_cursor_visible: true,
+            resize_increments: None,
+            aspect_ratio: None,
+            requested_size: None,
+            screenshot_requests: ScreenshotRequests::default(),
             application: PhantomData,
         }
     }
 
+    /// Checks whether the window is maximized, returning its new state if it
+    /// changed since the last check.
+    ///
+    /// The window is resized every time it is maximized or restored, so it
+    /// only needs to be checked after a resize.
+    pub fn update_maximized(&mut self, window: &Window) -> Option<bool> {
+        let is_maximized = window.is_maximized();
+
+        if self.is_maximized == is_maximized {
+            return None;
+        }
+
+        self.is_maximized = is_maximized;
+
+        Some(is_maximized)
+    }
+
+    /// Returns the current [`Mode`] of the window.
+    ///
+    /// It may differ from the [`Mode`] of the [`Application`], if it was
+    /// changed with a [`window::Action::ChangeMode`].
+    ///
+    /// [`window::Action::ChangeMode`]: iced_native::window::Action::ChangeMode
+    pub fn window_mode(&self) -> Mode {
+        self.window_mode
+    }
+
+    /// Changes the [`Mode`] of the window.
+    ///
+    /// Fullscreen uses a borderless window on the current monitor, and the
+    /// window gets its previous position and size back once it leaves it.
+    pub fn change_mode(&mut self, window: &Window, mode: Mode) {
+        if self.window_mode == mode {
+            return;
+        }
+
+        if mode == Mode::Fullscreen {
+            self.windowed_bounds = window
+                .outer_position()
+                .ok()
+                .map(|position| (position, window.inner_size()));
+        }
+
+        window.set_fullscreen(conversion::fullscreen(
+            window.current_monitor(),
+            mode,
+        ));
+
+        window.set_visible(conversion::visible(mode));
+
+        if self.window_mode == Mode::Fullscreen {
+            if let Some((position, size)) = self.windowed_bounds.take() {
+                window.set_inner_size(size);
+                window.set_outer_position(position);
+            }
+        }
+
+        self.window_mode = mode;
+    }
+
+    /// Changes how the cursor is grabbed by the window, returning the
+    /// [`CursorGrab`] that is applied.
+    ///
+    /// Platforms grab the cursor in a single way, which is used for any
+    /// [`CursorGrab`] other than [`CursorGrab::None`].
+    pub fn change_cursor_grab(
+        &mut self,
+        window: &Window,
+        grab: CursorGrab,
+    ) -> CursorGrab {
+        self.cursor_grab = apply_cursor_grab(window, grab);
+        self.cursor_grab
+    }
+
+    /// Shows or hides the cursor over the window.
+    pub fn set_cursor_visible(&mut self, window: &Window, is_visible: bool) {
+        window.set_cursor_visible(is_visible);
+
+        self.is_cursor_visible = is_visible;
+    }
+
+    /// Sets the logical increments the window is resized in, or removes them
+    /// if `None`.
+    ///
+    /// The current size of the window is constrained right away.
+    pub fn set_resize_increments(
+        &mut self,
+        window: &Window,
+        increments: Option<Size<u32>>,
+    ) {
+        self.resize_increments = increments;
+        self.constrain_size(window, window.inner_size());
+    }
+
+    /// Sets the ratio between the width and the height the window keeps
+    /// while resized, or removes it if `None`.
+    ///
+    /// The current size of the window is constrained right away.
+    pub fn set_aspect_ratio(&mut self, window: &Window, ratio: Option<f32>) {
+        self.aspect_ratio = ratio.filter(|ratio| *ratio > 0.0);
+        self.constrain_size(window, window.inner_size());
+    }
+
+    /// Requests a new size for the window if the given one does not honor
+    /// its resize increments and aspect ratio.
+    ///
+    /// Platforms may refuse the new size, which produces another resize, so
+    /// the same size is never requested twice in a row.
+    fn constrain_size(&mut self, window: &Window, size: PhysicalSize<u32>) {
+        if (self.resize_increments.is_none() && self.aspect_ratio.is_none())
+            || self.window_mode == Mode::Fullscreen
+            || window.is_maximized()
+        {
+            self.requested_size = None;
+            return;
+        }
+
+        let constrained = constrained_size(
+            size,
+            self.viewport.physical_size(),
+            window.scale_factor(),
+            self.resize_increments,
+            self.aspect_ratio,
+        );
+
+        // Conversions between logical and physical pixels may be off by one
+        let is_close = |a: u32, b: u32| a.max(b) - a.min(b) <= 1;
+
+        if is_close(size.width, constrained.width)
+            && is_close(size.height, constrained.height)
+        {
+            self.requested_size = None;
+            return;
+        }
+
+        if self.requested_size == Some(constrained) {
+            return;
+        }
+
+        window.set_inner_size(constrained);
+
+        self.requested_size = Some(constrained);
+    }
+
+    /// Requests a [`Screenshot`] of the next frame of the window, which is
+    /// given to the provided closure.
+    pub fn request_screenshot(
+        &mut self,
+        window: &Window,
+        f: impl FnOnce(Screenshot) + 'static,
+    ) {
+        self.screenshot_requests.0.push(Box::new(f));
+
+        window.request_redraw();
+    }
+
+    /// Returns whether a [`Screenshot`] of the next frame of the window was
+    /// requested.
+    pub fn is_screenshot_requested(&self) -> bool {
+        !self.screenshot_requests.0.is_empty()
+    }
+
+    /// Gives the [`Screenshot`] of a frame of the window to all of its
+    /// pending requests.
+    pub fn resolve_screenshot_requests(&mut self, screenshot: Screenshot) {
+        for request in self.screenshot_requests.0.drain(..) {
+            request(screenshot.clone());
+        }
+    }
+
+    /// Marks the window to be closed, exiting the [`Application`] after its
+    /// current update.
+    pub fn close(&mut self) {
+        self.should_close = true;
+    }
+
+    /// Returns whether the window was marked to be closed.
+    pub fn should_close(&self) -> bool {
+        self.should_close
+    }
+
     /// Returns the current background [`Color`] of the [`State`].
     pub fn background_color(&self) -> Color {
         self.background_color
@@ -105,6 +344,8 @@ impl<A: Application> State<A> {
     ) {
         match event {
             WindowEvent::Resized(new_size) => {
+                self.constrain_size(window, *new_size);
+
                 let size = Size::new(new_size.width, new_size.height);
 
                 self.viewport = Viewport::with_physical_size(
@@ -142,6 +383,17 @@ impl<A: Application> State<A> {
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
             }
+            WindowEvent::Focused(is_focused) => {
+                // The cursor is released while the window is unfocused, so it
+                // never traps the user
+                if *is_focused {
+                    let _ = apply_cursor_grab(window, self.cursor_grab);
+                    window.set_cursor_visible(self.is_cursor_visible);
+                } else {
+                    let _ = apply_cursor_grab(window, CursorGrab::None);
+                    window.set_cursor_visible(true);
+                }
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 input:
@@ -156,17 +408,17 @@ impl<A: Application> State<A> {
         }
     }
 
-    /// Synchronizes the [`State`] with its [`Application`] and its respective
-    /// window.
-    ///
-    /// Normally an [`Application`] should be synchronized with its [`State`]
-    /// and window after calling [`Application::update`].
-    ///
-    /// [`Application::update`]: crate::Program::update
-    pub fn synchronize(&mut self, application: &A, window: &Window) {
+    /// Synchronizes the [`State`] and its window with the given title,
+    /// [`Mode`], background [`Color`], and scale factor.
+    pub(crate) fn synchronize_parts(
+        &mut self,
+        new_title: String,
+        new_mode: Mode,
+        background_color: Color,
+        new_scale_factor: f64,
+        window: &Window,
+    ) {
         // Update window title
-        let new_title = application.title();
-
         if self.title != new_title {
             window.set_title(&new_title);
 
@@ -174,25 +426,16 @@ impl<A: Application> State<A> {
         }
 
         // Update window mode
-        let new_mode = application.mode();
-
         if self.mode != new_mode {
-            window.set_fullscreen(conversion::fullscreen(
-                window.current_monitor(),
-                new_mode,
-            ));
-
-            window.set_visible(conversion::visible(new_mode));
+            self.change_mode(window, new_mode);
 
             self.mode = new_mode;
         }
 
         // Update background color
-        self.background_color = application.background_color();
+        self.background_color = background_color;
 
         // Update scale factor
-        let new_scale_factor = application.scale_factor();
-
         if self.scale_factor != new_scale_factor {
             let size = window.inner_size();
 
@@ -205,3 +448,79 @@ impl<A: Application> State<A> {
         }
     }
 }
+
+/// The pending requests for a [`Screenshot`] of a window.
+#[derive(Default)]
+struct ScreenshotRequests(Vec<Box<dyn FnOnce(Screenshot)>>);
+
+impl fmt::Debug for ScreenshotRequests {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ScreenshotRequests({})", self.0.len())
+    }
+}
+
+/// Returns the size closest to the given one with the provided resize
+/// increments and aspect ratio, if any.
+///
+/// The aspect ratio is kept by adjusting the dimension that changed the
+/// least since the previous size, since the other one is being dragged.
+fn constrained_size(
+    size: PhysicalSize<u32>,
+    previous: Size<u32>,
+    scale_factor: f64,
+    increments: Option<Size<u32>>,
+    aspect_ratio: Option<f32>,
+) -> PhysicalSize<u32> {
+    let size = size.to_logical::<f64>(scale_factor);
+    let previous = PhysicalSize::new(previous.width, previous.height)
+        .to_logical::<f64>(scale_factor);
+
+    let mut width = size.width;
+    let mut height = size.height;
+
+    if let Some(ratio) = aspect_ratio.map(f64::from) {
+        if (width - previous.width).abs() >= (height - previous.height).abs() {
+            height = width / ratio;
+        } else {
+            width = height * ratio;
+        }
+    }
+
+    if let Some(increments) = increments {
+        let snap = |value: f64, increment: u32| {
+            if increment == 0 {
+                return value;
+            }
+
+            let increment = f64::from(increment);
+
+            ((value / increment).round() * increment).max(increment)
+        };
+
+        width = snap(width, increments.width);
+        height = snap(height, increments.height);
+    }
+
+    LogicalSize::new(width, height).to_physical(scale_factor)
+}
+
+/// Grabs the cursor with the given [`CursorGrab`], returning the one that is
+/// applied.
+fn apply_cursor_grab(window: &Window, grab: CursorGrab) -> CursorGrab {
+    if grab == CursorGrab::None {
+        let _ = window.set_cursor_grab(false);
+
+        return CursorGrab::None;
+    }
+
+    match window.set_cursor_grab(true) {
+        // macOS locks the cursor in place, while other platforms confine it
+        Ok(()) if cfg!(target_os = "macos") => CursorGrab::Locked,
+        Ok(()) => CursorGrab::Confined,
+        Err(error) => {
+            log::debug!("error grabbing cursor: {}", error);
+
+            CursorGrab::None
+        }
+    }
+}
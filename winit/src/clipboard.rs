@@ -1,5 +1,5 @@
 //! Access the clipboard.
-pub use iced_native::clipboard::Action;
+pub use iced_native::clipboard::{Action, ImageData, Kind, MimeType};
 
 use crate::command::{self, Command};
 
@@ -8,6 +8,7 @@ use crate::command::{self, Command};
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    platform: Option<platform::Clipboard>,
 }
 
 enum State {
@@ -23,7 +24,10 @@ impl Clipboard {
             .map(State::Connected)
             .unwrap_or(State::Unavailable);
 
-        Clipboard { state }
+        Clipboard {
+            state,
+            platform: platform::Clipboard::connect(),
+        }
     }
 
     /// Creates a new [`Clipboard`] that isn't associated with a window.
@@ -31,6 +35,7 @@ impl Clipboard {
     pub fn unconnected() -> Clipboard {
         Clipboard {
             state: State::Unavailable,
+            platform: None,
         }
     }
 
@@ -54,6 +59,88 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    ///
+    /// It returns `None` if the platform does not support images in the
+    /// clipboard.
+    pub fn read_image(&self) -> Option<ImageData> {
+        self.platform.as_ref()?.read_image()
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// It does nothing if the platform does not support images in the
+    /// clipboard.
+    pub fn write_image(&mut self, image: ImageData) {
+        if let Some(platform) = &self.platform {
+            platform.write_image(image);
+        }
+    }
+
+    /// Reads the current content of the primary selection of the
+    /// [`Clipboard`] as text.
+    ///
+    /// It returns `None` in platforms without a primary selection, like
+    /// Windows and macOS.
+    pub fn read_primary(&self) -> Option<String> {
+        self.platform.as_ref()?.read_primary()
+    }
+
+    /// Writes the given text contents to the primary selection of the
+    /// [`Clipboard`].
+    ///
+    /// It does nothing in platforms without a primary selection, like
+    /// Windows and macOS.
+    pub fn write_primary(&mut self, contents: String) {
+        if let Some(platform) = &self.platform {
+            platform.write_primary(contents);
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`MimeType`].
+    ///
+    /// [`MimeType::TEXT`] is read like with [`Clipboard::read`] everywhere.
+    /// Support for the rest depends on the platform:
+    ///
+    /// - [`MimeType::HTML`] can be read on Windows, macOS, and on X11 and
+    ///   Wayland in Linux and the BSDs.
+    /// - [`MimeType::RTF`] and any other [`MimeType`] cannot be read on any
+    ///   platform yet, so the result is always `None`.
+    /// - On the web, only [`MimeType::TEXT`] can be read.
+    pub fn read_data(&self, mime_type: &MimeType) -> Option<Vec<u8>> {
+        if *mime_type == MimeType::TEXT {
+            self.read().map(String::into_bytes)
+        } else if *mime_type == MimeType::HTML {
+            self.platform.as_ref()?.read_html().map(String::into_bytes)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the given contents to the [`Clipboard`], each one in its own
+    /// [`MimeType`].
+    ///
+    /// [`MimeType::HTML`] contents are offered together with the
+    /// [`MimeType::TEXT`] ones on Windows, macOS, and on X11 and Wayland in
+    /// Linux and the BSDs. Everywhere else, and for any other [`MimeType`],
+    /// only the [`MimeType::TEXT`] contents are written, like with
+    /// [`Clipboard::write`].
+    pub fn write_data(&mut self, data: Vec<(MimeType, Vec<u8>)>) {
+        let html = data
+            .iter()
+            .find(|(mime_type, _)| *mime_type == MimeType::HTML)
+            .and_then(|(_, bytes)| String::from_utf8(bytes.clone()).ok());
+
+        let text = iced_native::clipboard::text(data);
+
+        if let (Some(html), Some(platform)) = (html, &self.platform) {
+            platform.write_html(html, text);
+        } else if let Some(text) = text {
+            self.write(text);
+        }
+    }
 }
 
 impl iced_native::Clipboard for Clipboard {
@@ -64,16 +151,299 @@ impl iced_native::Clipboard for Clipboard {
     fn write(&mut self, contents: String) {
         self.write(contents)
     }
+
+    fn read_image(&self) -> Option<ImageData> {
+        self.read_image()
+    }
+
+    fn write_image(&mut self, image: ImageData) {
+        self.write_image(image)
+    }
+
+    fn read_primary(&self) -> Option<String> {
+        self.read_primary()
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        self.write_primary(contents)
+    }
+
+    fn read_data(&self, mime_type: &MimeType) -> Option<Vec<u8>> {
+        self.read_data(mime_type)
+    }
+
+    fn write_data(&mut self, data: Vec<(MimeType, Vec<u8>)>) {
+        self.write_data(data)
+    }
 }
 
 /// Read the current contents of the clipboard.
 pub fn read<Message>(
     f: impl Fn(Option<String>) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Clipboard(Action::Read(Box::new(f))))
+    Command::single(command::Action::Clipboard(Action::Read(
+        Box::new(f),
+        Kind::Standard,
+    )))
 }
 
 /// Write the given contents to the clipboard.
 pub fn write<Message>(contents: String) -> Command<Message> {
-    Command::single(command::Action::Clipboard(Action::Write(contents)))
+    Command::single(command::Action::Clipboard(Action::Write(
+        contents,
+        Kind::Standard,
+    )))
+}
+
+/// Read the current contents of the clipboard in the given [`MimeType`].
+///
+/// The result is `None` if the platform cannot read the [`MimeType`]. See
+/// [`Clipboard::read_data`] for the ones supported in each platform.
+pub fn read_data<Message>(
+    mime_type: MimeType,
+    f: impl Fn(Option<Vec<u8>>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadData(
+        mime_type,
+        Box::new(f),
+    )))
+}
+
+/// Write the given contents to the clipboard, each one in its own
+/// [`MimeType`], so applications pasting them can choose the representation
+/// they prefer.
+///
+/// For instance, a table can be copied both as [`MimeType::TEXT`] and
+/// [`MimeType::HTML`].
+pub fn write_data<Message>(data: Vec<(MimeType, Vec<u8>)>) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteData(data)))
+}
+
+/// Read the current contents of the primary selection.
+///
+/// The result is `None` in platforms without a primary selection, like
+/// Windows and macOS.
+pub fn read_primary<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::Read(
+        Box::new(f),
+        Kind::Primary,
+    )))
+}
+
+/// Write the given contents to the primary selection.
+///
+/// It does nothing in platforms without a primary selection, like Windows
+/// and macOS.
+pub fn write_primary<Message>(contents: String) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::Write(
+        contents,
+        Kind::Primary,
+    )))
+}
+
+/// Read the current contents of the clipboard as an image.
+///
+/// The result is `None` if the clipboard holds no image, or if the platform
+/// does not support images in the clipboard.
+pub fn read_image<Message>(
+    f: impl Fn(Option<ImageData>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadImage(Box::new(f))))
+}
+
+/// Write the given image to the clipboard.
+pub fn write_image<Message>(image: ImageData) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteImage(image)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use super::ImageData;
+
+    use std::cell::RefCell;
+
+    /// The clipboard of the platform, used for images and the primary
+    /// selection.
+    ///
+    /// Most applications never use either, so the connection is only opened
+    /// the first time it is needed.
+    pub struct Clipboard(RefCell<Connection>);
+
+    enum Connection {
+        Pending,
+        Connected(arboard::Clipboard),
+        Failed,
+    }
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            Some(Self(RefCell::new(Connection::Pending)))
+        }
+
+        /// Runs the given closure with the connection to the clipboard,
+        /// opening it if necessary.
+        ///
+        /// It returns `None` if the connection could not be opened.
+        fn with<T>(
+            &self,
+            f: impl FnOnce(&mut arboard::Clipboard) -> T,
+        ) -> Option<T> {
+            let mut connection = self.0.borrow_mut();
+
+            if let Connection::Pending = *connection {
+                *connection = match arboard::Clipboard::new() {
+                    Ok(clipboard) => Connection::Connected(clipboard),
+                    Err(error) => {
+                        log::warn!(
+                            "images and the primary selection of the \
+                            clipboard are unavailable: {}",
+                            error
+                        );
+
+                        Connection::Failed
+                    }
+                };
+            }
+
+            match &mut *connection {
+                Connection::Connected(clipboard) => Some(f(clipboard)),
+                Connection::Pending | Connection::Failed => None,
+            }
+        }
+
+        pub fn read_image(&self) -> Option<ImageData> {
+            let image = self.with(|clipboard| clipboard.get_image())?.ok()?;
+
+            Some(ImageData {
+                width: u32::try_from(image.width).ok()?,
+                height: u32::try_from(image.height).ok()?,
+                bytes: image.bytes.into_owned(),
+            })
+        }
+
+        pub fn write_image(&self, image: ImageData) {
+            let image = arboard::ImageData {
+                width: image.width as usize,
+                height: image.height as usize,
+                bytes: image.bytes.into(),
+            };
+
+            if let Some(Err(error)) =
+                self.with(|clipboard| clipboard.set_image(image))
+            {
+                log::warn!("error writing image to clipboard: {}", error);
+            }
+        }
+
+        pub fn read_html(&self) -> Option<String> {
+            self.with(|clipboard| clipboard.get().html())?.ok()
+        }
+
+        pub fn write_html(&self, html: String, alt_text: Option<String>) {
+            if let Some(Err(error)) =
+                self.with(|clipboard| clipboard.set_html(html, alt_text))
+            {
+                log::warn!("error writing HTML to clipboard: {}", error);
+            }
+        }
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        pub fn read_primary(&self) -> Option<String> {
+            use arboard::{GetExtLinux, LinuxClipboardKind};
+
+            self.with(|clipboard| {
+                clipboard
+                    .get()
+                    .clipboard(LinuxClipboardKind::Primary)
+                    .text()
+            })?
+            .ok()
+        }
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        pub fn write_primary(&self, contents: String) {
+            use arboard::{LinuxClipboardKind, SetExtLinux};
+
+            if let Some(Err(error)) = self.with(|clipboard| {
+                clipboard
+                    .set()
+                    .clipboard(LinuxClipboardKind::Primary)
+                    .text(contents)
+            }) {
+                log::warn!("error writing to primary selection: {}", error);
+            }
+        }
+
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )))]
+        pub fn read_primary(&self) -> Option<String> {
+            None
+        }
+
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )))]
+        pub fn write_primary(&self, _contents: String) {}
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use super::ImageData;
+
+    /// The web has no clipboard for images or a primary selection.
+    pub enum Clipboard {}
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            None
+        }
+
+        pub fn read_image(&self) -> Option<ImageData> {
+            match *self {}
+        }
+
+        pub fn write_image(&self, _image: ImageData) {
+            match *self {}
+        }
+
+        pub fn read_html(&self) -> Option<String> {
+            match *self {}
+        }
+
+        pub fn write_html(&self, _html: String, _alt_text: Option<String>) {
+            match *self {}
+        }
+
+        pub fn read_primary(&self) -> Option<String> {
+            match *self {}
+        }
+
+        pub fn write_primary(&self, _contents: String) {
+            match *self {}
+        }
+    }
 }
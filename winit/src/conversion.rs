@@ -79,6 +79,9 @@ pub fn window_event(
                 }))
             }
         },
+        // TODO: Deliver the composition of input methods once we upgrade
+        // `winit`. The 0.26 fork we depend on does not report input method
+        // events yet, so only the position of the editor is set.
         WindowEvent::ReceivedCharacter(c) if !is_private_use_character(*c) => {
             Some(Event::Keyboard(keyboard::Event::CharacterReceived(*c)))
         }
@@ -197,6 +200,22 @@ pub fn fullscreen(
     }
 }
 
+/// Converts a [`window::UserAttention`] to a [`winit`] user attention type.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn user_attention(
+    attention: window::UserAttention,
+) -> winit::window::UserAttentionType {
+    match attention {
+        window::UserAttention::Critical => {
+            winit::window::UserAttentionType::Critical
+        }
+        window::UserAttention::Informational => {
+            winit::window::UserAttentionType::Informational
+        }
+    }
+}
+
 /// Converts a [`Mode`] to a visibility flag.
 pub fn visible(mode: Mode) -> bool {
     match mode {
@@ -272,6 +291,19 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts a logical position of the input method editor to a physical
+/// position.
+pub fn ime_position(
+    position: Point,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::LogicalPosition::new(
+        f64::from(position.x),
+        f64::from(position.y),
+    )
+    .to_physical(scale_factor)
+}
+
 /// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
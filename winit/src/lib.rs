@@ -30,6 +30,8 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod multi_window;
 pub mod settings;
 pub mod window;
 
@@ -37,14 +39,13 @@ pub mod window;
 pub mod system;
 
 mod error;
-mod mode;
 mod position;
 mod proxy;
 
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use mode::Mode;
+pub use iced_native::window::Mode;
 pub use position::Position;
 pub use proxy::Proxy;
 pub use settings::Settings;
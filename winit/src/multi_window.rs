@@ -0,0 +1,1060 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+use crate::application::{self, State};
+use crate::clipboard::{self, Clipboard};
+use crate::conversion;
+use crate::mouse;
+use crate::{
+    Color, Command, Debug, Element, Error, Executor, Mode, Proxy, Renderer,
+    Runtime, Settings, Size, Subscription,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_graphics::compositor;
+use iced_graphics::window::Compositor;
+use iced_native::time::Instant;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::widget::operation;
+use iced_native::window;
+
+use std::collections::BTreeMap;
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// It works like an [`Application`], but every window of the application is
+/// identified by a [`window::Id`] and has its own view. The application
+/// starts with the [`window::Id::MAIN`] window, and it can open more of them
+/// with [`window::spawn`]. Window commands affect the main window, unless
+/// they are wrapped with [`window::target`].
+///
+/// Closing the main window exits the application. When any other window is
+/// closed, either by the user or with [`window::close`], a
+/// [`window::Event::Closed`] is produced for it.
+///
+/// [`Application`]: crate::Application
+/// [`window::spawn`]: crate::window::spawn
+/// [`window::target`]: crate::window::target
+/// [`window::close`]: crate::window::close
+pub trait Application: Sized {
+    /// The graphics backend to use to draw the windows.
+    type Renderer: Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    fn view(
+        &mut self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// Window events can be told apart with
+    /// [`iced_native::subscription::events_by_window`].
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the current [`Mode`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, windows run in windowed mode.
+    fn mode(&self, _window: window::Id) -> Mode {
+        Mode::Windowed
+    }
+
+    /// Returns the background [`Color`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// A color with some transparency lets the desktop show through a
+    /// transparent window. The `wgpu` renderer always presents opaque windows
+    /// for now, so the transparency only has an effect with `glow`.
+    ///
+    /// By default, it returns [`Color::WHITE`].
+    fn background_color(&self, _window: window::Id) -> Color {
+        Color::WHITE
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+}
+
+/// Runs an [`Application`] with an executor, compositor, and the provided
+/// settings.
+///
+/// The window settings are used for the main window.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoop;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoop::with_user_event();
+    let proxy = event_loop.create_proxy();
+
+    let mut runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    // The runtime keeps the size constraints of the window from now on
+    let init_command = Command::batch([
+        crate::window::set_resize_increments(settings.window.resize_increments),
+        crate::window::set_aspect_ratio(settings.window.aspect_ratio),
+        init_command,
+    ]);
+
+    let builder = settings.window.into_builder(
+        &application.title(window::Id::MAIN),
+        application.mode(window::Id::MAIN),
+        event_loop.primary_monitor(),
+        settings.id,
+    );
+
+    log::info!("Window builder: {:#?}", builder);
+
+    let window = builder
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let clipboard = Clipboard::connect(&window);
+
+    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+
+    let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        clipboard,
+        proxy,
+        debug,
+        receiver,
+        control_sender,
+        init_command,
+        window,
+        settings.exit_on_close_request,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    application::platform::run(
+        event_loop,
+        move |event, window_target, control_flow| {
+            use winit::event_loop::ControlFlow;
+
+            if let ControlFlow::Exit = control_flow {
+                return;
+            }
+
+            let event = match event {
+                winit::event::Event::WindowEvent {
+                    event:
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            new_inner_size,
+                            ..
+                        },
+                    window_id,
+                } => Some(winit::event::Event::WindowEvent {
+                    event: winit::event::WindowEvent::Resized(*new_inner_size),
+                    window_id,
+                }),
+                _ => event.to_static(),
+            };
+
+            if let Some(event) = event {
+                sender.start_send(Event::Winit(event)).expect("Send event");
+
+                loop {
+                    let poll = instance.as_mut().poll(&mut context);
+
+                    match poll {
+                        task::Poll::Pending => {
+                            let mut has_created_windows = false;
+
+                            while let Ok(Some(control)) =
+                                control_receiver.try_next()
+                            {
+                                match control {
+                                    Control::ChangeFlow(flow) => {
+                                        *control_flow = flow;
+                                    }
+                                    Control::CreateWindow {
+                                        id,
+                                        builder,
+                                        settings,
+                                    } => match builder.build(window_target) {
+                                        Ok(window) => {
+                                            sender
+                                                .start_send(
+                                                    Event::WindowCreated(
+                                                        id, window, settings,
+                                                    ),
+                                                )
+                                                .expect("Send event");
+
+                                            has_created_windows = true;
+                                        }
+                                        Err(error) => {
+                                            log::warn!(
+                                                "window {:?} was not \
+                                                    opened: {}",
+                                                id,
+                                                error
+                                            );
+                                        }
+                                    },
+                                }
+                            }
+
+                            // The new windows are handed to the instance
+                            // before waiting for more events
+                            if !has_created_windows {
+                                break;
+                            }
+                        }
+                        task::Poll::Ready(_) => {
+                            *control_flow = ControlFlow::Exit;
+
+                            break;
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// An event of the multi-window runtime.
+enum Event<Message: 'static> {
+    /// An event of the event loop.
+    Winit(winit::event::Event<'static, Message>),
+    /// A new window was built with the given [`window::Settings`].
+    WindowCreated(window::Id, winit::window::Window, window::Settings),
+}
+
+/// A request from the instance to the event loop.
+enum Control {
+    /// The [`ControlFlow`] of the event loop should change.
+    ///
+    /// [`ControlFlow`]: winit::event_loop::ControlFlow
+    ChangeFlow(winit::event_loop::ControlFlow),
+    /// A new window should be built.
+    CreateWindow {
+        id: window::Id,
+        builder: winit::window::WindowBuilder,
+        settings: window::Settings,
+    },
+}
+
+/// A window of a running [`Application`].
+struct Window<A: Application, C: Compositor<Renderer = A::Renderer>> {
+    // The surface must be dropped before its window
+    surface: C::Surface,
+    raw: winit::window::Window,
+    state: State<A>,
+    viewport_version: usize,
+    mouse_interaction: mouse::Interaction,
+    events: Vec<iced_native::Event>,
+    redraw_request: Option<Instant>,
+}
+
+impl<A, C> Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    fn new(
+        application: &A,
+        compositor: &mut C,
+        id: window::Id,
+        raw: winit::window::Window,
+    ) -> Self {
+        let mut surface = compositor.create_surface(&raw);
+
+        let state = State::with_parts(
+            application.title(id),
+            application.mode(id),
+            application.background_color(id),
+            application.scale_factor(id),
+            &raw,
+        );
+
+        let physical_size = state.physical_size();
+
+        compositor.configure_surface(
+            &mut surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        Self {
+            surface,
+            viewport_version: state.viewport_version(),
+            raw,
+            state,
+            mouse_interaction: mouse::Interaction::default(),
+            events: Vec::new(),
+            redraw_request: None,
+        }
+    }
+
+    fn synchronize(&mut self, application: &A, id: window::Id) {
+        self.state.synchronize_parts(
+            application.title(id),
+            application.mode(id),
+            application.background_color(id),
+            application.scale_factor(id),
+            &self.raw,
+        );
+    }
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut clipboard: Clipboard,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    window: winit::window::Window,
+    exit_on_close_request: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+
+    let mut windows = BTreeMap::new();
+    let _ = windows.insert(
+        window::Id::MAIN,
+        Window::<A, C>::new(
+            &application,
+            &mut compositor,
+            window::Id::MAIN,
+            window,
+        ),
+    );
+
+    run_command(
+        &mut application,
+        &mut windows,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &mut control_sender,
+        &mut debug,
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
+    let mut messages = Vec::new();
+
+    let _ = control_sender
+        .start_send(Control::ChangeFlow(application::control_flow(None)));
+
+    debug.startup_finished();
+
+    while let Some(event) = receiver.next().await {
+        let event = match event {
+            Event::Winit(event) => event,
+            Event::WindowCreated(id, raw, settings) => {
+                let mut window =
+                    Window::new(&application, &mut compositor, id, raw);
+
+                window.state.set_resize_increments(
+                    &window.raw,
+                    settings.resize_increments,
+                );
+                window
+                    .state
+                    .set_aspect_ratio(&window.raw, settings.aspect_ratio);
+
+                window.raw.request_redraw();
+
+                let _ = windows.insert(id, window);
+
+                continue;
+            }
+        };
+
+        match event {
+            event::Event::MainEventsCleared => {
+                if messages.is_empty()
+                    && windows.values().all(|window| window.events.is_empty())
+                {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut is_outdated = false;
+
+                for (&id, window) in windows.iter_mut() {
+                    if window.events.is_empty() {
+                        continue;
+                    }
+
+                    let mut user_interface = build_user_interface(
+                        &mut application,
+                        id,
+                        user_interface::Cache::default(),
+                        &mut renderer,
+                        window.state.logical_size(),
+                        &mut debug,
+                    );
+
+                    let (interface_state, statuses) = user_interface.update(
+                        &window.events,
+                        window.state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    if let Some(position) = user_interface.ime_position() {
+                        window.raw.set_ime_position(conversion::ime_position(
+                            position,
+                            window.state.scale_factor(),
+                        ));
+                    }
+
+                    window.redraw_request = user_interface.redraw_request();
+
+                    if matches!(
+                        interface_state,
+                        user_interface::State::Outdated
+                    ) {
+                        is_outdated = true;
+                    }
+
+                    for (event, status) in
+                        window.events.drain(..).zip(statuses.into_iter())
+                    {
+                        runtime.broadcast((id, event, status));
+                    }
+
+                    window.raw.request_redraw();
+                }
+
+                debug.event_processing_finished();
+
+                if !messages.is_empty() || is_outdated {
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut windows,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut proxy,
+                        &mut control_sender,
+                        &mut debug,
+                        &mut messages,
+                        || compositor.fetch_information(),
+                    );
+
+                    // Update windows
+                    for (&id, window) in windows.iter_mut() {
+                        window.synchronize(&application, id);
+                        window.raw.request_redraw();
+                    }
+
+                    let should_exit = application.should_exit()
+                        || windows[&window::Id::MAIN].state.should_close();
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                let redraw_request = windows
+                    .values()
+                    .filter_map(|window| window.redraw_request)
+                    .min();
+
+                let _ = control_sender.start_send(Control::ChangeFlow(
+                    application::control_flow(redraw_request),
+                ));
+            }
+            event::Event::NewEvents(_) => {
+                let now = Instant::now();
+
+                for window in windows.values_mut() {
+                    if let Some(at) = window.redraw_request {
+                        if now >= at {
+                            window.redraw_request = None;
+
+                            window.events.push(iced_native::Event::Window(
+                                iced_native::window::Event::RedrawRequested(
+                                    now,
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+            event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(url),
+            )) => {
+                use iced_native::event;
+
+                if let Some(window) = windows.get_mut(&window::Id::MAIN) {
+                    window.events.push(iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ));
+                }
+            }
+            event::Event::UserEvent(message) => {
+                messages.push(message);
+            }
+            event::Event::RedrawRequested(window_id) => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((&id, window)) => (id, window),
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                debug.render_started();
+
+                // The renderer is shared by all the windows, so the view is
+                // drawn right before presenting it
+                let mut user_interface = build_user_interface(
+                    &mut application,
+                    id,
+                    user_interface::Cache::default(),
+                    &mut renderer,
+                    window.state.logical_size(),
+                    &mut debug,
+                );
+
+                debug.draw_started();
+                let new_mouse_interaction = user_interface
+                    .draw(&mut renderer, window.state.cursor_position());
+                debug.draw_finished();
+
+                if new_mouse_interaction != window.mouse_interaction {
+                    window.raw.set_cursor_icon(conversion::mouse_interaction(
+                        new_mouse_interaction,
+                    ));
+
+                    window.mouse_interaction = new_mouse_interaction;
+                }
+
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                match compositor.present(
+                    &mut renderer,
+                    &mut window.surface,
+                    window.state.viewport(),
+                    window.state.background_color(),
+                    &debug.overlay(),
+                ) {
+                    Ok(()) => {
+                        if window.state.is_screenshot_requested() {
+                            let rgba = compositor.screenshot(
+                                &mut renderer,
+                                window.state.viewport(),
+                                window.state.background_color(),
+                                &debug.overlay(),
+                            );
+
+                            window.state.resolve_screenshot_requests(
+                                window::Screenshot::new(
+                                    rgba,
+                                    physical_size.width,
+                                    physical_size.height,
+                                ),
+                            );
+                        }
+
+                        debug.render_finished();
+                    }
+                    Err(error) => match error {
+                        // This is an unrecoverable error.
+                        compositor::SurfaceError::OutOfMemory => {
+                            panic!("{:?}", error);
+                        }
+                        _ => {
+                            debug.render_finished();
+
+                            // Try rendering again next frame.
+                            window.raw.request_redraw();
+                        }
+                    },
+                }
+            }
+            event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            } => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((&id, window)) => (id, window),
+                    None => continue,
+                };
+
+                if application::requests_exit(
+                    &window_event,
+                    window.state.modifiers(),
+                ) {
+                    if exit_on_close_request {
+                        if id == window::Id::MAIN {
+                            break;
+                        }
+
+                        close_window(&mut windows, &mut runtime, id);
+
+                        continue;
+                    }
+
+                    // Any other way to request closing reaches the application
+                    // like the close button of the window
+                    if !matches!(
+                        window_event,
+                        winit::event::WindowEvent::CloseRequested
+                    ) {
+                        window.events.push(iced_native::Event::Window(
+                            iced_native::window::Event::CloseRequested,
+                        ));
+                    }
+                }
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                if let Some(event) = conversion::window_event(
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                ) {
+                    window.events.push(event);
+                }
+
+                if let winit::event::WindowEvent::Resized(_) = window_event {
+                    if let Some(is_maximized) =
+                        window.state.update_maximized(&window.raw)
+                    {
+                        window.events.push(iced_native::Event::Window(
+                            iced_native::window::Event::Maximized(is_maximized),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Builds a [`UserInterface`] for the window of the provided [`Application`]
+/// with the given [`window::Id`], logging [`struct@Debug`] information
+/// accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a mut A,
+    id: window::Id,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+) -> UserInterface<'a, A::Message, A::Renderer> {
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Updates an [`Application`] by feeding it the provided messages, spawning
+/// any resulting [`Command`], and tracking its [`Subscription`].
+fn update<A, E, C>(
+    application: &mut A,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            windows,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            control_sender,
+            debug,
+            graphics_info,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`].
+fn run_command<A, E, C>(
+    application: &mut A,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    use iced_native::command;
+    use iced_native::system;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag, kind) => {
+                    let message = tag(match kind {
+                        clipboard::Kind::Standard => clipboard.read(),
+                        clipboard::Kind::Primary => clipboard.read_primary(),
+                    });
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents, kind) => match kind {
+                    clipboard::Kind::Standard => clipboard.write(contents),
+                    clipboard::Kind::Primary => {
+                        clipboard.write_primary(contents)
+                    }
+                },
+                clipboard::Action::ReadData(mime_type, tag) => {
+                    let message = tag(clipboard.read_data(&mime_type));
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteData(data) => {
+                    clipboard.write_data(data);
+                }
+                clipboard::Action::ReadImage(tag) => {
+                    let message = tag(clipboard.read_image());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteImage(image) => {
+                    clipboard.write_image(image);
+                }
+            },
+            command::Action::Window(action) => {
+                run_window_action(
+                    application,
+                    window::Id::MAIN,
+                    action,
+                    windows,
+                    runtime,
+                    proxy,
+                    control_sender,
+                );
+            }
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = _graphics_info();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action.into_operation());
+
+                // Operations go through the widgets of every window
+                while let Some(mut operation) = current_operation.take() {
+                    for (&id, window) in windows.iter() {
+                        let mut user_interface = build_user_interface(
+                            application,
+                            id,
+                            user_interface::Cache::default(),
+                            renderer,
+                            window.state.logical_size(),
+                            debug,
+                        );
+
+                        user_interface.operate(operation.as_mut());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs a window [`Action`] on the window with the given [`window::Id`].
+///
+/// [`Action`]: iced_native::window::Action
+fn run_window_action<A, E, C>(
+    application: &A,
+    id: window::Id,
+    action: window::Action<A::Message>,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    match action {
+        window::Action::Spawn(id, settings) => {
+            if windows.contains_key(&id) {
+                log::warn!("window {:?} is already open", id);
+
+                return;
+            }
+
+            let builder = window_builder(
+                settings.clone(),
+                &application.title(id),
+                application.mode(id),
+            );
+
+            let _ = control_sender.start_send(Control::CreateWindow {
+                id,
+                builder,
+                settings,
+            });
+        }
+        window::Action::Target(id, action) => {
+            run_window_action(
+                application,
+                id,
+                *action,
+                windows,
+                runtime,
+                proxy,
+                control_sender,
+            );
+        }
+        window::Action::Close if id != window::Id::MAIN => {
+            close_window(windows, runtime, id);
+        }
+        action => match windows.get_mut(&id) {
+            Some(window) => application::run_window_action(
+                action,
+                &mut window.state,
+                &window.raw,
+                proxy,
+            ),
+            None => log::warn!("window {:?} does not exist", id),
+        },
+    }
+}
+
+/// Closes the secondary window with the given [`window::Id`], notifying the
+/// subscriptions of the [`Application`] with a [`window::Event::Closed`].
+fn close_window<A, E, C>(
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    id: window::Id,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    if windows.remove(&id).is_some() {
+        runtime.broadcast((
+            id,
+            iced_native::Event::Window(window::Event::Closed),
+            iced_native::event::Status::Ignored,
+        ));
+    }
+}
+
+/// Converts the [`window::Settings`] of a new window into a `WindowBuilder`
+/// from `winit`.
+fn window_builder(
+    settings: window::Settings,
+    title: &str,
+    mode: Mode,
+) -> winit::window::WindowBuilder {
+    let mut builder = winit::window::WindowBuilder::new()
+        .with_title(title)
+        .with_inner_size(winit::dpi::LogicalSize {
+            width: settings.size.width,
+            height: settings.size.height,
+        })
+        .with_resizable(settings.resizable)
+        .with_decorations(settings.decorations)
+        .with_transparent(settings.transparent)
+        .with_always_on_top(settings.level == window::Level::AlwaysOnTop)
+        .with_visible(conversion::visible(mode));
+
+    if let Some(position) = settings.position {
+        builder = builder.with_position(winit::dpi::LogicalPosition {
+            x: position.x,
+            y: position.y,
+        });
+    }
+
+    if let Some(size) = settings.min_size {
+        builder = builder.with_min_inner_size(winit::dpi::LogicalSize {
+            width: size.width,
+            height: size.height,
+        });
+    }
+
+    if let Some(size) = settings.max_size {
+        builder = builder.with_max_inner_size(winit::dpi::LogicalSize {
+            width: size.width,
+            height: size.height,
+        });
+    }
+
+    if let Some(icon) = settings.icon {
+        let (rgba, width, height) = icon.into_raw();
+
+        match winit::window::Icon::from_rgba(rgba, width, height) {
+            Ok(icon) => builder = builder.with_window_icon(Some(icon)),
+            Err(error) => log::warn!("error setting window icon: {}", error),
+        }
+    }
+
+    builder
+}
@@ -14,7 +14,7 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
-use crate::{Mode, Position};
+use crate::{Mode, Position, Size};
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
 
@@ -65,6 +65,18 @@ pub struct Window {
     /// The maximum size of the window.
     pub max_size: Option<(u32, u32)>,
 
+    /// The logical increments the window is resized in, if any.
+    ///
+    /// They are honored by the platform during interactive resizes in macOS
+    /// and X11, and by the runtime everywhere else.
+    pub resize_increments: Option<Size<u32>>,
+
+    /// The ratio between the width and the height the window keeps while
+    /// resized, if any.
+    ///
+    /// The runtime corrects the size of the window after every resize.
+    pub aspect_ratio: Option<f32>,
+
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
@@ -72,6 +84,15 @@ pub struct Window {
     pub decorations: bool,
 
     /// Whether the window should be transparent.
+    ///
+    /// The background color of the application is composited with the
+    /// desktop behind the window, where supported. Only the `glow` renderer
+    /// composites transparent windows for now; with `wgpu`, and in platforms
+    /// without compositing, the window stays opaque.
+    ///
+    /// The `wgpu` renderer cannot choose how the alpha of its surface is
+    /// composited yet, so it always presents opaque windows, regardless of
+    /// the background color.
     pub transparent: bool,
 
     /// Whether the window will always be on top of other windows.
@@ -138,6 +159,15 @@ impl Window {
             if let Some(id) = _id {
                 window_builder = window_builder.with_app_id(id);
             }
+
+            if let Some(increments) = self.resize_increments {
+                window_builder = window_builder.with_resize_increments(
+                    winit::dpi::LogicalSize {
+                        width: increments.width,
+                        height: increments.height,
+                    },
+                );
+            }
         }
 
         #[cfg(target_os = "windows")]
@@ -164,6 +194,15 @@ impl Window {
                 .with_fullsize_content_view(
                     self.platform_specific.fullsize_content_view,
                 );
+
+            if let Some(increments) = self.resize_increments {
+                window_builder = window_builder.with_resize_increments(
+                    winit::dpi::LogicalSize {
+                        width: f64::from(increments.width),
+                        height: f64::from(increments.height),
+                    },
+                );
+            }
         }
 
         window_builder = window_builder
@@ -180,6 +219,8 @@ impl Default for Window {
             position: Position::default(),
             min_size: None,
             max_size: None,
+            resize_increments: None,
+            aspect_ratio: None,
             resizable: true,
             decorations: true,
             transparent: false,
@@ -1,8 +1,38 @@
 //! Interact with the window of your application.
 use crate::command::{self, Command};
 use iced_native::window;
+use iced_native::{Point, Size};
 
-pub use window::Event;
+pub use window::{
+    icon, CursorGrab, Event, Icon, Id, Level, Mode, Screenshot, Settings,
+    UserAttention,
+};
+
+/// Opens a new window with the given [`Id`] and [`Settings`].
+///
+/// Only a [`multi_window::Application`] can open new windows.
+///
+/// [`multi_window::Application`]: crate::multi_window::Application
+pub fn spawn<Message>(id: Id, settings: Settings) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Spawn(
+        id, settings,
+    )))
+}
+
+/// Runs the window actions of the given [`Command`] on the window with the
+/// given [`Id`], instead of the main one.
+///
+/// Any other actions of the [`Command`] are left untouched.
+pub fn target<Message>(id: Id, command: Command<Message>) -> Command<Message> {
+    Command::batch(command.actions().into_iter().map(|action| {
+        Command::single(match action {
+            command::Action::Window(action) => command::Action::Window(
+                window::Action::Target(id, Box::new(action)),
+            ),
+            action => action,
+        })
+    }))
+}
 
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
@@ -13,6 +43,215 @@ pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
 }
 
 /// Moves a window to the given logical coordinates.
+///
+/// The coordinates are those of the outer position of the window, including
+/// its decorations. It has no effect in platforms that do not let windows
+/// position themselves, like Wayland.
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Move { x, y }))
 }
+
+/// Moves the window to the center of its current monitor.
+///
+/// The whole monitor is used, since the area left by taskbars and docks is
+/// not known. Like [`move_to`], it has no effect in platforms that do not let
+/// windows position themselves.
+pub fn center<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Center))
+}
+
+/// Sets the minimum logical size of the window, or removes it if `None`.
+///
+/// A window smaller than the new minimum is resized to fit it.
+pub fn set_min_size<Message>(size: Option<Size<u32>>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMinSize(size)))
+}
+
+/// Sets the maximum logical size of the window, or removes it if `None`.
+///
+/// A window larger than the new maximum is resized to fit it.
+pub fn set_max_size<Message>(size: Option<Size<u32>>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMaxSize(size)))
+}
+
+/// Sets the logical increments the window is resized in, or removes them if
+/// `None`.
+///
+/// Sizes in between are rounded to the closest increment, like the cells of
+/// a terminal.
+pub fn set_resize_increments<Message>(
+    increments: Option<Size<u32>>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::SetResizeIncrements(increments),
+    ))
+}
+
+/// Sets the ratio between the width and the height the window keeps while
+/// resized, or removes it if `None`.
+///
+/// The window is not constrained while maximized or in fullscreen.
+pub fn set_aspect_ratio<Message>(ratio: Option<f32>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetAspectRatio(
+        ratio,
+    )))
+}
+
+/// Closes the window and exits the application.
+///
+/// When `exit_on_close_request` is disabled in the [`Settings`], close
+/// requests become [`window::Event::CloseRequested`] events instead, so the
+/// application can confirm them before closing.
+///
+/// [`Settings`]: crate::Settings
+pub fn close<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Close))
+}
+
+/// Starts moving the window with the mouse, until its button is released.
+///
+/// It must be performed in response to the press of a mouse button, while
+/// the button is still down, or platforms will ignore it. This lets custom
+/// title bars move windows without decorations.
+pub fn drag<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Drag))
+}
+
+/// Minimizes the window, or restores it if `minimized` is false.
+pub fn minimize<Message>(minimized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Minimize(
+        minimized,
+    )))
+}
+
+/// Maximizes the window, or restores it if `maximized` is false.
+///
+/// A [`window::Event::Maximized`] is produced once the window changes.
+pub fn maximize<Message>(maximized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Maximize(
+        maximized,
+    )))
+}
+
+/// Sets the title of the window.
+///
+/// The title stays until the one returned by [`Application::title`] changes.
+///
+/// [`Application::title`]: crate::Application::title
+pub fn set_title<Message>(title: impl Into<String>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetTitle(
+        title.into(),
+    )))
+}
+
+/// Sets the icon of the window.
+pub fn set_icon<Message>(icon: window::Icon) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetIcon(icon)))
+}
+
+/// Changes the [`Mode`] of the window.
+///
+/// The [`Mode`] stays until the one returned by [`Application::mode`]
+/// changes.
+///
+/// [`Application::mode`]: crate::Application::mode
+pub fn change_mode<Message>(mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ChangeMode(mode)))
+}
+
+/// Changes the [`Level`] of the window.
+///
+/// Levels that are not supported by the platform are ignored.
+pub fn change_level<Message>(level: Level) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ChangeLevel(level)))
+}
+
+/// Requests the attention of the user to the window, or cancels a previous
+/// request if `None`.
+///
+/// It has no effect if the window is already focused. A request can be
+/// cancelled once a [`window::Event::Focused`] is received.
+pub fn request_user_attention<Message>(
+    attention: Option<UserAttention>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::RequestUserAttention(attention),
+    ))
+}
+
+/// Changes how the cursor is grabbed by the window.
+///
+/// The cursor is released while the window is unfocused.
+pub fn change_cursor_grab<Message>(grab: CursorGrab) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ChangeCursorGrab(
+        grab, None,
+    )))
+}
+
+/// Changes how the cursor is grabbed by the window, producing a `Message`
+/// with the [`CursorGrab`] that is applied.
+///
+/// Platforms that do not support the requested [`CursorGrab`] fall back to
+/// the one they support, or to [`CursorGrab::None`] if they cannot grab the
+/// cursor at all.
+pub fn change_cursor_grab_with<Message>(
+    grab: CursorGrab,
+    f: impl FnOnce(CursorGrab) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ChangeCursorGrab(
+        grab,
+        Some(Box::new(f)),
+    )))
+}
+
+/// Shows or hides the cursor over the window.
+///
+/// Mouse events are still produced while the cursor is hidden, and the
+/// cursor is shown while the window is unfocused.
+pub fn set_cursor_visible<Message>(is_visible: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetCursorVisible(
+        is_visible,
+    )))
+}
+
+/// Fetches the current [`Mode`] of the window.
+pub fn fetch_mode<Message>(
+    f: impl FnOnce(Mode) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchMode(
+        Box::new(f),
+    )))
+}
+
+/// Fetches the current logical size of the window.
+pub fn fetch_size<Message>(
+    f: impl FnOnce(Size<u32>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchSize(
+        Box::new(f),
+    )))
+}
+
+/// Fetches the current logical position of the window.
+///
+/// The position is `None` in platforms that do not expose it, like Wayland.
+pub fn fetch_position<Message>(
+    f: impl FnOnce(Option<Point>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchPosition(
+        Box::new(f),
+    )))
+}
+
+/// Captures the next frame of the window, including the debug overlay, as
+/// a [`Screenshot`].
+///
+/// The frame is drawn again into an offscreen buffer when the renderer
+/// needs it, so the window may redraw once more than usual.
+pub fn screenshot<Message>(
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Screenshot(
+        Box::new(f),
+    )))
+}